@@ -4,11 +4,11 @@ use criterion::{criterion_group, criterion_main, Criterion};
 use feos::core::parameter::{IdentifierOption, Parameter, ParameterHetero};
 use feos::core::{PhaseEquilibrium, State, StateBuilder};
 use feos::dft::adsorption::{ExternalPotential, Pore1D, PoreSpecification};
-use feos::dft::{DFTSolver, Geometry};
+use feos::dft::{Axis, DFTSolver, Geometry};
 use feos::gc_pcsaft::{GcPcSaftFunctional, GcPcSaftFunctionalParameters};
 use feos::hard_sphere::{FMTFunctional, FMTVersion};
 use feos::pcsaft::{PcSaftFunctional, PcSaftParameters};
-use ndarray::arr1;
+use ndarray::{arr1, Array1};
 use quantity::{ANGSTROM, KELVIN, NAV};
 use std::sync::Arc;
 use typenum::P3;
@@ -130,5 +130,43 @@ fn gc_pcsaft(c: &mut Criterion) {
     });
 }
 
-criterion_group!(bench, fmt, pcsaft, gc_pcsaft);
+fn non_uniform(c: &mut Criterion) {
+    let mut group = c.benchmark_group("DFT_pore_non_uniform");
+    let parameters = PcSaftParameters::from_json(
+        vec!["butane"],
+        "../parameters/pcsaft/gross2001.json",
+        None,
+        IdentifierOption::Name,
+    )
+    .unwrap();
+    let func = Arc::new(PcSaftFunctional::new(Arc::new(parameters)));
+    let pore = Pore1D::new(
+        Geometry::Cartesian,
+        20.0 * ANGSTROM,
+        ExternalPotential::LJ93 {
+            sigma_ss: 3.0,
+            epsilon_k_ss: 100.0,
+            rho_s: 0.08,
+        },
+        None,
+        None,
+    );
+    let vle = PhaseEquilibrium::pure(&func, 300.0 * KELVIN, None, Default::default()).unwrap();
+    let bulk = vle.liquid();
+    group.bench_function("butane_liquid_uniform", |b| {
+        b.iter(|| pore.initialize(bulk, None, None).unwrap().solve(None))
+    });
+
+    // cells are refined quadratically towards the wall (smallest cell: 1.5e-4 Å)
+    let edges = Array1::linspace(0.0, 1.0, 257).mapv(|u: f64| 10.0 * (1.0 - (1.0 - u).powi(2)));
+    let axis =
+        Axis::new_cartesian_from_edges(&(edges * ANGSTROM), Some(2.0 * func.parameters.sigma[0]))
+            .unwrap();
+    let pore = pore.with_axis(axis).unwrap();
+    group.bench_function("butane_liquid_non_uniform", |b| {
+        b.iter(|| pore.initialize(bulk, None, None).unwrap().solve(None))
+    });
+}
+
+criterion_group!(bench, fmt, pcsaft, gc_pcsaft, non_uniform);
 criterion_main!(bench);
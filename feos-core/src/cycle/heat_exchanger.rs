@@ -0,0 +1,157 @@
+use super::process_state::Saturation;
+use super::{IsobaricSpecification, ProcessState};
+use crate::equation_of_state::{IdealGas, Residual};
+use crate::errors::{EosError, EosResult};
+use crate::{Contributions, ReferenceSystem};
+use ndarray::Array1;
+use quantity::{MolarEnergy, Temperature};
+
+/// Counterflow heat exchanger between two streams.
+///
+/// The temperature profiles of both streams are evaluated on isobars at
+/// equidistant values of the transferred heat. The bubble and dew points of
+/// both streams are added to the grid so that a pinch point at the onset of
+/// evaporation or condensation is resolved exactly.
+#[derive(Clone, Copy, Debug)]
+pub struct HeatExchanger {
+    /// Number of segments used to discretize the temperature profiles.
+    pub segments: usize,
+}
+
+impl Default for HeatExchanger {
+    fn default() -> Self {
+        Self { segments: 50 }
+    }
+}
+
+/// Temperature profiles and pinch point of a [HeatExchanger].
+///
+/// All arrays start at the hot inlet (cold outlet) and end at the hot
+/// outlet (cold inlet).
+#[derive(Clone, Debug)]
+pub struct PinchAnalysis {
+    /// Heat transferred per amount of substance of the hot stream.
+    pub heat: MolarEnergy<Array1<f64>>,
+    /// Temperature of the hot stream.
+    pub hot_temperature: Temperature<Array1<f64>>,
+    /// Temperature of the cold stream.
+    pub cold_temperature: Temperature<Array1<f64>>,
+    /// Ratio of the molar flow rates of the cold and the hot stream
+    /// from the energy balance.
+    pub flow_ratio: f64,
+    /// Index of the pinch point in the profiles.
+    pub pinch_index: usize,
+    /// Smallest temperature difference between the hot and the cold stream.
+    /// A negative value indicates that the heat transfer is infeasible.
+    pub minimum_temperature_difference: Temperature,
+}
+
+impl HeatExchanger {
+    pub fn new(segments: usize) -> Self {
+        Self { segments }
+    }
+
+    /// Calculate the temperature profiles of a counterflow heat exchanger
+    /// with given inlet and outlet states of both streams.
+    ///
+    /// The pressure of each stream is taken from the respective inlet and
+    /// the flow ratio of the streams follows from the energy balance.
+    pub fn counterflow<E1: Residual + IdealGas, E2: Residual + IdealGas>(
+        &self,
+        hot_inlet: &ProcessState<E1>,
+        hot_outlet: &ProcessState<E1>,
+        cold_inlet: &ProcessState<E2>,
+        cold_outlet: &ProcessState<E2>,
+    ) -> EosResult<PinchAnalysis> {
+        let hot_duty = (hot_inlet.molar_enthalpy() - hot_outlet.molar_enthalpy()).to_reduced();
+        let cold_duty = (cold_outlet.molar_enthalpy() - cold_inlet.molar_enthalpy()).to_reduced();
+        if hot_duty <= 0.0 || cold_duty <= 0.0 {
+            return Err(EosError::Error(String::from(
+                "The hot stream has to be cooled and the cold stream has to be heated.",
+            )));
+        }
+
+        let hot = Profile::new(hot_inlet, hot_outlet)?;
+        let cold = Profile::new(cold_outlet, cold_inlet)?;
+        let mut fractions: Vec<f64> = (0..=self.segments)
+            .map(|k| k as f64 / self.segments as f64)
+            .collect();
+        fractions.extend(hot.phase_boundaries());
+        fractions.extend(cold.phase_boundaries());
+        fractions.sort_by(|a, b| a.total_cmp(b));
+        fractions.dedup_by(|a, b| (*a - *b).abs() < 1e-12);
+
+        let mut hot_temperature = Vec::with_capacity(fractions.len());
+        let mut cold_temperature = Vec::with_capacity(fractions.len());
+        for &f in &fractions {
+            hot_temperature.push(hot.temperature(f)?);
+            cold_temperature.push(cold.temperature(f)?);
+        }
+        let (pinch_index, minimum_temperature_difference) = hot_temperature
+            .iter()
+            .zip(&cold_temperature)
+            .map(|(th, tc)| th - tc)
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .unwrap();
+
+        Ok(PinchAnalysis {
+            heat: MolarEnergy::from_reduced(Array1::from_vec(fractions) * hot_duty),
+            hot_temperature: Temperature::from_reduced(Array1::from_vec(hot_temperature)),
+            cold_temperature: Temperature::from_reduced(Array1::from_vec(cold_temperature)),
+            flow_ratio: hot_duty / cold_duty,
+            pinch_index,
+            minimum_temperature_difference: Temperature::from_reduced(
+                minimum_temperature_difference,
+            ),
+        })
+    }
+}
+
+/// Temperature as function of the enthalpy of a stream on an isobar
+/// between the states at both ends of the heat exchanger.
+struct Profile<'a, E> {
+    start: &'a ProcessState<E>,
+    h_start: f64,
+    h_end: f64,
+    saturation: Option<Saturation<E>>,
+}
+
+impl<'a, E: Residual + IdealGas> Profile<'a, E> {
+    fn new(start: &'a ProcessState<E>, end: &'a ProcessState<E>) -> EosResult<Self> {
+        Ok(Self {
+            start,
+            h_start: start.molar_enthalpy().to_reduced(),
+            h_end: end.molar_enthalpy().to_reduced(),
+            saturation: Saturation::new(start.eos(), start.pressure(), start.moles()),
+        })
+    }
+
+    /// Fractions of the heat duty at which the stream crosses the bubble
+    /// or the dew point.
+    fn phase_boundaries(&self) -> Vec<f64> {
+        let Some(saturation) = &self.saturation else {
+            return Vec::new();
+        };
+        let c = Contributions::Total;
+        let h_bubble = saturation.bubble.liquid().molar_enthalpy(c).to_reduced();
+        let h_dew = saturation.dew.vapor().molar_enthalpy(c).to_reduced();
+        [h_bubble, h_dew]
+            .into_iter()
+            .map(|h| (h - self.h_start) / (self.h_end - self.h_start))
+            .filter(|f| *f > 0.0 && *f < 1.0)
+            .collect()
+    }
+
+    fn temperature(&self, fraction: f64) -> EosResult<f64> {
+        let h = self.h_start + fraction * (self.h_end - self.h_start);
+        let state = ProcessState::new_isobaric_(
+            self.start.eos(),
+            self.start.pressure(),
+            IsobaricSpecification::Enthalpy(MolarEnergy::from_reduced(h)),
+            self.start.moles(),
+            self.saturation.as_ref(),
+        )?;
+        Ok(state.temperature().to_reduced())
+    }
+}
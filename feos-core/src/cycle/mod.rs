@@ -0,0 +1,451 @@
+//! Unit operations and thermodynamic cycles.
+//!
+//! Streams are represented by [ProcessState]s that can be single-phase
+//! or vapor-liquid equilibria. For mixtures, the bubble and dew points at
+//! the respective pressure are used to identify the phase of a stream, so
+//! that working fluids with a temperature glide are handled consistently.
+//!
+//! Unit operations ([Compressor], [Turbine], [Pump], [Valve] and
+//! [HeatTransfer]) calculate the outlet stream from the inlet stream and
+//! can be combined to a [Cycle], which evaluates the energy balance and
+//! the thermal efficiency or the coefficient of performance. Temperature
+//! profiles and pinch points of heat exchangers are calculated with
+//! [HeatExchanger].
+//!
+//! # Example: heat pump with propane
+//! ```
+//! # use feos_core::cubic::{PengRobinson, PengRobinsonParameters, PengRobinsonRecord};
+//! # use feos_core::cycle::*;
+//! # use feos_core::parameter::{Identifier, Parameter, PureRecord};
+//! # use feos_core::{Components, Contributions, EosResult, EquationOfState, IdealGas, PhaseEquilibrium, SolverOptions};
+//! # use ndarray::{arr1, Array1};
+//! # use num_dual::DualNum;
+//! # use quantity::{KELVIN, MOL};
+//! # use std::sync::Arc;
+//! # struct Joback;
+//! # impl Components for Joback {
+//! #     fn components(&self) -> usize { 1 }
+//! #     fn subset(&self, _: &[usize]) -> Self { Self }
+//! # }
+//! # impl IdealGas for Joback {
+//! #     fn ln_lambda3<D: DualNum<f64> + Copy>(&self, t: D) -> Array1<D> { arr1(&[-t.ln() * 4.0]) }
+//! #     fn ideal_gas_model(&self) -> String { "Joback".into() }
+//! # }
+//! # fn main() -> EosResult<()> {
+//! # let record = PureRecord::new(Identifier::default(), 44.0962, PengRobinsonRecord::new(369.96, 4250000.0, 0.153));
+//! # let residual = Arc::new(PengRobinson::new(Arc::new(PengRobinsonParameters::new_pure(record)?)));
+//! let eos = Arc::new(EquationOfState::new(Arc::new(Joback), residual));
+//! let moles = arr1(&[1.0]) * MOL;
+//! let options = SolverOptions::default();
+//! let p_low = PhaseEquilibrium::pure(&eos, 270.0 * KELVIN, None, options)?.liquid().pressure(Contributions::Total);
+//! let p_high = PhaseEquilibrium::pure(&eos, 320.0 * KELVIN, None, options)?.liquid().pressure(Contributions::Total);
+//!
+//! let superheating = IsobaricSpecification::Superheating(5.0 * KELVIN);
+//! let evaporator_outlet = ProcessState::new_isobaric(&eos, p_low, superheating, &moles)?;
+//! let cycle = Cycle::new(
+//!     evaporator_outlet,
+//!     &[
+//!         Compressor::new(p_high, 0.75).into(),
+//!         HeatTransfer::new(IsobaricSpecification::Subcooling(2.0 * KELVIN)).into(),
+//!         Valve::new(p_low).into(),
+//!         HeatTransfer::new(superheating).into(),
+//!     ],
+//! )?;
+//! assert!(cycle.cop_heating() > 1.0 && cycle.cop_heating() < 320.0 / 50.0);
+//! # Ok(())
+//! # }
+//! ```
+use crate::equation_of_state::{IdealGas, Residual};
+use crate::errors::{EosError, EosResult};
+use crate::ReferenceSystem;
+use quantity::MolarEnergy;
+
+mod heat_exchanger;
+mod process_state;
+mod unit_operations;
+pub use heat_exchanger::{HeatExchanger, PinchAnalysis};
+pub use process_state::{IsobaricSpecification, ProcessState};
+pub use unit_operations::{Compressor, HeatTransfer, Pump, Turbine, UnitOperation, Valve};
+
+/// Relative tolerance for the outlet of the last unit operation to
+/// coincide with the initial state of a cycle.
+const TOL_CLOSURE: f64 = 1e-6;
+
+/// A unit operation together with its inlet and outlet streams.
+#[derive(Clone, Debug)]
+pub struct CycleStep<E> {
+    pub operation: UnitOperation,
+    pub inlet: ProcessState<E>,
+    pub outlet: ProcessState<E>,
+}
+
+impl<E: Residual + IdealGas> CycleStep<E> {
+    /// Work supplied to the working fluid per amount of substance.
+    pub fn work(&self) -> MolarEnergy {
+        if self.operation.is_work() {
+            self.outlet.molar_enthalpy() - self.inlet.molar_enthalpy()
+        } else {
+            MolarEnergy::from_reduced(0.0)
+        }
+    }
+
+    /// Heat supplied to the working fluid per amount of substance.
+    pub fn heat(&self) -> MolarEnergy {
+        if self.operation.is_heat() {
+            self.outlet.molar_enthalpy() - self.inlet.molar_enthalpy()
+        } else {
+            MolarEnergy::from_reduced(0.0)
+        }
+    }
+}
+
+/// A closed thermodynamic cycle of unit operations, e.g., an
+/// organic Rankine cycle or a vapor compression heat pump.
+///
+/// All energies refer to the amount of substance of the working fluid.
+#[derive(Clone, Debug)]
+pub struct Cycle<E> {
+    pub steps: Vec<CycleStep<E>>,
+}
+
+impl<E: Residual + IdealGas> Cycle<E> {
+    /// Calculate the cycle by applying the unit operations successively,
+    /// starting from `initial_state`.
+    ///
+    /// Returns an error if the outlet of the last unit operation does
+    /// not coincide with the initial state.
+    pub fn new(initial_state: ProcessState<E>, operations: &[UnitOperation]) -> EosResult<Self> {
+        let mut steps: Vec<CycleStep<E>> = Vec::with_capacity(operations.len());
+        for &operation in operations {
+            let inlet = steps
+                .last()
+                .map_or_else(|| initial_state.clone(), |s| s.outlet.clone());
+            let outlet = operation.outlet(&inlet)?;
+            steps.push(CycleStep {
+                operation,
+                inlet,
+                outlet,
+            });
+        }
+
+        let last = &steps
+            .last()
+            .ok_or_else(|| EosError::Error(String::from("A cycle needs unit operations.")))?
+            .outlet;
+        let dp = (last.pressure() / initial_state.pressure()).into_value() - 1.0;
+        let dt = (last.temperature() / initial_state.temperature()).into_value() - 1.0;
+        let dh = (last.molar_enthalpy() - initial_state.molar_enthalpy()).to_reduced()
+            / steps
+                .iter()
+                .map(|s| s.work().to_reduced().abs() + s.heat().to_reduced().abs())
+                .sum::<f64>();
+        if dp.abs() > TOL_CLOSURE || dt.abs() > TOL_CLOSURE || dh.abs() > TOL_CLOSURE {
+            return Err(EosError::Error(format!(
+                "The cycle is not closed: the last outlet is at {:.5} and {:.5} but the initial state at {:.5} and {:.5}.",
+                last.temperature(),
+                last.pressure(),
+                initial_state.temperature(),
+                initial_state.pressure()
+            )));
+        }
+        Ok(Self { steps })
+    }
+
+    /// Net work supplied to the working fluid (negative for power cycles).
+    pub fn net_work(&self) -> MolarEnergy {
+        self.steps
+            .iter()
+            .fold(MolarEnergy::from_reduced(0.0), |acc, s| acc + s.work())
+    }
+
+    /// Heat supplied to the working fluid, e.g., in an evaporator.
+    pub fn heat_input(&self) -> MolarEnergy {
+        self.steps
+            .iter()
+            .map(|s| s.heat())
+            .filter(|q| q.to_reduced() > 0.0)
+            .fold(MolarEnergy::from_reduced(0.0), |acc, q| acc + q)
+    }
+
+    /// Heat rejected by the working fluid, e.g., in a condenser.
+    pub fn heat_rejection(&self) -> MolarEnergy {
+        self.steps
+            .iter()
+            .map(|s| s.heat())
+            .filter(|q| q.to_reduced() < 0.0)
+            .fold(MolarEnergy::from_reduced(0.0), |acc, q| acc - q)
+    }
+
+    /// Thermal efficiency of a power cycle: $\eta=\frac{-w}{q_\mathrm{in}}$
+    pub fn thermal_efficiency(&self) -> f64 {
+        (-self.net_work() / self.heat_input()).into_value()
+    }
+
+    /// Coefficient of performance of a heat pump: $\mathrm{COP}_\mathrm{h}=\frac{q_\mathrm{out}}{w}$
+    pub fn cop_heating(&self) -> f64 {
+        (self.heat_rejection() / self.net_work()).into_value()
+    }
+
+    /// Coefficient of performance of a refrigeration cycle: $\mathrm{COP}_\mathrm{c}=\frac{q_\mathrm{in}}{w}$
+    pub fn cop_cooling(&self) -> f64 {
+        (self.heat_input() / self.net_work()).into_value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cubic::{PengRobinson, PengRobinsonParameters, PengRobinsonRecord};
+    use crate::parameter::{Identifier, Parameter, PureRecord};
+    use crate::{Components, Contributions, EquationOfState, PhaseEquilibrium, SolverOptions};
+    use approx::assert_relative_eq;
+    use ndarray::{arr1, Array1};
+    use num_dual::DualNum;
+    use quantity::{Pressure, Temperature, BAR, KELVIN, MOL};
+    use std::sync::Arc;
+
+    struct ConstantHeatCapacity(Array1<f64>);
+
+    impl Components for ConstantHeatCapacity {
+        fn components(&self) -> usize {
+            self.0.len()
+        }
+
+        fn subset(&self, component_list: &[usize]) -> Self {
+            Self(component_list.iter().map(|&i| self.0[i]).collect())
+        }
+    }
+
+    impl IdealGas for ConstantHeatCapacity {
+        fn ln_lambda3<D: DualNum<f64> + Copy>(&self, temperature: D) -> Array1<D> {
+            self.0.mapv(|c| -temperature.ln() * c)
+        }
+
+        fn ideal_gas_model(&self) -> String {
+            "constant heat capacity".into()
+        }
+    }
+
+    type Eos = EquationOfState<ConstantHeatCapacity, PengRobinson>;
+
+    fn propane() -> PureRecord<PengRobinsonRecord> {
+        PureRecord::new(
+            Identifier::default(),
+            44.0962,
+            PengRobinsonRecord::new(369.96, 4250000.0, 0.153),
+        )
+    }
+
+    fn butane() -> PureRecord<PengRobinsonRecord> {
+        PureRecord::new(
+            Identifier::default(),
+            58.123,
+            PengRobinsonRecord::new(425.2, 3800000.0, 0.199),
+        )
+    }
+
+    fn pure(record: PureRecord<PengRobinsonRecord>) -> Arc<Eos> {
+        let parameters = PengRobinsonParameters::new_pure(record).unwrap();
+        Arc::new(EquationOfState::new(
+            Arc::new(ConstantHeatCapacity(arr1(&[4.0]))),
+            Arc::new(PengRobinson::new(Arc::new(parameters))),
+        ))
+    }
+
+    fn mixture() -> Arc<Eos> {
+        let parameters =
+            PengRobinsonParameters::new_binary(vec![propane(), butane()], None).unwrap();
+        Arc::new(EquationOfState::new(
+            Arc::new(ConstantHeatCapacity(arr1(&[4.0, 5.0]))),
+            Arc::new(PengRobinson::new(Arc::new(parameters))),
+        ))
+    }
+
+    fn vapor_pressure(eos: &Arc<Eos>, temperature: Temperature) -> Pressure {
+        PhaseEquilibrium::pure(eos, temperature, None, SolverOptions::default())
+            .unwrap()
+            .liquid()
+            .pressure(Contributions::Total)
+    }
+
+    fn heat_pump(eos: &Arc<Eos>, p_low: Pressure, p_high: Pressure) -> EosResult<Cycle<Eos>> {
+        let moles = arr1(&vec![1.0; eos.components()]) * MOL;
+        let superheating = IsobaricSpecification::Superheating(5.0 * KELVIN);
+        let initial_state = ProcessState::new_isobaric(eos, p_low, superheating, &moles)?;
+        Cycle::new(
+            initial_state,
+            &[
+                Compressor::new(p_high, 0.75).into(),
+                HeatTransfer::new(IsobaricSpecification::Subcooling(2.0 * KELVIN)).into(),
+                Valve::new(p_low).into(),
+                HeatTransfer::new(superheating).into(),
+            ],
+        )
+    }
+
+    #[test]
+    fn heat_pump_pure() -> EosResult<()> {
+        let eos = pure(propane());
+        let (t_low, t_high) = (270.0 * KELVIN, 320.0 * KELVIN);
+        let p_low = vapor_pressure(&eos, t_low);
+        let p_high = vapor_pressure(&eos, t_high);
+        let cycle = heat_pump(&eos, p_low, p_high)?;
+
+        // energy balance of the cycle
+        assert_relative_eq!(
+            cycle.cop_heating(),
+            cycle.cop_cooling() + 1.0,
+            epsilon = 1e-8
+        );
+        let carnot = (t_high / (t_high - t_low)).into_value();
+        assert!(cycle.cop_heating() > 1.0 && cycle.cop_heating() < carnot);
+
+        // isentropic compression: entropy increases, pressure is reached
+        let compressor = &cycle.steps[0];
+        assert_relative_eq!(compressor.outlet.pressure(), p_high, max_relative = 1e-8);
+        assert!(compressor.outlet.molar_entropy() > compressor.inlet.molar_entropy());
+
+        // the valve outlet is two-phase at the evaporation temperature
+        let valve = &cycle.steps[2];
+        let q = valve.outlet.vapor_fraction().unwrap();
+        assert!(q > 0.0 && q < 1.0);
+        assert_relative_eq!(valve.outlet.temperature(), t_low, max_relative = 1e-8);
+        assert_relative_eq!(
+            valve.outlet.molar_enthalpy(),
+            valve.inlet.molar_enthalpy(),
+            max_relative = 1e-8
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn organic_rankine_cycle() -> EosResult<()> {
+        let eos = pure(butane());
+        let (t_low, t_high) = (300.0 * KELVIN, 380.0 * KELVIN);
+        let p_low = vapor_pressure(&eos, t_low);
+        let p_high = vapor_pressure(&eos, t_high);
+        let moles = arr1(&[1.0]) * MOL;
+        let saturated_liquid = IsobaricSpecification::VaporFraction(0.0);
+        let initial_state = ProcessState::new_isobaric(&eos, p_low, saturated_liquid, &moles)?;
+        let cycle = Cycle::new(
+            initial_state,
+            &[
+                Pump::new(p_high, 0.7).into(),
+                HeatTransfer::new(IsobaricSpecification::Superheating(10.0 * KELVIN)).into(),
+                Turbine::new(p_low, 0.8).into(),
+                HeatTransfer::new(saturated_liquid).into(),
+            ],
+        )?;
+        assert_relative_eq!(
+            cycle.heat_input() - cycle.heat_rejection(),
+            -cycle.net_work(),
+            max_relative = 1e-8
+        );
+        let carnot = 1.0 - (t_low / (t_high + 10.0 * KELVIN)).into_value();
+        let efficiency = cycle.thermal_efficiency();
+        assert!(efficiency > 0.0 && efficiency < carnot);
+
+        // the pump cannot be used for two-phase streams
+        let two_phase = ProcessState::new_isobaric(
+            &eos,
+            p_low,
+            IsobaricSpecification::VaporFraction(0.5),
+            &moles,
+        )?;
+        assert!(Pump::new(p_high, 0.7).outlet(&two_phase).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn heat_pump_mixture() -> EosResult<()> {
+        let eos = mixture();
+        let cycle = heat_pump(&eos, 2.0 * BAR, 10.0 * BAR)?;
+        // the energy balance is only satisfied within the closure tolerance
+        assert_relative_eq!(
+            cycle.cop_heating(),
+            cycle.cop_cooling() + 1.0,
+            max_relative = TOL_CLOSURE
+        );
+
+        // temperature glide in the evaporator
+        let valve_outlet = &cycle.steps[2].outlet;
+        let evaporator_outlet = &cycle.steps[3].outlet;
+        let moles = valve_outlet.moles();
+        let dew = ProcessState::new_isobaric(
+            &eos,
+            2.0 * BAR,
+            IsobaricSpecification::VaporFraction(1.0),
+            moles,
+        )?;
+        assert!(dew.temperature() - valve_outlet.temperature() > 5.0 * KELVIN);
+        assert_relative_eq!(
+            evaporator_outlet.temperature(),
+            dew.temperature() + 5.0 * KELVIN,
+            max_relative = 1e-8
+        );
+
+        // the two-phase state after the valve satisfies the energy balance
+        // within the accuracy of the tp-flash
+        let q = valve_outlet.vapor_fraction().unwrap();
+        assert!(q > 0.0 && q < 1.0);
+        assert_relative_eq!(
+            valve_outlet.molar_enthalpy(),
+            cycle.steps[2].inlet.molar_enthalpy(),
+            max_relative = 1e-6
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn pinch_point() -> EosResult<()> {
+        let hot_eos = pure(propane());
+        let cold_eos = pure(butane());
+        let moles = arr1(&[1.0]) * MOL;
+        let t_sat = 320.0 * KELVIN;
+        let p_hot = vapor_pressure(&hot_eos, t_sat);
+        let hot_inlet = ProcessState::new_tp(&hot_eos, 335.0 * KELVIN, p_hot, &moles)?;
+        let hot_outlet = ProcessState::new_tp(&hot_eos, 318.0 * KELVIN, p_hot, &moles)?;
+        let cold_inlet = ProcessState::new_tp(&cold_eos, 295.0 * KELVIN, 10.0 * BAR, &moles)?;
+        let cold_outlet = ProcessState::new_tp(&cold_eos, 315.0 * KELVIN, 10.0 * BAR, &moles)?;
+        let pinch = HeatExchanger::default().counterflow(
+            &hot_inlet,
+            &hot_outlet,
+            &cold_inlet,
+            &cold_outlet,
+        )?;
+
+        // the pinch point is located at the dew point of the hot stream
+        let k = pinch.pinch_index;
+        assert_relative_eq!(pinch.hot_temperature.get(k), t_sat, max_relative = 1e-8);
+        let dt = pinch.minimum_temperature_difference;
+        assert!(dt > 0.0 * KELVIN && dt < 10.0 * KELVIN);
+        let n = pinch.heat.len();
+        assert_relative_eq!(
+            pinch.hot_temperature.get(0),
+            335.0 * KELVIN,
+            max_relative = 1e-8
+        );
+        assert_relative_eq!(
+            pinch.cold_temperature.get(0),
+            315.0 * KELVIN,
+            max_relative = 1e-8
+        );
+        assert_relative_eq!(
+            pinch.cold_temperature.get(n - 1),
+            295.0 * KELVIN,
+            max_relative = 1e-8
+        );
+
+        // energy balance
+        let hot_duty = hot_inlet.molar_enthalpy() - hot_outlet.molar_enthalpy();
+        let cold_duty = cold_outlet.molar_enthalpy() - cold_inlet.molar_enthalpy();
+        assert_relative_eq!(pinch.heat.get(n - 1), hot_duty, max_relative = 1e-12);
+        assert_relative_eq!(pinch.flow_ratio * cold_duty, hot_duty, max_relative = 1e-12);
+
+        // swapping the streams is not a valid heat exchanger
+        assert!(HeatExchanger::default()
+            .counterflow(&cold_inlet, &cold_outlet, &hot_inlet, &hot_outlet)
+            .is_err());
+        Ok(())
+    }
+}
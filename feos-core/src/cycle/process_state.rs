@@ -0,0 +1,510 @@
+use crate::equation_of_state::{IdealGas, Residual};
+use crate::errors::{EosError, EosResult};
+use crate::phase_equilibria::PhaseEquilibrium;
+use crate::state::{Contributions, DensityInitialization, State};
+use crate::{ReferenceSystem, SolverOptions};
+use ndarray::Array1;
+use quantity::{MolarEnergy, MolarEntropy, Moles, Pressure, Temperature};
+use std::sync::Arc;
+
+const MAX_ITER_TWO_PHASE: usize = 100;
+const TOL_TWO_PHASE: f64 = 1e-10;
+
+/// Specification of a state on an isobar.
+#[derive(Clone, Copy, Debug)]
+pub enum IsobaricSpecification {
+    /// Temperature of the stream.
+    Temperature(Temperature),
+    /// Molar enthalpy of the stream.
+    Enthalpy(MolarEnergy),
+    /// Molar entropy of the stream.
+    Entropy(MolarEntropy),
+    /// Molar vapor fraction in the two-phase region
+    /// (0 at the bubble point, 1 at the dew point).
+    VaporFraction(f64),
+    /// Temperature difference above the dew point.
+    Superheating(Temperature),
+    /// Temperature difference below the bubble point.
+    Subcooling(Temperature),
+}
+
+/// Thermodynamic state of a stream in a process.
+///
+/// In contrast to a [State], a process state can also be a vapor-liquid
+/// equilibrium, e.g., in an evaporator or after a throttle valve. The
+/// molar properties of two-phase states are averages of the coexisting
+/// phases weighted with the molar vapor fraction.
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum ProcessState<E> {
+    /// Liquid, vapor or supercritical state.
+    SinglePhase(State<E>),
+    /// Vapor-liquid equilibrium.
+    TwoPhase {
+        vle: PhaseEquilibrium<E, 2>,
+        /// Molar vapor fraction.
+        vapor_fraction: f64,
+        /// Overall amount of substance of each component.
+        moles: Moles<Array1<f64>>,
+    },
+}
+
+impl<E> Clone for ProcessState<E> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::SinglePhase(s) => Self::SinglePhase(s.clone()),
+            Self::TwoPhase {
+                vle,
+                vapor_fraction,
+                moles,
+            } => Self::TwoPhase {
+                vle: vle.clone(),
+                vapor_fraction: *vapor_fraction,
+                moles: moles.clone(),
+            },
+        }
+    }
+}
+
+/// The bubble and dew points of a stream at given pressure.
+///
+/// For pure components, both are the same phase equilibrium.
+pub(super) struct Saturation<E> {
+    pub bubble: PhaseEquilibrium<E, 2>,
+    pub dew: PhaseEquilibrium<E, 2>,
+}
+
+impl<E: Residual> Saturation<E> {
+    /// Returns `None` if no phase equilibrium exists at the given pressure.
+    pub fn new(eos: &Arc<E>, pressure: Pressure, moles: &Moles<Array1<f64>>) -> Option<Self> {
+        if eos.components() == 1 {
+            let vle = PhaseEquilibrium::pure(eos, pressure, None, SolverOptions::default()).ok()?;
+            Some(Self {
+                bubble: vle.clone(),
+                dew: vle,
+            })
+        } else {
+            let x = (moles / moles.sum()).into_value();
+            // initial temperature from the boiling temperatures of the pure components
+            let t_init = PhaseEquilibrium::boiling_temperature(eos, pressure)
+                .into_iter()
+                .zip(&x)
+                .map(|(t, &x)| t.map(|t| t * x))
+                .reduce(|a, b| Some(a? + b?))??;
+            let options = (SolverOptions::default(), SolverOptions::default());
+            let bubble =
+                PhaseEquilibrium::bubble_point(eos, pressure, &x, Some(t_init), None, options)
+                    .ok()?;
+            let dew =
+                PhaseEquilibrium::dew_point(eos, pressure, &x, Some(t_init), None, options).ok()?;
+            Some(Self { bubble, dew })
+        }
+    }
+
+    pub fn bubble_temperature(&self) -> Temperature {
+        self.bubble.liquid().temperature
+    }
+
+    pub fn dew_temperature(&self) -> Temperature {
+        self.dew.vapor().temperature
+    }
+}
+
+/// Intensive properties that are used to specify states on an isobar.
+#[derive(Clone, Copy)]
+enum Property {
+    Enthalpy,
+    Entropy,
+    VaporFraction,
+}
+
+impl Property {
+    /// The values of the property in the liquid and the vapor phase (in reduced units).
+    fn phase_values<E: Residual + IdealGas>(&self, vle: &PhaseEquilibrium<E, 2>) -> (f64, f64) {
+        let c = Contributions::Total;
+        let (l, v) = (vle.liquid(), vle.vapor());
+        match self {
+            Self::Enthalpy => (
+                l.molar_enthalpy(c).to_reduced(),
+                v.molar_enthalpy(c).to_reduced(),
+            ),
+            Self::Entropy => (
+                l.molar_entropy(c).to_reduced(),
+                v.molar_entropy(c).to_reduced(),
+            ),
+            Self::VaporFraction => (0.0, 1.0),
+        }
+    }
+}
+
+impl<E: Residual + IdealGas> ProcessState<E> {
+    /// Calculate the stream for given temperature and pressure.
+    ///
+    /// For mixtures within the two-phase region, a Tp-flash is performed.
+    pub fn new_tp(
+        eos: &Arc<E>,
+        temperature: Temperature,
+        pressure: Pressure,
+        moles: &Moles<Array1<f64>>,
+    ) -> EosResult<Self> {
+        let saturation = Saturation::new(eos, pressure, moles);
+        Self::new_tp_(eos, temperature, pressure, moles, saturation.as_ref())
+    }
+
+    fn new_tp_(
+        eos: &Arc<E>,
+        temperature: Temperature,
+        pressure: Pressure,
+        moles: &Moles<Array1<f64>>,
+        saturation: Option<&Saturation<E>>,
+    ) -> EosResult<Self> {
+        let Some(saturation) = saturation else {
+            let state = State::new_npt(
+                eos,
+                temperature,
+                pressure,
+                moles,
+                DensityInitialization::None,
+            )?;
+            return Ok(Self::SinglePhase(state));
+        };
+        let density_initialization = if temperature < saturation.bubble_temperature() {
+            DensityInitialization::Liquid
+        } else if temperature > saturation.dew_temperature() {
+            DensityInitialization::Vapor
+        } else if eos.components() == 1 {
+            return Err(EosError::UndeterminedState(String::from(
+                "temperature equals the saturation temperature of a pure component",
+            )));
+        } else {
+            let vle = PhaseEquilibrium::tp_flash(
+                eos,
+                temperature,
+                pressure,
+                moles,
+                Some(&saturation.bubble),
+                SolverOptions::default(),
+                None,
+            )?;
+            return Ok(Self::from_flash(vle, moles));
+        };
+        let state = State::new_npt(eos, temperature, pressure, moles, density_initialization)?;
+        Ok(Self::SinglePhase(state))
+    }
+
+    /// Calculate the stream for given pressure and molar enthalpy.
+    pub fn new_ph(
+        eos: &Arc<E>,
+        pressure: Pressure,
+        molar_enthalpy: MolarEnergy,
+        moles: &Moles<Array1<f64>>,
+    ) -> EosResult<Self> {
+        Self::new_isobaric(
+            eos,
+            pressure,
+            IsobaricSpecification::Enthalpy(molar_enthalpy),
+            moles,
+        )
+    }
+
+    /// Calculate the stream for given pressure and molar entropy.
+    pub fn new_ps(
+        eos: &Arc<E>,
+        pressure: Pressure,
+        molar_entropy: MolarEntropy,
+        moles: &Moles<Array1<f64>>,
+    ) -> EosResult<Self> {
+        Self::new_isobaric(
+            eos,
+            pressure,
+            IsobaricSpecification::Entropy(molar_entropy),
+            moles,
+        )
+    }
+
+    /// Calculate the stream for given pressure and an additional specification.
+    ///
+    /// The bubble and dew points at the given pressure are used to determine
+    /// whether the stream is liquid, vapor or a vapor-liquid equilibrium.
+    /// Above the critical pressure (of a pure component) or the cricondenbar
+    /// (of a mixture), the stream is always single-phase.
+    pub fn new_isobaric(
+        eos: &Arc<E>,
+        pressure: Pressure,
+        specification: IsobaricSpecification,
+        moles: &Moles<Array1<f64>>,
+    ) -> EosResult<Self> {
+        let saturation = Saturation::new(eos, pressure, moles);
+        Self::new_isobaric_(eos, pressure, specification, moles, saturation.as_ref())
+    }
+
+    pub(super) fn new_isobaric_(
+        eos: &Arc<E>,
+        pressure: Pressure,
+        specification: IsobaricSpecification,
+        moles: &Moles<Array1<f64>>,
+        saturation: Option<&Saturation<E>>,
+    ) -> EosResult<Self> {
+        use IsobaricSpecification::*;
+        match specification {
+            Temperature(t) => Self::new_tp_(eos, t, pressure, moles, saturation),
+            Enthalpy(h) => Self::new_p_property(
+                eos,
+                pressure,
+                Property::Enthalpy,
+                h.to_reduced(),
+                moles,
+                saturation,
+            ),
+            Entropy(s) => Self::new_p_property(
+                eos,
+                pressure,
+                Property::Entropy,
+                s.to_reduced(),
+                moles,
+                saturation,
+            ),
+            VaporFraction(q) => {
+                if !(0.0..=1.0).contains(&q) {
+                    return Err(EosError::InvalidState(
+                        String::from("ProcessState::new_isobaric"),
+                        String::from("vapor fraction"),
+                        q,
+                    ));
+                }
+                let saturation = saturation.ok_or(EosError::SuperCritical)?;
+                Self::two_phase(eos, pressure, Property::VaporFraction, q, moles, saturation)
+            }
+            Superheating(dt) => {
+                let saturation = saturation.ok_or(EosError::SuperCritical)?;
+                let state = State::new_npt(
+                    eos,
+                    saturation.dew_temperature() + dt,
+                    pressure,
+                    moles,
+                    DensityInitialization::Vapor,
+                )?;
+                Ok(Self::SinglePhase(state))
+            }
+            Subcooling(dt) => {
+                let saturation = saturation.ok_or(EosError::SuperCritical)?;
+                let state = State::new_npt(
+                    eos,
+                    saturation.bubble_temperature() - dt,
+                    pressure,
+                    moles,
+                    DensityInitialization::Liquid,
+                )?;
+                Ok(Self::SinglePhase(state))
+            }
+        }
+    }
+
+    fn new_p_property(
+        eos: &Arc<E>,
+        pressure: Pressure,
+        property: Property,
+        value: f64,
+        moles: &Moles<Array1<f64>>,
+        saturation: Option<&Saturation<E>>,
+    ) -> EosResult<Self> {
+        let single_phase = |density_initialization, initial_temperature| {
+            let state = match property {
+                Property::Enthalpy => State::new_nph(
+                    eos,
+                    pressure,
+                    MolarEnergy::from_reduced(value),
+                    moles,
+                    density_initialization,
+                    initial_temperature,
+                ),
+                Property::Entropy => State::new_nps(
+                    eos,
+                    pressure,
+                    MolarEntropy::from_reduced(value),
+                    moles,
+                    density_initialization,
+                    initial_temperature,
+                ),
+                Property::VaporFraction => unreachable!(),
+            }?;
+            Ok(Self::SinglePhase(state))
+        };
+
+        let Some(saturation) = saturation else {
+            return single_phase(DensityInitialization::None, None);
+        };
+        let bubble = property.phase_values(&saturation.bubble).0;
+        let dew = property.phase_values(&saturation.dew).1;
+        if value <= bubble {
+            single_phase(
+                DensityInitialization::Liquid,
+                Some(saturation.bubble_temperature()),
+            )
+        } else if value >= dew {
+            single_phase(
+                DensityInitialization::Vapor,
+                Some(saturation.dew_temperature()),
+            )
+        } else {
+            Self::two_phase(eos, pressure, property, value, moles, saturation)
+        }
+    }
+
+    /// Vapor-liquid equilibrium with the given value of the property.
+    ///
+    /// For mixtures, the temperature between the bubble and the dew point
+    /// is determined using the Illinois method with a Tp-flash at every
+    /// iteration.
+    fn two_phase(
+        eos: &Arc<E>,
+        pressure: Pressure,
+        property: Property,
+        value: f64,
+        moles: &Moles<Array1<f64>>,
+        saturation: &Saturation<E>,
+    ) -> EosResult<Self> {
+        if eos.components() == 1 {
+            let (l, v) = property.phase_values(&saturation.bubble);
+            return Ok(Self::TwoPhase {
+                vle: saturation.bubble.clone(),
+                vapor_fraction: (value - l) / (v - l),
+                moles: moles.clone(),
+            });
+        }
+
+        let (l, _) = property.phase_values(&saturation.bubble);
+        let (_, v) = property.phase_values(&saturation.dew);
+        if value == l {
+            return Ok(Self::TwoPhase {
+                vle: saturation.bubble.clone(),
+                vapor_fraction: 0.0,
+                moles: moles.clone(),
+            });
+        }
+        if value == v {
+            return Ok(Self::TwoPhase {
+                vle: saturation.dew.clone(),
+                vapor_fraction: 1.0,
+                moles: moles.clone(),
+            });
+        }
+
+        let (mut a, mut fa) = (saturation.bubble_temperature().to_reduced(), l - value);
+        let (mut b, mut fb) = (saturation.dew_temperature().to_reduced(), v - value);
+        let tol = TOL_TWO_PHASE * (value.abs() + (v - l).abs());
+        let mut vle = saturation.bubble.clone();
+        for _ in 0..MAX_ITER_TWO_PHASE {
+            let t = (a * fb - b * fa) / (fb - fa);
+            vle = PhaseEquilibrium::tp_flash(
+                eos,
+                Temperature::from_reduced(t),
+                pressure,
+                moles,
+                Some(&vle),
+                SolverOptions::default(),
+                None,
+            )?;
+            let beta = vapor_fraction(&vle);
+            let (pl, pv) = property.phase_values(&vle);
+            let ft = beta * pv + (1.0 - beta) * pl - value;
+            if ft.abs() < tol || (b - a).abs() < TOL_TWO_PHASE * t {
+                return Ok(Self::from_flash(vle, moles));
+            }
+            if ft * fb < 0.0 {
+                (a, fa) = (b, fb);
+            } else {
+                fa *= 0.5;
+            }
+            (b, fb) = (t, ft);
+        }
+        Err(EosError::NotConverged(String::from(
+            "ProcessState::two_phase",
+        )))
+    }
+
+    fn from_flash(vle: PhaseEquilibrium<E, 2>, moles: &Moles<Array1<f64>>) -> Self {
+        Self::TwoPhase {
+            vapor_fraction: vapor_fraction(&vle),
+            vle,
+            moles: moles.clone(),
+        }
+    }
+
+    /// Temperature $T$
+    pub fn temperature(&self) -> Temperature {
+        match self {
+            Self::SinglePhase(s) => s.temperature,
+            Self::TwoPhase { vle, .. } => vle.vapor().temperature,
+        }
+    }
+
+    /// Pressure $p$
+    pub fn pressure(&self) -> Pressure {
+        match self {
+            Self::SinglePhase(s) => s.pressure(Contributions::Total),
+            Self::TwoPhase { vle, .. } => vle.liquid().pressure(Contributions::Total),
+        }
+    }
+
+    /// Overall amount of substance of each component.
+    pub fn moles(&self) -> &Moles<Array1<f64>> {
+        match self {
+            Self::SinglePhase(s) => &s.moles,
+            Self::TwoPhase { moles, .. } => moles,
+        }
+    }
+
+    /// Overall mole fractions.
+    pub fn molefracs(&self) -> Array1<f64> {
+        let moles = self.moles();
+        (moles / moles.sum()).into_value()
+    }
+
+    /// Molar vapor fraction, if the stream is a vapor-liquid equilibrium.
+    pub fn vapor_fraction(&self) -> Option<f64> {
+        match self {
+            Self::SinglePhase(_) => None,
+            Self::TwoPhase { vapor_fraction, .. } => Some(*vapor_fraction),
+        }
+    }
+
+    /// Equation of state of the stream.
+    pub fn eos(&self) -> &Arc<E> {
+        match self {
+            Self::SinglePhase(s) => &s.eos,
+            Self::TwoPhase { vle, .. } => &vle.liquid().eos,
+        }
+    }
+
+    /// Molar enthalpy $h$
+    pub fn molar_enthalpy(&self) -> MolarEnergy {
+        self.average(|s| s.molar_enthalpy(Contributions::Total))
+    }
+
+    /// Molar entropy $s$
+    pub fn molar_entropy(&self) -> MolarEntropy {
+        self.average(|s| s.molar_entropy(Contributions::Total))
+    }
+
+    fn average<U, F: Fn(&State<E>) -> quantity::Quantity<f64, U>>(
+        &self,
+        f: F,
+    ) -> quantity::Quantity<f64, U> {
+        match self {
+            Self::SinglePhase(s) => f(s),
+            Self::TwoPhase {
+                vle,
+                vapor_fraction,
+                ..
+            } => f(vle.vapor()) * *vapor_fraction + f(vle.liquid()) * (1.0 - vapor_fraction),
+        }
+    }
+}
+
+/// Molar vapor fraction of a flash result.
+fn vapor_fraction<E: Residual>(vle: &PhaseEquilibrium<E, 2>) -> f64 {
+    let nv = vle.vapor().total_moles;
+    let nl = vle.liquid().total_moles;
+    (nv / (nv + nl)).into_value()
+}
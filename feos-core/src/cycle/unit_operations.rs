@@ -0,0 +1,258 @@
+use super::{IsobaricSpecification, ProcessState};
+use crate::equation_of_state::{IdealGas, Residual};
+use crate::errors::{EosError, EosResult};
+use quantity::Pressure;
+use std::fmt;
+
+/// Isentropic compression or expansion to the outlet pressure.
+fn isentropic<E: Residual + IdealGas>(
+    inlet: &ProcessState<E>,
+    outlet_pressure: Pressure,
+    isentropic_efficiency: f64,
+    expansion: bool,
+) -> EosResult<ProcessState<E>> {
+    if isentropic_efficiency <= 0.0 || isentropic_efficiency > 1.0 {
+        return Err(EosError::InvalidState(
+            String::from("isentropic efficiency"),
+            String::from("eta"),
+            isentropic_efficiency,
+        ));
+    }
+    let eos = inlet.eos();
+    let moles = inlet.moles();
+    let h_in = inlet.molar_enthalpy();
+    let isentropic = ProcessState::new_ps(eos, outlet_pressure, inlet.molar_entropy(), moles)?;
+    let dh_s = isentropic.molar_enthalpy() - h_in;
+    let h_out = if expansion {
+        h_in + dh_s * isentropic_efficiency
+    } else {
+        h_in + dh_s / isentropic_efficiency
+    };
+    ProcessState::new_ph(eos, outlet_pressure, h_out, moles)
+}
+
+/// Adiabatic compressor with constant isentropic efficiency.
+///
+/// $h_\mathrm{out}=h_\mathrm{in}+\frac{h_\mathrm{out}^s-h_\mathrm{in}}{\eta_s}$
+#[derive(Clone, Copy, Debug)]
+pub struct Compressor {
+    pub outlet_pressure: Pressure,
+    pub isentropic_efficiency: f64,
+}
+
+impl Compressor {
+    pub fn new(outlet_pressure: Pressure, isentropic_efficiency: f64) -> Self {
+        Self {
+            outlet_pressure,
+            isentropic_efficiency,
+        }
+    }
+
+    /// Calculate the outlet stream.
+    pub fn outlet<E: Residual + IdealGas>(
+        &self,
+        inlet: &ProcessState<E>,
+    ) -> EosResult<ProcessState<E>> {
+        isentropic(
+            inlet,
+            self.outlet_pressure,
+            self.isentropic_efficiency,
+            false,
+        )
+    }
+}
+
+/// Adiabatic turbine (expander) with constant isentropic efficiency.
+///
+/// $h_\mathrm{out}=h_\mathrm{in}-\eta_s\left(h_\mathrm{in}-h_\mathrm{out}^s\right)$
+#[derive(Clone, Copy, Debug)]
+pub struct Turbine {
+    pub outlet_pressure: Pressure,
+    pub isentropic_efficiency: f64,
+}
+
+impl Turbine {
+    pub fn new(outlet_pressure: Pressure, isentropic_efficiency: f64) -> Self {
+        Self {
+            outlet_pressure,
+            isentropic_efficiency,
+        }
+    }
+
+    /// Calculate the outlet stream.
+    pub fn outlet<E: Residual + IdealGas>(
+        &self,
+        inlet: &ProcessState<E>,
+    ) -> EosResult<ProcessState<E>> {
+        isentropic(
+            inlet,
+            self.outlet_pressure,
+            self.isentropic_efficiency,
+            true,
+        )
+    }
+}
+
+/// Adiabatic pump with constant isentropic efficiency.
+///
+/// The outlet is determined in the same way as for a [Compressor], but
+/// the inlet stream has to be a liquid or a saturated liquid.
+#[derive(Clone, Copy, Debug)]
+pub struct Pump {
+    pub outlet_pressure: Pressure,
+    pub isentropic_efficiency: f64,
+}
+
+impl Pump {
+    pub fn new(outlet_pressure: Pressure, isentropic_efficiency: f64) -> Self {
+        Self {
+            outlet_pressure,
+            isentropic_efficiency,
+        }
+    }
+
+    /// Calculate the outlet stream.
+    pub fn outlet<E: Residual + IdealGas>(
+        &self,
+        inlet: &ProcessState<E>,
+    ) -> EosResult<ProcessState<E>> {
+        if inlet.vapor_fraction().is_some_and(|q| q > 0.0) {
+            return Err(EosError::InvalidState(
+                String::from("Pump"),
+                String::from("inlet vapor fraction"),
+                inlet.vapor_fraction().unwrap(),
+            ));
+        }
+        isentropic(
+            inlet,
+            self.outlet_pressure,
+            self.isentropic_efficiency,
+            false,
+        )
+    }
+}
+
+/// Adiabatic throttle valve ($h_\mathrm{out}=h_\mathrm{in}$).
+#[derive(Clone, Copy, Debug)]
+pub struct Valve {
+    pub outlet_pressure: Pressure,
+}
+
+impl Valve {
+    pub fn new(outlet_pressure: Pressure) -> Self {
+        Self { outlet_pressure }
+    }
+
+    /// Calculate the outlet stream.
+    pub fn outlet<E: Residual + IdealGas>(
+        &self,
+        inlet: &ProcessState<E>,
+    ) -> EosResult<ProcessState<E>> {
+        ProcessState::new_ph(
+            inlet.eos(),
+            self.outlet_pressure,
+            inlet.molar_enthalpy(),
+            inlet.moles(),
+        )
+    }
+}
+
+/// Isobaric heat transfer, e.g., in an evaporator, a condenser
+/// or a gas cooler.
+#[derive(Clone, Copy, Debug)]
+pub struct HeatTransfer {
+    pub outlet: IsobaricSpecification,
+}
+
+impl HeatTransfer {
+    pub fn new(outlet: IsobaricSpecification) -> Self {
+        Self { outlet }
+    }
+
+    /// Calculate the outlet stream.
+    pub fn outlet<E: Residual + IdealGas>(
+        &self,
+        inlet: &ProcessState<E>,
+    ) -> EosResult<ProcessState<E>> {
+        ProcessState::new_isobaric(inlet.eos(), inlet.pressure(), self.outlet, inlet.moles())
+    }
+}
+
+/// The unit operations that can be combined in a [Cycle](super::Cycle).
+#[derive(Clone, Copy, Debug)]
+pub enum UnitOperation {
+    Compressor(Compressor),
+    Turbine(Turbine),
+    Pump(Pump),
+    Valve(Valve),
+    HeatTransfer(HeatTransfer),
+}
+
+impl UnitOperation {
+    /// Calculate the outlet stream.
+    pub fn outlet<E: Residual + IdealGas>(
+        &self,
+        inlet: &ProcessState<E>,
+    ) -> EosResult<ProcessState<E>> {
+        match self {
+            Self::Compressor(u) => u.outlet(inlet),
+            Self::Turbine(u) => u.outlet(inlet),
+            Self::Pump(u) => u.outlet(inlet),
+            Self::Valve(u) => u.outlet(inlet),
+            Self::HeatTransfer(u) => u.outlet(inlet),
+        }
+    }
+
+    /// Whether work is exchanged with the surroundings.
+    pub fn is_work(&self) -> bool {
+        matches!(self, Self::Compressor(_) | Self::Turbine(_) | Self::Pump(_))
+    }
+
+    /// Whether heat is exchanged with the surroundings.
+    pub fn is_heat(&self) -> bool {
+        matches!(self, Self::HeatTransfer(_))
+    }
+}
+
+impl fmt::Display for UnitOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Compressor(_) => "compressor",
+            Self::Turbine(_) => "turbine",
+            Self::Pump(_) => "pump",
+            Self::Valve(_) => "valve",
+            Self::HeatTransfer(_) => "heat transfer",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl From<Compressor> for UnitOperation {
+    fn from(u: Compressor) -> Self {
+        Self::Compressor(u)
+    }
+}
+
+impl From<Turbine> for UnitOperation {
+    fn from(u: Turbine) -> Self {
+        Self::Turbine(u)
+    }
+}
+
+impl From<Pump> for UnitOperation {
+    fn from(u: Pump) -> Self {
+        Self::Pump(u)
+    }
+}
+
+impl From<Valve> for UnitOperation {
+    fn from(u: Valve) -> Self {
+        Self::Valve(u)
+    }
+}
+
+impl From<HeatTransfer> for UnitOperation {
+    fn from(u: HeatTransfer) -> Self {
+        Self::HeatTransfer(u)
+    }
+}
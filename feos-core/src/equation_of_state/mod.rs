@@ -6,9 +6,12 @@ use quantity::{
 use std::sync::Arc;
 
 mod ideal_gas;
+mod reference_state;
 mod residual;
 
 pub use ideal_gas::IdealGas;
+pub use reference_state::ReferenceState;
+use reference_state::ReferenceStateOffset;
 pub use residual::{EntropyScaling, Molarweight, NoResidual, Residual};
 
 /// The number of components that the model is initialized for.
@@ -23,10 +26,20 @@ pub trait Components {
 
 /// An equation of state consisting of an ideal gas model
 /// and a residual Helmholtz energy model.
-#[derive(Clone)]
 pub struct EquationOfState<I, R> {
     pub ideal_gas: Arc<I>,
     pub residual: Arc<R>,
+    reference_state: Option<ReferenceStateOffset>,
+}
+
+impl<I, R> Clone for EquationOfState<I, R> {
+    fn clone(&self) -> Self {
+        Self {
+            ideal_gas: self.ideal_gas.clone(),
+            residual: self.residual.clone(),
+            reference_state: self.reference_state.clone(),
+        }
+    }
 }
 
 impl<I, R> EquationOfState<I, R> {
@@ -36,6 +49,7 @@ impl<I, R> EquationOfState<I, R> {
         Self {
             ideal_gas,
             residual,
+            reference_state: None,
         }
     }
 }
@@ -48,6 +62,7 @@ impl<I: IdealGas> EquationOfState<I, NoResidual> {
         Self {
             ideal_gas,
             residual,
+            reference_state: None,
         }
     }
 }
@@ -63,16 +78,29 @@ impl<I: Components, R: Components> Components for EquationOfState<I, R> {
     }
 
     fn subset(&self, component_list: &[usize]) -> Self {
-        Self::new(
-            Arc::new(self.ideal_gas.subset(component_list)),
-            Arc::new(self.residual.subset(component_list)),
-        )
+        Self {
+            ideal_gas: Arc::new(self.ideal_gas.subset(component_list)),
+            residual: Arc::new(self.residual.subset(component_list)),
+            reference_state: self
+                .reference_state
+                .as_ref()
+                .map(|r| r.subset(component_list)),
+        }
     }
 }
 
 impl<I: IdealGas, R: Components + Sync + Send> IdealGas for EquationOfState<I, R> {
     fn ln_lambda3<D: num_dual::DualNum<f64> + Copy>(&self, temperature: D) -> Array1<D> {
-        self.ideal_gas.ln_lambda3(temperature)
+        let ln_lambda3 = self.ideal_gas.ln_lambda3(temperature);
+        match &self.reference_state {
+            Some(r) => {
+                let t_inv = temperature.recip();
+                Array1::from_shape_fn(ln_lambda3.len(), |i| {
+                    ln_lambda3[i] + t_inv * r.beta[i] + r.alpha[i]
+                })
+            }
+            None => ln_lambda3,
+        }
     }
 
     fn ideal_gas_model(&self) -> String {
@@ -0,0 +1,264 @@
+use super::{Components, EquationOfState, IdealGas, Molarweight, Residual};
+use crate::errors::{EosError, EosResult};
+use crate::phase_equilibria::PhaseEquilibrium;
+use crate::state::{Contributions, State};
+use ndarray::{arr1, Array1};
+use quantity::{MolarEnergy, MolarEntropy, BAR, JOULE, KELVIN, KILO, KILOGRAM, MOL, PASCAL, RGAS};
+use std::sync::Arc;
+
+/// Conventions for the reference state of enthalpies and entropies.
+///
+/// The reference states are applied to every pure component.
+#[derive(Clone)]
+pub enum ReferenceState {
+    /// $h=200\\,\text{kJ/kg}$ and $s=1\\,\text{kJ/(kg K)}$ for the
+    /// saturated liquid at $0\\,°\text{C}$ (International Institute of Refrigeration).
+    IIR,
+    /// $h=0$ and $s=0$ for the saturated liquid at $-40\\,°\text{C}$ (ASHRAE).
+    ASHRAE,
+    /// $h=0$ and $s=0$ for the saturated liquid at the normal boiling point ($p=1\\,\text{atm}$).
+    NBP,
+    /// The given molar enthalpies and entropies of the ideal gases at $T=298.15\\,\text{K}$
+    /// and $p=1\\,\text{bar}$, e.g., enthalpies of formation and absolute entropies.
+    Formation {
+        enthalpy: MolarEnergy<Array1<f64>>,
+        entropy: MolarEntropy<Array1<f64>>,
+    },
+}
+
+/// Component-wise offsets of the logarithmic thermal de Broglie wavelength
+/// $\Delta\ln\Lambda_i^3=\alpha_i+\frac{\beta_i}{T}$ that shift the molar enthalpy
+/// by $R\beta_i$ and the molar entropy by $-R\alpha_i$.
+#[derive(Clone)]
+pub(super) struct ReferenceStateOffset {
+    pub(super) alpha: Array1<f64>,
+    pub(super) beta: Array1<f64>,
+}
+
+impl ReferenceStateOffset {
+    pub(super) fn subset(&self, component_list: &[usize]) -> Self {
+        Self {
+            alpha: component_list.iter().map(|&i| self.alpha[i]).collect(),
+            beta: component_list.iter().map(|&i| self.beta[i]).collect(),
+        }
+    }
+}
+
+impl<I: IdealGas, R: Residual + Molarweight> EquationOfState<I, R> {
+    /// Return the [EquationOfState] with enthalpies and entropies that
+    /// are consistent with the given [ReferenceState].
+    ///
+    /// Only the ideal gas contribution is modified, i.e., all other
+    /// properties remain unchanged.
+    pub fn with_reference_state(self, reference_state: ReferenceState) -> EosResult<Self> {
+        let eos = Self::new(self.ideal_gas, self.residual);
+        let n = eos.components();
+        let molar_weight = eos.molar_weight();
+        let mut alpha = Array1::zeros(n);
+        let mut beta = Array1::zeros(n);
+        if let ReferenceState::Formation { enthalpy, entropy } = &reference_state {
+            for len in [enthalpy.len(), entropy.len()] {
+                if len != n {
+                    return Err(EosError::IncompatibleComponents(n, len));
+                }
+            }
+        }
+        for i in 0..n {
+            let pure = Arc::new(eos.subset(&[i]));
+            let (state, h, s) = match &reference_state {
+                ReferenceState::IIR => {
+                    let vle =
+                        PhaseEquilibrium::pure(&pure, 273.15 * KELVIN, None, Default::default())?;
+                    let mw = molar_weight.get(i);
+                    (
+                        vle.liquid().clone(),
+                        200.0 * KILO * JOULE / KILOGRAM * mw,
+                        KILO * JOULE / KILOGRAM / KELVIN * mw,
+                    )
+                }
+                ReferenceState::ASHRAE => {
+                    let vle =
+                        PhaseEquilibrium::pure(&pure, 233.15 * KELVIN, None, Default::default())?;
+                    (
+                        vle.liquid().clone(),
+                        0.0 * JOULE / MOL,
+                        0.0 * JOULE / MOL / KELVIN,
+                    )
+                }
+                ReferenceState::NBP => {
+                    let vle =
+                        PhaseEquilibrium::pure(&pure, 101325.0 * PASCAL, None, Default::default())?;
+                    (
+                        vle.liquid().clone(),
+                        0.0 * JOULE / MOL,
+                        0.0 * JOULE / MOL / KELVIN,
+                    )
+                }
+                ReferenceState::Formation { enthalpy, entropy } => {
+                    let t = 298.15 * KELVIN;
+                    let moles = arr1(&[1.0]) * MOL;
+                    let volume = MOL * RGAS * t / BAR;
+                    let state = State::new_nvt(&pure, t, volume, &moles)?;
+                    (state, enthalpy.get(i), entropy.get(i))
+                }
+            };
+            // for the formation reference state, the ideal gas is evaluated at the given density
+            let contributions = match reference_state {
+                ReferenceState::Formation { .. } => Contributions::IdealGas,
+                _ => Contributions::Total,
+            };
+            beta[i] = ((h - state.molar_enthalpy(contributions)) / (RGAS * KELVIN)).into_value();
+            alpha[i] = ((state.molar_entropy(contributions) - s) / RGAS).into_value();
+        }
+        Ok(Self {
+            reference_state: Some(ReferenceStateOffset { alpha, beta }),
+            ..eos
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cubic::{PengRobinson, PengRobinsonParameters, PengRobinsonRecord};
+    use crate::parameter::{Identifier, Parameter, PureRecord};
+    use crate::{DensityInitialization, ReferenceSystem};
+    use approx::assert_relative_eq;
+    use num_dual::DualNum;
+
+    /// Ideal gas with a constant isobaric heat capacity of 4R.
+    struct ConstantHeatCapacity(usize);
+
+    impl Components for ConstantHeatCapacity {
+        fn components(&self) -> usize {
+            self.0
+        }
+
+        fn subset(&self, component_list: &[usize]) -> Self {
+            Self(component_list.len())
+        }
+    }
+
+    impl IdealGas for ConstantHeatCapacity {
+        fn ln_lambda3<D: DualNum<f64> + Copy>(&self, temperature: D) -> Array1<D> {
+            Array1::from_elem(self.0, temperature.ln() * -3.0)
+        }
+
+        fn ideal_gas_model(&self) -> String {
+            "Constant heat capacity".into()
+        }
+    }
+
+    fn propane_butane() -> EquationOfState<ConstantHeatCapacity, PengRobinson> {
+        let propane = PureRecord::new(
+            Identifier::default(),
+            44.0962,
+            PengRobinsonRecord::new(369.96, 4250000.0, 0.153),
+        );
+        let butane = PureRecord::new(
+            Identifier::default(),
+            58.123,
+            PengRobinsonRecord::new(425.2, 3800000.0, 0.199),
+        );
+        let parameters = PengRobinsonParameters::new_binary(vec![propane, butane], None).unwrap();
+        EquationOfState::new(
+            Arc::new(ConstantHeatCapacity(2)),
+            Arc::new(PengRobinson::new(Arc::new(parameters))),
+        )
+    }
+
+    #[test]
+    fn iir() -> EosResult<()> {
+        let eos = Arc::new(propane_butane().with_reference_state(ReferenceState::IIR)?);
+        for i in 0..2 {
+            let pure = Arc::new(eos.subset(&[i]));
+            let vle = PhaseEquilibrium::pure(&pure, 273.15 * KELVIN, None, Default::default())?;
+            let liquid = vle.liquid();
+            assert_relative_eq!(
+                liquid.specific_enthalpy(Contributions::Total),
+                200.0 * KILO * JOULE / KILOGRAM,
+                max_relative = 1e-10
+            );
+            assert_relative_eq!(
+                liquid.specific_entropy(Contributions::Total),
+                KILO * JOULE / KILOGRAM / KELVIN,
+                max_relative = 1e-10
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn nbp() -> EosResult<()> {
+        let eos = Arc::new(propane_butane().with_reference_state(ReferenceState::NBP)?);
+        let pure = Arc::new(eos.subset(&[1]));
+        let vle = PhaseEquilibrium::pure(&pure, 101325.0 * PASCAL, None, Default::default())?;
+        let liquid = vle.liquid();
+        assert_relative_eq!(
+            liquid.molar_enthalpy(Contributions::Total).to_reduced(),
+            0.0,
+            epsilon = 1e-8
+        );
+        assert_relative_eq!(
+            liquid.molar_entropy(Contributions::Total).to_reduced(),
+            0.0,
+            epsilon = 1e-8
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn formation() -> EosResult<()> {
+        let enthalpy = arr1(&[-104.7, -125.6]) * KILO * JOULE / MOL;
+        let entropy = arr1(&[270.3, 310.2]) * JOULE / MOL / KELVIN;
+        let reference_state = ReferenceState::Formation {
+            enthalpy: enthalpy.clone(),
+            entropy: entropy.clone(),
+        };
+        let eos = Arc::new(propane_butane().with_reference_state(reference_state)?);
+        let reference = Arc::new(propane_butane());
+
+        // ideal gas mixture at the reference conditions
+        let moles = arr1(&[0.4, 0.6]) * MOL;
+        let volume = MOL * RGAS * 298.15 * KELVIN / BAR;
+        let state = State::new_nvt(&eos, 298.15 * KELVIN, volume, &moles)?;
+        let h_mix = enthalpy.get(0) * 0.4 + enthalpy.get(1) * 0.6;
+        assert_relative_eq!(
+            state.molar_enthalpy(Contributions::IdealGas),
+            h_mix,
+            max_relative = 1e-10
+        );
+
+        // only enthalpies and entropies are affected
+        let t = 250.0 * KELVIN;
+        let p = 5.0 * BAR;
+        let s1 = State::new_npt(&eos, t, p, &moles, DensityInitialization::Liquid)?;
+        let s2 = State::new_npt(&reference, t, p, &moles, DensityInitialization::Liquid)?;
+        assert_relative_eq!(s1.density, s2.density, max_relative = 1e-12);
+        assert_relative_eq!(
+            s1.molar_isobaric_heat_capacity(Contributions::Total),
+            s2.molar_isobaric_heat_capacity(Contributions::Total),
+            max_relative = 1e-10
+        );
+        let s3 = State::new_npt(&eos, 2.0 * t, p, &moles, DensityInitialization::Vapor)?;
+        let s4 = State::new_npt(&reference, 2.0 * t, p, &moles, DensityInitialization::Vapor)?;
+        assert_relative_eq!(
+            s1.molar_enthalpy(Contributions::Total) - s2.molar_enthalpy(Contributions::Total),
+            s3.molar_enthalpy(Contributions::Total) - s4.molar_enthalpy(Contributions::Total),
+            max_relative = 1e-8
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn formation_wrong_length() {
+        let reference_state = ReferenceState::Formation {
+            enthalpy: arr1(&[-104.7]) * KILO * JOULE / MOL,
+            entropy: arr1(&[270.3, 310.2]) * JOULE / MOL / KELVIN,
+        };
+        assert!(matches!(
+            propane_butane().with_reference_state(reference_state),
+            Err(EosError::IncompatibleComponents(2, 1))
+        ));
+    }
+}
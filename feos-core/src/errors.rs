@@ -29,6 +29,10 @@ pub enum EosError {
     ParameterError(#[from] ParameterError),
     #[error(transparent)]
     LinAlgError(#[from] LinAlgError),
+    #[error(transparent)]
+    FileIO(#[from] std::io::Error),
+    #[error(transparent)]
+    Serde(#[from] serde_json::Error),
     #[cfg(feature = "rayon")]
     #[error(transparent)]
     RayonError(#[from] rayon::ThreadPoolBuildError),
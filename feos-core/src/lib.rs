@@ -26,21 +26,30 @@ macro_rules! log_result {
 }
 
 pub mod cubic;
+pub mod cycle;
 mod density_iteration;
 mod equation_of_state;
 mod errors;
 pub mod parameter;
 mod phase_equilibria;
+mod property_table;
 mod state;
+pub mod testing;
 pub use equation_of_state::{
-    Components, EntropyScaling, EquationOfState, IdealGas, Molarweight, NoResidual, Residual,
+    Components, EntropyScaling, EquationOfState, IdealGas, Molarweight, NoResidual, ReferenceState,
+    Residual,
 };
 pub use errors::{EosError, EosResult};
 pub use phase_equilibria::{
-    PhaseDiagram, PhaseDiagramHetero, PhaseEquilibrium, TemperatureOrPressure,
+    ElectroneutralPhaseEquilibrium, PhaseDiagram, PhaseDiagramHetero, PhaseEquilibrium,
+    TemperatureOrPressure,
+};
+pub use property_table::{
+    PropertyTable, SaturationCurve, TableCoordinates, TablePhase, TableProperty,
 };
 pub use state::{
-    Contributions, DensityInitialization, Derivative, State, StateBuilder, StateHD, StateVec,
+    Contributions, DensityInitialization, DensityRoot, Derivative, DiffusionMixingRule, PhaseLabel,
+    State, StateBatch, StateBuilder, StateHD, StateVec,
 };
 
 #[cfg(feature = "python")]
@@ -82,14 +82,15 @@ where
     ///
     /// If the identifiers in `binary_records` are not a subset of those in
     /// `pure_records`, the `Default` implementation of Self::Binary is used.
-    #[allow(clippy::expect_fun_call)]
+    /// Returns an error if a pure record does not contain the identifier
+    /// that is specified by `identifier_option`.
     fn binary_matrix_from_records(
         pure_records: &[PureRecord<Self::Pure>],
         binary_records: &[BinaryRecord<Identifier, Self::Binary>],
         identifier_option: IdentifierOption,
-    ) -> Option<Array2<Self::Binary>> {
+    ) -> Result<Option<Array2<Self::Binary>>, ParameterError> {
         if binary_records.is_empty() {
-            return None;
+            return Ok(None);
         }
 
         // Build Hashmap (id, id) -> BinaryRecord
@@ -103,28 +104,26 @@ where
                 })
                 .collect()
         };
+        let ids = pure_records
+            .iter()
+            .enumerate()
+            .map(|(i, pr)| {
+                pr.identifier.as_string(identifier_option).ok_or_else(|| {
+                    ParameterError::IncompatibleParameters(format!(
+                        "No identifier for given identifier_option for pure record {i}."
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
         let n = pure_records.len();
-        Some(Array2::from_shape_fn([n, n], |(i, j)| {
-            let id1 = pure_records[i]
-                .identifier
-                .as_string(identifier_option)
-                .expect(&format!(
-                    "No identifier for given identifier_option for pure record {}.",
-                    i
-                ));
-            let id2 = pure_records[j]
-                .identifier
-                .as_string(identifier_option)
-                .expect(&format!(
-                    "No identifier for given identifier_option for pure record {}.",
-                    j
-                ));
+        Ok(Some(Array2::from_shape_fn([n, n], |(i, j)| {
+            let (id1, id2) = (ids[i].clone(), ids[j].clone());
             binary_map
                 .get(&(id1.clone(), id2.clone()))
                 .or_else(|| binary_map.get(&(id2, id1)))
                 .cloned()
                 .unwrap_or_default()
-        }))
+        })))
     }
 
     /// Creates parameters from substance information stored in json files.
@@ -187,7 +186,7 @@ where
             Vec::new()
         };
         let record_matrix =
-            Self::binary_matrix_from_records(&records, &binary_records, identifier_option);
+            Self::binary_matrix_from_records(&records, &binary_records, identifier_option)?;
         Self::from_records(records, record_matrix)
     }
 
@@ -0,0 +1,391 @@
+use super::PhaseEquilibrium;
+use crate::equation_of_state::Residual;
+use crate::errors::{EosError, EosResult};
+use crate::state::{Contributions, DensityInitialization, State};
+use crate::{ReferenceSystem, SolverOptions, Verbosity};
+use ndarray::*;
+use num_dual::linalg::norm;
+use quantity::{Dimensionless, ElectricPotential, Moles, Pressure, Temperature, KB, QE};
+use std::sync::Arc;
+
+const MAX_ITER_ELECTRONEUTRAL: usize = 500;
+const TOL_ELECTRONEUTRAL: f64 = 1e-8;
+const MAX_ITER_RR: usize = 200;
+const TOL_RR: f64 = 1e-12;
+const MAX_STEP_POTENTIAL: f64 = 2.0;
+const TOL_NET_CHARGE: f64 = 1e-10;
+const DILUTION_IONS: f64 = 1e-8;
+
+/// Result of an electroneutral flash calculation.
+///
+/// Phase 0 (`phase_equilibrium.vapor()`) is the phase with the lower
+/// density, phase 1 (`phase_equilibrium.liquid()`) the phase with the
+/// higher density. Both phases are electroneutral.
+#[derive(Debug)]
+pub struct ElectroneutralPhaseEquilibrium<E> {
+    pub phase_equilibrium: PhaseEquilibrium<E, 2>,
+    /// Charge numbers of all components.
+    pub charges: Array1<f64>,
+    /// Reduced Galvani potential $\psi=\frac{e\left(\phi^{(0)}-\phi^{(1)}\right)}{k_\mathrm{B}T}$
+    reduced_galvani_potential: f64,
+}
+
+impl<E> Clone for ElectroneutralPhaseEquilibrium<E> {
+    fn clone(&self) -> Self {
+        Self {
+            phase_equilibrium: self.phase_equilibrium.clone(),
+            charges: self.charges.clone(),
+            reduced_galvani_potential: self.reduced_galvani_potential,
+        }
+    }
+}
+
+impl<E: Residual> ElectroneutralPhaseEquilibrium<E> {
+    /// Difference of the electric potentials of both phases
+    /// $\Delta\phi=\phi^{(0)}-\phi^{(1)}$ that results from the
+    /// partitioning of the ions.
+    pub fn galvani_potential(&self) -> ElectricPotential {
+        self.reduced_galvani_potential * KB * self.phase_equilibrium.vapor().temperature / QE
+    }
+
+    /// Fraction of the total amount of substance in phase 0.
+    pub fn phase_fraction(&self) -> f64 {
+        self.phase_equilibrium.vapor_phase_fraction()
+    }
+
+    /// Distribution coefficients $K_i=\frac{x_i^{(0)}}{x_i^{(1)}}$ of all components.
+    pub fn distribution_coefficients(&self) -> Array1<f64> {
+        &self.phase_equilibrium.vapor().molefracs / &self.phase_equilibrium.liquid().molefracs
+    }
+
+    /// Mean ionic distribution coefficient of the salt formed by a cation and an anion:
+    /// $K_\pm=\left(K_+^{\nu_+}K_-^{\nu_-}\right)^\frac{1}{\nu_++\nu_-}$
+    ///
+    /// The stoichiometric coefficients follow from electroneutrality
+    /// ($\nu_+=|z_-|$ and $\nu_-=|z_+|$). In contrast to the distribution
+    /// coefficients of the individual ions, $K_\pm$ does not depend on the
+    /// Galvani potential.
+    pub fn salt_distribution_coefficient(&self, cation: usize, anion: usize) -> EosResult<f64> {
+        let (z_c, z_a) = (self.charges[cation], self.charges[anion]);
+        if z_c <= 0.0 || z_a >= 0.0 {
+            return Err(EosError::Error(format!(
+                "Component {cation} (z = {z_c}) has to be a cation and component {anion} (z = {z_a}) an anion."
+            )));
+        }
+        let k = self.distribution_coefficients();
+        let (nu_c, nu_a) = (-z_a, z_c);
+        Ok(((nu_c * k[cation].ln() + nu_a * k[anion].ln()) / (nu_c + nu_a)).exp())
+    }
+}
+
+/// # Flash calculations
+impl<E: Residual> PhaseEquilibrium<E, 2> {
+    /// Perform a Tp-flash calculation for a system containing ions
+    /// with the given charge numbers.
+    ///
+    /// The ions partition between the phases such that each phase is
+    /// electroneutral, e.g., for salting-out in liquid-liquid equilibria.
+    /// If no initial values are given, the solution is initialized
+    /// using a stability analysis.
+    pub fn tp_flash_electroneutral(
+        eos: &Arc<E>,
+        temperature: Temperature,
+        pressure: Pressure,
+        feed: &Moles<Array1<f64>>,
+        charges: &Array1<f64>,
+        initial_state: Option<&PhaseEquilibrium<E, 2>>,
+        options: SolverOptions,
+    ) -> EosResult<ElectroneutralPhaseEquilibrium<E>> {
+        State::new_npt(
+            eos,
+            temperature,
+            pressure,
+            feed,
+            DensityInitialization::None,
+        )?
+        .tp_flash_electroneutral(charges, initial_state, options)
+    }
+}
+
+/// # Flash calculations
+impl<E: Residual> State<E> {
+    /// Perform an electroneutral Tp-flash calculation using the [State]
+    /// as feed. If no initial values are given, the solution is
+    /// initialized using a stability analysis.
+    ///
+    /// In equilibrium, the electrochemical potentials
+    /// $\mu_i+z_ie\phi$ of all components are equal in both phases.
+    /// The difference of the electric potentials $\phi$ of the phases
+    /// (the Galvani potential) is determined together with the phase
+    /// fraction from the condition that both phases are electroneutral.
+    pub fn tp_flash_electroneutral(
+        &self,
+        charges: &Array1<f64>,
+        initial_state: Option<&PhaseEquilibrium<E, 2>>,
+        options: SolverOptions,
+    ) -> EosResult<ElectroneutralPhaseEquilibrium<E>> {
+        if charges.len() != self.eos.components() {
+            return Err(EosError::IncompatibleComponents(
+                self.eos.components(),
+                charges.len(),
+            ));
+        }
+        let net_charge = (charges * &self.molefracs).sum();
+        if net_charge.abs() > TOL_NET_CHARGE * (charges.mapv(f64::abs) * &self.molefracs).sum() {
+            return Err(EosError::InvalidState(
+                String::from("tp_flash_electroneutral"),
+                String::from("net charge of the feed"),
+                net_charge,
+            ));
+        }
+
+        // initialization
+        if let Some(init) = initial_state {
+            let vle = self.tp_flash_electroneutral_(
+                init.clone()
+                    .update_pressure(self.temperature, self.pressure(Contributions::Total))?,
+                charges,
+                options,
+            );
+            if vle.is_ok() {
+                return vle;
+            }
+        }
+
+        let mut vle = Err(EosError::NoPhaseSplit);
+        for init in self.electroneutral_init_stability(charges)? {
+            vle = self.tp_flash_electroneutral_(init, charges, options);
+            if vle.is_ok() {
+                return vle;
+            }
+        }
+        vle
+    }
+
+    /// Initial values from a stability analysis of the uncharged components.
+    ///
+    /// A stability analysis of the full system would lead to phases that
+    /// are not electroneutral. Instead, the ions are initially distributed
+    /// according to their fugacity coefficients at infinite dilution in
+    /// the trial phases, subject to electroneutrality.
+    fn electroneutral_init_stability(
+        &self,
+        charges: &Array1<f64>,
+    ) -> EosResult<Vec<PhaseEquilibrium<E, 2>>> {
+        let n = self.eos.components();
+        let neutral: Vec<usize> = (0..n).filter(|&i| charges[i] == 0.0).collect();
+        if neutral.len() == n {
+            let (init1, init2) = PhaseEquilibrium::vle_init_stability(self)?;
+            return Ok([Some(init1), init2].into_iter().flatten().collect());
+        }
+
+        let eos = Arc::new(self.eos.subset(&neutral));
+        let moles = Moles::from_reduced(
+            neutral
+                .iter()
+                .map(|&i| self.moles.get(i).to_reduced())
+                .collect(),
+        );
+        let feed = State::new_npt(
+            &eos,
+            self.temperature,
+            self.pressure(Contributions::Total),
+            &moles,
+            DensityInitialization::InitialDensity(self.density),
+        )?;
+        let (init1, init2) = PhaseEquilibrium::vle_init_stability(&feed)?;
+        [Some(init1), init2]
+            .into_iter()
+            .flatten()
+            .map(|init| {
+                // distribution of the ions at infinite dilution in both phases
+                let [ln_phi_0, ln_phi_1] = [0, 1].map(|j| {
+                    let mut moles = self.molefracs.clone() * DILUTION_IONS;
+                    for (k, &i) in neutral.iter().enumerate() {
+                        moles[i] = init.0[j].molefracs[k];
+                    }
+                    State::new_npt(
+                        &self.eos,
+                        self.temperature,
+                        self.pressure(Contributions::Total),
+                        &Moles::from_reduced(moles),
+                        DensityInitialization::InitialDensity(init.0[j].density),
+                    )
+                    .map(|s| s.ln_phi())
+                });
+                let mut ln_k0 = ln_phi_1? - ln_phi_0?;
+                for (k, &i) in neutral.iter().enumerate() {
+                    ln_k0[i] = (init.vapor().molefracs[k] / init.liquid().molefracs[k]).ln();
+                }
+                let (beta, psi) = electroneutral_rachford_rice(
+                    &self.molefracs,
+                    &ln_k0,
+                    charges,
+                    init.vapor_phase_fraction(),
+                    0.0,
+                )?;
+                let moles = self.split_moles(&ln_k0, charges, beta, psi);
+                let [phase_0, phase_1] = [0, 1].map(|i| {
+                    State::new_npt(
+                        &self.eos,
+                        self.temperature,
+                        self.pressure(Contributions::Total),
+                        &moles[i],
+                        DensityInitialization::InitialDensity(init.0[i].density),
+                    )
+                });
+                Ok(PhaseEquilibrium([phase_0?, phase_1?]))
+            })
+            .collect()
+    }
+
+    /// Amount of substance in both phases for given phase fraction and Galvani potential.
+    fn split_moles(
+        &self,
+        ln_k0: &Array1<f64>,
+        charges: &Array1<f64>,
+        beta: f64,
+        psi: f64,
+    ) -> [Moles<Array1<f64>>; 2] {
+        let k = (ln_k0 - charges * psi).mapv(f64::exp);
+        let d = (&k - 1.0) * beta + 1.0;
+        [
+            self.moles.clone() * Dimensionless::new(beta * &k / &d),
+            self.moles.clone() * Dimensionless::new((1.0 - beta) / &d),
+        ]
+    }
+
+    fn tp_flash_electroneutral_(
+        &self,
+        mut new_vle_state: PhaseEquilibrium<E, 2>,
+        charges: &Array1<f64>,
+        options: SolverOptions,
+    ) -> EosResult<ElectroneutralPhaseEquilibrium<E>> {
+        // set options
+        let (max_iter, tol, verbosity) =
+            options.unwrap_or(MAX_ITER_ELECTRONEUTRAL, TOL_ELECTRONEUTRAL);
+
+        log_iter!(
+            verbosity,
+            " iter |    residual    |   potential   |  phase I mole fractions  |  phase II mole fractions  "
+        );
+        log_iter!(verbosity, "{:-<93}", "");
+
+        let pressure = self.pressure(Contributions::Total);
+        let mut beta = new_vle_state.vapor_phase_fraction();
+        let mut psi = 0.0;
+        for iter in 0..max_iter {
+            let ln_phi_0 = new_vle_state.vapor().ln_phi();
+            let ln_phi_1 = new_vle_state.liquid().ln_phi();
+
+            // check for convergence
+            let res_vec = new_vle_state.vapor().molefracs.mapv(f64::ln) + &ln_phi_0
+                - new_vle_state.liquid().molefracs.mapv(f64::ln)
+                - &ln_phi_1
+                + charges * psi;
+            let res = norm(&res_vec);
+            log_iter!(
+                verbosity,
+                " {:4} | {:14.8e} | {:13.8} | {:.8} | {:.8}",
+                iter,
+                res,
+                psi,
+                new_vle_state.vapor().molefracs,
+                new_vle_state.liquid().molefracs,
+            );
+            if iter > 0 && res < tol {
+                log_result!(
+                    verbosity,
+                    "Electroneutral Tp flash: calculation converged in {} step(s)\n",
+                    iter
+                );
+                let mut vle = new_vle_state.check_trivial_solution()?;
+                if vle.vapor().density > vle.liquid().density {
+                    vle.0.swap(0, 1);
+                    psi = -psi;
+                }
+                return Ok(ElectroneutralPhaseEquilibrium {
+                    phase_equilibrium: vle,
+                    charges: charges.clone(),
+                    reduced_galvani_potential: psi,
+                });
+            }
+
+            // phase fraction and Galvani potential for the current fugacity coefficients
+            let ln_k0 = ln_phi_1 - ln_phi_0;
+            (beta, psi) =
+                electroneutral_rachford_rice(&self.molefracs, &ln_k0, charges, beta, psi)?;
+
+            // update phases
+            let [moles_0, moles_1] = self.split_moles(&ln_k0, charges, beta, psi);
+            new_vle_state.update_moles(pressure, [&moles_0, &moles_1])?;
+        }
+        Err(EosError::NotConverged("electroneutral TP flash".to_owned()))
+    }
+}
+
+/// Solve the Rachford-Rice equation together with the electroneutrality
+/// condition for phase 0 for the phase fraction and the reduced Galvani
+/// potential.
+///
+/// The distribution coefficients are $K_i=\exp\left(\ln K_i^0-z_i\psi\right)$.
+fn electroneutral_rachford_rice(
+    feed: &Array1<f64>,
+    ln_k0: &Array1<f64>,
+    charges: &Array1<f64>,
+    mut beta: f64,
+    mut psi: f64,
+) -> EosResult<(f64, f64)> {
+    let ions = charges.iter().any(|&z| z != 0.0);
+    if !(beta > 0.0 && beta < 1.0) {
+        beta = 0.5;
+    }
+    for _ in 0..MAX_ITER_RR {
+        let k = (ln_k0 - charges * psi).mapv(f64::exp);
+        let dk = -charges * &k;
+        let d = (&k - 1.0) * beta + 1.0;
+        let d2 = &d * &d;
+
+        // material balance and electroneutrality of phase 0
+        let f1 = (feed * &(&k - 1.0) / &d).sum();
+        let j11 = -(feed * &(&k - 1.0).mapv(|k| k * k) / &d2).sum();
+        let j12 = (feed * &dk / &d2).sum();
+        let (f2, j21, j22) = if ions {
+            (
+                (charges * feed * &k / &d).sum(),
+                -(charges * feed * &k * &(&k - 1.0) / &d2).sum(),
+                (charges * feed * &dk / &d2).sum() * (1.0 - beta),
+            )
+        } else {
+            (psi, 0.0, 1.0)
+        };
+
+        let det = j11 * j22 - j12 * j21;
+        let mut dbeta = (f1 * j22 - f2 * j12) / det;
+        let mut dpsi = (j11 * f2 - j21 * f1) / det;
+        if !dbeta.is_finite() || !dpsi.is_finite() {
+            return Err(EosError::IterationFailed(String::from(
+                "electroneutral_rachford_rice",
+            )));
+        }
+        if dpsi.abs() > MAX_STEP_POTENTIAL {
+            let s = MAX_STEP_POTENTIAL / dpsi.abs();
+            dbeta *= s;
+            dpsi *= s;
+        }
+
+        // keep the phase fraction within its bounds
+        while beta - dbeta <= 0.0 || beta - dbeta >= 1.0 {
+            dbeta *= 0.5;
+        }
+        beta -= dbeta;
+        psi -= dpsi;
+
+        if dbeta.abs() < TOL_RR && dpsi.abs() < TOL_RR {
+            return Ok((beta, psi));
+        }
+    }
+    Err(EosError::NotConverged(String::from(
+        "electroneutral_rachford_rice",
+    )))
+}
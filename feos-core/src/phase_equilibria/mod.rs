@@ -9,6 +9,7 @@ use std::fmt::Write;
 use std::sync::Arc;
 
 mod bubble_dew;
+mod electroneutral_flash;
 mod phase_diagram_binary;
 mod phase_diagram_pure;
 mod phase_envelope;
@@ -16,6 +17,7 @@ mod stability_analysis;
 mod tp_flash;
 mod vle_pure;
 pub use bubble_dew::TemperatureOrPressure;
+pub use electroneutral_flash::ElectroneutralPhaseEquilibrium;
 pub use phase_diagram_binary::PhaseDiagramHetero;
 pub use phase_diagram_pure::PhaseDiagram;
 
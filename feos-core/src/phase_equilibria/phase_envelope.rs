@@ -1,10 +1,10 @@
 use super::{PhaseDiagram, PhaseEquilibrium};
 use crate::equation_of_state::Residual;
 use crate::errors::EosResult;
-use quantity::{Moles, Pressure, Temperature};
 use crate::state::{Contributions, State};
 use crate::SolverOptions;
 use ndarray::Array1;
+use quantity::{Moles, Pressure, Temperature};
 use std::sync::Arc;
 
 impl<E: Residual> PhaseDiagram<E, 2> {
@@ -600,7 +600,7 @@ macro_rules! impl_parameter {
                                 &prs,
                                 &brs,
                                 identifier_option,
-                            ))
+                            )?)
                         } else {
                             Err(PyErr::new::<PyTypeError, _>(format!(
                                 "Could not parse binary input!"
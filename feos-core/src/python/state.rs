@@ -284,6 +284,54 @@ macro_rules! impl_state {
                 Ok((PyState(state1), PyState(state2)))
             }
 
+            /// Find all density roots at given temperature and pressure.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos: EquationOfState
+            ///     The equation of state to use.
+            /// temperature: SINumber
+            ///     The temperature.
+            /// pressure: SINumber
+            ///     The pressure.
+            /// moles: SIArray1, optional
+            ///     Amount of substance of each component.
+            ///     Only optional for a pure component.
+            ///
+            /// Returns
+            /// -------
+            /// List[Tuple[State, float, bool, bool]]
+            ///     The roots in order of increasing density with their phase
+            ///     identification parameter, whether they are mechanically
+            ///     stable and whether they have the lowest Gibbs energy.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature, pressure, moles=None)")]
+            #[pyo3(signature = (eos, temperature, pressure, moles=None))]
+            fn density_roots(
+                eos: $py_eos,
+                temperature: Temperature,
+                pressure: Pressure,
+                moles: Option<Moles<Array1<f64>>>,
+            ) -> PyResult<Vec<(Self, f64, bool, bool)>> {
+                let roots = State::density_roots(
+                    &eos.0,
+                    temperature,
+                    pressure,
+                    moles.map(|m| m.try_into()).transpose()?.as_ref(),
+                )?;
+                Ok(roots
+                    .into_iter()
+                    .map(|r| {
+                        (
+                            PyState(r.state),
+                            r.phase_identification_parameter,
+                            r.mechanically_stable,
+                            r.lowest_gibbs_energy,
+                        )
+                    })
+                    .collect())
+            }
+
             /// Performs a stability analysis and returns a list of stable
             /// candidate states.
             ///
@@ -380,6 +428,66 @@ macro_rules! impl_state {
                 self.0.compressibility(contributions)
             }
 
+            /// Return the phase identification parameter.
+            ///
+            /// States with a phase identification parameter larger than 1
+            /// are liquid-like.
+            ///
+            /// Returns
+            /// -------
+            /// float
+            fn phase_identification_parameter(&self) -> f64 {
+                self.0.phase_identification_parameter()
+            }
+
+            /// Check the thermodynamic consistency of the residual Helmholtz
+            /// energy model at this state.
+            ///
+            /// Derivatives from all dual number types are compared to finite
+            /// differences and the Euler, Gibbs-Duhem and Maxwell relations,
+            /// virial coefficients and subsets of the model are validated.
+            ///
+            /// Parameters
+            /// ----------
+            /// step : float, optional
+            ///     Relative step size of the finite differences. Defaults to 1e-5.
+            /// tolerance : float, optional
+            ///     Maximum relative deviation of a check. Defaults to 1e-6.
+            ///
+            /// Returns
+            /// -------
+            /// List[Tuple[str, str, float, float, float, bool]]
+            ///     Category, name, value, reference value, relative deviation
+            ///     and whether the check passed for every check.
+            #[pyo3(signature = (step=None, tolerance=None))]
+            #[pyo3(text_signature = "($self, step=None, tolerance=None)")]
+            fn check_consistency(
+                &self,
+                step: Option<f64>,
+                tolerance: Option<f64>,
+            ) -> PyResult<Vec<(String, String, f64, f64, f64, bool)>> {
+                let default = feos_core::testing::ConsistencyOptions::default();
+                let options = feos_core::testing::ConsistencyOptions {
+                    step: step.unwrap_or(default.step),
+                    tolerance: tolerance.unwrap_or(default.tolerance),
+                };
+                let report = feos_core::testing::check_residual(&self.0, options)?;
+                Ok(report
+                    .checks
+                    .into_iter()
+                    .map(|c| {
+                        (
+                            c.category.to_string(),
+                            c.name,
+                            c.value,
+                            c.reference,
+                            c.relative_deviation,
+                            c.passed,
+                        )
+                    })
+                    .collect())
+            }
+
             /// Return partial derivative of pressure w.r.t. volume.
             ///
             /// Parameters
@@ -1463,6 +1571,62 @@ macro_rules! impl_state_entropy_scaling {
                 Ok(self.0.ln_diffusion_reduced()?)
             }
 
+            /// Return tracer diffusion coefficients of all components via entropy scaling.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray1
+            fn tracer_diffusion(&self) -> PyResult<Diffusivity<Array1<f64>>> {
+                Ok(self.0.tracer_diffusion()?)
+            }
+
+            /// Return diffusion coefficients at infinite dilution via entropy scaling.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray2
+            fn infinite_dilution_diffusion(&self) -> PyResult<Diffusivity<Array2<f64>>> {
+                Ok(self.0.infinite_dilution_diffusion()?)
+            }
+
+            /// Return Maxwell-Stefan diffusion coefficients.
+            ///
+            /// Parameters
+            /// ----------
+            /// mixing_rule: DiffusionMixingRule, optional
+            ///     the mixing rule used to combine the diffusion coefficients.
+            ///     Defaults to DiffusionMixingRule.Vignes.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray2
+            #[pyo3(signature = (mixing_rule=DiffusionMixingRule::Vignes), text_signature = "($self, mixing_rule)")]
+            fn maxwell_stefan_diffusion(
+                &self,
+                mixing_rule: DiffusionMixingRule,
+            ) -> PyResult<Diffusivity<Array2<f64>>> {
+                Ok(self.0.maxwell_stefan_diffusion(mixing_rule)?)
+            }
+
+            /// Return Fick diffusion coefficients in the molar reference frame.
+            ///
+            /// Parameters
+            /// ----------
+            /// mixing_rule: DiffusionMixingRule, optional
+            ///     the mixing rule used to combine the diffusion coefficients.
+            ///     Defaults to DiffusionMixingRule.Vignes.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray2
+            #[pyo3(signature = (mixing_rule=DiffusionMixingRule::Vignes), text_signature = "($self, mixing_rule)")]
+            fn fick_diffusion(
+                &self,
+                mixing_rule: DiffusionMixingRule,
+            ) -> PyResult<Diffusivity<Array2<f64>>> {
+                Ok(self.0.fick_diffusion(mixing_rule)?)
+            }
+
             /// Return thermal conductivity via entropy scaling.
             ///
             /// Returns
@@ -0,0 +1,427 @@
+use super::{Contributions, DensityInitialization, State, StateVec};
+use crate::equation_of_state::{IdealGas, Molarweight, Residual};
+use crate::errors::{EosError, EosResult};
+use ndarray::{Array1, Array2, ArrayView1};
+use quantity::{
+    Density, MassDensity, MolarEnergy, MolarEntropy, Moles, Pressure, Quantity, Temperature,
+    Velocity, MOL,
+};
+#[cfg(feature = "rayon")]
+use rayon::{prelude::*, ThreadPool};
+use std::sync::Arc;
+
+/// States that are evaluated for arrays of input variables.
+///
+/// Every point is calculated independently. Points for which the calculation
+/// of the state fails do not abort the evaluation; their errors are stored
+/// and can be accessed via [StateBatch::errors]. Properties of failed points
+/// are NaN.
+pub struct StateBatch<E>(pub Vec<EosResult<State<E>>>);
+
+/// Compositions of all points of a batch.
+///
+/// `molefracs` either contains a row for every point or a single row that
+/// is used for all points. For pure components, `None` can be used.
+fn batch_molefracs<E: Residual>(
+    eos: &Arc<E>,
+    n: usize,
+    molefracs: Option<&Array2<f64>>,
+) -> EosResult<Array2<f64>> {
+    let molefracs = match molefracs {
+        Some(x) => x.to_owned(),
+        None => Array2::ones((1, 1)),
+    };
+    if molefracs.ncols() != eos.components() {
+        return Err(EosError::IncompatibleComponents(
+            eos.components(),
+            molefracs.ncols(),
+        ));
+    }
+    if molefracs.nrows() != 1 && molefracs.nrows() != n {
+        return Err(EosError::Error(format!(
+            "Expected compositions for 1 or {n} points, got {}.",
+            molefracs.nrows()
+        )));
+    }
+    Ok(molefracs)
+}
+
+fn validate_length(n: usize, m: usize, name: &str) -> EosResult<()> {
+    if n != m {
+        return Err(EosError::Error(format!(
+            "Expected {n} values of the {name}, got {m}."
+        )));
+    }
+    Ok(())
+}
+
+fn moles(molefracs: ArrayView1<f64>) -> Moles<Array1<f64>> {
+    molefracs.to_owned() * MOL
+}
+
+impl<E: Residual> StateBatch<E> {
+    fn build<F: Fn(usize, Moles<Array1<f64>>) -> EosResult<State<E>>>(
+        n: usize,
+        molefracs: Array2<f64>,
+        state: F,
+    ) -> Self {
+        let row = |i: usize| if molefracs.nrows() == 1 { 0 } else { i };
+        Self(
+            (0..n)
+                .map(|i| state(i, moles(molefracs.row(row(i)))))
+                .collect(),
+        )
+    }
+
+    /// Calculate states for given temperatures, molar densities and compositions.
+    pub fn new_density(
+        eos: &Arc<E>,
+        temperature: &Temperature<Array1<f64>>,
+        density: &Density<Array1<f64>>,
+        molefracs: Option<&Array2<f64>>,
+    ) -> EosResult<Self> {
+        let n = temperature.len();
+        validate_length(n, density.len(), "density")?;
+        let molefracs = batch_molefracs(eos, n, molefracs)?;
+        Ok(Self::build(n, molefracs, |i, m| {
+            State::new_nvt(eos, temperature.get(i), MOL / density.get(i), &m)
+        }))
+    }
+
+    /// Calculate states for given temperatures, pressures and compositions.
+    pub fn new_pressure(
+        eos: &Arc<E>,
+        temperature: &Temperature<Array1<f64>>,
+        pressure: &Pressure<Array1<f64>>,
+        molefracs: Option<&Array2<f64>>,
+        density_initialization: DensityInitialization,
+    ) -> EosResult<Self> {
+        let n = temperature.len();
+        validate_length(n, pressure.len(), "pressure")?;
+        let molefracs = batch_molefracs(eos, n, molefracs)?;
+        Ok(Self::build(n, molefracs, |i, m| {
+            State::new_npt(
+                eos,
+                temperature.get(i),
+                pressure.get(i),
+                &m,
+                density_initialization,
+            )
+        }))
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<E: Residual + Send + Sync> StateBatch<E> {
+    fn par_build<F: Fn(usize, Moles<Array1<f64>>) -> EosResult<State<E>> + Sync>(
+        n: usize,
+        molefracs: Array2<f64>,
+        thread_pool: ThreadPool,
+        state: F,
+    ) -> Self {
+        let row = |i: usize| if molefracs.nrows() == 1 { 0 } else { i };
+        Self(thread_pool.install(|| {
+            (0..n)
+                .into_par_iter()
+                .map(|i| state(i, moles(molefracs.row(row(i)))))
+                .collect()
+        }))
+    }
+
+    /// Calculate states for given temperatures, molar densities and
+    /// compositions in parallel.
+    pub fn par_new_density(
+        eos: &Arc<E>,
+        temperature: &Temperature<Array1<f64>>,
+        density: &Density<Array1<f64>>,
+        molefracs: Option<&Array2<f64>>,
+        thread_pool: ThreadPool,
+    ) -> EosResult<Self> {
+        let n = temperature.len();
+        validate_length(n, density.len(), "density")?;
+        let molefracs = batch_molefracs(eos, n, molefracs)?;
+        Ok(Self::par_build(n, molefracs, thread_pool, |i, m| {
+            State::new_nvt(eos, temperature.get(i), MOL / density.get(i), &m)
+        }))
+    }
+
+    /// Calculate states for given temperatures, pressures and compositions
+    /// in parallel.
+    pub fn par_new_pressure(
+        eos: &Arc<E>,
+        temperature: &Temperature<Array1<f64>>,
+        pressure: &Pressure<Array1<f64>>,
+        molefracs: Option<&Array2<f64>>,
+        density_initialization: DensityInitialization,
+        thread_pool: ThreadPool,
+    ) -> EosResult<Self> {
+        let n = temperature.len();
+        validate_length(n, pressure.len(), "pressure")?;
+        let molefracs = batch_molefracs(eos, n, molefracs)?;
+        Ok(Self::par_build(n, molefracs, thread_pool, |i, m| {
+            State::new_npt(
+                eos,
+                temperature.get(i),
+                pressure.get(i),
+                &m,
+                density_initialization,
+            )
+        }))
+    }
+}
+
+impl<E> StateBatch<E> {
+    /// Number of points in the batch.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Return whether the batch contains any points.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Return whether the state of every point was calculated successfully.
+    pub fn is_ok(&self) -> Array1<bool> {
+        self.0.iter().map(|s| s.is_ok()).collect()
+    }
+
+    /// Indices and errors of all points for which the calculation failed.
+    pub fn errors(&self) -> Vec<(usize, &EosError)> {
+        self.0
+            .iter()
+            .enumerate()
+            .filter_map(|(i, s)| s.as_ref().err().map(|e| (i, e)))
+            .collect()
+    }
+
+    /// All successfully calculated states.
+    pub fn states(&self) -> StateVec<'_, E> {
+        self.0.iter().filter_map(|s| s.as_ref().ok()).collect()
+    }
+
+    /// Evaluate an arbitrary scalar property for all points.
+    ///
+    /// Failed points result in NaN.
+    pub fn map<U, F: Fn(&State<E>) -> Quantity<f64, U>>(&self, f: F) -> Quantity<Array1<f64>, U> {
+        Quantity::from_shape_fn(self.0.len(), |i| match &self.0[i] {
+            Ok(s) => f(s),
+            Err(_) => Quantity::new(f64::NAN),
+        })
+    }
+
+    fn map_array<F: Fn(&State<E>) -> Array1<f64>>(&self, components: usize, f: F) -> Array2<f64> {
+        let mut res = Array2::from_elem((self.0.len(), components), f64::NAN);
+        for (mut row, s) in res.outer_iter_mut().zip(&self.0) {
+            if let Ok(s) = s {
+                row.assign(&f(s));
+            }
+        }
+        res
+    }
+}
+
+impl<E: Residual> StateBatch<E> {
+    pub fn temperature(&self) -> Temperature<Array1<f64>> {
+        self.map(|s| s.temperature)
+    }
+
+    pub fn pressure(&self, contributions: Contributions) -> Pressure<Array1<f64>> {
+        self.map(|s| s.pressure(contributions))
+    }
+
+    pub fn density(&self) -> Density<Array1<f64>> {
+        self.map(|s| s.density)
+    }
+
+    pub fn compressibility(&self, contributions: Contributions) -> Array1<f64> {
+        self.map(|s| Quantity::new(s.compressibility(contributions)))
+            .into_value()
+    }
+
+    pub fn molefracs(&self) -> Array2<f64> {
+        self.map_array(self.components(), |s| s.molefracs.clone())
+    }
+
+    /// Logarithms of the fugacity coefficients.
+    pub fn ln_phi(&self) -> Array2<f64> {
+        self.map_array(self.components(), |s| s.ln_phi())
+    }
+
+    fn components(&self) -> usize {
+        self.0
+            .iter()
+            .find_map(|s| s.as_ref().ok().map(|s| s.eos.components()))
+            .unwrap_or(0)
+    }
+}
+
+impl<E: Residual + IdealGas> StateBatch<E> {
+    pub fn molar_enthalpy(&self, contributions: Contributions) -> MolarEnergy<Array1<f64>> {
+        self.map(|s| s.molar_enthalpy(contributions))
+    }
+
+    pub fn molar_entropy(&self, contributions: Contributions) -> MolarEntropy<Array1<f64>> {
+        self.map(|s| s.molar_entropy(contributions))
+    }
+
+    pub fn molar_internal_energy(&self, contributions: Contributions) -> MolarEnergy<Array1<f64>> {
+        self.map(|s| s.molar_internal_energy(contributions))
+    }
+
+    pub fn molar_gibbs_energy(&self, contributions: Contributions) -> MolarEnergy<Array1<f64>> {
+        self.map(|s| s.molar_gibbs_energy(contributions))
+    }
+
+    pub fn molar_isochoric_heat_capacity(
+        &self,
+        contributions: Contributions,
+    ) -> MolarEntropy<Array1<f64>> {
+        self.map(|s| s.molar_isochoric_heat_capacity(contributions))
+    }
+
+    pub fn molar_isobaric_heat_capacity(
+        &self,
+        contributions: Contributions,
+    ) -> MolarEntropy<Array1<f64>> {
+        self.map(|s| s.molar_isobaric_heat_capacity(contributions))
+    }
+}
+
+impl<E: Residual + Molarweight> StateBatch<E> {
+    pub fn mass_density(&self) -> MassDensity<Array1<f64>> {
+        self.map(|s| s.mass_density())
+    }
+}
+
+impl<E: Residual + Molarweight + IdealGas> StateBatch<E> {
+    pub fn speed_of_sound(&self) -> Velocity<Array1<f64>> {
+        self.map(|s| s.speed_of_sound())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cubic::{PengRobinson, PengRobinsonParameters, PengRobinsonRecord};
+    use crate::parameter::{Identifier, Parameter, PureRecord};
+    use crate::ReferenceSystem;
+    use approx::assert_relative_eq;
+    use ndarray::arr1;
+    use quantity::{BAR, KELVIN};
+
+    fn propane_butane() -> Arc<PengRobinson> {
+        let propane = PureRecord::new(
+            Identifier::default(),
+            44.0962,
+            PengRobinsonRecord::new(369.96, 4250000.0, 0.153),
+        );
+        let butane = PureRecord::new(
+            Identifier::default(),
+            58.123,
+            PengRobinsonRecord::new(425.2, 3800000.0, 0.199),
+        );
+        let parameters = PengRobinsonParameters::new_binary(vec![propane, butane], None).unwrap();
+        Arc::new(PengRobinson::new(Arc::new(parameters)))
+    }
+
+    fn inputs() -> (Temperature<Array1<f64>>, Pressure<Array1<f64>>, Array2<f64>) {
+        let temperature = arr1(&[300.0, -10.0, 350.0, 400.0]) * KELVIN;
+        let pressure = arr1(&[1.0, 1.0, 5.0, 50.0]) * BAR;
+        let x = Array1::linspace(0.1, 0.9, 4);
+        let molefracs =
+            Array2::from_shape_fn((4, 2), |(i, j)| if j == 0 { x[i] } else { 1.0 - x[i] });
+        (temperature, pressure, molefracs)
+    }
+
+    #[test]
+    fn batch_pressure() -> EosResult<()> {
+        let eos = propane_butane();
+        let (temperature, pressure, molefracs) = inputs();
+        let batch = StateBatch::new_pressure(
+            &eos,
+            &temperature,
+            &pressure,
+            Some(&molefracs),
+            DensityInitialization::None,
+        )?;
+        assert_eq!(batch.len(), 4);
+        assert_eq!(batch.is_ok(), arr1(&[true, false, true, true]));
+        let errors = batch.errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, 1);
+        assert_eq!(batch.states().len(), 3);
+
+        let density = batch.density();
+        let ln_phi = batch.ln_phi();
+        assert!(density.get(1).to_reduced().is_nan());
+        assert!(ln_phi.row(1).iter().all(|x| x.is_nan()));
+        for i in [0, 2, 3] {
+            let moles = molefracs.row(i).to_owned() * MOL;
+            let state = State::new_npt(
+                &eos,
+                temperature.get(i),
+                pressure.get(i),
+                &moles,
+                DensityInitialization::None,
+            )?;
+            assert_relative_eq!(density.get(i), state.density, max_relative = 1e-12);
+            assert_relative_eq!(ln_phi.row(i), state.ln_phi(), max_relative = 1e-12);
+        }
+
+        // states at the calculated densities reproduce the pressures
+        let batch = StateBatch::new_density(&eos, &temperature, &density, Some(&molefracs))?;
+        let p = batch.pressure(Contributions::Total);
+        for i in [0, 2, 3] {
+            assert_relative_eq!(p.get(i), pressure.get(i), max_relative = 1e-8);
+        }
+        assert_eq!(batch.errors().len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn batch_input_errors() {
+        let eos = propane_butane();
+        let (temperature, pressure, molefracs) = inputs();
+        let short = arr1(&[1.0, 2.0]) * BAR;
+        let init = DensityInitialization::None;
+        assert!(StateBatch::new_pressure(&eos, &temperature, &short, None, init).is_err());
+        assert!(StateBatch::new_pressure(&eos, &temperature, &pressure, None, init).is_err());
+        let rows = molefracs.slice(ndarray::s![0..2, ..]).to_owned();
+        assert!(
+            StateBatch::new_pressure(&eos, &temperature, &pressure, Some(&rows), init).is_err()
+        );
+        let single = molefracs.slice(ndarray::s![0..1, ..]).to_owned();
+        let batch =
+            StateBatch::new_pressure(&eos, &temperature, &pressure, Some(&single), init).unwrap();
+        assert_relative_eq!(
+            batch.molefracs().row(3),
+            molefracs.row(0),
+            max_relative = 1e-14
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_batch_pressure() -> EosResult<()> {
+        let eos = propane_butane();
+        let (temperature, pressure, molefracs) = inputs();
+        let init = DensityInitialization::None;
+        let batch =
+            StateBatch::new_pressure(&eos, &temperature, &pressure, Some(&molefracs), init)?;
+        let thread_pool = rayon::ThreadPoolBuilder::new().num_threads(2).build()?;
+        let par_batch = StateBatch::par_new_pressure(
+            &eos,
+            &temperature,
+            &pressure,
+            Some(&molefracs),
+            init,
+            thread_pool,
+        )?;
+        assert_eq!(batch.is_ok(), par_batch.is_ok());
+        for i in [0, 2, 3] {
+            assert_relative_eq!(batch.density().get(i), par_batch.density().get(i));
+        }
+        Ok(())
+    }
+}
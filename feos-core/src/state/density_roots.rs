@@ -0,0 +1,277 @@
+use super::{Contributions, State, StateHD};
+use crate::equation_of_state::Residual;
+use crate::errors::{EosError, EosResult};
+use crate::ReferenceSystem;
+use ndarray::Array1;
+use num_dual::HyperHyperDual64;
+use quantity::{Density, Moles, Pressure, Quantity, Temperature, Volume, RGAS};
+use std::ops::Div;
+use std::sync::Arc;
+
+const LOG_GRID_POINTS: usize = 100;
+const LINEAR_GRID_POINTS: usize = 200;
+const MAX_ITER_ROOT: usize = 100;
+
+/// Classification of a density root based on the phase identification parameter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PhaseLabel {
+    /// Vapor-like root ($\Pi\leq 1$).
+    Vapor,
+    /// Liquid-like root ($\Pi>1$).
+    Liquid,
+}
+
+/// A solution of $p(T,\rho,\mathbf{x})=p$ at given temperature, pressure and composition.
+pub struct DensityRoot<E> {
+    pub state: State<E>,
+    /// Phase identification parameter $\Pi$ of the root.
+    pub phase_identification_parameter: f64,
+    /// Vapor- or liquid-like according to $\Pi$.
+    pub phase: PhaseLabel,
+    /// Whether the root satisfies $\left(\frac{\partial p}{\partial\rho}\right)_{T,N_i}>0$.
+    pub mechanically_stable: bool,
+    /// Whether the root has the lowest Gibbs energy of all mechanically stable roots.
+    pub lowest_gibbs_energy: bool,
+}
+
+impl<E: Residual> State<E> {
+    /// Phase identification parameter of Venkatarathnam and Oellrich (2011):
+    /// $$\Pi=V\left(\frac{\left(\frac{\partial^2p}{\partial T\partial V}\right)_{N_i}}{\left(\frac{\partial p}{\partial T}\right)_{V,N_i}}-\frac{\left(\frac{\partial^2p}{\partial V^2}\right)_{T,N_i}}{\left(\frac{\partial p}{\partial V}\right)_{T,N_i}}\right)$$
+    ///
+    /// States with $\Pi>1$ are liquid-like and states with $\Pi\leq 1$ are vapor-like.
+    pub fn phase_identification_parameter(&self) -> f64 {
+        let d2p_dtdv = self.d2p_dtdv();
+        let dp_dt = self.dp_dt(Contributions::Total);
+        let dp_dv = self.dp_dv(Contributions::Total);
+        let d2p_dv2 = self.d2p_dv2(Contributions::Total);
+        (self.volume * (d2p_dtdv / dp_dt - d2p_dv2 / dp_dv)).into_value()
+    }
+
+    /// Mixed second partial derivative of pressure w.r.t. temperature and
+    /// volume: $\left(\frac{\partial^2p}{\partial T\partial V}\right)_{N_i}$
+    fn d2p_dtdv(&self) -> <<Pressure as Div<Temperature>>::Output as Div<Volume>>::Output {
+        let t = HyperHyperDual64::from_re(self.reduced_temperature).derivative3();
+        let v = HyperHyperDual64::from_re(self.reduced_volume)
+            .derivative1()
+            .derivative2();
+        let n = self.reduced_moles.mapv(HyperHyperDual64::from_re);
+        let a = self.eos.residual_helmholtz_energy(&StateHD::new(t, v, n)) * t;
+        let ideal_gas = -self.density * RGAS / self.volume;
+        ideal_gas + Quantity::from_reduced(-a.eps1eps2eps3)
+    }
+
+    /// Find all density roots at given temperature, pressure and amount of substance.
+    ///
+    /// The roots are located on a grid of densities between a fraction of
+    /// the ideal gas density and the maximum density of the model and are
+    /// returned in order of increasing density. Roots that are closer to each
+    /// other than the grid spacing, e.g., in the immediate vicinity of a
+    /// critical point, can be missed. If the pressure cannot be reached,
+    /// an empty list is returned.
+    pub fn density_roots(
+        eos: &Arc<E>,
+        temperature: Temperature,
+        pressure: Pressure,
+        moles: Option<&Moles<Array1<f64>>>,
+    ) -> EosResult<Vec<DensityRoot<E>>> {
+        let moles = &eos.validate_moles(moles)?;
+        let max_density = eos.max_density(Some(moles))?;
+        let ideal_gas_density = pressure / (RGAS * temperature);
+        let n = moles.sum();
+        let residual = |rho: Density| -> EosResult<(f64, f64)> {
+            let (p, dp_drho) = State::new_nvt(eos, temperature, n / rho, moles)?.p_dpdrho();
+            Ok(((p - pressure).to_reduced(), dp_drho.to_reduced()))
+        };
+
+        // density grid: logarithmic at low densities and linear at high densities
+        let rho_max = max_density.to_reduced();
+        let rho_switch = 0.01 * rho_max;
+        let rho_min = (1e-3 * ideal_gas_density.to_reduced()).min(0.1 * rho_switch);
+        let mut grid: Vec<f64> =
+            Array1::logspace(10.0, rho_min.log10(), rho_switch.log10(), LOG_GRID_POINTS).to_vec();
+        grid.extend(
+            Array1::linspace(rho_switch, rho_max, LINEAR_GRID_POINTS)
+                .iter()
+                .skip(1),
+        );
+
+        let mut densities = Vec::new();
+        let mut previous: Option<(f64, f64)> = None;
+        for &rho in &grid {
+            let (f, _) = residual(Density::from_reduced(rho))?;
+            if f == 0.0 {
+                densities.push(rho);
+            } else if let Some((rho_prev, f_prev)) = previous {
+                if f_prev * f < 0.0 {
+                    densities.push(refine_root(&residual, rho_prev, rho, f_prev)?);
+                }
+            }
+            previous = Some((rho, f));
+        }
+
+        let mut roots: Vec<_> = densities
+            .into_iter()
+            .map(|rho| {
+                let state =
+                    State::new_nvt(eos, temperature, n / Density::from_reduced(rho), moles)?;
+                let phase_identification_parameter = state.phase_identification_parameter();
+                let mechanically_stable = state.dp_drho(Contributions::Total).to_reduced() > 0.0;
+                Ok(DensityRoot {
+                    state,
+                    phase_identification_parameter,
+                    phase: if phase_identification_parameter > 1.0 {
+                        PhaseLabel::Liquid
+                    } else {
+                        PhaseLabel::Vapor
+                    },
+                    mechanically_stable,
+                    lowest_gibbs_energy: false,
+                })
+            })
+            .collect::<EosResult<_>>()?;
+
+        // at constant T, p and composition, the Gibbs energies only differ
+        // in the residual contribution sum_i x_i ln(phi_i)
+        let gibbs = |r: &DensityRoot<E>| (&r.state.molefracs * &r.state.ln_phi()).sum();
+        if let Some(k) = (0..roots.len())
+            .filter(|&k| roots[k].mechanically_stable)
+            .min_by(|&i, &j| gibbs(&roots[i]).total_cmp(&gibbs(&roots[j])))
+        {
+            roots[k].lowest_gibbs_energy = true;
+        }
+        Ok(roots)
+    }
+}
+
+/// Safeguarded Newton iteration for a root of the pressure within a bracket.
+fn refine_root<F: Fn(Density) -> EosResult<(f64, f64)>>(
+    residual: &F,
+    mut a: f64,
+    mut b: f64,
+    fa: f64,
+) -> EosResult<f64> {
+    // a is the end of the bracket with negative residual
+    if fa > 0.0 {
+        std::mem::swap(&mut a, &mut b);
+    }
+    let mut rho = 0.5 * (a + b);
+    for _ in 0..MAX_ITER_ROOT {
+        let (f, df) = residual(Density::from_reduced(rho))?;
+        if f < 0.0 {
+            a = rho;
+        } else {
+            b = rho;
+        }
+        let newton = rho - f / df;
+        let next = if (newton - a) * (newton - b) < 0.0 {
+            newton
+        } else {
+            0.5 * (a + b)
+        };
+        if (next - rho).abs() < 1e-12 * rho {
+            return Ok(next);
+        }
+        rho = next;
+    }
+    Err(EosError::NotConverged(String::from("density_roots")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cubic::{PengRobinson, PengRobinsonParameters, PengRobinsonRecord};
+    use crate::parameter::{Identifier, Parameter, PureRecord};
+    use crate::{DensityInitialization, PhaseEquilibrium};
+    use approx::assert_relative_eq;
+    use ndarray::arr1;
+    use quantity::{BAR, KELVIN, MOL};
+
+    fn propane() -> Arc<PengRobinson> {
+        let record = PureRecord::new(
+            Identifier::default(),
+            44.0962,
+            PengRobinsonRecord::new(369.96, 4250000.0, 0.153),
+        );
+        let parameters = PengRobinsonParameters::new_pure(record).unwrap();
+        Arc::new(PengRobinson::new(Arc::new(parameters)))
+    }
+
+    #[test]
+    fn three_roots() -> EosResult<()> {
+        let eos = propane();
+        let moles = arr1(&[1.0]) * MOL;
+        let t = 300.0 * KELVIN;
+        let vle = PhaseEquilibrium::pure(&eos, t, None, Default::default())?;
+        let p_sat = vle.vapor().pressure(Contributions::Total);
+        for (p, stable) in [
+            (0.99 * p_sat, PhaseLabel::Vapor),
+            (1.01 * p_sat, PhaseLabel::Liquid),
+        ] {
+            let roots = State::density_roots(&eos, t, p, None)?;
+            assert_eq!(roots.len(), 3);
+            let labels: Vec<_> = roots.iter().map(|r| r.phase).collect();
+            assert_eq!(labels[0], PhaseLabel::Vapor);
+            assert_eq!(labels[2], PhaseLabel::Liquid);
+            let stability: Vec<_> = roots.iter().map(|r| r.mechanically_stable).collect();
+            assert_eq!(stability, vec![true, false, true]);
+            let lowest: Vec<_> = roots.iter().filter(|r| r.lowest_gibbs_energy).collect();
+            assert_eq!(lowest.len(), 1);
+            assert_eq!(lowest[0].phase, stable);
+
+            let vapor = State::new_npt(&eos, t, p, &moles, DensityInitialization::Vapor)?;
+            let liquid = State::new_npt(&eos, t, p, &moles, DensityInitialization::Liquid)?;
+            assert_relative_eq!(roots[0].state.density, vapor.density, max_relative = 1e-10);
+            assert_relative_eq!(roots[2].state.density, liquid.density, max_relative = 1e-10);
+            for r in &roots {
+                assert_relative_eq!(
+                    r.state.pressure(Contributions::Total),
+                    p,
+                    max_relative = 1e-10
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn supercritical_roots() -> EosResult<()> {
+        let eos = propane();
+        let t = 400.0 * KELVIN;
+        for (p, label) in [
+            (10.0 * BAR, PhaseLabel::Vapor),
+            (200.0 * BAR, PhaseLabel::Liquid),
+        ] {
+            let roots = State::density_roots(&eos, t, p, None)?;
+            assert_eq!(roots.len(), 1);
+            assert_eq!(roots[0].phase, label);
+            assert!(roots[0].mechanically_stable && roots[0].lowest_gibbs_energy);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn phase_identification_parameter() -> EosResult<()> {
+        // the phase identification parameter of a dilute gas approaches 1
+        let eos = propane();
+        let moles = arr1(&[1.0]) * MOL;
+        let state = State::new_npt(
+            &eos,
+            500.0 * KELVIN,
+            1e-3 * BAR,
+            &moles,
+            DensityInitialization::Vapor,
+        )?;
+        assert_relative_eq!(state.phase_identification_parameter(), 1.0, epsilon = 1e-4);
+
+        // mixed derivative compared to finite differences
+        let t = 300.0 * KELVIN;
+        let state = State::new_npt(&eos, t, 20.0 * BAR, &moles, DensityInitialization::Liquid)?;
+        let h = 1e-4 * KELVIN;
+        let dp_dv = |t| -> EosResult<_> {
+            Ok(State::new_nvt(&eos, t, state.volume, &moles)?.dp_dv(Contributions::Total))
+        };
+        let fd = (dp_dv(t + h)? - dp_dv(t - h)?) / (2.0 * h);
+        assert_relative_eq!(state.d2p_dtdv(), fd, max_relative = 1e-6);
+        Ok(())
+    }
+}
@@ -19,12 +19,16 @@ use std::ops::Sub;
 use std::sync::{Arc, Mutex};
 use typenum::{N1, N2, P1, Z0};
 
+mod batch;
 mod builder;
 mod cache;
+mod density_roots;
 mod properties;
 mod residual_properties;
 mod statevec;
+pub use batch::StateBatch;
 pub use builder::StateBuilder;
+pub use density_roots::{DensityRoot, PhaseLabel};
 pub use statevec::StateVec;
 
 /// Possible contributions that can be computed.
@@ -41,6 +45,16 @@ pub enum Contributions {
     Total,
 }
 
+/// Mixing rules for Maxwell-Stefan diffusion coefficients.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "python", pyo3::pyclass(eq))]
+pub enum DiffusionMixingRule {
+    /// Generalized Darken relation using the tracer diffusion coefficients in the mixture.
+    Darken,
+    /// Multicomponent Vignes relation using the diffusion coefficients at infinite dilution.
+    Vignes,
+}
+
 /// Initial values in a density iteration.
 #[derive(Clone, Copy)]
 pub enum DensityInitialization {
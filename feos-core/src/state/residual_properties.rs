@@ -1,9 +1,13 @@
-use super::{Contributions, Derivative::*, PartialDerivative, State};
+use super::{
+    Contributions, DensityInitialization, Derivative::*, DiffusionMixingRule, PartialDerivative,
+    State,
+};
 use crate::equation_of_state::{EntropyScaling, Molarweight, Residual};
 use crate::errors::EosResult;
 use crate::phase_equilibria::PhaseEquilibrium;
 use crate::ReferenceSystem;
 use ndarray::{arr1, Array1, Array2};
+use num_dual::linalg::LU;
 use quantity::*;
 use std::ops::{Add, Div};
 use std::sync::Arc;
@@ -566,6 +570,132 @@ impl<E: Residual + EntropyScaling> State<E> {
             .diffusion_reference(self.temperature, self.volume, &self.moles)
     }
 
+    /// Return the tracer diffusion coefficients of all components via entropy scaling.
+    ///
+    /// The pure component correlations are evaluated at the temperature, density and
+    /// residual entropy of the mixture.
+    pub fn tracer_diffusion(&self) -> EosResult<Diffusivity<Array1<f64>>> {
+        let s = self.residual_molar_entropy().to_reduced();
+        let eos: Vec<_> = (0..self.eos.components())
+            .map(|i| self.eos.subset(&[i]))
+            .collect();
+        Ok(Diffusivity::from_reduced(self.tracer_diffusion_reduced(
+            &eos,
+            self.density,
+            s,
+        )?))
+    }
+
+    fn tracer_diffusion_reduced(
+        &self,
+        eos: &[E],
+        density: Density,
+        s_res: f64,
+    ) -> EosResult<Array1<f64>> {
+        let moles = arr1(&[1.0]) * MOL;
+        let volume = MOL / density;
+        eos.iter()
+            .map(|e| {
+                let d_ref = e.diffusion_reference(self.temperature, volume, &moles)?;
+                let ln_d = e.diffusion_correlation(s_res, &arr1(&[1.0]))?;
+                Ok((d_ref * ln_d.exp()).to_reduced())
+            })
+            .collect()
+    }
+
+    /// Return the diffusion coefficients at infinite dilution via entropy scaling.
+    ///
+    /// The element $D_{ij}^\infty$ is the tracer diffusion coefficient of component $i$
+    /// infinitely diluted in pure component $j$ at the temperature and pressure of the state.
+    pub fn infinite_dilution_diffusion(&self) -> EosResult<Diffusivity<Array2<f64>>> {
+        let n = self.eos.components();
+        let eos: Vec<_> = (0..n).map(|i| self.eos.subset(&[i])).collect();
+        let pressure = self.pressure(Contributions::Total);
+        let mut d_inf = Array2::zeros((n, n));
+        for j in 0..n {
+            let solvent = State::new_npt(
+                &Arc::new(self.eos.subset(&[j])),
+                self.temperature,
+                pressure,
+                &(arr1(&[1.0]) * MOL),
+                DensityInitialization::InitialDensity(self.density),
+            )?;
+            let s = solvent.residual_molar_entropy().to_reduced();
+            d_inf
+                .column_mut(j)
+                .assign(&self.tracer_diffusion_reduced(&eos, solvent.density, s)?);
+        }
+        Ok(Diffusivity::from_reduced(d_inf))
+    }
+
+    /// Return the Maxwell-Stefan diffusion coefficients $\mathcal{D}_{ij}$ using the given mixing rule.
+    ///
+    /// The diagonal elements are not defined and set to zero.
+    pub fn maxwell_stefan_diffusion(
+        &self,
+        mixing_rule: DiffusionMixingRule,
+    ) -> EosResult<Diffusivity<Array2<f64>>> {
+        let n = self.eos.components();
+        let x = &self.molefracs;
+        let d_ms = match mixing_rule {
+            DiffusionMixingRule::Darken => {
+                let d = self.tracer_diffusion()?.to_reduced();
+                let x_d = (x / &d).sum();
+                Array2::from_shape_fn(
+                    (n, n),
+                    |(i, j)| {
+                        if i == j {
+                            0.0
+                        } else {
+                            d[i] * d[j] * x_d
+                        }
+                    },
+                )
+            }
+            DiffusionMixingRule::Vignes => {
+                let d_inf = self.infinite_dilution_diffusion()?.to_reduced();
+                Array2::from_shape_fn((n, n), |(i, j)| {
+                    if i == j {
+                        return 0.0;
+                    }
+                    let mut ln_d = x[j] * d_inf[[i, j]].ln() + x[i] * d_inf[[j, i]].ln();
+                    for k in (0..n).filter(|&k| k != i && k != j) {
+                        ln_d += 0.5 * x[k] * (d_inf[[i, k]] * d_inf[[j, k]]).ln();
+                    }
+                    ln_d.exp()
+                })
+            }
+        };
+        Ok(Diffusivity::from_reduced(d_ms))
+    }
+
+    /// Return the Fick diffusion coefficients in the molar reference frame.
+    ///
+    /// The $(n-1)\times(n-1)$ matrix is calculated from the Maxwell-Stefan diffusion
+    /// coefficients and the thermodynamic factor as $[D]=[B]^{-1}[\Gamma]$
+    /// with the last component as reference.
+    pub fn fick_diffusion(
+        &self,
+        mixing_rule: DiffusionMixingRule,
+    ) -> EosResult<Diffusivity<Array2<f64>>> {
+        let d_ms = self.maxwell_stefan_diffusion(mixing_rule)?.to_reduced();
+        let x = &self.molefracs;
+        let n = x.len() - 1;
+        let b = Array2::from_shape_fn((n, n), |(i, j)| {
+            if i == j {
+                x[i] / d_ms[[i, n]]
+                    + (0..=n)
+                        .filter(|&k| k != i)
+                        .map(|k| x[k] / d_ms[[i, k]])
+                        .sum::<f64>()
+            } else {
+                -x[i] * (1.0 / d_ms[[i, j]] - 1.0 / d_ms[[i, n]])
+            }
+        });
+        let d_fick = LU::new(b)?.inverse().dot(&self.thermodynamic_factor());
+        Ok(Diffusivity::from_reduced(d_fick))
+    }
+
     /// Return the thermal conductivity via entropy scaling.
     pub fn thermal_conductivity(&self) -> EosResult<ThermalConductivity> {
         let s = self.residual_molar_entropy().to_reduced();
@@ -0,0 +1,983 @@
+//! Thermodynamic consistency tests for implementations of [Residual] and [IdealGas].
+//!
+//! The functions in this module evaluate a model at a given [State] and
+//! compare quantities that have to agree for every thermodynamically
+//! consistent implementation:
+//!
+//! + derivatives obtained with the different dual number types that are used
+//!   throughout `feos` are compared to finite differences,
+//! + the individual Helmholtz energy contributions have to sum up to the
+//!   total residual Helmholtz energy,
+//! + the Euler and Gibbs-Duhem relations,
+//! + Maxwell relations (evaluated with finite differences of first derivatives),
+//! + the low density limit of the compressibility factor has to be consistent
+//!   with the second and third virial coefficients,
+//! + a model created with [Components::subset](crate::Components::subset) has to agree with the full
+//!   model evaluated for the respective components only.
+//!
+//! All values in the resulting [ConsistencyReport] are given in reduced units.
+//! Derivatives of the Helmholtz energy refer to the reduced residual Helmholtz
+//! energy $\beta A^\mathrm{res}$ as returned by [Residual::residual_helmholtz_energy].
+//!
+//! # Example
+//! ```
+//! # use feos_core::cubic::{PengRobinson, PengRobinsonParameters, PengRobinsonRecord};
+//! # use feos_core::parameter::{Identifier, Parameter, PureRecord};
+//! # use feos_core::testing::{check_residual, ConsistencyOptions};
+//! # use feos_core::{EosResult, State};
+//! # use quantity::{KELVIN, MOL, METER};
+//! # use ndarray::arr1;
+//! # use std::sync::Arc;
+//! # fn main() -> EosResult<()> {
+//! # let record = PureRecord::new(Identifier::default(), 44.0962, PengRobinsonRecord::new(369.96, 4250000.0, 0.153));
+//! # let eos = Arc::new(PengRobinson::new(Arc::new(PengRobinsonParameters::new_pure(record)?)));
+//! let state = State::new_nvt(&eos, 300.0 * KELVIN, 1e-3 * METER * METER * METER, &(arr1(&[1.0]) * MOL))?;
+//! let report = check_residual(&state, ConsistencyOptions::default())?;
+//! assert!(report.passed(), "{report}");
+//! # Ok(())
+//! # }
+//! ```
+use crate::equation_of_state::{IdealGas, Residual};
+use crate::errors::EosResult;
+use crate::state::{Contributions, DensityInitialization, Derivative, State};
+use crate::ReferenceSystem;
+use ndarray::Array1;
+use num_dual::{Dual2_64, Dual3_64, Dual64, DualNum};
+use quantity::{Density, Moles, Temperature, Volume, RGAS};
+use std::fmt;
+use std::sync::Arc;
+use Derivative::*;
+
+/// Options for the consistency tests.
+#[derive(Clone, Copy, Debug)]
+pub struct ConsistencyOptions {
+    /// Step size of the central finite differences relative to the respective variable.
+    pub step: f64,
+    /// Maximum relative deviation for a check to pass.
+    pub tolerance: f64,
+}
+
+impl Default for ConsistencyOptions {
+    fn default() -> Self {
+        Self {
+            step: 1e-5,
+            tolerance: 1e-6,
+        }
+    }
+}
+
+/// The group of thermodynamic relations a check belongs to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConsistencyCategory {
+    /// Dual number derivatives compared to finite differences and to other dual number types.
+    Derivative,
+    /// Sum of the individual Helmholtz energy contributions.
+    Contributions,
+    /// Euler and Gibbs-Duhem relations.
+    GibbsDuhem,
+    /// Maxwell relations.
+    Maxwell,
+    /// Low density limit and virial coefficients.
+    Virial,
+    /// Models created with [Components::subset](crate::Components::subset).
+    Subset,
+    /// Heat capacities compared to finite differences of caloric properties.
+    HeatCapacity,
+}
+
+impl fmt::Display for ConsistencyCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Derivative => "derivative",
+            Self::Contributions => "contributions",
+            Self::GibbsDuhem => "Gibbs-Duhem",
+            Self::Maxwell => "Maxwell",
+            Self::Virial => "virial",
+            Self::Subset => "subset",
+            Self::HeatCapacity => "heat capacity",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// The result of a single consistency check.
+#[derive(Clone, Debug)]
+pub struct ConsistencyCheck {
+    pub category: ConsistencyCategory,
+    /// Description of the compared quantities.
+    pub name: String,
+    /// The value obtained from the model.
+    pub value: f64,
+    /// The reference value that `value` has to agree with.
+    pub reference: f64,
+    /// Deviation between `value` and `reference` relative to the magnitude of the compared quantities.
+    pub relative_deviation: f64,
+    /// Whether the relative deviation is within the tolerance.
+    pub passed: bool,
+}
+
+/// Collection of the results of all consistency checks.
+#[derive(Clone, Debug)]
+pub struct ConsistencyReport {
+    pub checks: Vec<ConsistencyCheck>,
+    tolerance: f64,
+}
+
+impl ConsistencyReport {
+    fn new(options: ConsistencyOptions) -> Self {
+        Self {
+            checks: Vec::new(),
+            tolerance: options.tolerance,
+        }
+    }
+
+    /// Add a check. The deviation is calculated relative to the larger
+    /// of the magnitude of the reference and the given `scale`, which is
+    /// used for quantities that vanish identically.
+    fn push(
+        &mut self,
+        category: ConsistencyCategory,
+        name: String,
+        value: f64,
+        reference: f64,
+        scale: f64,
+    ) {
+        let relative_deviation =
+            (value - reference).abs() / reference.abs().max(scale.abs()).max(f64::MIN_POSITIVE);
+        self.checks.push(ConsistencyCheck {
+            category,
+            name,
+            value,
+            reference,
+            relative_deviation,
+            passed: relative_deviation <= self.tolerance,
+        });
+    }
+
+    fn extend(&mut self, other: Self) {
+        self.checks.extend(other.checks);
+    }
+
+    /// Whether all checks passed.
+    pub fn passed(&self) -> bool {
+        self.checks.iter().all(|c| c.passed)
+    }
+
+    /// All checks that failed.
+    pub fn failures(&self) -> Vec<&ConsistencyCheck> {
+        self.checks.iter().filter(|c| !c.passed).collect()
+    }
+
+    /// All checks of the given category.
+    pub fn category(&self, category: ConsistencyCategory) -> Vec<&ConsistencyCheck> {
+        self.checks
+            .iter()
+            .filter(|c| c.category == category)
+            .collect()
+    }
+}
+
+impl fmt::Display for ConsistencyReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let failures = self.failures().len();
+        writeln!(
+            f,
+            "{} of {} checks passed (tolerance {:e})",
+            self.checks.len() - failures,
+            self.checks.len(),
+            self.tolerance
+        )?;
+        for c in &self.checks {
+            writeln!(
+                f,
+                "{} {:<14} {:<40} {:>24.16e} {:>24.16e} {:>10.3e}",
+                if c.passed { "ok    " } else { "FAILED" },
+                c.category.to_string(),
+                c.name,
+                c.value,
+                c.reference,
+                c.relative_deviation
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn variable_name(variable: Derivative) -> String {
+    match variable {
+        DT => "T".into(),
+        DV => "V".into(),
+        DN(i) => format!("N{i}"),
+    }
+}
+
+fn reduced_variable<E>(state: &State<E>, variable: Derivative) -> f64 {
+    match variable {
+        DT => state.temperature.to_reduced(),
+        DV => state.volume.to_reduced(),
+        DN(i) => state.moles.get(i).to_reduced(),
+    }
+}
+
+/// The same state with one of the variables changed by `h` (in reduced units).
+fn perturb<E: Residual>(state: &State<E>, variable: Derivative, h: f64) -> EosResult<State<E>> {
+    let mut t = state.temperature.to_reduced();
+    let mut v = state.volume.to_reduced();
+    let mut n = state.moles.to_reduced();
+    match variable {
+        DT => t += h,
+        DV => v += h,
+        DN(i) => n[i] += h,
+    }
+    State::new_nvt(
+        &state.eos,
+        Temperature::from_reduced(t),
+        Volume::from_reduced(v),
+        &Moles::from_reduced(n),
+    )
+}
+
+/// Central finite difference of `f` w.r.t. `variable`.
+fn central_difference<E: Residual, F: Fn(&State<E>) -> f64>(
+    state: &State<E>,
+    variable: Derivative,
+    step: f64,
+    f: F,
+) -> EosResult<f64> {
+    let h = step * reduced_variable(state, variable);
+    Ok((f(&perturb(state, variable, h)?) - f(&perturb(state, variable, -h)?)) / (2.0 * h))
+}
+
+/// Variables with non-vanishing values that can be perturbed in finite differences.
+fn variables<E: Residual>(state: &State<E>) -> Vec<Derivative> {
+    let mut variables = vec![DT, DV];
+    variables.extend(
+        (0..state.eos.components())
+            .filter_map(|i| (state.moles.get(i).to_reduced() > 0.0).then_some(DN(i))),
+    );
+    variables
+}
+
+/// Run all consistency checks for the residual Helmholtz energy model of the state.
+///
+/// Returns an error if one of the auxiliary states required for the
+/// finite differences cannot be created.
+pub fn check_residual<E: Residual>(
+    state: &State<E>,
+    options: ConsistencyOptions,
+) -> EosResult<ConsistencyReport> {
+    let mut report = ConsistencyReport::new(options);
+    check_derivatives(state, options, &mut report)?;
+    check_contributions(state, &mut report);
+    check_gibbs_duhem(state, &mut report);
+    check_maxwell(state, options, &mut report)?;
+    check_virial(state, options, &mut report)?;
+    check_subset(state, &mut report)?;
+    Ok(report)
+}
+
+/// Run all consistency checks for the ideal gas model at the given temperature.
+pub fn check_ideal_gas<I: IdealGas>(
+    ideal_gas: &I,
+    temperature: Temperature,
+    options: ConsistencyOptions,
+) -> ConsistencyReport {
+    use ConsistencyCategory::Subset;
+    let mut report = ConsistencyReport::new(options);
+    let t = temperature.to_reduced();
+    let h = options.step * t;
+    let f = ideal_gas.ln_lambda3(t);
+    let fp = ideal_gas.ln_lambda3(t + h);
+    let fm = ideal_gas.ln_lambda3(t - h);
+    let d1 = ideal_gas.ln_lambda3(Dual64::from(t).derivative());
+    let d1p = ideal_gas.ln_lambda3(Dual64::from(t + h).derivative());
+    let d1m = ideal_gas.ln_lambda3(Dual64::from(t - h).derivative());
+    let d2 = ideal_gas.ln_lambda3(Dual2_64::from(t).derivative());
+    let d2p = ideal_gas.ln_lambda3(Dual2_64::from(t + h).derivative());
+    let d2m = ideal_gas.ln_lambda3(Dual2_64::from(t - h).derivative());
+    let d3 = ideal_gas.ln_lambda3(Dual3_64::from(t).derivative());
+
+    for i in 0..ideal_gas.components() {
+        let mut push = |name: &str, value, reference, scale| {
+            let category = ConsistencyCategory::Derivative;
+            report.push(category, format!("{name}_{i}"), value, reference, scale)
+        };
+        push("ln_lambda3 [Dual64]", d1[i].re, f[i], 0.0);
+        push("ln_lambda3 [Dual2_64]", d2[i].re, f[i], 0.0);
+        push("ln_lambda3 [Dual3_64]", d3[i].re, f[i], 0.0);
+        let s1 = f[i].abs() / t;
+        push(
+            "dln_lambda3/dT [Dual64]",
+            d1[i].eps,
+            (fp[i] - fm[i]) / (2.0 * h),
+            s1,
+        );
+        push("dln_lambda3/dT [Dual2_64]", d2[i].v1, d1[i].eps, s1);
+        push("dln_lambda3/dT [Dual3_64]", d3[i].v1, d1[i].eps, s1);
+        let s2 = d1[i].eps.abs() / t;
+        push(
+            "d2ln_lambda3/dT2 [Dual2_64]",
+            d2[i].v2,
+            (d1p[i].eps - d1m[i].eps) / (2.0 * h),
+            s2,
+        );
+        push("d2ln_lambda3/dT2 [Dual3_64]", d3[i].v2, d2[i].v2, s2);
+        push(
+            "d3ln_lambda3/dT3 [Dual3_64]",
+            d3[i].v3,
+            (d2p[i].v2 - d2m[i].v2) / (2.0 * h),
+            d2[i].v2.abs() / t,
+        );
+    }
+
+    let n = ideal_gas.components();
+    if n > 1 {
+        for i in 0..n {
+            let sub = ideal_gas.subset(&[i]).ln_lambda3(t);
+            report.push(Subset, format!("ln_lambda3_{i} (pure)"), sub[0], f[i], 0.0);
+        }
+        let reversed: Vec<_> = (0..n).rev().collect();
+        let sub = ideal_gas.subset(&reversed).ln_lambda3(t);
+        for (k, &i) in reversed.iter().enumerate() {
+            report.push(
+                Subset,
+                format!("ln_lambda3_{i} (permuted)"),
+                sub[k],
+                f[i],
+                0.0,
+            );
+        }
+    }
+    report
+}
+
+/// Run all consistency checks for the residual and the ideal gas model
+/// of the state and additionally compare total properties, like the heat
+/// capacities, to finite differences.
+pub fn check_equation_of_state<E: Residual + IdealGas>(
+    state: &State<E>,
+    options: ConsistencyOptions,
+) -> EosResult<ConsistencyReport> {
+    use ConsistencyCategory::{HeatCapacity, Maxwell};
+    let mut report = check_residual(state, options)?;
+    report.extend(check_ideal_gas(
+        state.eos.as_ref(),
+        state.temperature,
+        options,
+    ));
+    let step = options.step;
+    let n = state.total_moles.to_reduced();
+
+    // isochoric heat capacity
+    let cv = state.molar_isochoric_heat_capacity(Contributions::Total) * state.total_moles;
+    let du_dt = central_difference(state, DT, step, |s| {
+        s.internal_energy(Contributions::Total).to_reduced()
+    })?;
+    report.push(
+        HeatCapacity,
+        "C_V = (dU/dT)_V".into(),
+        cv.to_reduced(),
+        du_dt,
+        0.0,
+    );
+
+    // isobaric heat capacity
+    let cp = state.molar_isobaric_heat_capacity(Contributions::Total) * state.total_moles;
+    let p = state.pressure(Contributions::Total);
+    let h = step * state.temperature;
+    let enthalpy = |t| -> EosResult<f64> {
+        let s = State::new_npt(
+            &state.eos,
+            t,
+            p,
+            &state.moles,
+            DensityInitialization::InitialDensity(state.density),
+        )?;
+        Ok(s.enthalpy(Contributions::Total).to_reduced())
+    };
+    let dh_dt = (enthalpy(state.temperature + h)? - enthalpy(state.temperature - h)?)
+        / (2.0 * h.to_reduced());
+    report.push(
+        HeatCapacity,
+        "C_p = (dH/dT)_p".into(),
+        cp.to_reduced(),
+        dh_dt,
+        0.0,
+    );
+
+    // Maxwell relation including the ideal gas contribution
+    let ds_dv = central_difference(state, DV, step, |s| {
+        s.entropy(Contributions::Total).to_reduced()
+    })?;
+    report.push(
+        Maxwell,
+        "(dp/dT)_V = (dS/dV)_T (total)".into(),
+        state.dp_dt(Contributions::Total).to_reduced(),
+        ds_dv,
+        0.0,
+    );
+
+    // chemical potentials including the ideal gas contribution
+    let mu = state.chemical_potential(Contributions::Total).to_reduced();
+    for v in variables(state).into_iter().skip(2) {
+        let DN(i) = v else { unreachable!() };
+        let da_dn = central_difference(state, v, step, |s| {
+            s.helmholtz_energy(Contributions::Total).to_reduced()
+        })?;
+        report.push(
+            ConsistencyCategory::Derivative,
+            format!("mu_{i} = dA/dN{i} (total)"),
+            mu[i],
+            da_dn,
+            state
+                .helmholtz_energy(Contributions::Total)
+                .to_reduced()
+                .abs()
+                / n,
+        );
+    }
+    Ok(report)
+}
+
+/// Derivatives from all dual number types used in [State] compared to
+/// finite differences of the next lower derivative.
+fn check_derivatives<E: Residual>(
+    state: &State<E>,
+    options: ConsistencyOptions,
+    report: &mut ConsistencyReport,
+) -> EosResult<()> {
+    let category = ConsistencyCategory::Derivative;
+    let eos = &state.eos;
+    let step = options.step;
+    let a0 = |s: &State<E>| eos.residual_helmholtz_energy(&s.derive0());
+    let a1 = |s: &State<E>, v| eos.residual_helmholtz_energy(&s.derive1(v));
+    let a2 = |s: &State<E>, v| eos.residual_helmholtz_energy(&s.derive2(v));
+    let a3 = |s: &State<E>, v| eos.residual_helmholtz_energy(&s.derive3(v));
+    let a = a0(state);
+
+    let variables = variables(state);
+    for (k, &v) in variables.iter().enumerate() {
+        let x = reduced_variable(state, v);
+        let name = variable_name(v);
+        let d1 = a1(state, v);
+        let d2 = a2(state, v);
+        let d3 = a3(state, v);
+        if k == 0 {
+            report.push(category, "A [Dual64]".into(), d1.re, a, 0.0);
+            report.push(category, "A [Dual2_64]".into(), d2.re, a, 0.0);
+            report.push(category, "A [Dual3_64]".into(), d3.re, a, 0.0);
+        }
+
+        let s1 = a.abs() / x;
+        let fd = central_difference(state, v, step, a0)?;
+        report.push(category, format!("dA/d{name} [Dual64]"), d1.eps, fd, s1);
+        report.push(
+            category,
+            format!("dA/d{name} [Dual2_64]"),
+            d2.v1,
+            d1.eps,
+            s1,
+        );
+        report.push(
+            category,
+            format!("dA/d{name} [Dual3_64]"),
+            d3.v1,
+            d1.eps,
+            s1,
+        );
+
+        let s2 = d1.eps.abs() / x;
+        let fd = central_difference(state, v, step, |s| a1(s, v).eps)?;
+        report.push(category, format!("d2A/d{name}2 [Dual2_64]"), d2.v2, fd, s2);
+        report.push(
+            category,
+            format!("d2A/d{name}2 [Dual3_64]"),
+            d3.v2,
+            d2.v2,
+            s2,
+        );
+
+        let fd = central_difference(state, v, step, |s| a2(s, v).v2)?;
+        report.push(
+            category,
+            format!("d3A/d{name}3 [Dual3_64]"),
+            d3.v3,
+            fd,
+            d2.v2.abs() / x,
+        );
+
+        for &w in &variables[k + 1..] {
+            let hd = eos.residual_helmholtz_energy(&state.derive2_mixed(v, w));
+            let name2 = variable_name(w);
+            let fd = central_difference(state, w, step, |s| a1(s, v).eps)?;
+            report.push(
+                category,
+                format!("d2A/d{name}d{name2} [HyperDual64]"),
+                hd.eps1eps2,
+                fd,
+                d1.eps.abs() / reduced_variable(state, w),
+            );
+            if k == 0 {
+                report.push(
+                    category,
+                    format!("A [HyperDual64, {name}{name2}]"),
+                    hd.re,
+                    a,
+                    0.0,
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The contributions have to sum up to the total residual Helmholtz energy
+/// (and its first derivatives).
+fn check_contributions<E: Residual>(state: &State<E>, report: &mut ConsistencyReport) {
+    use ConsistencyCategory::Contributions;
+    fn sum<D: DualNum<f64> + Copy>(contributions: &[(String, D)], f: fn(&D) -> f64) -> (f64, f64) {
+        contributions
+            .iter()
+            .fold((0.0, 0.0), |(s, a), (_, c)| (s + f(c), a + f(c).abs()))
+    }
+    let eos = &state.eos;
+    let s = state.derive0();
+    let (total, scale) = sum(&eos.residual_helmholtz_energy_contributions(&s), |&c| c);
+    report.push(
+        Contributions,
+        "sum of contributions A".into(),
+        total,
+        eos.residual_helmholtz_energy(&s),
+        scale,
+    );
+    for v in variables(state) {
+        let s = state.derive1(v);
+        let (total, scale) = sum(&eos.residual_helmholtz_energy_contributions(&s), |c| c.eps);
+        report.push(
+            Contributions,
+            format!("sum of contributions dA/d{}", variable_name(v)),
+            total,
+            eos.residual_helmholtz_energy(&s).eps,
+            scale,
+        );
+    }
+}
+
+/// Euler relation, its derivatives and the Gibbs-Duhem relations of the
+/// fugacity coefficients.
+fn check_gibbs_duhem<E: Residual>(state: &State<E>, report: &mut ConsistencyReport) {
+    use ConsistencyCategory::GibbsDuhem;
+    let n = state.eos.components();
+    let moles = state.moles.to_reduced();
+    let x = &state.molefracs;
+    let v = state.volume.to_reduced();
+    let a = state.residual_helmholtz_energy().to_reduced();
+    let p = state.pressure(Contributions::Residual).to_reduced();
+    let mu = state.residual_chemical_potential().to_reduced();
+    let dp_dv = state.dp_dv(Contributions::Residual).to_reduced();
+    let dp_dt = state.dp_dt(Contributions::Residual).to_reduced();
+    let dp_dn = state.dp_dni(Contributions::Residual).to_reduced();
+    let dmu_dt = state.dmu_res_dt().to_reduced();
+    let dmu_dn = state.dmu_dni(Contributions::Residual).to_reduced();
+
+    // Euler relation A = -pV + sum_i N_i mu_i and its derivatives
+    let nmu = &moles * &mu;
+    report.push(
+        GibbsDuhem,
+        "A = -pV + sum_i N_i mu_i".into(),
+        -p * v + nmu.sum(),
+        a,
+        (p * v).abs() + nmu.mapv(f64::abs).sum(),
+    );
+    let s = state.residual_entropy().to_reduced();
+    let ndmu = &moles * &dmu_dt;
+    report.push(
+        GibbsDuhem,
+        "-S = -V dp/dT + sum_i N_i dmu_i/dT".into(),
+        -v * dp_dt + ndmu.sum(),
+        -s,
+        (v * dp_dt).abs() + ndmu.mapv(f64::abs).sum(),
+    );
+    let ndp = &moles * &dp_dn;
+    report.push(
+        GibbsDuhem,
+        "V dp/dV = -sum_i N_i dp/dN_i".into(),
+        -ndp.sum(),
+        v * dp_dv,
+        ndp.mapv(f64::abs).sum(),
+    );
+    for j in 0..n {
+        let ndmu = &moles * &dmu_dn.column(j);
+        report.push(
+            GibbsDuhem,
+            format!("V dp/dN{j} = sum_i N_i dmu_i/dN{j}"),
+            ndmu.sum(),
+            v * dp_dn[j],
+            ndmu.mapv(f64::abs).sum(),
+        );
+    }
+
+    // fugacity coefficients
+    let rt = (RGAS * state.temperature).to_reduced();
+    let ln_phi = state.ln_phi();
+    let xln_phi = x * &ln_phi;
+    report.push(
+        GibbsDuhem,
+        "sum_i x_i ln(phi_i) = g_res/RT".into(),
+        xln_phi.sum(),
+        state.residual_molar_gibbs_energy().to_reduced() / rt,
+        xln_phi.mapv(f64::abs).sum(),
+    );
+    let t = state.temperature.to_reduced();
+    let xdln_phi = x * &state.dln_phi_dt().to_reduced();
+    report.push(
+        GibbsDuhem,
+        "sum_i x_i dln(phi_i)/dT = -h_res/RT2".into(),
+        xdln_phi.sum(),
+        -state.residual_molar_enthalpy().to_reduced() / (rt * t),
+        xdln_phi.mapv(f64::abs).sum(),
+    );
+    let p_total = state.pressure(Contributions::Total).to_reduced();
+    let xdln_phi = x * &state.dln_phi_dp().to_reduced();
+    report.push(
+        GibbsDuhem,
+        "sum_i x_i dln(phi_i)/dp = (Z-1)/p".into(),
+        xdln_phi.sum(),
+        (state.compressibility(Contributions::Total) - 1.0) / p_total,
+        xdln_phi.mapv(f64::abs).sum(),
+    );
+    let dln_phi_dn = state.dln_phi_dnj().to_reduced();
+    let total_moles = state.total_moles.to_reduced();
+    for j in 0..n {
+        let xdln_phi = x * &dln_phi_dn.column(j);
+        report.push(
+            GibbsDuhem,
+            format!("sum_i x_i dln(phi_i)/dN{j} = 0"),
+            xdln_phi.sum(),
+            0.0,
+            xdln_phi.mapv(f64::abs).sum() + 1.0 / total_moles,
+        );
+    }
+}
+
+/// Maxwell relations with one side evaluated by finite differences of first derivatives.
+fn check_maxwell<E: Residual>(
+    state: &State<E>,
+    options: ConsistencyOptions,
+    report: &mut ConsistencyReport,
+) -> EosResult<()> {
+    use ConsistencyCategory::Maxwell;
+    let step = options.step;
+    let dp_dt = state.dp_dt(Contributions::Residual).to_reduced();
+    let dp_dn = state.dp_dni(Contributions::Residual).to_reduced();
+    let dmu_dt = state.dmu_res_dt().to_reduced();
+    let dmu_dn = state.dmu_dni(Contributions::Residual).to_reduced();
+
+    let ds_dv = central_difference(state, DV, step, |s| s.residual_entropy().to_reduced())?;
+    report.push(Maxwell, "(dp/dT)_V = (dS/dV)_T".into(), dp_dt, ds_dv, 0.0);
+    let components: Vec<_> = variables(state)
+        .into_iter()
+        .filter_map(|v| if let DN(i) = v { Some(i) } else { None })
+        .collect();
+    for &i in &components {
+        let ds_dn = central_difference(state, DN(i), step, |s| s.residual_entropy().to_reduced())?;
+        report.push(
+            Maxwell,
+            format!("dmu_{i}/dT = -dS/dN{i}"),
+            dmu_dt[i],
+            -ds_dn,
+            0.0,
+        );
+        let dmu_dv = central_difference(state, DV, step, |s| {
+            s.residual_chemical_potential().to_reduced()[i]
+        })?;
+        report.push(
+            Maxwell,
+            format!("dp/dN{i} = -dmu_{i}/dV"),
+            dp_dn[i],
+            -dmu_dv,
+            0.0,
+        );
+        for &j in components.iter().filter(|&&j| j > i) {
+            let dmu_j = central_difference(state, DN(i), step, |s| {
+                s.residual_chemical_potential().to_reduced()[j]
+            })?;
+            report.push(
+                Maxwell,
+                format!("dmu_{i}/dN{j} = dmu_{j}/dN{i}"),
+                dmu_dn[[i, j]],
+                dmu_j,
+                (dmu_dn[[i, i]] * dmu_dn[[j, j]]).abs().sqrt(),
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Second and third virial coefficients compared to the low density limit
+/// of the compressibility factor.
+fn check_virial<E: Residual>(
+    state: &State<E>,
+    options: ConsistencyOptions,
+    report: &mut ConsistencyReport,
+) -> EosResult<()> {
+    use ConsistencyCategory::Virial;
+    let eos = &state.eos;
+    let t = state.temperature;
+    let moles = Some(&state.moles);
+    let b = eos.second_virial_coefficient(t, moles)?.to_reduced();
+    let c = eos.third_virial_coefficient(t, moles)?.to_reduced();
+    let db_dt = eos
+        .second_virial_coefficient_temperature_derivative(t, moles)?
+        .to_reduced();
+    let dc_dt = eos
+        .third_virial_coefficient_temperature_derivative(t, moles)?
+        .to_reduced();
+
+    // (Z-1)/rho = B + C rho + D rho^2 + O(rho^3); the linear and quadratic
+    // terms are eliminated by Richardson extrapolation from three low densities.
+    let rho0 = 3e-5 * eos.max_density(moles)?;
+    let z_res = |rho: Density| -> EosResult<f64> {
+        let s = State::new_nvt(eos, t, state.total_moles / rho, &state.moles)?;
+        Ok((s.pressure(Contributions::Residual) / (rho * rho * RGAS * t)).to_reduced())
+    };
+    let richardson = |f1: f64, f2: f64, f4: f64| (8.0 * f1 - 6.0 * f2 + f4) / 3.0;
+    let (f1, f2, f4) = (z_res(rho0)?, z_res(2.0 * rho0)?, z_res(4.0 * rho0)?);
+    report.push(
+        Virial,
+        "B = lim (Z-1)/rho".into(),
+        b,
+        richardson(f1, f2, f4),
+        0.0,
+    );
+    let rho0 = rho0.to_reduced();
+    let g = |f: f64, k: f64| (f - b) / (k * rho0);
+    report.push(
+        Virial,
+        "C = lim ((Z-1)/rho - B)/rho".into(),
+        c,
+        richardson(g(f1, 1.0), g(f2, 2.0), g(f4, 4.0)),
+        0.0,
+    );
+
+    let h = options.step * t;
+    let tr = h.to_reduced();
+    let fd_b = (eos.second_virial_coefficient(t + h, moles)?
+        - eos.second_virial_coefficient(t - h, moles)?)
+    .to_reduced()
+        / (2.0 * tr);
+    report.push(
+        Virial,
+        "dB/dT".into(),
+        db_dt,
+        fd_b,
+        b.abs() / t.to_reduced(),
+    );
+    let fd_c = (eos.third_virial_coefficient(t + h, moles)?
+        - eos.third_virial_coefficient(t - h, moles)?)
+    .to_reduced()
+        / (2.0 * tr);
+    report.push(
+        Virial,
+        "dC/dT".into(),
+        dc_dt,
+        fd_c,
+        c.abs() / t.to_reduced(),
+    );
+    Ok(())
+}
+
+/// Subsets of single components and a permutation of all components
+/// compared to the full model.
+fn check_subset<E: Residual>(state: &State<E>, report: &mut ConsistencyReport) -> EosResult<()> {
+    use ConsistencyCategory::Subset;
+    let n = state.eos.components();
+    if n == 1 {
+        return Ok(());
+    }
+    let moles = state.moles.to_reduced();
+    let properties = |s: &State<E>| {
+        (
+            s.residual_helmholtz_energy().to_reduced(),
+            s.pressure(Contributions::Residual).to_reduced(),
+            s.residual_chemical_potential().to_reduced(),
+        )
+    };
+
+    let mut compare = |name: String, component_list: &[usize], full: &State<E>| {
+        let eos = Arc::new(state.eos.subset(component_list));
+        let m = Array1::from_shape_fn(component_list.len(), |k| {
+            full.moles.get(component_list[k]).to_reduced()
+        });
+        let sub = State::new_nvt(&eos, full.temperature, full.volume, &Moles::from_reduced(m))?;
+        let (a, p, mu) = properties(full);
+        let (a_sub, p_sub, mu_sub) = properties(&sub);
+        report.push(Subset, format!("A ({name})"), a_sub, a, 0.0);
+        report.push(Subset, format!("p ({name})"), p_sub, p, 0.0);
+        for (k, &i) in component_list.iter().enumerate() {
+            report.push(Subset, format!("mu_{i} ({name})"), mu_sub[k], mu[i], 0.0);
+        }
+        EosResult::Ok(())
+    };
+
+    for i in 0..n {
+        if moles[i] > 0.0 {
+            let m = Array1::from_shape_fn(n, |k| if k == i { moles[i] } else { 0.0 });
+            let full = State::new_nvt(
+                &state.eos,
+                state.temperature,
+                state.volume,
+                &Moles::from_reduced(m),
+            )?;
+            compare(format!("pure {i}"), &[i], &full)?;
+        }
+    }
+    let reversed: Vec<_> = (0..n).rev().collect();
+    compare("permuted".into(), &reversed, state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cubic::{PengRobinson, PengRobinsonParameters, PengRobinsonRecord};
+    use crate::parameter::{Identifier, Parameter, PureRecord};
+    use crate::{Components, EquationOfState, StateHD};
+    use ndarray::{arr1, ScalarOperand};
+    use quantity::{BAR, KELVIN, MOL};
+
+    fn mixture() -> Arc<PengRobinson> {
+        let propane = PureRecord::new(
+            Identifier::default(),
+            44.0962,
+            PengRobinsonRecord::new(369.96, 4250000.0, 0.153),
+        );
+        let butane = PureRecord::new(
+            Identifier::default(),
+            58.123,
+            PengRobinsonRecord::new(425.2, 3800000.0, 0.199),
+        );
+        let parameters = PengRobinsonParameters::new_binary(vec![propane, butane], None).unwrap();
+        Arc::new(PengRobinson::new(Arc::new(parameters)))
+    }
+
+    struct ConstantHeatCapacity(Array1<f64>);
+
+    impl Components for ConstantHeatCapacity {
+        fn components(&self) -> usize {
+            self.0.len()
+        }
+
+        fn subset(&self, component_list: &[usize]) -> Self {
+            Self(component_list.iter().map(|&i| self.0[i]).collect())
+        }
+    }
+
+    impl IdealGas for ConstantHeatCapacity {
+        fn ln_lambda3<D: DualNum<f64> + Copy>(&self, temperature: D) -> Array1<D> {
+            self.0.mapv(|c| -temperature.ln() * c)
+        }
+
+        fn ideal_gas_model(&self) -> String {
+            "constant heat capacity".into()
+        }
+    }
+
+    /// A model that (incorrectly) treats the volume dependence of one
+    /// contribution as a constant for dual numbers.
+    struct Inconsistent(Arc<PengRobinson>);
+
+    impl Components for Inconsistent {
+        fn components(&self) -> usize {
+            self.0.components()
+        }
+
+        fn subset(&self, component_list: &[usize]) -> Self {
+            Self(Arc::new(self.0.subset(component_list)))
+        }
+    }
+
+    impl Residual for Inconsistent {
+        fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
+            self.0.compute_max_density(moles)
+        }
+
+        fn residual_helmholtz_energy_contributions<D: DualNum<f64> + Copy + ScalarOperand>(
+            &self,
+            state: &StateHD<D>,
+        ) -> Vec<(String, D)> {
+            let mut contributions = self.0.residual_helmholtz_energy_contributions(state);
+            let n = state.moles.sum().re();
+            contributions.push(("inconsistent".into(), D::from(n * n / state.volume.re())));
+            contributions
+        }
+    }
+
+    #[test]
+    fn consistent_residual() -> EosResult<()> {
+        let eos = mixture();
+        let moles = arr1(&[0.3, 0.7]) * MOL;
+        for (p, density_initialization) in [
+            (BAR, DensityInitialization::Vapor),
+            (50.0 * BAR, DensityInitialization::Liquid),
+        ] {
+            let state = State::new_npt(&eos, 350.0 * KELVIN, p, &moles, density_initialization)?;
+            let report = check_residual(&state, ConsistencyOptions::default())?;
+            assert!(report.passed(), "{report}");
+            for category in [
+                ConsistencyCategory::Derivative,
+                ConsistencyCategory::Contributions,
+                ConsistencyCategory::GibbsDuhem,
+                ConsistencyCategory::Maxwell,
+                ConsistencyCategory::Virial,
+                ConsistencyCategory::Subset,
+            ] {
+                assert!(!report.category(category).is_empty());
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn consistent_equation_of_state() -> EosResult<()> {
+        let eos = Arc::new(EquationOfState::new(
+            Arc::new(ConstantHeatCapacity(arr1(&[2.5, 3.5]))),
+            mixture(),
+        ));
+        let moles = arr1(&[0.3, 0.7]) * MOL;
+        let state = State::new_npt(
+            &eos,
+            350.0 * KELVIN,
+            50.0 * BAR,
+            &moles,
+            DensityInitialization::Liquid,
+        )?;
+        let report = check_equation_of_state(&state, ConsistencyOptions::default())?;
+        assert!(report.passed(), "{report}");
+        assert_eq!(report.category(ConsistencyCategory::HeatCapacity).len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn inconsistent_residual() -> EosResult<()> {
+        let eos = Arc::new(Inconsistent(mixture()));
+        let moles = arr1(&[0.3, 0.7]) * MOL;
+        let state = State::new_npt(
+            &eos,
+            350.0 * KELVIN,
+            50.0 * BAR,
+            &moles,
+            DensityInitialization::Liquid,
+        )?;
+        let report = check_residual(&state, ConsistencyOptions::default())?;
+        assert!(!report.passed());
+        let failures: Vec<_> = report.failures().iter().map(|c| c.name.clone()).collect();
+        assert!(failures.contains(&"dA/dV [Dual64]".to_string()));
+        assert!(failures.contains(&"dA/dN0 [Dual64]".to_string()));
+        assert!(!failures.contains(&"dA/dT [Dual64]".to_string()));
+        assert!(report
+            .category(ConsistencyCategory::Contributions)
+            .iter()
+            .all(|c| c.passed));
+        Ok(())
+    }
+}
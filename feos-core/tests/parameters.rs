@@ -88,7 +88,7 @@ fn from_records() -> Result<(), ParameterError> {
         &pure_records,
         &binary_records,
         IdentifierOption::Cas,
-    );
+    )?;
     let p = MyParameter::from_records(pure_records, binary_matrix)?;
 
     assert_eq!(p.pure_records[0].identifier.cas, Some("123-4-5".into()));
@@ -207,7 +207,7 @@ fn from_records_missing_binary() -> Result<(), ParameterError> {
         &pure_records,
         &binary_records,
         IdentifierOption::Cas,
-    );
+    )?;
     let p = MyParameter::from_records(pure_records, binary_matrix)?;
 
     assert_eq!(p.pure_records[0].identifier.cas, Some("123-4-5".into()));
@@ -272,7 +272,7 @@ fn from_records_correct_binary_order() -> Result<(), ParameterError> {
         &pure_records,
         &binary_records,
         IdentifierOption::Cas,
-    );
+    )?;
     let p = MyParameter::from_records(pure_records, binary_matrix)?;
 
     assert_eq!(p.pure_records[0].identifier.cas, Some("000-0-0".into()));
@@ -0,0 +1,343 @@
+use super::{Adsorption, FluidParameters};
+use crate::functional::HelmholtzEnergyFunctional;
+use feos_core::{EosError, EosResult, ReferenceSystem};
+use ndarray::{Array1, Array2, Dimension, RemoveAxis};
+use quantity::{_Pressure, Moles, Pressure, Quantity};
+use typenum::Negate;
+
+const MAX_ITER_IAST: usize = 50;
+const TOL_IAST: f64 = 1e-10;
+const TOTH_INTEGRATION_POINTS: usize = 256;
+
+pub type _InversePressure = Negate<_Pressure>;
+pub type InversePressure<T> = Quantity<T, _InversePressure>;
+
+/// Adsorption isotherm of a pure component used in the ideal adsorbed solution theory.
+#[derive(Clone)]
+pub enum PureComponentIsotherm {
+    /// Langmuir isotherm $n=n_\mathrm{sat}\frac{bp}{1+bp}$.
+    Langmuir {
+        n_sat: Moles,
+        b: InversePressure<f64>,
+    },
+    /// Toth isotherm $n=n_\mathrm{sat}\frac{bp}{\left(1+(bp)^t\right)^{1/t}}$.
+    Toth {
+        n_sat: Moles,
+        b: InversePressure<f64>,
+        t: f64,
+    },
+    /// Isotherm that is interpolated linearly between tabulated values.
+    ///
+    /// Below the lowest pressure, the adsorption is assumed to follow Henry's law,
+    /// above the highest pressure, the adsorption is kept constant.
+    Tabulated {
+        pressure: Pressure<Array1<f64>>,
+        adsorption: Moles<Array1<f64>>,
+    },
+}
+
+impl PureComponentIsotherm {
+    /// Tabulated isotherm from an adsorption isotherm of a pure component.
+    ///
+    /// Points at which the DFT calculation failed are skipped.
+    pub fn from_adsorption<D: Dimension + RemoveAxis + 'static, F>(
+        adsorption: &Adsorption<D, F>,
+    ) -> EosResult<Self>
+    where
+        F: HelmholtzEnergyFunctional + FluidParameters,
+        D::Larger: Dimension<Smaller = D>,
+        D::Smaller: Dimension<Larger = D>,
+        <D::Larger as Dimension>::Larger: Dimension<Smaller = D::Larger>,
+    {
+        if adsorption.components != 1 {
+            return Err(EosError::IncompatibleComponents(adsorption.components, 1));
+        }
+        let p = adsorption.pressure().to_reduced();
+        let n = adsorption.total_adsorption().to_reduced();
+        let mut points: Vec<_> = p
+            .into_iter()
+            .zip(n)
+            .filter(|(p, n)| p.is_finite() && n.is_finite())
+            .collect();
+        points.sort_by(|(p1, _), (p2, _)| p1.total_cmp(p2));
+        points.dedup_by(|(p1, _), (p2, _)| p1 == p2);
+        if points.len() < 2 {
+            return Err(EosError::Error(
+                "At least two converged points are required for a tabulated isotherm.".into(),
+            ));
+        }
+        let (p, n): (Vec<_>, Vec<_>) = points.into_iter().unzip();
+        Ok(Self::Tabulated {
+            pressure: Pressure::from_reduced(Array1::from_vec(p)),
+            adsorption: Moles::from_reduced(Array1::from_vec(n)),
+        })
+    }
+
+    /// Adsorbed amount at the given pressure.
+    pub fn adsorption(&self, pressure: Pressure) -> Moles {
+        Moles::from_reduced(self.adsorption_reduced(pressure.to_reduced()))
+    }
+
+    /// Reduced spreading pressure $\psi=\frac{\pi A}{RT}=\int_0^p\frac{n(p')}{p'}\mathrm{d}p'$.
+    pub fn spreading_pressure(&self, pressure: Pressure) -> Moles {
+        Moles::from_reduced(self.spreading_pressure_reduced(pressure.to_reduced()))
+    }
+
+    fn adsorption_reduced(&self, p: f64) -> f64 {
+        match self {
+            Self::Langmuir { n_sat, b } => {
+                let bp = b.to_reduced() * p;
+                n_sat.to_reduced() * bp / (1.0 + bp)
+            }
+            Self::Toth { n_sat, b, t } => {
+                let bp = b.to_reduced() * p;
+                n_sat.to_reduced() * bp / (1.0 + bp.powf(*t)).powf(1.0 / t)
+            }
+            Self::Tabulated {
+                pressure,
+                adsorption,
+            } => {
+                let (p_tab, n_tab) = (pressure.to_reduced(), adsorption.to_reduced());
+                let n = p_tab.len();
+                if p <= p_tab[0] {
+                    n_tab[0] * p / p_tab[0]
+                } else if p >= p_tab[n - 1] {
+                    n_tab[n - 1]
+                } else {
+                    let k = (1..n).find(|&k| p_tab[k] >= p).unwrap() - 1;
+                    let s = (p - p_tab[k]) / (p_tab[k + 1] - p_tab[k]);
+                    n_tab[k] + (n_tab[k + 1] - n_tab[k]) * s
+                }
+            }
+        }
+    }
+
+    fn spreading_pressure_reduced(&self, p: f64) -> f64 {
+        match self {
+            Self::Langmuir { n_sat, b } => n_sat.to_reduced() * (b.to_reduced() * p).ln_1p(),
+            Self::Toth { n_sat, b, t } => {
+                // Simpson's rule for n_sat * int_0^bp (1 + u^t)^(-1/t) du
+                let bp = b.to_reduced() * p;
+                let h = bp / TOTH_INTEGRATION_POINTS as f64;
+                let f = |u: f64| (1.0 + u.powf(*t)).powf(-1.0 / t);
+                let integral = (0..=TOTH_INTEGRATION_POINTS)
+                    .map(|k| {
+                        let w = if k == 0 || k == TOTH_INTEGRATION_POINTS {
+                            1.0
+                        } else if k % 2 == 1 {
+                            4.0
+                        } else {
+                            2.0
+                        };
+                        w * f(k as f64 * h)
+                    })
+                    .sum::<f64>()
+                    * h
+                    / 3.0;
+                n_sat.to_reduced() * integral
+            }
+            Self::Tabulated {
+                pressure,
+                adsorption,
+            } => {
+                let (p_tab, n_tab) = (pressure.to_reduced(), adsorption.to_reduced());
+                let n = p_tab.len();
+                if p <= p_tab[0] {
+                    return n_tab[0] * p / p_tab[0];
+                }
+                // exact integral of n/p for n = a + c*p on every interval
+                let integral = |k: usize, p: f64| {
+                    let c = (n_tab[k + 1] - n_tab[k]) / (p_tab[k + 1] - p_tab[k]);
+                    let a = n_tab[k] - c * p_tab[k];
+                    a * (p / p_tab[k]).ln() + c * (p - p_tab[k])
+                };
+                let mut psi = n_tab[0];
+                for k in 0..n - 1 {
+                    if p <= p_tab[k + 1] {
+                        return psi + integral(k, p);
+                    }
+                    psi += integral(k, p_tab[k + 1]);
+                }
+                psi + n_tab[n - 1] * (p / p_tab[n - 1]).ln()
+            }
+        }
+    }
+
+    /// Pressure of the pure component at which the reduced spreading pressure is `psi`.
+    fn pressure_reduced(&self, psi: f64) -> EosResult<f64> {
+        if let Self::Langmuir { n_sat, b } = self {
+            return Ok((psi / n_sat.to_reduced()).exp_m1() / b.to_reduced());
+        }
+
+        // Newton iteration in ln p using dpsi/dln(p) = n
+        let mut ln_p = match self {
+            Self::Toth { n_sat, b, .. } => (psi / n_sat.to_reduced()).exp_m1() / b.to_reduced(),
+            Self::Tabulated {
+                pressure,
+                adsorption,
+            } => psi * pressure.get(0).to_reduced() / adsorption.get(0).to_reduced(),
+            Self::Langmuir { .. } => unreachable!(),
+        }
+        .ln();
+        for _ in 0..MAX_ITER_IAST {
+            let p = ln_p.exp();
+            let n = self.adsorption_reduced(p);
+            let delta = (self.spreading_pressure_reduced(p) - psi) / n;
+            if !delta.is_finite() {
+                return Err(EosError::IterationFailed(
+                    "PureComponentIsotherm::pressure".into(),
+                ));
+            }
+            ln_p -= delta.clamp(-2.0, 2.0);
+            if delta.abs() < TOL_IAST {
+                return Ok(ln_p.exp());
+            }
+        }
+        Err(EosError::NotConverged(
+            "PureComponentIsotherm::pressure".into(),
+        ))
+    }
+}
+
+/// Ideal adsorbed solution theory (IAST) for the prediction of mixture
+/// adsorption from pure-component isotherms.
+pub struct IdealAdsorbedSolution {
+    pub isotherms: Vec<PureComponentIsotherm>,
+}
+
+impl IdealAdsorbedSolution {
+    pub fn new(isotherms: Vec<PureComponentIsotherm>) -> Self {
+        Self { isotherms }
+    }
+
+    /// Initialize IAST with tabulated isotherms from pure-component adsorption isotherms.
+    pub fn from_adsorption<D: Dimension + RemoveAxis + 'static, F>(
+        adsorption: &[Adsorption<D, F>],
+    ) -> EosResult<Self>
+    where
+        F: HelmholtzEnergyFunctional + FluidParameters,
+        D::Larger: Dimension<Smaller = D>,
+        D::Smaller: Dimension<Larger = D>,
+        <D::Larger as Dimension>::Larger: Dimension<Smaller = D::Larger>,
+    {
+        Ok(Self::new(
+            adsorption
+                .iter()
+                .map(PureComponentIsotherm::from_adsorption)
+                .collect::<EosResult<_>>()?,
+        ))
+    }
+
+    pub fn components(&self) -> usize {
+        self.isotherms.len()
+    }
+
+    /// Composition of the adsorbed phase and total adsorption.
+    fn solve(&self, pressure: f64, molefracs: &Array1<f64>) -> EosResult<(Array1<f64>, f64)> {
+        if molefracs.len() != self.components() {
+            return Err(EosError::IncompatibleComponents(
+                self.components(),
+                molefracs.len(),
+            ));
+        }
+
+        // Newton iteration for the reduced spreading pressure
+        let mut psi = self
+            .isotherms
+            .iter()
+            .zip(molefracs)
+            .map(|(iso, &y)| y * iso.spreading_pressure_reduced(pressure))
+            .sum::<f64>();
+        for _ in 0..MAX_ITER_IAST {
+            let p0 = self
+                .isotherms
+                .iter()
+                .map(|iso| iso.pressure_reduced(psi))
+                .collect::<EosResult<Array1<f64>>>()?;
+            let x = molefracs * pressure / &p0;
+            let n0 =
+                Array1::from_shape_fn(p0.len(), |i| self.isotherms[i].adsorption_reduced(p0[i]));
+            let f = x.sum() - 1.0;
+            let df = -(&x / &n0).sum();
+            let delta = f / df;
+            if !delta.is_finite() {
+                return Err(EosError::IterationFailed(
+                    "IdealAdsorbedSolution::solve".into(),
+                ));
+            }
+            psi = (psi - delta).max(0.5 * psi);
+            if delta.abs() < TOL_IAST * psi {
+                let n_total = 1.0 / (&x / &n0).sum();
+                return Ok((x, n_total));
+            }
+        }
+        Err(EosError::NotConverged(
+            "IdealAdsorbedSolution::solve".into(),
+        ))
+    }
+
+    /// Mole fractions of the adsorbed phase in equilibrium with a gas phase
+    /// with the given pressure and composition.
+    pub fn adsorbed_phase_molefracs(
+        &self,
+        pressure: Pressure,
+        molefracs: &Array1<f64>,
+    ) -> EosResult<Array1<f64>> {
+        Ok(self.solve(pressure.to_reduced(), molefracs)?.0)
+    }
+
+    /// Adsorbed amount of every component.
+    pub fn adsorption(
+        &self,
+        pressure: Pressure,
+        molefracs: &Array1<f64>,
+    ) -> EosResult<Moles<Array1<f64>>> {
+        let (x, n_total) = self.solve(pressure.to_reduced(), molefracs)?;
+        Ok(Moles::from_reduced(x * n_total))
+    }
+
+    /// Adsorption selectivities $S_{ij}=\frac{x_i/x_j}{y_i/y_j}$.
+    pub fn selectivity(
+        &self,
+        pressure: Pressure,
+        molefracs: &Array1<f64>,
+    ) -> EosResult<Array2<f64>> {
+        let (x, _) = self.solve(pressure.to_reduced(), molefracs)?;
+        let n = x.len();
+        Ok(Array2::from_shape_fn((n, n), |(i, j)| {
+            x[i] / x[j] * molefracs[j] / molefracs[i]
+        }))
+    }
+
+    /// Relative deviation $\frac{n_i^\mathrm{IAST}-n_i^\mathrm{DFT}}{n_i^\mathrm{DFT}}$
+    /// of the IAST prediction from a mixture adsorption isotherm.
+    ///
+    /// The pressure and composition of the gas phase are taken from the bulk
+    /// states of the mixture isotherm. Points at which either calculation
+    /// fails result in `NaN`.
+    pub fn deviation<D: Dimension + RemoveAxis + 'static, F>(
+        &self,
+        mixture: &Adsorption<D, F>,
+    ) -> Array2<f64>
+    where
+        F: HelmholtzEnergyFunctional + FluidParameters,
+        D::Larger: Dimension<Smaller = D>,
+        D::Smaller: Dimension<Larger = D>,
+        <D::Larger as Dimension>::Larger: Dimension<Smaller = D::Larger>,
+    {
+        let pressure = mixture.pressure();
+        let n_dft = mixture.adsorption().to_reduced();
+        let mut deviation = Array2::from_elem(n_dft.raw_dim(), f64::NAN);
+        for (i, profile) in mixture.profiles.iter().enumerate() {
+            let Ok(profile) = profile else { continue };
+            let molefracs = &profile.profile.bulk.molefracs;
+            if let Ok(n_iast) = self.adsorption(pressure.get(i), molefracs) {
+                let n_iast = n_iast.to_reduced();
+                for j in 0..n_iast.len().min(n_dft.shape()[0]) {
+                    deviation[(j, i)] = (n_iast[j] - n_dft[(j, i)]) / n_dft[(j, i)];
+                }
+            }
+        }
+        deviation
+    }
+}
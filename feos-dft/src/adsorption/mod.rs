@@ -13,11 +13,19 @@ use std::sync::Arc;
 mod external_potential;
 #[cfg(feature = "rayon")]
 mod fea_potential;
+mod iast;
 mod pore;
 mod pore2d;
+mod pore_axisymmetric;
+mod solvation_force;
+mod wetting;
 pub use external_potential::{ExternalPotential, FluidParameters};
+pub use iast::{IdealAdsorbedSolution, InversePressure, PureComponentIsotherm};
 pub use pore::{HenryCoefficient, Pore1D, PoreProfile, PoreProfile1D, PoreSpecification};
 pub use pore2d::{Pore2D, PoreProfile2D};
+pub use pore_axisymmetric::{PoreAxisymmetric, PoreProfileAxisymmetric, PoreShape};
+pub use solvation_force::SolvationForceCurve;
+pub use wetting::{WettingDiagram, WettingProfile, YoungContactAngle};
 
 #[cfg(feature = "rayon")]
 mod pore3d;
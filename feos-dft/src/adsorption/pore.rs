@@ -3,22 +3,27 @@ use crate::convolver::ConvolverFFT;
 use crate::functional::{HelmholtzEnergyFunctional, MoleculeShape};
 use crate::functional_contribution::FunctionalContribution;
 use crate::geometry::{Axis, Geometry, Grid};
-use crate::profile::{DFTProfile, MAX_POTENTIAL};
+use crate::profile::{DFTProfile, DFTProfileRecord, MAX_POTENTIAL};
 use crate::solver::DFTSolver;
 use crate::WeightFunctionInfo;
 use feos_core::{
-    Components, Contributions, EosResult, ReferenceSystem, Residual, State, StateBuilder, StateHD,
+    Components, Contributions, EosError, EosResult, ReferenceSystem, Residual, State, StateBuilder,
+    StateHD,
 };
 use ndarray::{prelude::*, ScalarOperand};
 use ndarray::{Axis as Axis_nd, RemoveAxis};
 use num_dual::linalg::LU;
 use num_dual::{Dual64, DualNum};
 use quantity::{
-    Density, Dimensionless, Energy, Length, MolarEnergy, Quantity, Temperature, Volume, _Moles,
-    _Pressure, KELVIN, RGAS,
+    _Moles, _Pressure, Density, Dimensionless, Energy, Length, MolarEnergy, Quantity, Temperature,
+    Volume, KELVIN, RGAS,
 };
 use rustdct::DctNum;
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
 use std::sync::Arc;
 use typenum::Diff;
 
@@ -35,6 +40,7 @@ pub struct Pore1D {
     pub potential: ExternalPotential,
     pub n_grid: Option<usize>,
     pub potential_cutoff: Option<f64>,
+    pub axis: Option<Axis>,
 }
 
 impl Pore1D {
@@ -51,8 +57,69 @@ impl Pore1D {
             potential,
             n_grid,
             potential_cutoff,
+            axis: None,
         }
     }
+
+    /// Use the given (possibly non-uniform) axis instead of an equidistant one.
+    ///
+    /// The axis has to have the geometry of the pore and extend from the
+    /// center of the pore to the wall. For cartesian pores, the potential
+    /// offset of the axis is added on top of the half pore width.
+    pub fn with_axis(mut self, axis: Axis) -> EosResult<Self> {
+        self.check_axis(&axis)?;
+        self.axis = Some(axis);
+        Ok(self)
+    }
+
+    fn check_axis(&self, axis: &Axis) -> EosResult<()> {
+        let length = match self.geometry {
+            Geometry::Cartesian => 0.5 * self.pore_size.to_reduced() + axis.potential_offset(),
+            Geometry::Cylindrical | Geometry::Spherical => self.pore_size.to_reduced(),
+        };
+        if axis.geometry != self.geometry || (axis.length() / length - 1.0).abs() > 1e-10 {
+            return Err(EosError::Error(
+                "The axis does not match the geometry or the size of the pore.".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Interpolate the density profile of a pore onto a new axis.
+    ///
+    /// The external potential is evaluated on the new axis.
+    pub fn regrid<F: HelmholtzEnergyFunctional + FluidParameters>(
+        &self,
+        pore: &PoreProfile1D<F>,
+        axis: Axis,
+    ) -> EosResult<PoreProfile1D<F>> {
+        self.check_axis(&axis)?;
+        let external_potential = external_potential_1d(
+            self.pore_size,
+            pore.profile.temperature,
+            &self.potential,
+            pore.profile.dft.as_ref(),
+            &axis,
+            self.potential_cutoff,
+        );
+        Ok(PoreProfile {
+            profile: pore.profile.regrid(axis, Some(external_potential))?,
+            grand_potential: None,
+            interfacial_tension: None,
+        })
+    }
+
+    /// Interpolate the density profile of a pore onto a new axis with
+    /// `points` grid points that is adapted to the density profile.
+    ///
+    /// See [DFTProfile::adapted_axis] for details.
+    pub fn refine<F: HelmholtzEnergyFunctional + FluidParameters>(
+        &self,
+        pore: &PoreProfile1D<F>,
+        points: usize,
+    ) -> EosResult<PoreProfile1D<F>> {
+        self.regrid(pore, pore.profile.adapted_axis(points)?)
+    }
 }
 
 /// Trait for the generic implementation of adsorption applications.
@@ -193,6 +260,44 @@ where
     }
 }
 
+/// Serializable representation of a [PoreProfile].
+#[derive(Serialize, Deserialize)]
+struct PoreProfileRecord {
+    profile: DFTProfileRecord,
+    grand_potential: Option<f64>,
+    interfacial_tension: Option<f64>,
+}
+
+impl<D: Dimension + RemoveAxis + 'static, F: HelmholtzEnergyFunctional> PoreProfile<D, F>
+where
+    D::Larger: Dimension<Smaller = D>,
+    D::Smaller: Dimension<Larger = D>,
+    <D::Larger as Dimension>::Larger: Dimension<Smaller = D::Larger>,
+{
+    /// Write the pore profile to a json file.
+    pub fn to_json<P: AsRef<Path>>(&self, path: P) -> EosResult<()> {
+        let record = PoreProfileRecord {
+            profile: self.profile.to_record(),
+            grand_potential: self.grand_potential.map(|o| o.to_reduced()),
+            interfacial_tension: self.interfacial_tension.map(|g| g.to_reduced()),
+        };
+        Ok(serde_json::to_writer(
+            BufWriter::new(File::create(path)?),
+            &record,
+        )?)
+    }
+
+    /// Read a pore profile from a json file for the given functional.
+    pub fn from_json<P: AsRef<Path>>(functional: &Arc<F>, path: P) -> EosResult<Self> {
+        let record: PoreProfileRecord = serde_json::from_reader(BufReader::new(File::open(path)?))?;
+        Ok(Self {
+            profile: DFTProfile::from_record(functional, record.profile)?,
+            grand_potential: record.grand_potential.map(Energy::from_reduced),
+            interfacial_tension: record.interfacial_tension.map(Energy::from_reduced),
+        })
+    }
+}
+
 impl PoreSpecification<Ix1> for Pore1D {
     fn initialize<F: HelmholtzEnergyFunctional + FluidParameters>(
         &self,
@@ -203,8 +308,9 @@ impl PoreSpecification<Ix1> for Pore1D {
         let dft: &F = &bulk.eos;
         let n_grid = self.n_grid.unwrap_or(DEFAULT_GRID_POINTS);
 
-        let axis = match self.geometry {
-            Geometry::Cartesian => {
+        let axis = match (&self.axis, self.geometry) {
+            (Some(axis), _) => axis.clone(),
+            (None, Geometry::Cartesian) => {
                 let potential_offset = POTENTIAL_OFFSET
                     * bulk
                         .eos
@@ -214,8 +320,8 @@ impl PoreSpecification<Ix1> for Pore1D {
                         .unwrap();
                 Axis::new_cartesian(n_grid, 0.5 * self.pore_size, Some(potential_offset))
             }
-            Geometry::Cylindrical => Axis::new_polar(n_grid, self.pore_size),
-            Geometry::Spherical => Axis::new_spherical(n_grid, self.pore_size),
+            (None, Geometry::Cylindrical) => Axis::new_polar(n_grid, self.pore_size),
+            (None, Geometry::Spherical) => Axis::new_spherical(n_grid, self.pore_size),
         };
 
         // calculate external potential
@@ -0,0 +1,388 @@
+use super::pore::{PoreProfile, PoreSpecification};
+use super::{ExternalPotential, FluidParameters};
+use crate::functional::HelmholtzEnergyFunctional;
+use crate::geometry::{Axis, Grid};
+use crate::profile::{DFTProfile, MAX_POTENTIAL};
+use feos_core::{EosError, EosResult, ReferenceSystem, State};
+use ndarray::prelude::*;
+use quantity::{Density, Length};
+use std::f64::consts::PI;
+
+/// Nodes and weights of the 8-point Gauss-Legendre quadrature on $[-1, 1]$.
+const GAUSS_LEGENDRE: [[f64; 2]; 8] = [
+    [-0.9602898564975363, 0.1012285362903763],
+    [-0.7966664774136267, 0.2223810344533745],
+    [-0.525532409916329, 0.3137066458778873],
+    [-0.1834346424956498, 0.362683783378362],
+    [0.1834346424956498, 0.362683783378362],
+    [0.525532409916329, 0.3137066458778873],
+    [0.7966664774136267, 0.2223810344533745],
+    [0.9602898564975363, 0.1012285362903763],
+];
+/// Radial extent (in units of $\sigma_{si}$) of the solid that is considered in the
+/// integration of the Lennard-Jones interactions.
+const SOLID_CUTOFF: f64 = 100.0;
+
+/// Radius profile of the solid surrounding an axisymmetric pore.
+///
+/// The pore is symmetric with respect to the plane $z=0$, i.e., all lengths
+/// refer to the full pore, of which only the half with $z\geq 0$ is
+/// discretized. Beyond the end of the pore, the solid is bounded by a
+/// planar face that is exposed to the reservoir.
+#[derive(Clone)]
+pub enum PoreShape {
+    /// Cylindrical pore of finite length that is open to the reservoir at both ends.
+    FiniteCylinder { radius: Length, length: Length },
+    /// Cavity that is connected to the reservoir by narrower necks at both ends.
+    InkBottle {
+        cavity_radius: Length,
+        cavity_length: Length,
+        neck_radius: Length,
+        neck_length: Length,
+    },
+    /// Cylindrical pore with a narrower constriction at its center.
+    Constriction {
+        radius: Length,
+        length: Length,
+        constriction_radius: Length,
+        constriction_length: Length,
+    },
+    /// Piecewise linear radius profile given at the (non-decreasing) positions `z`
+    /// starting from the center of the pore.
+    Profile {
+        z: Length<Array1<f64>>,
+        radius: Length<Array1<f64>>,
+    },
+}
+
+impl PoreShape {
+    /// Corner points $(z, R)$ of the radius profile in reduced units.
+    fn corners(&self) -> EosResult<Vec<[f64; 2]>> {
+        let corners = match self {
+            Self::FiniteCylinder { radius, length } => {
+                let (r, l) = (radius.to_reduced(), 0.5 * length.to_reduced());
+                vec![[0.0, r], [l, r]]
+            }
+            Self::InkBottle {
+                cavity_radius,
+                cavity_length,
+                neck_radius,
+                neck_length,
+            } => {
+                let (rc, lc) = (cavity_radius.to_reduced(), 0.5 * cavity_length.to_reduced());
+                let (rn, ln) = (neck_radius.to_reduced(), neck_length.to_reduced());
+                vec![[0.0, rc], [lc, rc], [lc, rn], [lc + ln, rn]]
+            }
+            Self::Constriction {
+                radius,
+                length,
+                constriction_radius,
+                constriction_length,
+            } => {
+                let (r, l) = (radius.to_reduced(), 0.5 * length.to_reduced());
+                let rc = constriction_radius.to_reduced();
+                let lc = 0.5 * constriction_length.to_reduced();
+                vec![[0.0, rc], [lc, rc], [lc, r], [l, r]]
+            }
+            Self::Profile { z, radius } => {
+                if z.len() != radius.len() {
+                    return Err(EosError::Error(
+                        "The radius profile requires a radius for every position!".into(),
+                    ));
+                }
+                z.to_reduced()
+                    .iter()
+                    .zip(radius.to_reduced().iter())
+                    .map(|(&z, &r)| [z, r])
+                    .collect()
+            }
+        };
+        if corners.len() < 2
+            || corners[0][0] < 0.0
+            || corners.windows(2).any(|c| c[1][0] < c[0][0])
+            || corners[corners.len() - 1][0] <= corners[0][0]
+            || corners.iter().any(|c| c[1] <= 0.0)
+        {
+            return Err(EosError::Error(
+                "The radius profile has to be given at non-decreasing positions z >= 0 with positive radii!".into(),
+            ));
+        }
+        Ok(corners)
+    }
+}
+
+/// Parameters required to specify an axisymmetric pore in cylindrical
+/// coordinates $(r, z)$.
+///
+/// The system is discretized on a cylinder with radius `system_size[0]`
+/// and length `system_size[1]`, that has to be larger than the pore, so
+/// that the reservoir at the pore mouth is included in the calculation.
+/// At $z=0$ and at the end of the system, the density profile is
+/// mirrored.
+///
+/// For [ExternalPotential::HardWall], the external potential is determined
+/// from the distance to the closest point on the surface of the solid. For
+/// [ExternalPotential::LJ93], the Lennard-Jones interactions are integrated
+/// over the volume of the solid, which reduces to the 9-3 Lennard-Jones
+/// potential for planar walls. Other potentials have to be passed to
+/// [PoreSpecification::initialize] directly.
+pub struct PoreAxisymmetric {
+    pub shape: PoreShape,
+    pub system_size: [Length; 2],
+    pub n_grid: [usize; 2],
+    pub potential: ExternalPotential,
+    pub potential_cutoff: Option<f64>,
+    pub lanczos: Option<i32>,
+}
+
+/// Density profile and properties of an axisymmetric confined system.
+pub type PoreProfileAxisymmetric<F> = PoreProfile<Ix2, F>;
+
+impl PoreAxisymmetric {
+    pub fn new(
+        shape: PoreShape,
+        system_size: [Length; 2],
+        n_grid: [usize; 2],
+        potential: ExternalPotential,
+        potential_cutoff: Option<f64>,
+        lanczos: Option<i32>,
+    ) -> Self {
+        Self {
+            shape,
+            system_size,
+            n_grid,
+            potential,
+            potential_cutoff,
+            lanczos,
+        }
+    }
+}
+
+impl PoreSpecification<Ix2> for PoreAxisymmetric {
+    fn initialize<F: HelmholtzEnergyFunctional + FluidParameters>(
+        &self,
+        bulk: &State<F>,
+        density: Option<&Density<Array3<f64>>>,
+        external_potential: Option<&Array3<f64>>,
+    ) -> EosResult<PoreProfileAxisymmetric<F>> {
+        // generate grid
+        let r = Axis::new_polar(self.n_grid[0], self.system_size[0]);
+        let z = Axis::new_cartesian(self.n_grid[1], self.system_size[1], None);
+
+        // calculate external potential
+        let external_potential = match external_potential {
+            Some(e) => e.clone(),
+            None => external_potential_axisymmetric(
+                bulk.eos.as_ref(),
+                [&r, &z],
+                self.system_size,
+                &self.shape,
+                &self.potential,
+                self.potential_cutoff,
+                bulk.temperature.to_reduced(),
+            )?,
+        };
+        let grid = Grid::Cylindrical { r, z };
+
+        Ok(PoreProfile {
+            profile: DFTProfile::new(grid, bulk, Some(external_potential), density, self.lanczos),
+            grand_potential: None,
+            interfacial_tension: None,
+        })
+    }
+}
+
+fn external_potential_axisymmetric<F: FluidParameters>(
+    functional: &F,
+    [r, z]: [&Axis; 2],
+    system_size: [Length; 2],
+    shape: &PoreShape,
+    potential: &ExternalPotential,
+    potential_cutoff: Option<f64>,
+    reduced_temperature: f64,
+) -> EosResult<Array3<f64>> {
+    if !matches!(
+        potential,
+        ExternalPotential::HardWall { .. } | ExternalPotential::LJ93 { .. }
+    ) {
+        return Err(EosError::Error(
+            "Only hard walls and 9-3 Lennard-Jones potentials are available for axisymmetric pores. Other potentials have to be passed to `initialize`!".into(),
+        ));
+    }
+    let corners = shape.corners()?;
+    let [r_max, z_max] = [system_size[0].to_reduced(), system_size[1].to_reduced()];
+    let [z_start, _] = corners[0];
+    let [z_end, _] = corners[corners.len() - 1];
+    if corners.iter().any(|&[_, r]| r >= r_max) || z_end >= z_max {
+        return Err(EosError::Error(
+            "The system has to be larger than the pore to include the reservoir!".into(),
+        ));
+    }
+
+    // surface of the solid in the (z, r) plane
+    let mut surface = Vec::with_capacity(corners.len() + 1);
+    if z_start > 0.0 {
+        surface.push([[z_start, r_max], corners[0]]);
+    }
+    surface.extend(corners.windows(2).map(|c| [c[0], c[1]]));
+    surface.push([corners[corners.len() - 1], [z_end, r_max]]);
+
+    // distance of every grid point to the surface (or `None` within the solid)
+    let distance: Vec<_> = z
+        .grid
+        .iter()
+        .flat_map(|&z| r.grid.iter().map(move |&r| [z, r]))
+        .map(|p| {
+            (!is_solid(p, &corners)).then(|| {
+                surface
+                    .iter()
+                    .map(|&s| distance_to_segment(p, s))
+                    .fold(f64::INFINITY, f64::min)
+            })
+        })
+        .collect();
+    let wall_potential = match *potential {
+        ExternalPotential::LJ93 {
+            sigma_ss,
+            epsilon_k_ss,
+            rho_s,
+        } => {
+            let points: Vec<_> = z
+                .grid
+                .iter()
+                .flat_map(|&z| r.grid.iter().map(move |&r| [z, r]))
+                .zip(distance.iter())
+                .filter_map(|(p, d)| d.map(|d| (p, d)))
+                .collect();
+            let m = functional.m();
+            let sigma_ff = functional.sigma_ff();
+            let epsilon_k_ff = functional.epsilon_k_ff();
+            Array2::from_shape_fn((m.len(), points.len()), |(i, k)| {
+                let ([z, r], d) = points[k];
+                let sigma_sf = 0.5 * (sigma_ff[i] + sigma_ss);
+                let epsilon_k_sf = (epsilon_k_ff[i] * epsilon_k_ss).sqrt();
+                let u: f64 = corners
+                    .windows(2)
+                    .filter(|c| c[1][0] > c[0][0])
+                    .map(|c| {
+                        // the solid is mirrored at z = 0
+                        lj_solid_segment([z, r], [c[0], c[1]], sigma_sf, d)
+                            + lj_solid_segment([-z, r], [c[0], c[1]], sigma_sf, d)
+                    })
+                    .sum();
+                4.0 * m[i] * epsilon_k_sf * rho_s * u / reduced_temperature
+            })
+        }
+        _ => {
+            let fluid: Array1<f64> = distance.iter().filter_map(|&d| d).collect();
+            potential.calculate_cartesian_potential(&fluid, functional, reduced_temperature)
+                / reduced_temperature
+        }
+    };
+
+    // assemble the external potential on the grid
+    let potential_cutoff = potential_cutoff.unwrap_or(MAX_POTENTIAL);
+    let (n_r, n_z) = (r.grid.len(), z.grid.len());
+    let mut external_potential =
+        Array3::from_elem((wall_potential.nrows(), n_r, n_z), potential_cutoff);
+    let mut k = 0;
+    for (i, d) in distance.iter().enumerate() {
+        if d.is_some() {
+            let (iz, ir) = (i / n_r, i % n_r);
+            for (c, &v) in wall_potential.column(k).iter().enumerate() {
+                external_potential[(c, ir, iz)] = v.min(potential_cutoff);
+            }
+            k += 1;
+        }
+    }
+    Ok(external_potential)
+}
+
+/// Check whether the point $(z, r)$ lies within the solid.
+fn is_solid([z, r]: [f64; 2], corners: &[[f64; 2]]) -> bool {
+    corners.windows(2).any(|c| {
+        let ([z0, r0], [z1, r1]) = (c[0], c[1]);
+        z1 > z0 && (z0..=z1).contains(&z) && r > r0 + (r1 - r0) * (z - z0) / (z1 - z0)
+    })
+}
+
+/// Euclidian distance between the point `p` and the line segment `s`.
+fn distance_to_segment(p: [f64; 2], [a, b]: [[f64; 2]; 2]) -> f64 {
+    let ab = [b[0] - a[0], b[1] - a[1]];
+    let ap = [p[0] - a[0], p[1] - a[1]];
+    let l2 = ab[0] * ab[0] + ab[1] * ab[1];
+    let t = if l2 > 0.0 {
+        ((ap[0] * ab[0] + ap[1] * ab[1]) / l2).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    ((ap[0] - t * ab[0]).powi(2) + (ap[1] - t * ab[1]).powi(2)).sqrt()
+}
+
+/// Lennard-Jones interactions (divided by $4\varepsilon$) of the point $(z, r)$ with
+/// a solid of unit density that occupies $r^\prime>R(z^\prime)$ for
+/// $z_0<z^\prime<z_1$, where $R$ is linear between the end points of the segment.
+///
+/// The integration over the angle is done analytically, the remaining integrals
+/// over $z^\prime$ and $r^\prime$ with Gauss-Legendre quadratures on panels, whose
+/// widths grow with the distance from the point. `distance` is the distance of
+/// the point to the surface of the solid.
+fn lj_solid_segment(
+    [z, r]: [f64; 2],
+    [[z0, r0], [z1, r1]]: [[f64; 2]; 2],
+    sigma: f64,
+    distance: f64,
+) -> f64 {
+    let h = 0.5 * distance.max(1e-3 * sigma);
+    let sigma6 = sigma.powi(6);
+    let sigma12 = sigma6 * sigma6;
+    let z_panels = graded_panels(z, h, z0, z1);
+    let s_panels = graded_panels(0.0, h, 0.0, SOLID_CUTOFF * sigma);
+    let mut u = 0.0;
+    for zp in z_panels.windows(2) {
+        let (zm, dz) = (0.5 * (zp[0] + zp[1]), 0.5 * (zp[1] - zp[0]));
+        for &[xz, wz] in GAUSS_LEGENDRE.iter() {
+            let z_s = zm + dz * xz;
+            let r_s = r0 + (r1 - r0) * (z_s - z0) / (z1 - z0);
+            let dz2 = (z - z_s).powi(2);
+            for sp in s_panels.windows(2) {
+                let (sm, ds) = (0.5 * (sp[0] + sp[1]), 0.5 * (sp[1] - sp[0]));
+                for &[xs, ws] in GAUSS_LEGENDRE.iter() {
+                    let rho = r_s + sm + ds * xs;
+                    // integral over the angle: 2 pi (a^2-b^2)^(-n/2) P_(n-1)(a / sqrt(a^2-b^2))
+                    // with the squared distance a - b cos(phi)
+                    let a = r * r + rho * rho + dz2;
+                    let c_inv = (((r - rho).powi(2) + dz2) * ((r + rho).powi(2) + dz2)).recip();
+                    let c_inv_sqrt = c_inv.sqrt();
+                    let x = a * c_inv_sqrt;
+                    let x2 = x * x;
+                    let p5 = x * (x2 * (63.0 * x2 - 70.0) + 15.0) / 8.0;
+                    let p2 = 0.5 * (3.0 * x2 - 1.0);
+                    let ring = sigma12 * c_inv.powi(3) * p5 - sigma6 * c_inv * c_inv_sqrt * p2;
+                    u += wz * dz * ws * ds * rho * ring;
+                }
+            }
+        }
+    }
+    2.0 * PI * u
+}
+
+/// Boundaries of panels in the interval $[x_\mathrm{min}, x_\mathrm{max}]$, whose widths
+/// double with increasing distance from `x0` starting from `h`.
+fn graded_panels(x0: f64, h: f64, x_min: f64, x_max: f64) -> Vec<f64> {
+    let mut panels = vec![x_min, x_max];
+    let mut dx = 0.0;
+    let mut width = h;
+    while x0 - dx > x_min || x0 + dx < x_max {
+        for x in [x0 - dx, x0 + dx] {
+            if x > x_min && x < x_max {
+                panels.push(x);
+            }
+        }
+        dx += width;
+        width *= 2.0;
+    }
+    panels.sort_by(|a, b| a.total_cmp(b));
+    panels.dedup();
+    panels
+}
@@ -0,0 +1,169 @@
+use super::{FluidParameters, Pore1D, PoreProfile1D, PoreSpecification};
+use crate::functional::HelmholtzEnergyFunctional;
+use crate::geometry::Geometry;
+use crate::solver::DFTSolver;
+use feos_core::{EosError, EosResult, ReferenceSystem, State};
+use ndarray::{Array1, Array2};
+use quantity::{Area, Density, Energy, Length, Pressure, SurfaceTension};
+use std::f64::consts::PI;
+
+/// Container structure for the calculation of solvation forces and
+/// disjoining pressures between two planar walls.
+///
+/// The density profiles are calculated in slit pores with the given widths
+/// that are all in equilibrium with the same bulk phase. Every profile is
+/// initialized with the converged profile of the previous width, so that
+/// the widths should be sorted (usually in descending order).
+pub struct SolvationForceCurve<F> {
+    pub pore_size: Length<Array1<f64>>,
+    pub profiles: Vec<EosResult<PoreProfile1D<F>>>,
+}
+
+impl<F: HelmholtzEnergyFunctional + FluidParameters> SolvationForceCurve<F> {
+    /// Calculate density profiles in slit pores for all values of `pore_size`.
+    ///
+    /// The geometry, external potential and discretization are taken from `pore`.
+    /// Pore widths for which the calculation fails result in `NaN` values for
+    /// all properties.
+    pub fn new(
+        bulk: &State<F>,
+        pore: &Pore1D,
+        pore_size: &Length<Array1<f64>>,
+        solver: Option<&DFTSolver>,
+    ) -> EosResult<Self> {
+        if pore.geometry != Geometry::Cartesian {
+            return Err(EosError::Error(
+                "Solvation forces can only be calculated in slit (cartesian) pores.".into(),
+            ));
+        }
+
+        let mut profiles: Vec<EosResult<PoreProfile1D<F>>> = Vec::with_capacity(pore_size.len());
+        for h in pore_size {
+            let pore = Pore1D::new(
+                pore.geometry,
+                h,
+                pore.potential.clone(),
+                pore.n_grid,
+                pore.potential_cutoff,
+            );
+
+            // warm start from the previous profile if available
+            let p2 = pore.initialize(bulk, None, None)?;
+            let profile = match profiles.last() {
+                Some(Ok(p)) => {
+                    let density = shift_density(p, p2.profile.grid.grids()[0], h);
+                    let external_potential = Some(&p2.profile.external_potential);
+                    pore.initialize(bulk, Some(&density), external_potential)?
+                        .solve(solver)
+                        .or_else(|_| p2.solve(solver))
+                }
+                _ => p2.solve(solver),
+            };
+            profiles.push(profile);
+        }
+
+        Ok(Self {
+            pore_size: pore_size.clone(),
+            profiles,
+        })
+    }
+
+    pub fn grand_potential(&self) -> Energy<Array1<f64>> {
+        Energy::from_shape_fn(self.profiles.len(), |i| match &self.profiles[i] {
+            Ok(p) => p.grand_potential.unwrap(),
+            Err(_) => Energy::from_reduced(f64::NAN),
+        })
+    }
+
+    /// Excess grand potential per wall area $\gamma=\frac{\Omega+pV}{2A}$.
+    ///
+    /// $\Omega$ and $V$ refer to the whole pore, so that $\gamma$ is the
+    /// interfacial tension of one of the two walls.
+    pub fn interfacial_tension(&self) -> SurfaceTension<Array1<f64>> {
+        let area = Area::from_reduced(1.0);
+        SurfaceTension::from_shape_fn(self.profiles.len(), |i| match &self.profiles[i] {
+            Ok(p) => p.interfacial_tension.unwrap() / area,
+            Err(_) => SurfaceTension::from_reduced(f64::NAN),
+        })
+    }
+
+    /// Disjoining pressure $\Pi=-\frac{1}{A}\frac{\partial\Omega}{\partial H}-p=-2\frac{\partial\gamma}{\partial H}$.
+    ///
+    /// The disjoining pressure is equal to the solvation force per wall
+    /// area. The derivative is evaluated with finite differences
+    /// (second order in the interior, first order at the boundaries) of the
+    /// interfacial tension along the sweep.
+    pub fn disjoining_pressure(&self) -> Pressure<Array1<f64>> {
+        let h = self.pore_size.to_reduced();
+        let gamma = self.interfacial_tension().to_reduced();
+        Pressure::from_reduced(-2.0 * gradient(&h, &gamma))
+    }
+
+    /// Interaction potential per wall area $W(H)=2\left(\gamma(H)-\gamma(H_\mathrm{max})\right)$.
+    ///
+    /// The widest pore in the sweep is used as reference and should be wide
+    /// enough that the films at the two walls do not interact.
+    pub fn interaction_potential(&self) -> SurfaceTension<Array1<f64>> {
+        let gamma = self.interfacial_tension();
+        let h = self.pore_size.to_reduced();
+        let i_max = (0..h.len())
+            .filter(|&i| !gamma.get(i).to_reduced().is_nan())
+            .max_by(|&i, &j| h[i].total_cmp(&h[j]));
+        match i_max {
+            Some(i) => {
+                let gamma_max = gamma.get(i);
+                SurfaceTension::from_shape_fn(h.len(), |j| 2.0 * (gamma.get(j) - gamma_max))
+            }
+            None => SurfaceTension::from_reduced(Array1::from_elem(h.len(), f64::NAN)),
+        }
+    }
+
+    /// Solvation force divided by the radius of curvature $\frac{F}{R}=2\pi W(H)$.
+    ///
+    /// The result is obtained from the Derjaguin approximation for a sphere
+    /// and a plane or two crossed cylinders, as measured in surface force
+    /// apparatus experiments.
+    pub fn solvation_force(&self) -> SurfaceTension<Array1<f64>> {
+        2.0 * PI * self.interaction_potential()
+    }
+}
+
+/// Density profile of `profile` shifted to a pore with width `pore_size`
+/// such that the density at a given distance from the wall is retained.
+fn shift_density<F: HelmholtzEnergyFunctional>(
+    profile: &PoreProfile1D<F>,
+    z: &Array1<f64>,
+    pore_size: Length,
+) -> Density<Array2<f64>> {
+    let z_old = profile.profile.grid.grids()[0];
+    let rho_old = profile.profile.density.to_reduced();
+    let shift = profile.profile.grid.axes()[0].volume() - 0.5 * pore_size.to_reduced();
+    let n = z_old.len();
+    Density::from_reduced(Array2::from_shape_fn(
+        (rho_old.shape()[0], z.len()),
+        |(s, i)| {
+            let x = (z[i] + shift).clamp(z_old[0], z_old[n - 1]);
+            let k = (1..n - 1).find(|&k| z_old[k] >= x).unwrap_or(n - 1);
+            let w = (x - z_old[k - 1]) / (z_old[k] - z_old[k - 1]);
+            (1.0 - w) * rho_old[(s, k - 1)] + w * rho_old[(s, k)]
+        },
+    ))
+}
+
+/// Derivative of `y` with respect to `x` using finite differences.
+fn gradient(x: &Array1<f64>, y: &Array1<f64>) -> Array1<f64> {
+    let n = x.len();
+    Array1::from_shape_fn(n, |i| {
+        if n < 2 {
+            f64::NAN
+        } else if i == 0 {
+            (y[1] - y[0]) / (x[1] - x[0])
+        } else if i == n - 1 {
+            (y[n - 1] - y[n - 2]) / (x[n - 1] - x[n - 2])
+        } else {
+            let (h1, h2) = (x[i] - x[i - 1], x[i + 1] - x[i]);
+            (h1 * h1 * y[i + 1] - h2 * h2 * y[i - 1] + (h2 * h2 - h1 * h1) * y[i])
+                / (h1 * h2 * (h1 + h2))
+        }
+    })
+}
@@ -0,0 +1,558 @@
+use super::{Adsorption, Adsorption1D, ExternalPotential, FluidParameters, Pore1D};
+use super::{PoreProfile1D, PoreSpecification};
+use crate::functional::HelmholtzEnergyFunctional;
+use crate::geometry::{Axis, Geometry, Grid};
+use crate::interface::PlanarInterface;
+use crate::profile::{DFTProfile, DFTSpecifications, MAX_POTENTIAL};
+use crate::solver::DFTSolver;
+use feos_core::{
+    Contributions, EosError, EosResult, PhaseEquilibrium, ReferenceSystem, SolverOptions, State,
+    StateBuilder,
+};
+use nalgebra::Matrix4;
+use ndarray::{Array1, Array2, Array3, Ix2};
+use quantity::{
+    Angle, Area, Density, Length, Moles, Pressure, SurfaceTension, Temperature, RADIANS,
+};
+use std::sync::Arc;
+
+const POTENTIAL_OFFSET: f64 = 2.0;
+const WALL_LAYER: f64 = 3.0;
+const DEFAULT_GRID_POINTS: usize = 2048;
+const MAX_ITER_PREWETTING: usize = 50;
+const TOL_PREWETTING: f64 = 1e-8;
+
+/// Density profile of a vapor-liquid interface in contact with a wall.
+///
+/// The wall is located at the end of the first axis and the liquid initially occupies
+/// the lower half of the second axis, so that the planar vapor-liquid interface is
+/// perpendicular to the wall. The total number of particles is kept constant during the
+/// iteration which allows the interface to relax into a meniscus with the equilibrium
+/// contact angle.
+pub struct WettingProfile<F> {
+    pub profile: DFTProfile<Ix2, F>,
+    pub vle: PhaseEquilibrium<F, 2>,
+    pub contact_angle: Option<Angle>,
+}
+
+impl<F> Clone for WettingProfile<F> {
+    fn clone(&self) -> Self {
+        Self {
+            profile: self.profile.clone(),
+            vle: self.vle.clone(),
+            contact_angle: self.contact_angle,
+        }
+    }
+}
+
+impl<F: HelmholtzEnergyFunctional + FluidParameters> WettingProfile<F> {
+    pub fn new(
+        vle: &PhaseEquilibrium<F, 2>,
+        potential: &ExternalPotential,
+        system_size: [Length; 2],
+        n_grid: [usize; 2],
+        potential_cutoff: Option<f64>,
+    ) -> Self {
+        let dft = &vle.liquid().eos;
+        let t = vle.liquid().temperature.to_reduced();
+        let sigma_max = dft.sigma_ff().iter().fold(0.0, |s: f64, &x| s.max(x));
+
+        // generate grid
+        let x = Axis::new_cartesian(
+            n_grid[0],
+            system_size[0],
+            Some(POTENTIAL_OFFSET * sigma_max),
+        );
+        let y = Axis::new_cartesian(n_grid[1], system_size[1], None);
+
+        // calculate external potential as a function of the distance to the wall
+        let wall = system_size[0].to_reduced();
+        let potential_cutoff = potential_cutoff.unwrap_or(MAX_POTENTIAL);
+        let potential_wall =
+            potential.calculate_cartesian_potential(&(wall - &x.grid), dft.as_ref(), t) / t;
+        let external_potential = Array3::from_shape_fn(
+            (potential_wall.shape()[0], n_grid[0], n_grid[1]),
+            |(s, i, _)| {
+                if x.grid[i] > wall {
+                    potential_cutoff
+                } else {
+                    potential_wall[(s, i)].min(potential_cutoff)
+                }
+            },
+        );
+
+        // initialize a planar interface perpendicular to the wall
+        let indices = dft.component_index();
+        let rho_l = vle.liquid().partial_density.to_reduced();
+        let rho_v = vle.vapor().partial_density.to_reduced();
+        let y0 = 0.5 * system_size[1].to_reduced();
+        let density = Array3::from_shape_fn(external_potential.raw_dim(), |(s, i, j)| {
+            let (rho_l, rho_v) = (rho_l[indices[s]], rho_v[indices[s]]);
+            let rho = 0.5 * (rho_l + rho_v)
+                - 0.5 * (rho_l - rho_v) * ((y.grid[j] - y0) / sigma_max).tanh();
+            rho * (-external_potential[(s, i, j)]).exp().min(1.0)
+        });
+
+        let grid = Grid::Cartesian2(x, y);
+        let mut profile = DFTProfile::new(
+            grid,
+            vle.liquid(),
+            Some(external_potential),
+            Some(&Density::from_reduced(density)),
+            Some(1),
+        );
+        profile.specification = DFTSpecifications::total_moles_from_profile(&profile);
+
+        Self {
+            profile,
+            vle: vle.clone(),
+            contact_angle: None,
+        }
+    }
+
+    pub fn solve_inplace(&mut self, solver: Option<&DFTSolver>, debug: bool) -> EosResult<()> {
+        // Solve the profile
+        self.profile.solve(solver, debug)?;
+
+        // postprocess
+        self.contact_angle = Some(self.contact_angle_from_profile()?);
+
+        Ok(())
+    }
+
+    pub fn solve(mut self, solver: Option<&DFTSolver>) -> EosResult<Self> {
+        self.solve_inplace(solver, false)?;
+        Ok(self)
+    }
+
+    /// Distance from the wall of every grid point on the first axis.
+    fn wall_distance(&self) -> Array1<f64> {
+        let x = &self.profile.grid.axes()[0];
+        let wall = x.length() - POTENTIAL_OFFSET * self.sigma_max();
+        wall - &x.grid
+    }
+
+    fn sigma_max(&self) -> f64 {
+        self.profile
+            .dft
+            .sigma_ff()
+            .iter()
+            .fold(0.0, |s: f64, &x| s.max(x))
+    }
+
+    /// Total segment density and its value in the middle of the interface.
+    fn segment_density(&self) -> (Array2<f64>, f64) {
+        let m = self.profile.dft.m();
+        let indices = self.profile.dft.component_index();
+        let density = self.profile.density.to_reduced();
+        let rho_l = self.vle.liquid().partial_density.to_reduced();
+        let rho_v = self.vle.vapor().partial_density.to_reduced();
+        let shape = density.shape();
+        let mut rho = Array2::zeros((shape[1], shape[2]));
+        let mut rho_mid = 0.0;
+        for (s, (rho_s, &m)) in density.outer_iter().zip(m.iter()).enumerate() {
+            rho.scaled_add(m, &rho_s);
+            rho_mid += 0.5 * m * (rho_l[indices[s]] + rho_v[indices[s]]);
+        }
+        (rho, rho_mid)
+    }
+
+    /// Points (distance to the wall, position along the wall) on the contour
+    /// at which the density is halfway between the vapor and liquid densities.
+    fn contour(&self) -> Vec<[f64; 2]> {
+        let (rho, rho_mid) = self.segment_density();
+        let d = self.wall_distance();
+        let y = &self.profile.grid.grids()[1];
+        let d_min = WALL_LAYER * self.sigma_max();
+        let (nx, ny) = rho.dim();
+        let mut points = Vec::new();
+        for i in 0..nx {
+            for j in 0..ny {
+                let f = rho[(i, j)] - rho_mid;
+                if i + 1 < nx {
+                    let f1 = rho[(i + 1, j)] - rho_mid;
+                    if f * f1 < 0.0 {
+                        points.push([d[i] + f / (f - f1) * (d[i + 1] - d[i]), y[j]]);
+                    }
+                }
+                if j + 1 < ny {
+                    let f1 = rho[(i, j + 1)] - rho_mid;
+                    if f * f1 < 0.0 {
+                        points.push([d[i], y[j] + f / (f - f1) * (y[j + 1] - y[j])]);
+                    }
+                }
+            }
+        }
+        points.retain(|&[d, _]| d > d_min);
+        points
+    }
+
+    /// Calculate the contact angle from the shape of the density profile.
+    ///
+    /// A circle (or a straight line) is fitted to the contour of the mean density
+    /// outside of the layers adsorbed at the wall. The contact angle is the angle
+    /// between the fit and the wall at the position of the wall.
+    fn contact_angle_from_profile(&self) -> EosResult<Angle> {
+        let points = self.contour();
+        if points.len() < 5 {
+            return Err(EosError::Error(
+                "Not enough points on the contour of the interface to determine the contact angle."
+                    .into(),
+            ));
+        }
+
+        // center and scale points for a well-conditioned fit
+        let n = points.len() as f64;
+        let d_mean = points.iter().map(|p| p[0]).sum::<f64>() / n;
+        let y_mean = points.iter().map(|p| p[1]).sum::<f64>() / n;
+        let scale = (points
+            .iter()
+            .map(|p| (p[0] - d_mean).powi(2) + (p[1] - y_mean).powi(2))
+            .sum::<f64>()
+            / n)
+            .sqrt();
+        let uv: Vec<_> = points
+            .iter()
+            .map(|p| [(p[0] - d_mean) / scale, (p[1] - y_mean) / scale])
+            .collect();
+
+        // fit a(u² + v²) + bu + cv + e = 0 in the least squares sense
+        let mut scatter = Matrix4::zeros();
+        for &[u, v] in &uv {
+            let z = nalgebra::Vector4::new(u * u + v * v, u, v, 1.0);
+            scatter += z * z.transpose();
+        }
+        let eigen = scatter.symmetric_eigen();
+        let k = eigen.eigenvalues.imin();
+        let [a, b, c, e] = [0, 1, 2, 3].map(|i| eigen.eigenvectors[(i, k)]);
+
+        // contour point closest to the wall
+        let [u_p, v_p] = *uv.iter().min_by(|p1, p2| p1[0].total_cmp(&p2[0])).unwrap();
+
+        // intersection of the fit with the wall
+        let u_w = -d_mean / scale;
+        let e_w = a * u_w * u_w + b * u_w + e;
+        let v_w = if a.abs() < f64::EPSILON * (b.abs() + c.abs()) {
+            -e_w / c
+        } else {
+            let discriminant = c * c - 4.0 * a * e_w;
+            if discriminant < 0.0 {
+                return Err(EosError::Error(
+                    "The fitted contour of the interface does not intersect the wall.".into(),
+                ));
+            }
+            let v1 = (-c + discriminant.sqrt()) / (2.0 * a);
+            let v2 = (-c - discriminant.sqrt()) / (2.0 * a);
+            if (v1 - v_p).abs() < (v2 - v_p).abs() {
+                v1
+            } else {
+                v2
+            }
+        };
+
+        // orientation of the normal vector (pointing from the liquid to the vapor)
+        let normal = |u: f64, v: f64| {
+            let (nu, nv) = (2.0 * a * u + b, 2.0 * a * v + c);
+            let norm = (nu * nu + nv * nv).sqrt();
+            [nu / norm, nv / norm]
+        };
+        let [nu, nv] = normal(u_p, v_p);
+        let h =
+            2.0 * self.profile.grid.axes()[0].edges[1].max(self.profile.grid.axes()[1].edges[1]);
+        let sample = |sign: f64| {
+            self.density_at(
+                u_p * scale + d_mean + sign * h * nu,
+                v_p * scale + y_mean + sign * h * nv,
+            )
+        };
+        let sign = if sample(1.0) < sample(-1.0) {
+            1.0
+        } else {
+            -1.0
+        };
+
+        // the contact angle is measured through the liquid
+        let [nu, _] = normal(u_w, v_w);
+        Ok(Angle::acos((sign * nu).clamp(-1.0, 1.0)))
+    }
+
+    /// Total segment density at the grid point closest to the given position.
+    fn density_at(&self, d: f64, y: f64) -> f64 {
+        let (rho, _) = self.segment_density();
+        let d_grid = self.wall_distance();
+        let y_grid = &self.profile.grid.grids()[1];
+        let closest = |grid: &Array1<f64>, x: f64| {
+            (0..grid.len())
+                .min_by(|&i, &j| (grid[i] - x).abs().total_cmp(&(grid[j] - x).abs()))
+                .unwrap()
+        };
+        rho[(closest(&d_grid, d), closest(y_grid, y))]
+    }
+}
+
+/// Interfacial tensions and contact angle according to Young's equation.
+///
+/// The wall-liquid and wall-vapor tensions are calculated from 1D density
+/// profiles in a slit pore that is in equilibrium with the coexisting liquid
+/// and vapor phases, respectively.
+#[derive(Clone, Copy)]
+pub struct YoungContactAngle {
+    pub temperature: Temperature,
+    pub wall_liquid_tension: SurfaceTension,
+    pub wall_vapor_tension: SurfaceTension,
+    pub surface_tension: SurfaceTension,
+}
+
+impl YoungContactAngle {
+    pub fn new<F: HelmholtzEnergyFunctional + FluidParameters>(
+        vle: &PhaseEquilibrium<F, 2>,
+        pore: &Pore1D,
+        solver: Option<&DFTSolver>,
+    ) -> EosResult<Self> {
+        if pore.geometry != Geometry::Cartesian {
+            return Err(EosError::Error(
+                "Young's equation requires a planar (cartesian) wall.".into(),
+            ));
+        }
+        let area = Area::from_reduced(1.0);
+        let wall_tension = |bulk: &State<F>| -> EosResult<SurfaceTension> {
+            let profile = pore.initialize(bulk, None, None)?.solve(solver)?;
+            Ok(profile.interfacial_tension.unwrap() / area)
+        };
+        let wall_liquid_tension = wall_tension(vle.liquid())?;
+        let wall_vapor_tension = wall_tension(vle.vapor())?;
+
+        // initialize with pDGT for single segments and tanh for mixtures and segment DFT
+        let interface = if vle.vapor().eos.component_index().len() == 1 {
+            PlanarInterface::from_pdgt(vle, DEFAULT_GRID_POINTS, false)?
+        } else {
+            PlanarInterface::from_tanh(
+                vle,
+                DEFAULT_GRID_POINTS,
+                Length::from_reduced(100.0),
+                Temperature::from_reduced(500.0),
+                false,
+            )
+        }
+        .solve(solver)?;
+
+        Ok(Self {
+            temperature: vle.vapor().temperature,
+            wall_liquid_tension,
+            wall_vapor_tension,
+            surface_tension: interface.surface_tension.unwrap(),
+        })
+    }
+
+    /// Cosine of the contact angle $\cos\theta=\frac{\gamma_\mathrm{wv}-\gamma_\mathrm{wl}}{\gamma_\mathrm{lv}}$.
+    ///
+    /// Values larger than 1 (smaller than -1) indicate that the wall is
+    /// completely wet (dry).
+    pub fn cos_contact_angle(&self) -> f64 {
+        ((self.wall_vapor_tension - self.wall_liquid_tension) / self.surface_tension).into_value()
+    }
+
+    /// Contact angle $\theta$ measured through the liquid.
+    pub fn contact_angle(&self) -> Angle {
+        Angle::acos(self.cos_contact_angle().clamp(-1.0, 1.0))
+    }
+}
+
+/// Container structure for the calculation of contact angles along the
+/// vapor-liquid coexistence curve.
+pub struct WettingDiagram {
+    pub contact_angles: Vec<YoungContactAngle>,
+}
+
+impl WettingDiagram {
+    /// Calculate contact angles for all phase equilibria in `dia`.
+    ///
+    /// Phase equilibria for which the calculation fails are skipped.
+    pub fn new<F: HelmholtzEnergyFunctional + FluidParameters>(
+        dia: &[PhaseEquilibrium<F, 2>],
+        pore: &Pore1D,
+        solver: Option<&DFTSolver>,
+    ) -> Self {
+        let contact_angles = dia
+            .iter()
+            .filter(|vle| !PhaseEquilibrium::is_trivial_solution(vle.vapor(), vle.liquid()))
+            .filter_map(|vle| YoungContactAngle::new(vle, pore, solver).ok())
+            .collect();
+        Self { contact_angles }
+    }
+
+    pub fn temperature(&self) -> Temperature<Array1<f64>> {
+        self.contact_angles.iter().map(|c| c.temperature).collect()
+    }
+
+    pub fn cos_contact_angle(&self) -> Array1<f64> {
+        self.contact_angles
+            .iter()
+            .map(|c| c.cos_contact_angle())
+            .collect()
+    }
+
+    pub fn contact_angle(&self) -> Angle<Array1<f64>> {
+        Angle::new(
+            self.contact_angles
+                .iter()
+                .map(|c| c.contact_angle().convert_into(RADIANS))
+                .collect(),
+        )
+    }
+
+    /// Temperature at which the contact angle vanishes.
+    ///
+    /// The wetting temperature is linearly interpolated between the lowest
+    /// temperature at which the wall is completely wet and the preceding one.
+    /// Returns `None` if no wetting transition is found in the temperature range.
+    pub fn wetting_temperature(&self) -> Option<Temperature> {
+        let cos = self.cos_contact_angle();
+        let t = self.temperature();
+        (1..cos.len())
+            .find(|&i| cos[i - 1] < 1.0 && cos[i] >= 1.0)
+            .map(|i| {
+                t.get(i - 1)
+                    + (t.get(i) - t.get(i - 1)) * (1.0 - cos[i - 1]) / (cos[i] - cos[i - 1])
+            })
+    }
+
+    /// Calculate the prewetting line of a pure fluid.
+    ///
+    /// For every temperature, the thin-thick film transition is calculated
+    /// with [Adsorption::prewetting_transition] starting from 99% of the
+    /// saturation pressure. Temperatures for which no transition is found
+    /// result in `NaN`.
+    pub fn prewetting_line<F: HelmholtzEnergyFunctional + FluidParameters>(
+        functional: &Arc<F>,
+        temperature: &Temperature<Array1<f64>>,
+        pore: &Pore1D,
+        film_thickness: Length,
+        solver: Option<&DFTSolver>,
+    ) -> Pressure<Array1<f64>> {
+        let mut ratio = 0.99;
+        Pressure::from_shape_fn(temperature.len(), |i| {
+            let t = temperature.get(i);
+            PhaseEquilibrium::pure(functional, t, None, SolverOptions::default())
+                .and_then(|vle| {
+                    let p_sat = vle.vapor().pressure(Contributions::Total);
+                    let p = Adsorption::prewetting_transition(
+                        functional,
+                        t,
+                        p_sat * ratio,
+                        pore,
+                        film_thickness,
+                        None,
+                        solver,
+                        SolverOptions::default(),
+                    )?
+                    .pressure()
+                    .get(0);
+                    ratio = (p / p_sat).into_value();
+                    Ok(p)
+                })
+                .unwrap_or(Pressure::from_reduced(f64::NAN))
+        })
+    }
+}
+
+impl<F: HelmholtzEnergyFunctional + FluidParameters> Adsorption1D<F> {
+    /// Calculate the prewetting transition between a thin and a thick film at a wall.
+    ///
+    /// The thick film is initialized with liquid density up to `film_thickness`
+    /// from the wall. The pore should be wide enough that the films at opposing
+    /// walls do not interact. The result contains the thin and the thick film
+    /// at the transition pressure.
+    #[expect(clippy::too_many_arguments)]
+    pub fn prewetting_transition(
+        functional: &Arc<F>,
+        temperature: Temperature,
+        pressure: Pressure,
+        pore: &Pore1D,
+        film_thickness: Length,
+        molefracs: Option<&Array1<f64>>,
+        solver: Option<&DFTSolver>,
+        options: SolverOptions,
+    ) -> EosResult<Self> {
+        let moles = functional
+            .validate_moles(molefracs.map(|x| Moles::from_reduced(x.clone())).as_ref())?;
+
+        // calculate thin and thick films at the initial pressure
+        let mut bulk = StateBuilder::new(functional)
+            .temperature(temperature)
+            .pressure(pressure)
+            .moles(&moles)
+            .vapor()
+            .build()?;
+        let liquid = StateBuilder::new(functional)
+            .temperature(temperature)
+            .pressure(pressure)
+            .moles(&moles)
+            .liquid()
+            .build()?;
+        let mut thin = pore.initialize(&bulk, None, None)?;
+        let film = film_density(&thin, &bulk, &liquid, pore, film_thickness);
+        let mut thick =
+            pore.initialize(&bulk, Some(&film), Some(&thin.profile.external_potential))?;
+        let mut rho = bulk.density;
+
+        for _ in 0..options.max_iter.unwrap_or(MAX_ITER_PREWETTING) {
+            thin = thin.update_bulk(&bulk).solve(solver)?;
+            thick = thick.update_bulk(&bulk).solve(solver)?;
+
+            // calculate moles
+            let delta_n = thick.profile.moles() - thin.profile.moles();
+            let n_dp_drho = (&delta_n * bulk.dp_drho(Contributions::Total)).sum();
+            if (delta_n.sum() / thick.profile.total_moles())
+                .into_value()
+                .abs()
+                < TOL_PREWETTING
+            {
+                return Err(EosError::TrivialSolution);
+            }
+
+            // Newton step
+            let delta_rho = (thick.grand_potential.unwrap() - thin.grand_potential.unwrap())
+                / n_dp_drho
+                * bulk.density;
+            if delta_rho.to_reduced().abs() < options.tol.unwrap_or(TOL_PREWETTING) {
+                return Ok(Adsorption::new(functional, vec![Ok(thin), Ok(thick)]));
+            }
+            rho += delta_rho;
+
+            // update bulk phase
+            bulk = State::new_nvt(functional, temperature, moles.sum() / rho, &moles)?;
+        }
+        Err(EosError::NotConverged(
+            "Adsorption::prewetting_transition".into(),
+        ))
+    }
+}
+
+/// Initial density of a liquid film with given thickness at the wall of a 1D pore.
+fn film_density<F: HelmholtzEnergyFunctional + FluidParameters>(
+    profile: &PoreProfile1D<F>,
+    vapor: &State<F>,
+    liquid: &State<F>,
+    pore: &Pore1D,
+    film_thickness: Length,
+) -> Density<Array2<f64>> {
+    let wall = match pore.geometry {
+        Geometry::Cartesian => 0.5 * pore.pore_size,
+        Geometry::Cylindrical | Geometry::Spherical => pore.pore_size,
+    }
+    .to_reduced();
+    let indices = profile.profile.dft.component_index();
+    let z = profile.profile.grid.grids()[0];
+    let density = profile.profile.density.to_reduced();
+    let rho_v = vapor.partial_density.to_reduced();
+    let rho_l = liquid.partial_density.to_reduced();
+    let film_thickness = film_thickness.to_reduced();
+    Density::from_reduced(Array2::from_shape_fn(density.raw_dim(), |(s, i)| {
+        if wall - z[i] < film_thickness {
+            density[(s, i)] * rho_l[indices[s]] / rho_v[indices[s]]
+        } else {
+            density[(s, i)]
+        }
+    }))
+}
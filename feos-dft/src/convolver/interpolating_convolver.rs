@@ -0,0 +1,120 @@
+use super::{Convolver, ConvolverFFT};
+use crate::geometry::{Axis, CubicInterpolation, Geometry, Grid};
+use crate::weight_functions::{WeightFunction, WeightFunctionInfo};
+use feos_core::ReferenceSystem;
+use ndarray::{Array, Dimension, RemoveAxis, ScalarOperand};
+use num_dual::DualNum;
+use quantity::Length;
+use rustdct::DctNum;
+use std::sync::Arc;
+
+/// Number of cells of the auxiliary grid per (smallest) kernel radius.
+const CELLS_PER_KERNEL_RADIUS: f64 = 32.0;
+
+/// Convolver for one-dimensional systems on non-uniform grids.
+///
+/// The profiles are interpolated onto an equidistant auxiliary grid.
+/// The convolution is performed with FFT on the auxiliary grid and the
+/// results are interpolated back onto the original grid points.
+///
+/// The cell size of the auxiliary grid is determined by the weight
+/// functions rather than by the smallest cell of the non-uniform grid:
+/// it corresponds to the smallest kernel radius divided by
+/// [CELLS_PER_KERNEL_RADIUS], unless all cells of the original grid
+/// are larger than that. Very small cells, e.g., close to walls, therefore
+/// do not increase the cost of the convolutions.
+pub(super) struct InterpolatingConvolver<T, D> {
+    convolver: Arc<dyn Convolver<T, D>>,
+    to_auxiliary: CubicInterpolation,
+    to_original: CubicInterpolation,
+}
+
+impl<T, D: Dimension + RemoveAxis + 'static> InterpolatingConvolver<T, D>
+where
+    T: DctNum + DualNum<f64> + ScalarOperand,
+    D::Larger: Dimension<Smaller = D>,
+    D::Smaller: Dimension<Larger = D>,
+    <D::Larger as Dimension>::Larger: Dimension<Smaller = D::Larger>,
+{
+    #[expect(clippy::new_ret_no_self)]
+    pub(super) fn new(
+        axis: &Axis,
+        weight_functions: &[WeightFunctionInfo<T>],
+        lanczos: Option<i32>,
+    ) -> Arc<dyn Convolver<T, D>> {
+        let length = axis.length();
+        let points = (length / auxiliary_cell_size(axis, weight_functions) - 1e-8).ceil() as usize;
+        let auxiliary_axis = match axis.geometry {
+            Geometry::Cartesian => Axis::new_cartesian(points, Length::from_reduced(length), None),
+            Geometry::Cylindrical => Axis::new_polar(points, Length::from_reduced(length)),
+            Geometry::Spherical => Axis::new_spherical(points, Length::from_reduced(length)),
+        };
+        Arc::new(Self {
+            to_auxiliary: CubicInterpolation::new(&axis.grid, &auxiliary_axis.grid),
+            to_original: CubicInterpolation::new(&auxiliary_axis.grid, &axis.grid),
+            convolver: ConvolverFFT::plan(&Grid::new_1d(auxiliary_axis), weight_functions, lanczos),
+        })
+    }
+}
+
+/// Cell size of the auxiliary grid for the given axis and weight functions.
+fn auxiliary_cell_size<T: DualNum<f64>>(
+    axis: &Axis,
+    weight_functions: &[WeightFunctionInfo<T>],
+) -> f64 {
+    let min_cell_size = axis
+        .edges
+        .windows(2)
+        .into_iter()
+        .map(|e| e[1] - e[0])
+        .fold(f64::INFINITY, f64::min);
+    let min_kernel_radius = weight_functions
+        .iter()
+        .flat_map(|wf| {
+            wf.scalar_component_weighted_densities
+                .iter()
+                .chain(&wf.vector_component_weighted_densities)
+                .chain(&wf.scalar_fmt_weighted_densities)
+                .chain(&wf.vector_fmt_weighted_densities)
+        })
+        .flat_map(|w| w.kernel_radius.iter().map(|r| r.re()))
+        .filter(|&r| r > 0.0)
+        .fold(f64::INFINITY, f64::min);
+    if min_kernel_radius.is_finite() {
+        min_cell_size.max(min_kernel_radius / CELLS_PER_KERNEL_RADIUS)
+    } else {
+        min_cell_size
+    }
+}
+
+impl<T, D: Dimension> Convolver<T, D> for InterpolatingConvolver<T, D>
+where
+    T: DualNum<f64> + Copy + Send + Sync,
+{
+    fn convolve(&self, profile: Array<T, D>, weight_function: &WeightFunction<T>) -> Array<T, D> {
+        let profile = self.to_auxiliary.apply(profile.view(), 0);
+        let result = self.convolver.convolve(profile, weight_function);
+        self.to_original.apply(result.view(), 0)
+    }
+
+    fn weighted_densities(&self, density: &Array<T, D::Larger>) -> Vec<Array<T, D::Larger>> {
+        let density = self.to_auxiliary.apply(density.view(), 1);
+        self.convolver
+            .weighted_densities(&density)
+            .into_iter()
+            .map(|wd| self.to_original.apply(wd.view(), 1))
+            .collect()
+    }
+
+    fn functional_derivative(
+        &self,
+        partial_derivatives: &[Array<T, D::Larger>],
+    ) -> Array<T, D::Larger> {
+        let partial_derivatives: Vec<_> = partial_derivatives
+            .iter()
+            .map(|pd| self.to_auxiliary.apply(pd.view(), 1))
+            .collect();
+        let functional_derivative = self.convolver.functional_derivative(&partial_derivatives);
+        self.to_original.apply(functional_derivative.view(), 1)
+    }
+}
@@ -9,8 +9,10 @@ use rustdct::DctNum;
 use std::ops::{AddAssign, MulAssign, SubAssign};
 use std::sync::Arc;
 
+mod interpolating_convolver;
 mod periodic_convolver;
 mod transform;
+use interpolating_convolver::InterpolatingConvolver;
 pub use periodic_convolver::PeriodicConvolver;
 use transform::*;
 
@@ -134,12 +136,18 @@ where
     <D::Larger as Dimension>::Larger: Dimension<Smaller = D::Larger>,
 {
     /// Create the appropriate FFT convolver for the given grid.
+    ///
+    /// One-dimensional cartesian and spherical grids with non-uniform
+    /// axes are convolved on an equidistant auxiliary grid.
     pub fn plan(
         grid: &Grid,
         weight_functions: &[WeightFunctionInfo<T>],
         lanczos: Option<i32>,
     ) -> Arc<dyn Convolver<T, D>> {
         match grid {
+            Grid::Cartesian1(r) | Grid::Spherical(r) if !r.is_equidistant() => {
+                InterpolatingConvolver::new(r, weight_functions, lanczos)
+            }
             Grid::Polar(r) => CurvilinearConvolver::new(r, &[], weight_functions, lanczos),
             Grid::Spherical(r) => CurvilinearConvolver::new(r, &[], weight_functions, lanczos),
             Grid::Cartesian1(z) => Self::new(Some(z), &[], weight_functions, lanczos),
@@ -1,6 +1,8 @@
-use feos_core::ReferenceSystem;
-use ndarray::{Array1, Array2};
+use feos_core::{EosError, EosResult, ReferenceSystem};
+use ndarray::{Array, Array1, Array2, ArrayView, Axis as Axis_nd, Dimension};
+use num_dual::DualNum;
 use quantity::{Angle, Length, Quantity};
+use serde::{Deserialize, Serialize};
 use std::f64::consts::{FRAC_PI_3, PI};
 
 /// Grids with up to three dimensions.
@@ -72,7 +74,7 @@ impl Grid {
 }
 
 /// Geometries of individual axes.
-#[derive(Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "python", pyo3::pyclass(eq))]
 pub enum Geometry {
     Cartesian,
@@ -92,7 +94,7 @@ impl Geometry {
 }
 
 /// An individual discretized axis.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Axis {
     pub geometry: Geometry,
     pub grid: Array1<f64>,
@@ -140,6 +142,122 @@ impl Axis {
         }
     }
 
+    /// Create a new (non-uniform) cartesian axis from the edges of the cells.
+    ///
+    /// The edges have to start at 0 and be strictly increasing. The
+    /// potential_offset is discretized with cells of (approximately) the
+    /// mean cell size. Non-uniform axes are only supported for
+    /// one-dimensional grids.
+    pub fn new_cartesian_from_edges(
+        edges: &Length<Array1<f64>>,
+        potential_offset: Option<f64>,
+    ) -> EosResult<Self> {
+        let potential_offset = potential_offset.unwrap_or(0.0);
+        let mut edges = edges.to_reduced().to_vec();
+        let n = edges.len();
+        if n > 1 && potential_offset > 0.0 && edges[n - 1] > edges[0] {
+            let l = edges[n - 1];
+            let cells = (potential_offset / (l - edges[0]) * (n - 1) as f64).ceil();
+            let cell_size = potential_offset / cells;
+            edges.extend((1..=cells as usize).map(|i| l + i as f64 * cell_size));
+        }
+        Self::from_edges(Geometry::Cartesian, edges.into(), potential_offset)
+    }
+
+    /// Create a new (non-uniform) spherical axis from the edges of the cells.
+    ///
+    /// The edges have to start at 0 and be strictly increasing. Non-uniform
+    /// axes are only supported for one-dimensional grids.
+    pub fn new_spherical_from_edges(edges: &Length<Array1<f64>>) -> EosResult<Self> {
+        Self::from_edges(Geometry::Spherical, edges.to_reduced(), 0.0)
+    }
+
+    fn from_edges(
+        geometry: Geometry,
+        edges: Array1<f64>,
+        potential_offset: f64,
+    ) -> EosResult<Self> {
+        if edges.len() < 2 || edges[0] != 0.0 || edges.windows(2).into_iter().any(|e| e[1] <= e[0])
+        {
+            return Err(EosError::Error(
+                "The edges of an axis have to start at 0 and be strictly increasing.".into(),
+            ));
+        }
+        let n = edges.len() - 1;
+        let grid = Array1::from_shape_fn(n, |i| 0.5 * (edges[i] + edges[i + 1]));
+        let integration_weights =
+            match geometry {
+                Geometry::Cartesian => Array1::from_shape_fn(n, |i| edges[i + 1] - edges[i]),
+                Geometry::Spherical => Array1::from_shape_fn(n, |i| {
+                    4.0 * FRAC_PI_3 * (edges[i + 1].powi(3) - edges[i].powi(3))
+                }),
+                Geometry::Cylindrical => return Err(EosError::Error(
+                    "Non-uniform axes are only available for cartesian and spherical geometries."
+                        .into(),
+                )),
+            };
+        Ok(Self {
+            geometry,
+            grid,
+            edges,
+            integration_weights,
+            potential_offset,
+        })
+    }
+
+    /// Create a new axis with the same geometry and length that
+    /// equidistributes the given monitor function.
+    ///
+    /// The monitor function is evaluated at the grid points of `self`
+    /// and has to be positive. Regions in which the monitor function
+    /// is large are resolved with smaller cells. Only cartesian and
+    /// spherical axes can be adapted.
+    pub fn adapt(&self, points: usize, monitor: &Array1<f64>) -> EosResult<Self> {
+        let n = self.grid.len();
+        if monitor.len() != n {
+            return Err(EosError::Error(format!(
+                "The monitor function has {} values, but the axis has {n} grid points.",
+                monitor.len()
+            )));
+        }
+        if points == 0 || monitor.iter().any(|&m| !(m > 0.0 && m.is_finite())) {
+            return Err(EosError::Error(
+                "The monitor function has to be positive and the number of points nonzero.".into(),
+            ));
+        }
+
+        // cumulative integral of the monitor function at the edges
+        let mut cumulative = Vec::with_capacity(self.edges.len());
+        cumulative.push(0.0);
+        for (i, m) in monitor.iter().enumerate() {
+            cumulative.push(cumulative[i] + m * (self.edges[i + 1] - self.edges[i]));
+        }
+        let total = cumulative[n];
+
+        // place new edges at equal increments of the cumulative integral
+        let mut k = 0;
+        let mut edges = Array1::zeros(points + 1);
+        for (j, e) in edges.iter_mut().enumerate().skip(1) {
+            let target = total * j as f64 / points as f64;
+            while k < n - 1 && cumulative[k + 1] < target {
+                k += 1;
+            }
+            let w = (target - cumulative[k]) / (cumulative[k + 1] - cumulative[k]);
+            *e = self.edges[k] + w * (self.edges[k + 1] - self.edges[k]);
+        }
+        edges[points] = self.edges[n];
+        Self::from_edges(self.geometry, edges, self.potential_offset)
+    }
+
+    /// Returns `true` if all cells of the axis have the same width.
+    pub fn is_equidistant(&self) -> bool {
+        let cell_size = self.edges[1] - self.edges[0];
+        self.edges
+            .windows(2)
+            .into_iter()
+            .all(|e| ((e[1] - e[0]) / cell_size - 1.0).abs() < 1e-10)
+    }
+
     /// Create a new logarithmically scaled cylindrical axis.
     pub fn new_polar(points: usize, length: Length) -> Self {
         let l = length.to_reduced();
@@ -183,6 +301,12 @@ impl Axis {
         }
     }
 
+    /// Returns the offset that is added to the axis to make sure that
+    /// particles can not interact through walls.
+    pub fn potential_offset(&self) -> f64 {
+        self.potential_offset
+    }
+
     /// Returns the total length of the axis.
     ///
     /// This includes the `potential_offset` and used e.g.
@@ -219,7 +343,9 @@ impl Axis {
                 n - 1
             } else {
                 match self.geometry {
-                    Geometry::Cartesian | Geometry::Spherical => (x / self.edges[1]) as usize,
+                    Geometry::Cartesian | Geometry::Spherical => {
+                        self.edges.iter().skip(1).take_while(|&&e| e <= x).count()
+                    }
                     Geometry::Cylindrical => {
                         if x < self.edges[1] {
                             0
@@ -235,3 +361,78 @@ impl Axis {
         ))
     }
 }
+
+/// Piecewise cubic Hermite interpolation between two sets of grid points.
+///
+/// The derivatives at the grid points are approximated with second order
+/// finite differences and set to zero at the boundaries, which is
+/// consistent with the reflecting boundary conditions of the Fourier
+/// transforms. Points outside of the original grid are extrapolated with
+/// the value of the closest grid point.
+pub(crate) struct CubicInterpolation {
+    weights: Vec<[(usize, f64); 4]>,
+}
+
+impl CubicInterpolation {
+    /// Precalculate the interpolation from `x` to `x_new`.
+    pub(crate) fn new(x: &Array1<f64>, x_new: &Array1<f64>) -> Self {
+        let n = x.len();
+
+        // finite difference weights of the derivative at x[k] for the
+        // points k-1, k and k+1
+        let derivative = |k: usize| {
+            if k == 0 || k == n - 1 {
+                [0.0; 3]
+            } else {
+                let (h_l, h_r) = (x[k] - x[k - 1], x[k + 1] - x[k]);
+                let d = h_l * h_r * (h_l + h_r);
+                [-h_r * h_r / d, (h_r * h_r - h_l * h_l) / d, h_l * h_l / d]
+            }
+        };
+
+        let weights = x_new
+            .iter()
+            .map(|&x_new| {
+                let k = x.iter().take_while(|&&x| x <= x_new).count();
+                if k == 0 || k == n {
+                    let k = k.min(n - 1);
+                    return [(k, 1.0), (k, 0.0), (k, 0.0), (k, 0.0)];
+                }
+                let k = k - 1;
+                let h = x[k + 1] - x[k];
+                let t = (x_new - x[k]) / h;
+                let h00 = (1.0 + 2.0 * t) * (1.0 - t) * (1.0 - t);
+                let h10 = t * (1.0 - t) * (1.0 - t) * h;
+                let h01 = t * t * (3.0 - 2.0 * t);
+                let h11 = t * t * (t - 1.0) * h;
+                let [a0, a1, a2] = derivative(k);
+                let [b0, b1, b2] = derivative(k + 1);
+                [
+                    (k.saturating_sub(1), h10 * a0),
+                    (k, h00 + h10 * a1 + h11 * b0),
+                    (k + 1, h01 + h10 * a2 + h11 * b1),
+                    ((k + 2).min(n - 1), h11 * b2),
+                ]
+            })
+            .collect();
+        Self { weights }
+    }
+
+    /// Interpolate the array `f` along the given axis.
+    pub(crate) fn apply<T, D>(&self, f: ArrayView<T, D>, axis: usize) -> Array<T, D>
+    where
+        T: DualNum<f64> + Copy,
+        D: Dimension,
+    {
+        let axis = Axis_nd(axis);
+        let mut shape = f.raw_dim();
+        shape[axis.index()] = self.weights.len();
+        let mut result = Array::zeros(shape);
+        for (mut l_new, l) in result.lanes_mut(axis).into_iter().zip(f.lanes(axis)) {
+            for (y, w) in l_new.iter_mut().zip(self.weights.iter()) {
+                *y = w.iter().fold(T::zero(), |acc, &(k, w)| acc + l[k] * w);
+            }
+        }
+        result
+    }
+}
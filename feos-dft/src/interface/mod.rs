@@ -1,12 +1,19 @@
 //! Density profiles at planar interfaces and interfacial tensions.
 use crate::functional::HelmholtzEnergyFunctional;
-use crate::geometry::{Axis, Grid};
+use crate::geometry::{Axis, Geometry, Grid};
 use crate::pdgt::PdgtFunctionalProperties;
-use crate::profile::{DFTProfile, DFTSpecifications};
+use crate::profile::{DFTProfile, DFTProfileRecord, DFTSpecifications};
 use crate::solver::DFTSolver;
-use feos_core::{Contributions, EosError, EosResult, PhaseEquilibrium, ReferenceSystem};
+use feos_core::{
+    Contributions, EosError, EosResult, PhaseEquilibrium, ReferenceSystem, StateBuilder,
+};
 use ndarray::{s, Array1, Array2, Axis as Axis_nd, Ix1};
 use quantity::{Area, Density, Length, Moles, SurfaceTension, Temperature};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+use std::sync::Arc;
 
 mod surface_tension_diagram;
 pub use surface_tension_diagram::SurfaceTensionDiagram;
@@ -75,6 +82,39 @@ impl<F: HelmholtzEnergyFunctional> PlanarInterface<F> {
         }
     }
 
+    /// Create a planar interface on a given (possibly non-uniform) cartesian axis.
+    pub fn from_axis(vle: &PhaseEquilibrium<F, 2>, axis: Axis) -> EosResult<Self> {
+        if axis.geometry != Geometry::Cartesian {
+            return Err(EosError::Error(
+                "Planar interfaces require a cartesian axis.".into(),
+            ));
+        }
+        Ok(Self {
+            profile: DFTProfile::new(Grid::Cartesian1(axis), vle.vapor(), None, None, None),
+            vle: vle.clone(),
+            surface_tension: None,
+            equimolar_radius: None,
+        })
+    }
+
+    /// Interpolate the density profile onto a new cartesian axis.
+    pub fn regrid(&self, axis: Axis) -> EosResult<Self> {
+        Ok(Self {
+            profile: self.profile.regrid(axis, None)?,
+            vle: self.vle.clone(),
+            surface_tension: None,
+            equimolar_radius: None,
+        })
+    }
+
+    /// Interpolate the density profile onto a new axis with `points`
+    /// grid points that is adapted to the density profile.
+    ///
+    /// See [DFTProfile::adapted_axis] for details.
+    pub fn refine(&self, points: usize) -> EosResult<Self> {
+        self.regrid(self.profile.adapted_axis(points)?)
+    }
+
     pub fn from_tanh(
         vle: &PhaseEquilibrium<F, 2>,
         n_grid: usize,
@@ -234,7 +274,6 @@ impl<F: HelmholtzEnergyFunctional> PlanarInterface<F> {
         let s = self.profile.density.shape();
         let rho = self.profile.density.sum_axis(Axis_nd(0)).to_reduced();
         let z = self.profile.grid.grids()[0];
-        let dz = z[1] - z[0];
 
         let limits = (0.9_f64, 0.1_f64);
         let (limit_upper, limit_lower) = if limits.0 > limits.1 {
@@ -301,11 +340,11 @@ impl<F: HelmholtzEnergyFunctional> PlanarInterface<F> {
         let z_upper = z[index_upper_plus - 1]
             + (rho_upper - rho[index_upper_plus - 1])
                 / (rho[index_upper_plus] - rho[index_upper_plus - 1])
-                * dz;
+                * (z[index_upper_plus] - z[index_upper_plus - 1]);
         let z_lower = z[index_lower_plus - 1]
             + (rho_lower - rho[index_lower_plus - 1])
                 / (rho[index_lower_plus] - rho[index_lower_plus - 1])
-                * dz;
+                * (z[index_lower_plus] - z[index_lower_plus - 1]);
 
         // Return
         Ok(Length::from_reduced(z_lower - z_upper))
@@ -341,6 +380,62 @@ impl<F: HelmholtzEnergyFunctional> PlanarInterface<F> {
     }
 }
 
+/// Serializable representation of a [PlanarInterface].
+#[derive(Serialize, Deserialize)]
+struct PlanarInterfaceRecord {
+    profile: DFTProfileRecord,
+    vapor_density: Array1<f64>,
+    liquid_density: Array1<f64>,
+    surface_tension: Option<f64>,
+    equimolar_radius: Option<f64>,
+}
+
+impl<F: HelmholtzEnergyFunctional> PlanarInterface<F> {
+    /// Write the interface to a json file.
+    pub fn to_json<P: AsRef<Path>>(&self, path: P) -> EosResult<()> {
+        let record = PlanarInterfaceRecord {
+            profile: self.profile.to_record(),
+            vapor_density: self.vle.vapor().partial_density.to_reduced(),
+            liquid_density: self.vle.liquid().partial_density.to_reduced(),
+            surface_tension: self.surface_tension.map(|g| g.to_reduced()),
+            equimolar_radius: self.equimolar_radius.map(|r| r.to_reduced()),
+        };
+        Ok(serde_json::to_writer(
+            BufWriter::new(File::create(path)?),
+            &record,
+        )?)
+    }
+
+    /// Read an interface from a json file for the given functional.
+    ///
+    /// The phase equilibrium is recalculated at the temperature and pressure
+    /// of the stored vapor phase.
+    pub fn from_json<P: AsRef<Path>>(functional: &Arc<F>, path: P) -> EosResult<Self> {
+        let record: PlanarInterfaceRecord =
+            serde_json::from_reader(BufReader::new(File::open(path)?))?;
+        let profile = DFTProfile::from_record(functional, record.profile)?;
+        let temperature = profile.temperature;
+        let vapor = StateBuilder::new(functional)
+            .temperature(temperature)
+            .partial_density(&Density::from_reduced(record.vapor_density))
+            .build()?;
+        let liquid_moles = Moles::from_reduced(record.liquid_density);
+        let vle = PhaseEquilibrium::new_npt(
+            functional,
+            temperature,
+            vapor.pressure(Contributions::Total),
+            &vapor.moles,
+            &liquid_moles,
+        )?;
+        Ok(Self {
+            profile,
+            vle,
+            surface_tension: record.surface_tension.map(SurfaceTension::from_reduced),
+            equimolar_radius: record.equimolar_radius.map(Length::from_reduced),
+        })
+    }
+}
+
 fn interp_symmetric<F: HelmholtzEnergyFunctional>(
     vle_pdgt: &PhaseEquilibrium<F, 2>,
     z_pdgt: Length<Array1<f64>>,
@@ -0,0 +1,175 @@
+use super::{DFTProfile, DFTSpecifications};
+use crate::functional::HelmholtzEnergyFunctional;
+use crate::geometry::{Axis, Grid};
+use feos_core::{EosError, EosResult, ReferenceSystem, State, StateBuilder};
+use ndarray::{Array1, ArrayD, Dimension, RemoveAxis};
+use quantity::{Angle, Density, Temperature, RADIANS};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+use std::sync::Arc;
+
+/// Serializable representation of a [Grid] with angles in radians.
+#[derive(Serialize, Deserialize)]
+enum GridRecord {
+    Cartesian1(Axis),
+    Cartesian2(Axis, Axis),
+    Periodical2(Axis, Axis, f64),
+    Cartesian3(Axis, Axis, Axis),
+    Periodical3(Axis, Axis, Axis, [f64; 3]),
+    Spherical(Axis),
+    Polar(Axis),
+    Cylindrical { r: Axis, z: Axis },
+}
+
+impl From<&Grid> for GridRecord {
+    fn from(grid: &Grid) -> Self {
+        let rad = |a: &Angle| a.convert_into(RADIANS);
+        match grid.clone() {
+            Grid::Cartesian1(x) => Self::Cartesian1(x),
+            Grid::Cartesian2(x, y) => Self::Cartesian2(x, y),
+            Grid::Periodical2(x, y, alpha) => Self::Periodical2(x, y, rad(&alpha)),
+            Grid::Cartesian3(x, y, z) => Self::Cartesian3(x, y, z),
+            Grid::Periodical3(x, y, z, angles) => {
+                Self::Periodical3(x, y, z, angles.map(|a| rad(&a)))
+            }
+            Grid::Spherical(r) => Self::Spherical(r),
+            Grid::Polar(r) => Self::Polar(r),
+            Grid::Cylindrical { r, z } => Self::Cylindrical { r, z },
+        }
+    }
+}
+
+impl From<GridRecord> for Grid {
+    fn from(record: GridRecord) -> Self {
+        match record {
+            GridRecord::Cartesian1(x) => Self::Cartesian1(x),
+            GridRecord::Cartesian2(x, y) => Self::Cartesian2(x, y),
+            GridRecord::Periodical2(x, y, alpha) => Self::Periodical2(x, y, alpha * RADIANS),
+            GridRecord::Cartesian3(x, y, z) => Self::Cartesian3(x, y, z),
+            GridRecord::Periodical3(x, y, z, angles) => {
+                Self::Periodical3(x, y, z, angles.map(|a| a * RADIANS))
+            }
+            GridRecord::Spherical(r) => Self::Spherical(r),
+            GridRecord::Polar(r) => Self::Polar(r),
+            GridRecord::Cylindrical { r, z } => Self::Cylindrical { r, z },
+        }
+    }
+}
+
+/// Serializable representation of a [DFTProfile].
+///
+/// All quantities are stored in reduced units. The functional itself is not
+/// part of the record and has to be provided when the profile is restored.
+#[derive(Serialize, Deserialize)]
+pub struct DFTProfileRecord {
+    grid: GridRecord,
+    temperature: f64,
+    bulk_density: Array1<f64>,
+    density: ArrayD<f64>,
+    external_potential: ArrayD<f64>,
+    specification: Option<DFTSpecifications>,
+    lanczos: Option<i32>,
+}
+
+impl DFTProfileRecord {
+    /// Read a record from a json file.
+    pub fn from_json<P: AsRef<Path>>(path: P) -> EosResult<Self> {
+        Ok(serde_json::from_reader(BufReader::new(File::open(path)?))?)
+    }
+
+    /// Write the record to a json file.
+    pub fn to_json<P: AsRef<Path>>(&self, path: P) -> EosResult<()> {
+        Ok(serde_json::to_writer(
+            BufWriter::new(File::create(path)?),
+            self,
+        )?)
+    }
+}
+
+impl<D: Dimension + RemoveAxis + 'static, F: HelmholtzEnergyFunctional> DFTProfile<D, F>
+where
+    D::Larger: Dimension<Smaller = D>,
+    D::Smaller: Dimension<Larger = D>,
+    <D::Larger as Dimension>::Larger: Dimension<Smaller = D::Larger>,
+{
+    /// Create a serializable record of the density profile.
+    ///
+    /// Specifications other than [DFTSpecifications] are not stored and
+    /// result in a grand canonical profile after restoring.
+    pub fn to_record(&self) -> DFTProfileRecord {
+        DFTProfileRecord {
+            grid: (&self.grid).into(),
+            temperature: self.temperature.to_reduced(),
+            bulk_density: self.bulk.partial_density.to_reduced(),
+            density: self.density.to_reduced().into_dyn(),
+            external_potential: self.external_potential.clone().into_dyn(),
+            specification: self.specification.as_specifications().cloned(),
+            lanczos: self.lanczos,
+        }
+    }
+
+    /// Restore a density profile from a record for the given functional.
+    ///
+    /// The bulk state is recalculated from the stored temperature and partial
+    /// densities. The weight functions are evaluated again, so that the
+    /// profile can directly be used to restart the solver.
+    pub fn from_record(functional: &Arc<F>, record: DFTProfileRecord) -> EosResult<Self> {
+        let components = functional.components();
+        if record.bulk_density.len() != components {
+            return Err(EosError::IncompatibleComponents(
+                components,
+                record.bulk_density.len(),
+            ));
+        }
+        let segments = functional.component_index().len();
+        if record.density.shape()[0] != segments {
+            return Err(EosError::Error(format!(
+                "The functional has {segments} segments while the stored profile has {}.",
+                record.density.shape()[0]
+            )));
+        }
+        let wrong_shape = |_| EosError::Error("Invalid dimension of the stored profile.".into());
+        let density = record.density.into_dimensionality().map_err(wrong_shape)?;
+        let external_potential = record
+            .external_potential
+            .into_dimensionality()
+            .map_err(wrong_shape)?;
+
+        let bulk = bulk_state(functional, record.temperature, record.bulk_density)?;
+        let mut profile = Self::new(
+            record.grid.into(),
+            &bulk,
+            Some(external_potential),
+            Some(&Density::from_reduced(density)),
+            record.lanczos,
+        );
+        if let Some(specification) = record.specification {
+            profile.specification = Arc::new(specification);
+        }
+        Ok(profile)
+    }
+
+    /// Write the density profile to a json file.
+    pub fn to_json<P: AsRef<Path>>(&self, path: P) -> EosResult<()> {
+        self.to_record().to_json(path)
+    }
+
+    /// Read a density profile from a json file for the given functional.
+    pub fn from_json<P: AsRef<Path>>(functional: &Arc<F>, path: P) -> EosResult<Self> {
+        Self::from_record(functional, DFTProfileRecord::from_json(path)?)
+    }
+}
+
+/// Bulk state from reduced temperature and partial densities.
+fn bulk_state<F: HelmholtzEnergyFunctional>(
+    functional: &Arc<F>,
+    temperature: f64,
+    partial_density: Array1<f64>,
+) -> EosResult<State<F>> {
+    StateBuilder::new(functional)
+        .temperature(Temperature::from_reduced(temperature))
+        .partial_density(&Density::from_reduced(partial_density))
+        .build()
+}
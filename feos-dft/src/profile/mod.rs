@@ -1,19 +1,22 @@
 use crate::convolver::{BulkConvolver, Convolver, ConvolverFFT};
 use crate::functional::HelmholtzEnergyFunctional;
-use crate::geometry::Grid;
+use crate::geometry::{Axis, CubicInterpolation, Grid};
 use crate::solver::{DFTSolver, DFTSolverLog};
 use feos_core::{EosError, EosResult, ReferenceSystem, State};
 use ndarray::{
-    Array, Array1, Array2, Array3, ArrayBase, Axis as Axis_nd, Data, Dimension, Ix1, Ix2, Ix3,
+    s, Array, Array1, Array2, Array3, ArrayBase, Axis as Axis_nd, Data, Dimension, Ix1, Ix2, Ix3,
     RemoveAxis,
 };
 use num_dual::DualNum;
-use quantity::{Density, Length, Moles, Quantity, Temperature, Volume, _Volume, DEGREES};
+use quantity::{_Volume, Density, Length, Moles, Quantity, Temperature, Volume, DEGREES};
+use serde::{Deserialize, Serialize};
 use std::ops::{Add, MulAssign};
 use std::sync::Arc;
 use typenum::Sum;
 
+mod checkpoint;
 mod properties;
+pub use checkpoint::DFTProfileRecord;
 
 pub(crate) const MAX_POTENTIAL: f64 = 50.0;
 #[cfg(feature = "rayon")]
@@ -31,9 +34,18 @@ pub trait DFTSpecification<D: Dimension, F>: Send + Sync {
         bulk_density: &Array1<f64>,
         z: &Array1<f64>,
     ) -> EosResult<Array1<f64>>;
+
+    /// Return the specification as one of the common [DFTSpecifications].
+    ///
+    /// Only specifications that return a value here are stored
+    /// when a profile is written to a file.
+    fn as_specifications(&self) -> Option<&DFTSpecifications> {
+        None
+    }
 }
 
 /// Common specifications for the grand potentials in a DFT calculation.
+#[derive(Clone, Serialize, Deserialize)]
 pub enum DFTSpecifications {
     /// DFT with specified chemical potential.
     ChemicalPotential,
@@ -95,6 +107,10 @@ impl<D: Dimension, F: HelmholtzEnergyFunctional> DFTSpecification<D, F> for DFTS
             }
         })
     }
+
+    fn as_specifications(&self) -> Option<&DFTSpecifications> {
+        Some(self)
+    }
 }
 
 /// A one-, two-, or three-dimensional density profile.
@@ -258,6 +274,85 @@ where
     }
 }
 
+impl<F: HelmholtzEnergyFunctional> DFTProfile<Ix1, F> {
+    /// Interpolate the density profile onto a new axis.
+    ///
+    /// The external potential is not interpolated, because it is usually
+    /// poorly resolved close to walls. Instead, it has to be evaluated on
+    /// the new axis by the caller (it is set to 0 if `None`). The bulk
+    /// state and the specification are retained.
+    pub fn regrid(&self, axis: Axis, external_potential: Option<Array2<f64>>) -> EosResult<Self> {
+        let old_axis = self.grid.axes()[0];
+        if axis.geometry != old_axis.geometry {
+            return Err(EosError::Error(
+                "The new axis has to have the same geometry as the profile.".into(),
+            ));
+        }
+        if let Some(external_potential) = &external_potential {
+            let shape = [self.density.shape()[0], axis.grid.len()];
+            if external_potential.shape() != shape {
+                return Err(EosError::Error(format!(
+                    "The external potential has shape {:?}, expected {:?}.",
+                    external_potential.shape(),
+                    shape
+                )));
+            }
+        }
+        let interpolation = CubicInterpolation::new(&old_axis.grid, &axis.grid);
+        let mut density = interpolation.apply(self.density.to_reduced().view(), 1);
+
+        // Cells in which the external potential is overwhelming are excluded from
+        // the iteration and the interpolation can overshoot to negative values
+        // close to walls. In both cases, the density is reinitialized from the
+        // bulk density.
+        if let Some(external_potential) = &external_potential {
+            let bulk_density = self.bulk.partial_density.to_reduced();
+            let component_index = self.dft.component_index();
+            for (((s, _), rho), &v) in density.indexed_iter_mut().zip(external_potential) {
+                if v + f64::EPSILON >= MAX_POTENTIAL || *rho <= 0.0 {
+                    *rho = bulk_density[component_index[s]] * (-v).exp();
+                }
+            }
+        }
+        let mut profile = Self::new(
+            Grid::new_1d(axis),
+            &self.bulk,
+            external_potential,
+            Some(&Density::from_reduced(density)),
+            self.lanczos,
+        );
+        profile.specification = self.specification.clone();
+        Ok(profile)
+    }
+
+    /// Create a new axis that is adapted to the current density profile.
+    ///
+    /// Half of the grid points of the new (non-uniform) axis are distributed
+    /// uniformly, the other half according to the square root of the
+    /// absolute gradient of the total density profile. Compared to the
+    /// gradient itself, the square root also refines the tails of interfaces
+    /// and density oscillations close to walls.
+    pub fn adapted_axis(&self, points: usize) -> EosResult<Axis> {
+        let axis = self.grid.axes()[0];
+        let z = &axis.grid;
+        let n = z.len();
+        let rho = self.density.to_reduced().sum_axis(Axis_nd(0));
+        let gradient = Array1::from_shape_fn(n, |i| {
+            let (k0, k1) = (i.saturating_sub(1), (i + 1).min(n - 1));
+            ((rho[k1] - rho[k0]) / (z[k1] - z[k0])).abs().sqrt()
+        });
+        let length = axis.length();
+        let integral =
+            (&gradient * &(&axis.edges.slice(s![1..]) - &axis.edges.slice(s![..-1]))).sum();
+        let monitor = if integral > 0.0 {
+            gradient * (length / integral) + 1.0
+        } else {
+            Array1::ones(n)
+        };
+        axis.adapt(points, &monitor)
+    }
+}
+
 impl<D: Dimension, F: HelmholtzEnergyFunctional> DFTProfile<D, F>
 where
     D::Larger: Dimension<Smaller = D>,
@@ -446,6 +541,9 @@ where
             .bond_integrals(temperature, &exp_dfdrho, &self.convolver);
         let mut rho_projected = &exp_dfdrho * bonds;
 
+        // integrals required for the calculation of the bulk densities
+        let z = self.integrate_reduced_comp(&rho_projected);
+
         // multiply bulk density
         rho_projected
             .outer_iter_mut()
@@ -468,7 +566,6 @@ where
             .for_each(|(r, _)| *r = 0.0);
 
         // additional residuals for the calculation of the bulk densities
-        let z = self.integrate_reduced_comp(&rho_projected);
         let res_bulk = bulk_density
             - self
                 .specification
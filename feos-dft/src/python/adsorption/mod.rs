@@ -1,7 +1,9 @@
 mod external_potential;
 mod pore;
+mod pore_shape;
 
 pub use external_potential::PyExternalPotential;
+pub use pore_shape::PyPoreShape;
 
 #[macro_export]
 macro_rules! impl_adsorption {
@@ -10,11 +12,22 @@ macro_rules! impl_adsorption {
         #[pyclass(name = "Adsorption1D")]
         pub struct PyAdsorption1D(Adsorption1D<$func>);
 
+        /// Container structure for adsorption isotherms in axisymmetric pores.
+        #[pyclass(name = "AdsorptionAxisymmetric")]
+        pub struct PyAdsorptionAxisymmetric(Adsorption<ndarray::Ix2, $func>);
+
         /// Container structure for adsorption isotherms in 3D pores.
         #[pyclass(name = "Adsorption3D")]
         pub struct PyAdsorption3D(Adsorption3D<$func>);
 
         impl_adsorption_isotherm!($func, $py_func, PyAdsorption1D, PyPore1D, PyPoreProfile1D);
+        impl_adsorption_isotherm!(
+            $func,
+            $py_func,
+            PyAdsorptionAxisymmetric,
+            PyPoreAxisymmetric,
+            PyPoreProfileAxisymmetric
+        );
         impl_adsorption_isotherm!($func, $py_func, PyAdsorption3D, PyPore3D, PyPoreProfile3D);
     };
 }
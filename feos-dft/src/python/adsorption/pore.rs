@@ -177,6 +177,108 @@ macro_rules! impl_pore {
             }
         }
 
+        /// Parameters required to specify an axisymmetric pore.
+        ///
+        /// Parameters
+        /// ----------
+        /// shape : PoreShape
+        ///     The radius profile of the pore.
+        /// system_size : [SINumber; 2]
+        ///     The radius and length of the system including the reservoir.
+        /// n_grid : [int; 2]
+        ///     The number of grid points in r and z direction.
+        /// potential : ExternalPotential
+        ///     The potential used to model wall-fluid interactions.
+        /// potential_cutoff : float, optional
+        ///     Maximum value for the external potential.
+        /// lanczos : int, optional
+        ///     Exponent of the Lanczos sigma factor used to reduce
+        ///     Gibbs oscillations in the weighted densities.
+        ///
+        /// Returns
+        /// -------
+        /// PoreAxisymmetric
+        ///
+        #[pyclass(name = "PoreAxisymmetric")]
+        pub struct PyPoreAxisymmetric(PoreAxisymmetric);
+
+        #[pyclass(name = "PoreProfileAxisymmetric")]
+        pub struct PyPoreProfileAxisymmetric(PoreProfileAxisymmetric<$func>);
+
+        impl_2d_profile!(PyPoreProfileAxisymmetric, get_r, get_z);
+        impl_pore_profile!(PyPoreProfileAxisymmetric);
+
+        #[pymethods]
+        impl PyPoreAxisymmetric {
+            #[new]
+            #[pyo3(text_signature = "(shape, system_size, n_grid, potential, potential_cutoff=None, lanczos=None)")]
+            #[pyo3(signature = (shape, system_size, n_grid, potential, potential_cutoff=None, lanczos=None))]
+            fn new(
+                shape: PyPoreShape,
+                system_size: [Length; 2],
+                n_grid: [usize; 2],
+                potential: PyExternalPotential,
+                potential_cutoff: Option<f64>,
+                lanczos: Option<i32>,
+            ) -> Self {
+                Self(PoreAxisymmetric::new(
+                    shape.0,
+                    system_size,
+                    n_grid,
+                    potential.0,
+                    potential_cutoff,
+                    lanczos,
+                ))
+            }
+
+            /// Initialize the pore for the given bulk state.
+            ///
+            /// Parameters
+            /// ----------
+            /// bulk : State
+            ///     The bulk state in equilibrium with the pore.
+            /// density : SIArray3, optional
+            ///     Initial values for the density profile.
+            /// external_potential : numpy.ndarray[float], optional
+            ///     The external potential in the pore. Used to
+            ///     save computation time in the case of costly
+            ///     evaluations of external potentials.
+            ///
+            /// Returns
+            /// -------
+            /// PoreProfileAxisymmetric
+            #[pyo3(text_signature = "($self, bulk, density=None, external_potential=None)")]
+            #[pyo3(signature = (bulk, density=None, external_potential=None))]
+            fn initialize(
+                &self,
+                bulk: &PyState,
+                density: Option<Density<Array3<f64>>>,
+                external_potential: Option<&Bound<'_, PyArray3<f64>>>,
+            ) -> PyResult<PyPoreProfileAxisymmetric> {
+                Ok(PyPoreProfileAxisymmetric(self.0.initialize(
+                    &bulk.0,
+                    density.map(|d| d.try_into()).transpose()?.as_ref(),
+                    external_potential.map(|e| e.to_owned_array()).as_ref(),
+                )?))
+            }
+
+            #[getter]
+            fn get_shape(&self) -> PyPoreShape {
+                PyPoreShape(self.0.shape.clone())
+            }
+
+            #[getter]
+            fn get_potential(&self) -> PyExternalPotential {
+                PyExternalPotential(self.0.potential.clone())
+            }
+
+            /// The pore volume using Helium at 298 K as reference.
+            #[getter]
+            fn get_pore_volume(&self) -> PyResult<Volume> {
+                Ok(self.0.pore_volume()?)
+            }
+        }
+
         /// Parameters required to specify a 3D pore.
         ///
         /// Parameters
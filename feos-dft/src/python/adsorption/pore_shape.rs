@@ -0,0 +1,118 @@
+use crate::adsorption::PoreShape;
+use ndarray::Array1;
+use pyo3::prelude::*;
+use quantity::Length;
+
+/// Radius profiles of the solid surrounding axisymmetric pores.
+///
+/// The pores are symmetric with respect to the plane z=0 and
+/// all lengths refer to the full pore.
+#[pyclass(name = "PoreShape")]
+#[derive(Clone)]
+pub struct PyPoreShape(pub PoreShape);
+
+#[pymethods]
+#[expect(non_snake_case)]
+impl PyPoreShape {
+    /// Cylindrical pore of finite length that is open to the
+    /// reservoir at both ends.
+    ///
+    /// Parameters
+    /// ----------
+    /// radius : SINumber
+    ///     Radius of the pore.
+    /// length : SINumber
+    ///     Length of the pore.
+    ///
+    /// Returns
+    /// -------
+    /// PoreShape
+    ///
+    #[staticmethod]
+    pub fn FiniteCylinder(radius: Length, length: Length) -> Self {
+        Self(PoreShape::FiniteCylinder { radius, length })
+    }
+
+    /// Cavity that is connected to the reservoir by narrower
+    /// necks at both ends.
+    ///
+    /// Parameters
+    /// ----------
+    /// cavity_radius : SINumber
+    ///     Radius of the cavity.
+    /// cavity_length : SINumber
+    ///     Length of the cavity.
+    /// neck_radius : SINumber
+    ///     Radius of the necks.
+    /// neck_length : SINumber
+    ///     Length of each neck.
+    ///
+    /// Returns
+    /// -------
+    /// PoreShape
+    ///
+    #[staticmethod]
+    pub fn InkBottle(
+        cavity_radius: Length,
+        cavity_length: Length,
+        neck_radius: Length,
+        neck_length: Length,
+    ) -> Self {
+        Self(PoreShape::InkBottle {
+            cavity_radius,
+            cavity_length,
+            neck_radius,
+            neck_length,
+        })
+    }
+
+    /// Cylindrical pore with a narrower constriction at its center.
+    ///
+    /// Parameters
+    /// ----------
+    /// radius : SINumber
+    ///     Radius of the pore.
+    /// length : SINumber
+    ///     Length of the pore.
+    /// constriction_radius : SINumber
+    ///     Radius of the constriction.
+    /// constriction_length : SINumber
+    ///     Length of the constriction.
+    ///
+    /// Returns
+    /// -------
+    /// PoreShape
+    ///
+    #[staticmethod]
+    pub fn Constriction(
+        radius: Length,
+        length: Length,
+        constriction_radius: Length,
+        constriction_length: Length,
+    ) -> Self {
+        Self(PoreShape::Constriction {
+            radius,
+            length,
+            constriction_radius,
+            constriction_length,
+        })
+    }
+
+    /// Piecewise linear radius profile.
+    ///
+    /// Parameters
+    /// ----------
+    /// z : SIArray1
+    ///     Non-decreasing positions starting from the center of the pore.
+    /// radius : SIArray1
+    ///     Radius of the pore at the given positions.
+    ///
+    /// Returns
+    /// -------
+    /// PoreShape
+    ///
+    #[staticmethod]
+    pub fn Profile(z: Length<Array1<f64>>, radius: Length<Array1<f64>>) -> Self {
+        Self(PoreShape::Profile { z, radius })
+    }
+}
@@ -4,5 +4,5 @@ mod profile;
 mod solvation;
 mod solver;
 
-pub use adsorption::PyExternalPotential;
+pub use adsorption::{PyExternalPotential, PyPoreShape};
 pub use solver::{PyDFTSolver, PyDFTSolverLog};
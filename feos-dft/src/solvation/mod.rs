@@ -1,6 +1,8 @@
 //! Solvation free energies and pair correlaion functions.
 mod pair_correlation;
+mod pair_potential_tables;
 pub use pair_correlation::{PairCorrelation, PairPotential};
+pub use pair_potential_tables::PairPotentialTableFormat;
 
 #[cfg(feature = "rayon")]
 mod solvation_profile;
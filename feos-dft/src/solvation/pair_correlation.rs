@@ -1,17 +1,37 @@
 //! Functionalities for the calculation of pair correlation functions.
+use super::pair_potential_tables::write_pair_potential_tables;
+use super::PairPotentialTableFormat;
 use crate::functional::HelmholtzEnergyFunctional;
 use crate::profile::MAX_POTENTIAL;
 use crate::solver::DFTSolver;
 use crate::{Axis, DFTProfile, Grid};
 use feos_core::{Contributions, EosResult, ReferenceSystem, State};
 use ndarray::prelude::*;
-use quantity::{Energy, Length};
+use quantity::{Energy, Length, Temperature};
+use std::path::Path;
 
 /// The underlying pair potential, that the Helmholtz energy functional
 /// models.
 pub trait PairPotential {
     /// Return the pair potential of particle i with all other particles.
     fn pair_potential(&self, i: usize, r: &Array1<f64>, temperature: f64) -> Array2<f64>;
+
+    /// Write tables of the pair potential and the force for all pure components
+    /// and all unique pairs of components to `directory`.
+    ///
+    /// The file names contain the component `names` and the `temperature`,
+    /// e.g., "methane_ethane_150K.table" for LAMMPS. GROMACS expects the
+    /// distances `r` to start at 0; non-finite values are written as 0.
+    fn pair_potential_tables(
+        &self,
+        temperature: Temperature,
+        r: &Length<Array1<f64>>,
+        format: PairPotentialTableFormat,
+        names: &[String],
+        directory: &Path,
+    ) -> std::io::Result<()> {
+        write_pair_potential_tables(self, temperature, r, format, names, directory)
+    }
 }
 
 /// Density profile and properties of a test particle system.
@@ -0,0 +1,127 @@
+use super::PairPotential;
+use feos_core::ReferenceSystem;
+use ndarray::{Array1, Array2};
+use quantity::{Length, Temperature};
+use std::fs::File;
+use std::io::{BufWriter, Result, Write};
+use std::path::Path;
+
+/// Energy conversion from K to kcal/mol.
+const K_TO_KCAL_MOL: f64 = 8.314462618 / 4184.0;
+/// Energy conversion from K to kJ/mol.
+const K_TO_KJ_MOL: f64 = 8.314462618 / 1000.0;
+/// Step size (in Å) for the numerical differentiation of the pair potential.
+const DR: f64 = 1e-6;
+
+/// File formats for tabulated pair potentials.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "python", pyo3::pyclass(eq))]
+pub enum PairPotentialTableFormat {
+    /// LAMMPS `pair_style table` with `units real` (Å, kcal/mol).
+    Lammps,
+    /// GROMACS user tables (nm, kJ/mol). The potential is written to the
+    /// repulsive columns, i.e., $C_6=0$ and $C_{12}=1$ have to be used in
+    /// the topology.
+    Gromacs,
+    /// Comma-separated values in Å and K.
+    Csv,
+}
+
+impl PairPotentialTableFormat {
+    fn file_name(&self, name: &str, temperature: f64) -> String {
+        match self {
+            Self::Lammps => format!("{name}_{temperature}K.table"),
+            Self::Gromacs => format!("table_{name}_{temperature}K.xvg"),
+            Self::Csv => format!("{name}_{temperature}K.csv"),
+        }
+    }
+}
+
+pub(super) fn write_pair_potential_tables<P: PairPotential + ?Sized>(
+    potential: &P,
+    temperature: Temperature,
+    r: &Length<Array1<f64>>,
+    format: PairPotentialTableFormat,
+    names: &[String],
+    directory: &Path,
+) -> Result<()> {
+    let t = temperature.to_reduced();
+    let r = r.to_reduced();
+    let n = names.len();
+    for i in 0..n {
+        let (u, du) = pair_potential_and_derivative(potential, i, &r, t);
+        for j in i..n {
+            let name = if i == j {
+                names[i].clone()
+            } else {
+                format!("{}_{}", names[i], names[j])
+            };
+            let file = File::create(directory.join(format.file_name(&name, t)))?;
+            let mut stream = BufWriter::new(file);
+            let u = u.row(j);
+            let du = du.row(j);
+            match format {
+                PairPotentialTableFormat::Lammps => {
+                    writeln!(stream, "# Pair potential for {name} at T = {temperature}")?;
+                    writeln!(stream, "# UNITS: real")?;
+                    writeln!(stream, "{name}")?;
+                    writeln!(stream, "N {}\n", r.len())?;
+                    for (k, ((r, u), du)) in r.iter().zip(u).zip(du).enumerate() {
+                        writeln!(
+                            stream,
+                            "{} {:12.8} {:16.8e} {:16.8e}",
+                            k + 1,
+                            r,
+                            u * K_TO_KCAL_MOL,
+                            -du * K_TO_KCAL_MOL
+                        )?;
+                    }
+                }
+                PairPotentialTableFormat::Gromacs => {
+                    writeln!(stream, "# Pair potential for {name} at T = {temperature}")?;
+                    for ((r, u), du) in r.iter().zip(u).zip(du) {
+                        let (u, f) = if u.is_finite() && du.is_finite() {
+                            (u * K_TO_KJ_MOL, -du * K_TO_KJ_MOL * 10.0)
+                        } else {
+                            (0.0, 0.0)
+                        };
+                        writeln!(
+                            stream,
+                            "{:12.8} {:16.8e} {:16.8e} {:16.8e} {:16.8e} {:16.8e} {:16.8e}",
+                            r * 0.1,
+                            0.0,
+                            0.0,
+                            0.0,
+                            0.0,
+                            u,
+                            f
+                        )?;
+                    }
+                }
+                PairPotentialTableFormat::Csv => {
+                    writeln!(stream, "r/A,u/K,f/(K/A)")?;
+                    for ((r, u), du) in r.iter().zip(u).zip(du) {
+                        writeln!(stream, "{},{},{}", r, u, -du)?;
+                    }
+                }
+            }
+            stream.flush()?;
+        }
+    }
+    Ok(())
+}
+
+/// Return the pair potential of particle `i` with all other particles
+/// and its derivative w.r.t. the distance (in reduced units).
+fn pair_potential_and_derivative<P: PairPotential + ?Sized>(
+    potential: &P,
+    i: usize,
+    r: &Array1<f64>,
+    temperature: f64,
+) -> (Array2<f64>, Array2<f64>) {
+    let u = potential.pair_potential(i, r, temperature);
+    let du = (potential.pair_potential(i, &(r + DR), temperature)
+        - potential.pair_potential(i, &(r - DR), temperature))
+        / (2.0 * DR);
+    (u, du)
+}
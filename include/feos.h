@@ -0,0 +1,334 @@
+/* C interface of FeOs. This file is generated by cbindgen, do not edit it manually. */
+
+#ifndef FEOS_H
+#define FEOS_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Contributions to the Helmholtz energy considered in the calculation of properties.
+typedef enum FeosContributions {
+  FEOS_CONTRIBUTIONS_IDEAL_GAS = 0,
+  FEOS_CONTRIBUTIONS_RESIDUAL = 1,
+  FEOS_CONTRIBUTIONS_TOTAL = 2,
+} FeosContributions;
+
+// Initialization of the density iteration for states at given pressure.
+typedef enum FeosDensityInitialization {
+  // Calculate the most stable phase.
+  FEOS_DENSITY_INITIALIZATION_NONE = 0,
+  // Calculate a vapor phase.
+  FEOS_DENSITY_INITIALIZATION_VAPOR = 1,
+  // Calculate a liquid phase.
+  FEOS_DENSITY_INITIALIZATION_LIQUID = 2,
+} FeosDensityInitialization;
+
+// Identifier used to select substances from parameter files.
+typedef enum FeosIdentifierOption {
+  FEOS_IDENTIFIER_OPTION_CAS = 0,
+  FEOS_IDENTIFIER_OPTION_NAME = 1,
+  FEOS_IDENTIFIER_OPTION_IUPAC_NAME = 2,
+  FEOS_IDENTIFIER_OPTION_SMILES = 3,
+  FEOS_IDENTIFIER_OPTION_INCHI = 4,
+  FEOS_IDENTIFIER_OPTION_FORMULA = 5,
+} FeosIdentifierOption;
+
+// Ideal gas models available in the C interface.
+typedef enum FeosIdealGasModel {
+  // No ideal gas model. Only residual properties can be calculated.
+  FEOS_IDEAL_GAS_MODEL_NO_MODEL = 0,
+  FEOS_IDEAL_GAS_MODEL_JOBACK = 1,
+  FEOS_IDEAL_GAS_MODEL_DIPPR = 2,
+  FEOS_IDEAL_GAS_MODEL_NASA = 3,
+  FEOS_IDEAL_GAS_MODEL_RRHO = 4,
+} FeosIdealGasModel;
+
+// Scalar properties of a state that can be evaluated with [feos_state_property].
+typedef enum FeosProperty {
+  // Temperature in K.
+  FEOS_PROPERTY_TEMPERATURE = 0,
+  // Volume in m³.
+  FEOS_PROPERTY_VOLUME = 1,
+  // Total amount of substance in mol.
+  FEOS_PROPERTY_TOTAL_MOLES = 2,
+  // Molar density in mol/m³.
+  FEOS_PROPERTY_DENSITY = 3,
+  // Pressure in Pa.
+  FEOS_PROPERTY_PRESSURE = 4,
+  // Compressibility factor.
+  FEOS_PROPERTY_COMPRESSIBILITY = 5,
+  // Molar enthalpy in J/mol.
+  FEOS_PROPERTY_MOLAR_ENTHALPY = 6,
+  // Molar entropy in J/(mol K).
+  FEOS_PROPERTY_MOLAR_ENTROPY = 7,
+  // Molar internal energy in J/mol.
+  FEOS_PROPERTY_MOLAR_INTERNAL_ENERGY = 8,
+  // Molar Helmholtz energy in J/mol.
+  FEOS_PROPERTY_MOLAR_HELMHOLTZ_ENERGY = 9,
+  // Molar Gibbs energy in J/mol.
+  FEOS_PROPERTY_MOLAR_GIBBS_ENERGY = 10,
+  // Molar isochoric heat capacity in J/(mol K).
+  FEOS_PROPERTY_MOLAR_ISOCHORIC_HEAT_CAPACITY = 11,
+  // Molar isobaric heat capacity in J/(mol K).
+  FEOS_PROPERTY_MOLAR_ISOBARIC_HEAT_CAPACITY = 12,
+  // Speed of sound in m/s. Requires molar weights.
+  FEOS_PROPERTY_SPEED_OF_SOUND = 13,
+  // Isothermal compressibility in 1/Pa.
+  FEOS_PROPERTY_ISOTHERMAL_COMPRESSIBILITY = 14,
+  // Joule-Thomson coefficient in K/Pa.
+  FEOS_PROPERTY_JOULE_THOMSON = 15,
+} FeosProperty;
+
+// Residual Helmholtz energy models available in the C interface.
+//
+// Models that are not included in the build (via features) result in
+// [FeosStatus::InvalidArgument].
+typedef enum FeosResidualModel {
+  FEOS_RESIDUAL_MODEL_PENG_ROBINSON = 0,
+  FEOS_RESIDUAL_MODEL_PC_SAFT = 1,
+  FEOS_RESIDUAL_MODEL_PETS = 2,
+  FEOS_RESIDUAL_MODEL_SAFT_VR_MIE = 3,
+  FEOS_RESIDUAL_MODEL_UV_THEORY = 4,
+} FeosResidualModel;
+
+// Variable that is specified in the calculation of bubble and dew points.
+typedef enum FeosSpecification {
+  // Temperature in K.
+  FEOS_SPECIFICATION_TEMPERATURE = 0,
+  // Pressure in Pa.
+  FEOS_SPECIFICATION_PRESSURE = 1,
+} FeosSpecification;
+
+// Status codes returned by all functions of the C interface.
+//
+// The codes of the variants of [EosError] are stable.
+typedef enum FeosStatus {
+  FEOS_STATUS_OK = 0,
+  FEOS_STATUS_ERROR = 1,
+  FEOS_STATUS_NOT_CONVERGED = 2,
+  FEOS_STATUS_ITERATION_FAILED = 3,
+  FEOS_STATUS_TRIVIAL_SOLUTION = 4,
+  FEOS_STATUS_INCOMPATIBLE_COMPONENTS = 5,
+  FEOS_STATUS_INVALID_STATE = 6,
+  FEOS_STATUS_UNDETERMINED_STATE = 7,
+  FEOS_STATUS_SUPER_CRITICAL = 8,
+  FEOS_STATUS_NO_PHASE_SPLIT = 9,
+  FEOS_STATUS_WRONG_UNITS = 10,
+  FEOS_STATUS_PARAMETER_ERROR = 11,
+  FEOS_STATUS_LIN_ALG_ERROR = 12,
+  FEOS_STATUS_FILE_IO = 13,
+  FEOS_STATUS_SERDE = 14,
+  // A required pointer argument is null.
+  FEOS_STATUS_NULL_POINTER = 15,
+  // An argument is invalid, e.g., a string that is not valid UTF-8
+  // or a model that is not included in the build.
+  FEOS_STATUS_INVALID_ARGUMENT = 16,
+  // An unexpected panic occurred.
+  FEOS_STATUS_PANIC = 17,
+} FeosStatus;
+
+// Opaque handle of an equation of state.
+typedef struct FeosEos FeosEos;
+
+// Opaque handle of a vapor-liquid equilibrium.
+typedef struct FeosPhaseEquilibrium FeosPhaseEquilibrium;
+
+// Opaque handle of a thermodynamic state.
+typedef struct FeosState FeosState;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Message of the last error that occurred in the calling thread.
+//
+// Returns null if no error occurred so far. The string is owned by the
+// library and valid until the next failing call in the same thread.
+const char *feos_last_error_message(void);
+
+// Create an equation of state from JSON parameter files.
+//
+// `binary_path` and `ideal_gas_path` are optional and can be null. An
+// `ideal_gas_path` is required for all ideal gas models except
+// `NoModel`.
+//
+// # Safety
+// `substances` has to point to `n_substances` null-terminated strings.
+// All other non-null string arguments have to be null-terminated.
+FeosStatus feos_eos_from_json(FeosResidualModel residual_model,
+                              const char *const *substances,
+                              size_t n_substances,
+                              const char *parameter_path,
+                              const char *binary_path,
+                              FeosIdealGasModel ideal_gas_model,
+                              const char *ideal_gas_path,
+                              FeosIdentifierOption identifier_option,
+                              FeosEos **eos);
+
+// Release an equation of state.
+//
+// # Safety
+// `eos` has to be null or a handle created by this library that is not used afterwards.
+void feos_eos_free(FeosEos *eos);
+
+// Number of components of the equation of state.
+//
+// # Safety
+// `eos` has to be a valid handle and `components` a valid pointer.
+FeosStatus feos_eos_components(const FeosEos *eos, size_t *components);
+
+// Molar weights of all components in kg/mol.
+//
+// # Safety
+// `eos` has to be a valid handle and `molar_weight` has to point to an
+// array with one element per component.
+FeosStatus feos_eos_molar_weight(const FeosEos *eos, double *molar_weight);
+
+// Perform a Tp-flash for the given temperature (K), pressure (Pa) and feed (mol).
+//
+// # Safety
+// `eos` has to be a valid handle, `feed` has to point to an array with one
+// element per component and `vle` has to be a valid pointer.
+FeosStatus feos_tp_flash(const FeosEos *eos,
+                         double temperature,
+                         double pressure,
+                         const double *feed,
+                         FeosPhaseEquilibrium **vle);
+
+// Calculate the bubble point of a liquid at given temperature (K) or pressure (Pa).
+//
+// `tp_init` is an initial value for the pressure (Pa) or temperature (K). It
+// can be null for a given temperature but is required for a given pressure.
+//
+// # Safety
+// `eos` has to be a valid handle, `liquid_molefracs` has to point to an array
+// with one element per component and `vle` has to be a valid pointer.
+FeosStatus feos_bubble_point(const FeosEos *eos,
+                             FeosSpecification specification,
+                             double temperature_or_pressure,
+                             const double *liquid_molefracs,
+                             const double *tp_init,
+                             FeosPhaseEquilibrium **vle);
+
+// Calculate the dew point of a vapor at given temperature (K) or pressure (Pa).
+//
+// `tp_init` is an initial value for the pressure (Pa) or temperature (K). It
+// can be null for a given temperature but is required for a given pressure.
+//
+// # Safety
+// `eos` has to be a valid handle, `vapor_molefracs` has to point to an array
+// with one element per component and `vle` has to be a valid pointer.
+FeosStatus feos_dew_point(const FeosEos *eos,
+                          FeosSpecification specification,
+                          double temperature_or_pressure,
+                          const double *vapor_molefracs,
+                          const double *tp_init,
+                          FeosPhaseEquilibrium **vle);
+
+// Release a phase equilibrium.
+//
+// # Safety
+// `vle` has to be null or a handle created by this library that is not used afterwards.
+void feos_phase_equilibrium_free(FeosPhaseEquilibrium *vle);
+
+// Copy of the vapor phase of a phase equilibrium.
+//
+// The returned state has to be released with [feos_state_free].
+//
+// # Safety
+// `vle` has to be a valid handle and `state` a valid pointer.
+FeosStatus feos_phase_equilibrium_vapor(const FeosPhaseEquilibrium *vle, FeosState **state);
+
+// Copy of the liquid phase of a phase equilibrium.
+//
+// The returned state has to be released with [feos_state_free].
+//
+// # Safety
+// `vle` has to be a valid handle and `state` a valid pointer.
+FeosStatus feos_phase_equilibrium_liquid(const FeosPhaseEquilibrium *vle, FeosState **state);
+
+// Create a state at given temperature (K), volume (m³) and amount of substance (mol).
+//
+// # Safety
+// `eos` has to be a valid handle, `moles` has to point to an array with one
+// element per component and `state` has to be a valid pointer.
+FeosStatus feos_state_new_nvt(const FeosEos *eos,
+                              double temperature,
+                              double volume,
+                              const double *moles,
+                              FeosState **state);
+
+// Create a state at given temperature (K), pressure (Pa) and amount of substance (mol).
+//
+// # Safety
+// `eos` has to be a valid handle, `moles` has to point to an array with one
+// element per component and `state` has to be a valid pointer.
+FeosStatus feos_state_new_npt(const FeosEos *eos,
+                              double temperature,
+                              double pressure,
+                              const double *moles,
+                              FeosDensityInitialization density_initialization,
+                              FeosState **state);
+
+// Create a state at given pressure (Pa), molar enthalpy (J/mol) and amount of substance (mol).
+//
+// `initial_temperature` (K) is optional and can be null.
+//
+// # Safety
+// `eos` has to be a valid handle, `moles` has to point to an array with one
+// element per component and `state` has to be a valid pointer.
+FeosStatus feos_state_new_nph(const FeosEos *eos,
+                              double pressure,
+                              double molar_enthalpy,
+                              const double *moles,
+                              FeosDensityInitialization density_initialization,
+                              const double *initial_temperature,
+                              FeosState **state);
+
+// Release a state.
+//
+// # Safety
+// `state` has to be null or a handle created by this library that is not used afterwards.
+void feos_state_free(FeosState *state);
+
+// Evaluate a scalar property of a state in SI units.
+//
+// The contributions are ignored for properties that do not depend on them
+// (e.g. temperature or density).
+//
+// # Safety
+// `state` has to be a valid handle and `value` a valid pointer.
+FeosStatus feos_state_property(const FeosState *state,
+                               FeosProperty property,
+                               FeosContributions contributions,
+                               double *value);
+
+// Mole fractions of all components.
+//
+// # Safety
+// `state` has to be a valid handle and `molefracs` has to point to an array
+// with one element per component.
+FeosStatus feos_state_molefracs(const FeosState *state, double *molefracs);
+
+// Logarithms of the fugacity coefficients of all components.
+//
+// # Safety
+// `state` has to be a valid handle and `ln_phi` has to point to an array
+// with one element per component.
+FeosStatus feos_state_ln_phi(const FeosState *state, double *ln_phi);
+
+// Fugacities $f_i=\varphi_ix_ip$ of all components in Pa.
+//
+// # Safety
+// `state` has to be a valid handle and `fugacity` has to point to an array
+// with one element per component.
+FeosStatus feos_state_fugacity(const FeosState *state, double *fugacity);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* FEOS_H */
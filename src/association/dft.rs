@@ -121,9 +121,9 @@ impl<P: AssociationStrength> Association<P> {
         match (
             a.sites_a.len() * a.sites_b.len(),
             a.sites_c.len(),
-            self.force_cross_association,
+            self.force_cross_association || !a.sites_x.is_empty(),
         ) {
-            (0, 0, _) => Ok(Array::zeros(n3i.len())),
+            (0, 0, false) => Ok(Array::zeros(n3i.len())),
             (1, 0, false) => {
                 Ok(self.helmholtz_energy_density_ab_analytic(temperature, rho0, &d, n2, n3i, xi))
             }
@@ -144,28 +144,21 @@ impl<P: AssociationStrength> Association<P> {
                 )
             }
             _ => {
-                let mut x: Array1<f64> = Array::from_elem(a.sites_a.len() + a.sites_b.len(), 0.2);
-                let (assoc_comp_ab, n_ab): (Vec<_>, Vec<_>) = a
-                    .sites_a
-                    .iter()
-                    .chain(a.sites_b.iter())
-                    .map(|s| (s.assoc_comp, s.n))
-                    .unzip();
-                let rhoab = Array2::from_shape_fn((x.len(), n3i.len()), |(i, j)| {
-                    rho0[(assoc_comp_ab[i], j)] * n_ab[i]
+                let (assoc_comp, n): (Vec<_>, Vec<_>) =
+                    a.sites().map(|s| (s.assoc_comp, s.n)).unzip();
+                let mut x: Array1<f64> = Array::from_elem(n.len(), 0.2);
+                let rho = Array2::from_shape_fn((x.len(), n3i.len()), |(i, j)| {
+                    rho0[(assoc_comp[i], j)] * n[i]
                 });
-                rhoab
-                    .axis_iter(Axis(1))
+                rho.axis_iter(Axis(1))
                     .zip(n2.iter())
                     .zip(n3i.iter())
                     .zip(xi.iter())
                     .map(|(((rho, &n2), &n3i), &xi)| {
-                        let [delta_ab, delta_cc] =
-                            self.association_strength(temperature, &d, n2, n3i, xi);
+                        let delta = self.association_strength(temperature, &d, n2, n3i, xi);
                         Self::helmholtz_energy_density_cross_association(
                             &rho,
-                            &delta_ab,
-                            &delta_cc,
+                            &delta,
                             self.max_iter,
                             self.tol,
                             Some(&mut x),
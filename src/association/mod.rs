@@ -34,7 +34,11 @@ impl<A> AssociationSite<A> {
 }
 
 /// Pure component association parameters.
-#[derive(Serialize, Deserialize, Clone, Copy)]
+///
+/// The sites of type A, B and C are a shorthand for the usual association
+/// schemes, in which A sites bond to B sites and C sites bond to C sites.
+/// Sites of arbitrary types can be specified in `sites`.
+#[derive(Serialize, Deserialize, Clone)]
 pub struct AssociationRecord<A> {
     #[serde(flatten)]
     pub parameters: A,
@@ -50,6 +54,10 @@ pub struct AssociationRecord<A> {
     #[serde(skip_serializing_if = "f64::is_zero")]
     #[serde(default)]
     pub nc: f64,
+    /// Association sites of user-defined types
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub sites: Vec<AssociationSiteRecord<A>>,
 }
 
 impl<A> AssociationRecord<A> {
@@ -59,8 +67,24 @@ impl<A> AssociationRecord<A> {
             na,
             nb,
             nc,
+            sites: Vec::new(),
+        }
+    }
+
+    pub fn with_sites(parameters: A, sites: Vec<AssociationSiteRecord<A>>) -> Self {
+        Self {
+            parameters,
+            na: 0.0,
+            nb: 0.0,
+            nc: 0.0,
+            sites,
         }
     }
+
+    /// Total number of association sites.
+    pub fn n_sites(&self) -> f64 {
+        self.na + self.nb + self.nc + self.sites.iter().map(|s| s.n).sum::<f64>()
+    }
 }
 
 impl<A: fmt::Display> fmt::Display for AssociationRecord<A> {
@@ -75,6 +99,90 @@ impl<A: fmt::Display> fmt::Display for AssociationRecord<A> {
         if self.nc > 0.0 {
             write!(f, ", nc={}", self.nc)?;
         }
+        if !self.sites.is_empty() {
+            write!(f, ", sites=[")?;
+            for (i, site) in self.sites.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{site}")?;
+            }
+            write!(f, "]")?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Association sites of a user-defined type.
+///
+/// Two sites can bond if either of them lists the type of the other
+/// in `bonds_with` or `bond_parameters`. The types "A", "B" and "C" refer
+/// to the sites specified via `na`, `nb` and `nc`, so that, e.g., a site
+/// of type "B" bonds to all sites of type A.
+///
+/// By default, the parameters of a bond are obtained from the parameters
+/// of both sites using the combining rule of the model. Parameters of
+/// individual pairs of site types can be specified in `bond_parameters`.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct AssociationSiteRecord<A> {
+    /// Name of the site type
+    pub site_type: String,
+    /// \# of association sites of this type
+    pub n: f64,
+    /// Site types that this site bonds to
+    #[serde(default)]
+    pub bonds_with: Vec<String>,
+    /// Association parameters of this site type. Defaults to
+    /// the parameters of the association record.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub parameters: Option<A>,
+    /// Association parameters of bonds with the given site types
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    #[serde(default)]
+    pub bond_parameters: HashMap<String, A>,
+}
+
+impl<A> AssociationSiteRecord<A> {
+    pub fn new(
+        site_type: String,
+        n: f64,
+        bonds_with: Vec<String>,
+        parameters: Option<A>,
+        bond_parameters: HashMap<String, A>,
+    ) -> Self {
+        Self {
+            site_type,
+            n,
+            bonds_with,
+            parameters,
+            bond_parameters,
+        }
+    }
+}
+
+impl<A: fmt::Display> fmt::Display for AssociationSiteRecord<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "AssociationSiteRecord(site_type={}, n={}, bonds_with={:?}",
+            self.site_type, self.n, self.bonds_with
+        )?;
+        if let Some(p) = &self.parameters {
+            write!(f, ", parameters={p}")?;
+        }
+        if !self.bond_parameters.is_empty() {
+            let mut bonds: Vec<_> = self.bond_parameters.iter().collect();
+            bonds.sort_by_key(|(site_type, _)| *site_type);
+            write!(f, ", bond_parameters={{")?;
+            for (i, (site_type, p)) in bonds.into_iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{site_type}: {p}")?;
+            }
+            write!(f, "}}")?;
+        }
         write!(f, ")")
     }
 }
@@ -112,8 +220,10 @@ pub struct AssociationParameters<P: AssociationStrength> {
     sites_a: Array1<AssociationSite<P::Record>>,
     sites_b: Array1<AssociationSite<P::Record>>,
     sites_c: Array1<AssociationSite<P::Record>>,
+    sites_x: Array1<AssociationSite<P::Record>>,
     parameters_ab: Array2<P::Record>,
     parameters_cc: Array2<P::Record>,
+    parameters_x: Array2<Option<P::Record>>,
 }
 
 impl<P: AssociationStrength> AssociationParameters<P> {
@@ -125,6 +235,8 @@ impl<P: AssociationStrength> AssociationParameters<P> {
         let mut sites_a = Vec::new();
         let mut sites_b = Vec::new();
         let mut sites_c = Vec::new();
+        let mut sites_x = Vec::new();
+        let mut types_x = Vec::new();
 
         for (i, record) in records.iter().enumerate() {
             for (s, site) in record.iter().enumerate() {
@@ -137,6 +249,11 @@ impl<P: AssociationStrength> AssociationParameters<P> {
                 if site.nc > 0.0 {
                     sites_c.push(AssociationSite::new(i, s, site.nc, site.parameters));
                 }
+                for x in site.sites.iter().filter(|x| x.n > 0.0) {
+                    let parameters = x.parameters.unwrap_or(site.parameters);
+                    sites_x.push(AssociationSite::new(i, s, x.n, parameters));
+                    types_x.push(x);
+                }
             }
         }
 
@@ -165,6 +282,40 @@ impl<P: AssociationStrength> AssociationParameters<P> {
             P::combining_rule(sites_c[i].parameters, sites_c[j].parameters)
         });
 
+        // Sites of user-defined types can bond to any other site (including the
+        // sites of type A, B and C), if either site lists the type of the other.
+        // Explicit parameters of a pair of site types replace the combining rule.
+        let sites: Vec<_> = sites_a
+            .iter()
+            .chain(sites_b.iter())
+            .chain(sites_c.iter())
+            .chain(sites_x.iter())
+            .collect();
+        let site_types: Vec<_> = sites_a
+            .iter()
+            .map(|_| ("A", vec!["B"]))
+            .chain(sites_b.iter().map(|_| ("B", vec!["A"])))
+            .chain(sites_c.iter().map(|_| ("C", vec!["C"])))
+            .chain(types_x.iter().map(|x| {
+                let bonds_with = x.bonds_with.iter().map(String::as_str).collect();
+                (x.site_type.as_str(), bonds_with)
+            }))
+            .collect();
+        let nabc = sites.len() - sites_x.len();
+        let mut parameters_x = Array2::from_shape_fn([sites_x.len(), sites.len()], |(i, j)| {
+            let (type_i, bonds_i) = &site_types[nabc + i];
+            let (type_j, bonds_j) = &site_types[j];
+            let explicit = types_x[i].bond_parameters.get(*type_j).or_else(|| {
+                j.checked_sub(nabc)
+                    .and_then(|j| types_x[j].bond_parameters.get(*type_i))
+            });
+            match explicit {
+                Some(&parameters) => Some(parameters),
+                None => (bonds_i.contains(type_j) || bonds_j.contains(type_i))
+                    .then(|| P::combining_rule(sites_x[i].parameters, sites[j].parameters)),
+            }
+        });
+
         for &([i, j], record) in binary_records.iter() {
             let [a, b] = record.site_indices;
             if let (Some(x), Some(y)) = (indices_a.get(&(i, a)), indices_b.get(&(j, b))) {
@@ -177,6 +328,22 @@ impl<P: AssociationStrength> AssociationParameters<P> {
                 P::update_binary(&mut parameters_cc[[*x, *y]], record.parameters);
                 P::update_binary(&mut parameters_cc[[*y, *x]], record.parameters);
             }
+            for (x, site_x) in sites_x.iter().enumerate() {
+                let other = if (site_x.assoc_comp, site_x.site_index) == (i, a) {
+                    (j, b)
+                } else if (site_x.assoc_comp, site_x.site_index) == (j, b) {
+                    (i, a)
+                } else {
+                    continue;
+                };
+                for (y, site) in sites.iter().enumerate() {
+                    if (site.assoc_comp, site.site_index) == other {
+                        if let Some(p) = parameters_x[[x, y]].as_mut() {
+                            P::update_binary(p, record.parameters);
+                        }
+                    }
+                }
+            }
         }
 
         Self {
@@ -186,13 +353,46 @@ impl<P: AssociationStrength> AssociationParameters<P> {
             sites_a: Array1::from_vec(sites_a),
             sites_b: Array1::from_vec(sites_b),
             sites_c: Array1::from_vec(sites_c),
+            sites_x: Array1::from_vec(sites_x),
             parameters_ab,
             parameters_cc,
+            parameters_x,
         }
     }
 
     pub fn is_empty(&self) -> bool {
-        (self.sites_a.is_empty() | self.sites_b.is_empty()) & self.sites_c.is_empty()
+        (self.sites_a.is_empty() | self.sites_b.is_empty())
+            & self.sites_c.is_empty()
+            & self.parameters_x.iter().all(Option::is_none)
+    }
+
+    /// All association sites in the order A, B, C and user-defined types.
+    fn sites(&self) -> impl Iterator<Item = &AssociationSite<P::Record>> {
+        self.sites_a
+            .iter()
+            .chain(self.sites_b.iter())
+            .chain(self.sites_c.iter())
+            .chain(self.sites_x.iter())
+    }
+
+    /// Association parameters of two sites, if they can bond.
+    fn pair_parameters(&self, i: usize, j: usize) -> Option<P::Record> {
+        let a = self.sites_a.len();
+        let b = self.sites_b.len();
+        let c = self.sites_c.len();
+        if i >= a + b + c {
+            self.parameters_x[(i - a - b - c, j)]
+        } else if j >= a + b + c {
+            self.parameters_x[(j - a - b - c, i)]
+        } else if i < a && (a..a + b).contains(&j) {
+            Some(self.parameters_ab[(i, j - a)])
+        } else if j < a && (a..a + b).contains(&i) {
+            Some(self.parameters_ab[(j, i - a)])
+        } else if i >= a + b && j >= a + b {
+            Some(self.parameters_cc[(i - a - b, j - a - b)])
+        } else {
+            None
+        }
     }
 }
 
@@ -250,6 +450,16 @@ pub trait AssociationStrength: HardSphereProperties {
     fn combining_rule(parameters_i: Self::Record, parameters_j: Self::Record) -> Self::Record;
 
     fn update_binary(_parameters_ij: &mut Self::Record, _binary_parameters: Self::BinaryRecord) {}
+
+    /// Contact value of the radial distribution function between two
+    /// segments with diameters `d_i` and `d_j`.
+    ///
+    /// Defaults to the contact value of a hard-sphere mixture, where `xi`
+    /// accounts for inhomogeneities in the Helmholtz energy functional.
+    fn contact_value<D: DualNum<f64> + Copy>(&self, d_i: D, d_j: D, n2: D, n3i: D, xi: D) -> D {
+        let k = d_i * d_j / (d_i + d_j) * (n2 * n3i);
+        n3i * (k * xi * (k / 18.0 + 0.5) + 1.0)
+    }
 }
 
 impl<P: AssociationStrength> Association<P> {
@@ -269,36 +479,32 @@ impl<P: AssociationStrength> Association<P> {
         let n3i = (-n3 + 1.0).recip();
 
         // association strength
-        let [delta_ab, delta_cc] =
-            self.association_strength(state.temperature, diameter, n2, n3i, D::one());
+        let delta = self.association_strength(state.temperature, diameter, n2, n3i, D::one());
+        let nab = a.sites_a.len() + a.sites_b.len();
 
         match (
             a.sites_a.len() * a.sites_b.len(),
             a.sites_c.len(),
-            self.force_cross_association,
+            self.force_cross_association || !a.sites_x.is_empty(),
         ) {
-            (0, 0, _) => D::zero(),
-            (1, 0, false) => self.helmholtz_energy_ab_analytic(state, delta_ab[(0, 0)]),
-            (0, 1, false) => self.helmholtz_energy_cc_analytic(state, delta_cc[(0, 0)]),
+            (0, 0, false) => D::zero(),
+            (1, 0, false) => self.helmholtz_energy_ab_analytic(state, delta[(0, 1)]),
+            (0, 1, false) => self.helmholtz_energy_cc_analytic(state, delta[(nab, nab)]),
             (1, 1, false) => {
-                self.helmholtz_energy_ab_analytic(state, delta_ab[(0, 0)])
-                    + self.helmholtz_energy_cc_analytic(state, delta_cc[(0, 0)])
+                self.helmholtz_energy_ab_analytic(state, delta[(0, 1)])
+                    + self.helmholtz_energy_cc_analytic(state, delta[(2, 2)])
             }
             _ => {
                 // extract site densities of associating segments
                 let rho: Array1<_> = a
-                    .sites_a
-                    .iter()
-                    .chain(a.sites_b.iter())
-                    .chain(a.sites_c.iter())
+                    .sites()
                     .map(|s| state.partial_density[a.component_index[s.assoc_comp]] * s.n)
                     .collect();
 
                 // Helmholtz energy
                 Self::helmholtz_energy_density_cross_association(
                     &rho,
-                    &delta_ab,
-                    &delta_cc,
+                    &delta,
                     self.max_iter,
                     self.tol,
                     None,
@@ -309,6 +515,7 @@ impl<P: AssociationStrength> Association<P> {
         }
     }
 
+    /// Association strength between all pairs of association sites.
     fn association_strength<D: DualNum<f64> + Copy>(
         &self,
         temperature: D,
@@ -316,34 +523,23 @@ impl<P: AssociationStrength> Association<P> {
         n2: D,
         n3i: D,
         xi: D,
-    ) -> [Array2<D>; 2] {
+    ) -> Array2<D> {
         let p = &self.association_parameters;
-
-        let delta_ab = Array2::from_shape_fn([p.sites_a.len(), p.sites_b.len()], |(i, j)| {
-            let di = diameter[p.sites_a[i].assoc_comp];
-            let dj = diameter[p.sites_b[j].assoc_comp];
-            let k = di * dj / (di + dj) * (n2 * n3i);
-            n3i * (k * xi * (k / 18.0 + 0.5) + 1.0)
-                * self.parameters.association_strength(
-                    temperature,
-                    p.sites_a[i].assoc_comp,
-                    p.sites_b[j].assoc_comp,
-                    p.parameters_ab[(i, j)],
-                )
-        });
-        let delta_cc = Array2::from_shape_fn([p.sites_c.len(); 2], |(i, j)| {
-            let di = diameter[p.sites_c[i].assoc_comp];
-            let dj = diameter[p.sites_c[j].assoc_comp];
-            let k = di * dj / (di + dj) * (n2 * n3i);
-            n3i * (k * xi * (k / 18.0 + 0.5) + 1.0)
-                * self.parameters.association_strength(
-                    temperature,
-                    p.sites_c[i].assoc_comp,
-                    p.sites_c[j].assoc_comp,
-                    p.parameters_cc[(i, j)],
-                )
-        });
-        [delta_ab, delta_cc]
+        let sites: Vec<_> = p.sites().collect();
+
+        Array2::from_shape_fn([sites.len(); 2], |(i, j)| {
+            p.pair_parameters(i, j).map_or(D::zero(), |parameters| {
+                let di = diameter[sites[i].assoc_comp];
+                let dj = diameter[sites[j].assoc_comp];
+                self.parameters.contact_value(di, dj, n2, n3i, xi)
+                    * self.parameters.association_strength(
+                        temperature,
+                        sites[i].assoc_comp,
+                        sites[j].assoc_comp,
+                        parameters,
+                    )
+            })
+        })
     }
 }
 
@@ -394,8 +590,7 @@ impl<P: AssociationStrength> Association<P> {
 
     fn helmholtz_energy_density_cross_association<D: DualNum<f64> + Copy, S: Data<Elem = D>>(
         rho: &ArrayBase<S, Ix1>,
-        delta_ab: &Array2<D>,
-        delta_cc: &Array2<D>,
+        delta: &Array2<D>,
         max_iter: usize,
         tol: f64,
         x0: Option<&mut Array1<f64>>,
@@ -415,17 +610,10 @@ impl<P: AssociationStrength> Association<P> {
             None => Array::from_elem(rho.len(), 0.2),
         };
 
-        let delta_ab_re = delta_ab.map(D::re);
-        let delta_cc_re = delta_cc.map(D::re);
+        let delta_re = delta.map(D::re);
         let rho_re = rho.map(D::re);
         for k in 0..max_iter {
-            if Self::newton_step_cross_association(
-                &mut x,
-                &delta_ab_re,
-                &delta_cc_re,
-                &rho_re,
-                tol,
-            )? {
+            if Self::newton_step_cross_association(&mut x, &delta_re, &rho_re, tol)? {
                 break;
             }
             if k == max_iter - 1 {
@@ -436,7 +624,7 @@ impl<P: AssociationStrength> Association<P> {
         // calculate derivatives
         let mut x_dual = x.mapv(D::from);
         for _ in 0..D::NDERIV {
-            Self::newton_step_cross_association(&mut x_dual, delta_ab, delta_cc, rho, tol)?;
+            Self::newton_step_cross_association(&mut x_dual, delta, rho, tol)?;
         }
 
         // save monomer fraction
@@ -451,8 +639,7 @@ impl<P: AssociationStrength> Association<P> {
 
     fn newton_step_cross_association<D: DualNum<f64> + Copy, S: Data<Elem = D>>(
         x: &mut Array1<D>,
-        delta_ab: &Array2<D>,
-        delta_cc: &Array2<D>,
+        delta: &Array2<D>,
         rho: &ArrayBase<S, Ix1>,
         tol: f64,
     ) -> EosResult<bool> {
@@ -462,44 +649,17 @@ impl<P: AssociationStrength> Association<P> {
         // Hessian
         let mut h: Array2<D> = Array::zeros([nassoc; 2]);
 
-        // split arrays
-        let &[a, b] = delta_ab.shape() else {
-            panic!("wrong shape!")
-        };
-        let c = delta_cc.shape()[0];
-        let (xa, xc) = x.view().split_at(Axis(0), a + b);
-        let (xa, xb) = xa.split_at(Axis(0), a);
-        let (rhoa, rhoc) = rho.view().split_at(Axis(0), a + b);
-        let (rhoa, rhob) = rhoa.split_at(Axis(0), a);
-
+        let rhox = &*x * rho;
         for i in 0..nassoc {
             // calculate gradients
-            let (d, dnx) = if i < a {
-                let d = delta_ab.index_axis(Axis(0), i);
-                (d, (&xb * &rhob * d).sum() + 1.0)
-            } else if i < a + b {
-                let d = delta_ab.index_axis(Axis(1), i - a);
-                (d, (&xa * &rhoa * d).sum() + 1.0)
-            } else {
-                let d = delta_cc.index_axis(Axis(0), i - a - b);
-                (d, (&xc * &rhoc * d).sum() + 1.0)
-            };
+            let d = delta.index_axis(Axis(0), i);
+            let dnx = (&rhox * &d).sum() + 1.0;
             g[i] -= dnx;
 
             // approximate hessian
             h[(i, i)] = -dnx / x[i];
-            if i < a {
-                for j in 0..b {
-                    h[(i, a + j)] = -d[j] * rhob[j];
-                }
-            } else if i < a + b {
-                for j in 0..a {
-                    h[(i, j)] = -d[j] * rhoa[j];
-                }
-            } else {
-                for j in 0..c {
-                    h[(i, a + b + j)] -= d[j] * rhoc[j];
-                }
+            for j in 0..nassoc {
+                h[(i, j)] -= d[j] * rho[j];
             }
         }
 
@@ -621,6 +781,142 @@ mod tests_pcsaft {
         assert_relative_eq!(a_rust, -4.229878997054543, epsilon = 1e-10);
     }
 
+    #[test]
+    fn test_site_types() {
+        let site = |site_type: &str, bonds_with: &[&str]| {
+            let bonds_with = bonds_with.iter().map(|s| s.to_string()).collect();
+            AssociationSiteRecord::new(site_type.into(), 1.0, bonds_with, None, HashMap::new())
+        };
+        let pcsaft = PcSaftAssociationRecord::new(Some(0.1), Some(2000.));
+        let sites = vec![site("D", &["A1", "A2"]), site("A1", &[]), site("A2", &[])];
+        let comp1 = vec![AssociationRecord::with_sites(pcsaft, sites)];
+        let comp2 = vec![record(0.1, 1000., 1.0, 1.0)];
+        let comp3 = vec![AssociationRecord::with_sites(pcsaft, vec![site("B", &[])])];
+        let assoc =
+            AssociationParameters::<PcSaftParameters>::new(&[comp1, comp2, comp3], &[], None);
+        let bonds = assoc.parameters_x.map(Option::is_some);
+        assert_eq!(
+            bonds,
+            arr2(&[
+                [false, false, false, true, true, false],
+                [false, false, true, false, false, false],
+                [false, false, true, false, false, false],
+                [true, false, false, false, false, false],
+            ])
+        );
+        assert_eq!(
+            assoc.parameters_x[(3, 0)].unwrap().epsilon_k_ab,
+            Some(1500.)
+        );
+    }
+
+    #[test]
+    fn test_bond_parameters() {
+        let pcsaft = PcSaftAssociationRecord::new(Some(0.1), Some(2000.));
+        let explicit = PcSaftAssociationRecord::new(Some(0.01), Some(3000.));
+        let donor = AssociationSiteRecord::new(
+            "D".into(),
+            1.0,
+            vec![],
+            None,
+            HashMap::from([("A2".to_string(), explicit)]),
+        );
+        let acceptor = |site_type: &str| {
+            let bonds_with = vec!["D".to_string()];
+            AssociationSiteRecord::new(site_type.into(), 1.0, bonds_with, None, HashMap::new())
+        };
+        let sites = vec![donor, acceptor("A1"), acceptor("A2")];
+        let comp = vec![AssociationRecord::with_sites(pcsaft, sites)];
+        let assoc = AssociationParameters::<PcSaftParameters>::new(&[comp], &[], None);
+        let bonds = assoc.parameters_x.map(Option::is_some);
+        assert_eq!(
+            bonds,
+            arr2(&[
+                [false, true, true],
+                [true, false, false],
+                [true, false, false]
+            ])
+        );
+        for (i, j) in [(0, 1), (1, 0)] {
+            assert_eq!(
+                assoc.parameters_x[(i, j)].unwrap().epsilon_k_ab,
+                Some(2000.)
+            );
+        }
+        for (i, j) in [(0, 2), (2, 0)] {
+            let p = assoc.parameters_x[(i, j)].unwrap();
+            assert_eq!(p.kappa_ab, Some(0.01));
+            assert_eq!(p.epsilon_k_ab, Some(3000.));
+        }
+    }
+
+    #[test]
+    fn helmholtz_energy_site_types() -> Result<(), ParameterError> {
+        let mut params = water_parameters();
+        let mut record = params.pure_records.pop().unwrap();
+        let association_record = record.model_record.association_record.unwrap();
+        let sites = vec![
+            AssociationSiteRecord::new(
+                "donor".into(),
+                1.0,
+                vec!["acceptor".into()],
+                None,
+                HashMap::new(),
+            ),
+            AssociationSiteRecord::new("acceptor".into(), 1.0, vec![], None, HashMap::new()),
+        ];
+        record.model_record.association_record = Some(AssociationRecord::with_sites(
+            association_record.parameters,
+            sites,
+        ));
+        let params = Arc::new(PcSaftParameters::new_pure(record)?);
+        let assoc = Association::new(&params, &params.association, 50, 1e-10);
+        let t = 350.0;
+        let v = 41.248289328513216;
+        let n = 1.23;
+        let s = StateHD::new(t, v, arr1(&[n]));
+        let d = params.hs_diameter(t);
+        let a_rust = assoc.helmholtz_energy(&s, &d) / n;
+        assert_relative_eq!(a_rust, -4.229878997054543, epsilon = 1e-10);
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_site_types() {
+        let json = r#"
+            {
+                "kappa_ab": 0.03,
+                "epsilon_k_ab": 2500.0,
+                "na": 1.0,
+                "sites": [
+                    {"site_type": "B", "n": 2.0},
+                    {
+                        "site_type": "E",
+                        "n": 1.0,
+                        "bonds_with": ["B", "E"],
+                        "parameters": {"epsilon_k_ab": 1000.0},
+                        "bond_parameters": {"A": {"kappa_ab": 0.01, "epsilon_k_ab": 2000.0}}
+                    }
+                ]
+            }"#;
+        let record: AssociationRecord<PcSaftAssociationRecord> =
+            serde_json::from_str(json).unwrap();
+        assert_eq!(record.na, 1.0);
+        assert_eq!(record.nb, 0.0);
+        assert_eq!(record.sites.len(), 2);
+        assert_eq!(record.sites[1].bonds_with, vec!["B", "E"]);
+        assert!(record.sites[0].parameters.is_none());
+        assert_eq!(
+            record.sites[1].parameters.unwrap().epsilon_k_ab,
+            Some(1000.0)
+        );
+        assert_eq!(
+            record.sites[1].bond_parameters["A"].epsilon_k_ab,
+            Some(2000.0)
+        );
+        assert_eq!(record.n_sites(), 4.0);
+    }
+
     #[test]
     fn helmholtz_energy_cross_3b() -> Result<(), ParameterError> {
         let mut params = water_parameters();
@@ -0,0 +1,275 @@
+use super::*;
+use crate::ideal_gas::{Dippr, Joback, Nasa, Rrho};
+use feos_core::cubic::{PengRobinson, PengRobinsonParameters};
+use feos_core::parameter::{IdentifierOption, Parameter};
+use feos_core::{Components, Molarweight};
+use quantity::{KILOGRAM, MOL};
+use std::sync::Arc;
+
+/// Opaque handle of an equation of state.
+pub struct FeosEos(pub(super) Arc<Eos>);
+
+/// Residual Helmholtz energy models available in the C interface.
+///
+/// Models that are not included in the build (via features) result in
+/// [FeosStatus::InvalidArgument].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeosResidualModel {
+    PengRobinson = 0,
+    PcSaft = 1,
+    Pets = 2,
+    SaftVRMie = 3,
+    UVTheory = 4,
+}
+
+/// Ideal gas models available in the C interface.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeosIdealGasModel {
+    /// No ideal gas model. Only residual properties can be calculated.
+    NoModel = 0,
+    Joback = 1,
+    Dippr = 2,
+    Nasa = 3,
+    Rrho = 4,
+}
+
+/// Identifier used to select substances from parameter files.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeosIdentifierOption {
+    Cas = 0,
+    Name = 1,
+    IupacName = 2,
+    Smiles = 3,
+    Inchi = 4,
+    Formula = 5,
+}
+
+impl From<FeosIdentifierOption> for IdentifierOption {
+    fn from(option: FeosIdentifierOption) -> Self {
+        match option {
+            FeosIdentifierOption::Cas => Self::Cas,
+            FeosIdentifierOption::Name => Self::Name,
+            FeosIdentifierOption::IupacName => Self::IupacName,
+            FeosIdentifierOption::Smiles => Self::Smiles,
+            FeosIdentifierOption::Inchi => Self::Inchi,
+            FeosIdentifierOption::Formula => Self::Formula,
+        }
+    }
+}
+
+#[cfg(not(all(
+    feature = "pcsaft",
+    feature = "pets",
+    feature = "saftvrmie",
+    feature = "uvtheory"
+)))]
+fn unavailable(model: FeosResidualModel, feature: &str) -> FfiError {
+    FfiError::InvalidArgument(format!(
+        "{model:?} is not available. Enable the `{feature}` feature."
+    ))
+}
+
+fn build_residual_model(
+    model: FeosResidualModel,
+    substances: Vec<&str>,
+    path: &str,
+    binary_path: Option<&str>,
+    identifier_option: IdentifierOption,
+) -> FfiResult<ResidualModel> {
+    Ok(match model {
+        FeosResidualModel::PengRobinson => {
+            let parameters = PengRobinsonParameters::from_json(
+                substances,
+                path,
+                binary_path,
+                identifier_option,
+            )?;
+            ResidualModel::PengRobinson(PengRobinson::new(Arc::new(parameters)))
+        }
+        #[cfg(feature = "pcsaft")]
+        FeosResidualModel::PcSaft => {
+            use crate::pcsaft::{PcSaft, PcSaftParameters};
+            let parameters =
+                PcSaftParameters::from_json(substances, path, binary_path, identifier_option)?;
+            ResidualModel::PcSaft(PcSaft::new(Arc::new(parameters)))
+        }
+        #[cfg(not(feature = "pcsaft"))]
+        FeosResidualModel::PcSaft => return Err(unavailable(model, "pcsaft")),
+        #[cfg(feature = "pets")]
+        FeosResidualModel::Pets => {
+            use crate::pets::{Pets, PetsParameters};
+            let parameters =
+                PetsParameters::from_json(substances, path, binary_path, identifier_option)?;
+            ResidualModel::Pets(Pets::new(Arc::new(parameters)))
+        }
+        #[cfg(not(feature = "pets"))]
+        FeosResidualModel::Pets => return Err(unavailable(model, "pets")),
+        #[cfg(feature = "saftvrmie")]
+        FeosResidualModel::SaftVRMie => {
+            use crate::saftvrmie::{SaftVRMie, SaftVRMieParameters};
+            let parameters =
+                SaftVRMieParameters::from_json(substances, path, binary_path, identifier_option)?;
+            ResidualModel::SaftVRMie(SaftVRMie::new(Arc::new(parameters)))
+        }
+        #[cfg(not(feature = "saftvrmie"))]
+        FeosResidualModel::SaftVRMie => return Err(unavailable(model, "saftvrmie")),
+        #[cfg(feature = "uvtheory")]
+        FeosResidualModel::UVTheory => {
+            use crate::uvtheory::{UVTheory, UVTheoryParameters};
+            let parameters =
+                UVTheoryParameters::from_json(substances, path, binary_path, identifier_option)?;
+            ResidualModel::UVTheory(UVTheory::new(Arc::new(parameters)))
+        }
+        #[cfg(not(feature = "uvtheory"))]
+        FeosResidualModel::UVTheory => return Err(unavailable(model, "uvtheory")),
+    })
+}
+
+fn build_ideal_gas_model(
+    model: FeosIdealGasModel,
+    substances: Vec<&str>,
+    path: Option<&str>,
+    identifier_option: IdentifierOption,
+) -> FfiResult<IdealGasModel> {
+    let n = substances.len();
+    let path = match (model, path) {
+        (FeosIdealGasModel::NoModel, _) => return Ok(IdealGasModel::NoModel(n)),
+        (_, Some(path)) => path,
+        (_, None) => return Err(FfiError::NullPointer("ideal_gas_path")),
+    };
+    Ok(match model {
+        FeosIdealGasModel::NoModel => unreachable!(),
+        FeosIdealGasModel::Joback => IdealGasModel::Joback(Arc::new(Joback::from_json(
+            substances,
+            path,
+            None,
+            identifier_option,
+        )?)),
+        FeosIdealGasModel::Dippr => IdealGasModel::Dippr(Arc::new(Dippr::from_json(
+            substances,
+            path,
+            None,
+            identifier_option,
+        )?)),
+        FeosIdealGasModel::Nasa => IdealGasModel::Nasa(Arc::new(Nasa::from_json(
+            substances,
+            path,
+            None,
+            identifier_option,
+        )?)),
+        FeosIdealGasModel::Rrho => IdealGasModel::Rrho(Arc::new(Rrho::from_json(
+            substances,
+            path,
+            None,
+            identifier_option,
+        )?)),
+    })
+}
+
+/// Create an equation of state from JSON parameter files.
+///
+/// `binary_path` and `ideal_gas_path` are optional and can be null. An
+/// `ideal_gas_path` is required for all ideal gas models except
+/// `NoModel`.
+///
+/// # Safety
+/// `substances` has to point to `n_substances` null-terminated strings.
+/// All other non-null string arguments have to be null-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn feos_eos_from_json(
+    residual_model: FeosResidualModel,
+    substances: *const *const c_char,
+    n_substances: usize,
+    parameter_path: *const c_char,
+    binary_path: *const c_char,
+    ideal_gas_model: FeosIdealGasModel,
+    ideal_gas_path: *const c_char,
+    identifier_option: FeosIdentifierOption,
+    eos: *mut *mut FeosEos,
+) -> FeosStatus {
+    ffi_call(|| {
+        if substances.is_null() {
+            return Err(FfiError::NullPointer("substances"));
+        }
+        let substances = std::slice::from_raw_parts(substances, n_substances)
+            .iter()
+            .map(|&s| to_str(s, "substances"))
+            .collect::<FfiResult<Vec<_>>>()?;
+        let parameter_path = to_str(parameter_path, "parameter_path")?;
+        let binary_path = to_optional_str(binary_path, "binary_path")?;
+        let ideal_gas_path = to_optional_str(ideal_gas_path, "ideal_gas_path")?;
+        let identifier_option = identifier_option.into();
+
+        let residual = build_residual_model(
+            residual_model,
+            substances.clone(),
+            parameter_path,
+            binary_path,
+            identifier_option,
+        )?;
+        let ideal_gas = build_ideal_gas_model(
+            ideal_gas_model,
+            substances,
+            ideal_gas_path,
+            identifier_option,
+        )?;
+        if residual.components() != ideal_gas.components() {
+            return Err(EosError::IncompatibleComponents(
+                residual.components(),
+                ideal_gas.components(),
+            )
+            .into());
+        }
+        let model = EquationOfState::new(Arc::new(ideal_gas), Arc::new(residual));
+        write_handle(eos, FeosEos(Arc::new(model)), "eos")
+    })
+}
+
+/// Release an equation of state.
+///
+/// # Safety
+/// `eos` has to be null or a handle created by this library that is not used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn feos_eos_free(eos: *mut FeosEos) {
+    free_handle(eos)
+}
+
+/// Number of components of the equation of state.
+///
+/// # Safety
+/// `eos` has to be a valid handle and `components` a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn feos_eos_components(
+    eos: *const FeosEos,
+    components: *mut usize,
+) -> FeosStatus {
+    ffi_call(|| {
+        let eos = deref(eos, "eos")?;
+        write(components, eos.0.components(), "components")
+    })
+}
+
+/// Molar weights of all components in kg/mol.
+///
+/// # Safety
+/// `eos` has to be a valid handle and `molar_weight` has to point to an
+/// array with one element per component.
+#[no_mangle]
+pub unsafe extern "C" fn feos_eos_molar_weight(
+    eos: *const FeosEos,
+    molar_weight: *mut f64,
+) -> FeosStatus {
+    ffi_call(|| {
+        let eos = deref(eos, "eos")?;
+        if !eos.0.residual.has_molar_weight() {
+            return Err(FfiError::InvalidArgument(
+                "The equation of state does not provide molar weights.".into(),
+            ));
+        }
+        let mw = eos.0.molar_weight().convert_into(KILOGRAM / MOL);
+        write_array(molar_weight, &mw, "molar_weight")
+    })
+}
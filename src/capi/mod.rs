@@ -0,0 +1,223 @@
+//! C interface for equations of state, thermodynamic states and phase equilibria.
+//!
+//! Equations of state, states and phase equilibria are passed across the
+//! interface as opaque handles. Handles are created by the constructors
+//! (e.g. [feos_eos_from_json], [feos_state_new_npt] or [feos_tp_flash]) and
+//! have to be released with the corresponding `*_free` function.
+//!
+//! Every function returns a [FeosStatus] and writes its results to the
+//! output pointers given as last arguments. If a function fails, a description
+//! of the error can be obtained from [feos_last_error_message].
+//!
+//! All quantities are given in SI units (K, Pa, m³, mol, J).
+//!
+//! The header `include/feos.h` is generated using
+//! [cbindgen](https://github.com/mozilla/cbindgen):
+//! ```bash
+//! cbindgen --config cbindgen.toml --output include/feos.h
+//! ```
+use crate::ideal_gas::IdealGasModel;
+use crate::ResidualModel;
+use feos_core::parameter::ParameterError;
+use feos_core::{EosError, EquationOfState};
+use ndarray::Array1;
+use std::cell::RefCell;
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+mod eos;
+mod phase_equilibria;
+mod state;
+pub use eos::*;
+pub use phase_equilibria::*;
+pub use state::*;
+
+/// Equation of state used in the C interface.
+type Eos = EquationOfState<IdealGasModel, ResidualModel>;
+
+/// Status codes returned by all functions of the C interface.
+///
+/// The codes of the variants of [EosError] are stable.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeosStatus {
+    Ok = 0,
+    Error = 1,
+    NotConverged = 2,
+    IterationFailed = 3,
+    TrivialSolution = 4,
+    IncompatibleComponents = 5,
+    InvalidState = 6,
+    UndeterminedState = 7,
+    SuperCritical = 8,
+    NoPhaseSplit = 9,
+    WrongUnits = 10,
+    ParameterError = 11,
+    LinAlgError = 12,
+    FileIO = 13,
+    Serde = 14,
+    /// A required pointer argument is null.
+    NullPointer = 15,
+    /// An argument is invalid, e.g., a string that is not valid UTF-8
+    /// or a model that is not included in the build.
+    InvalidArgument = 16,
+    /// An unexpected panic occurred.
+    Panic = 17,
+}
+
+impl From<&EosError> for FeosStatus {
+    fn from(error: &EosError) -> Self {
+        match error {
+            EosError::Error(_) => Self::Error,
+            EosError::NotConverged(_) => Self::NotConverged,
+            EosError::IterationFailed(_) => Self::IterationFailed,
+            EosError::TrivialSolution => Self::TrivialSolution,
+            EosError::IncompatibleComponents(_, _) => Self::IncompatibleComponents,
+            EosError::InvalidState(_, _, _) => Self::InvalidState,
+            EosError::UndeterminedState(_) => Self::UndeterminedState,
+            EosError::SuperCritical => Self::SuperCritical,
+            EosError::NoPhaseSplit => Self::NoPhaseSplit,
+            EosError::WrongUnits(_, _) => Self::WrongUnits,
+            EosError::ParameterError(_) => Self::ParameterError,
+            EosError::LinAlgError(_) => Self::LinAlgError,
+            EosError::FileIO(_) => Self::FileIO,
+            EosError::Serde(_) => Self::Serde,
+            #[cfg(feature = "rayon")]
+            EosError::RayonError(_) => Self::Error,
+        }
+    }
+}
+
+/// Errors that occur within the C interface.
+enum FfiError {
+    Eos(EosError),
+    NullPointer(&'static str),
+    InvalidArgument(String),
+}
+
+impl From<EosError> for FfiError {
+    fn from(error: EosError) -> Self {
+        Self::Eos(error)
+    }
+}
+
+impl From<ParameterError> for FfiError {
+    fn from(error: ParameterError) -> Self {
+        Self::Eos(error.into())
+    }
+}
+
+impl FfiError {
+    fn status(&self) -> FeosStatus {
+        match self {
+            Self::Eos(e) => e.into(),
+            Self::NullPointer(_) => FeosStatus::NullPointer,
+            Self::InvalidArgument(_) => FeosStatus::InvalidArgument,
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            Self::Eos(e) => e.to_string(),
+            Self::NullPointer(name) => format!("Argument `{name}` must not be null."),
+            Self::InvalidArgument(message) => message.clone(),
+        }
+    }
+}
+
+type FfiResult<T> = Result<T, FfiError>;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(message: String) {
+    let message = CString::new(message.replace('\0', ""))
+        .expect("the null bytes have been removed from the message");
+    LAST_ERROR.with(|e| *e.borrow_mut() = Some(message));
+}
+
+/// Evaluate `f`, store the error message in case of a failure and return the status code.
+fn ffi_call<F: FnOnce() -> FfiResult<()>>(f: F) -> FeosStatus {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => FeosStatus::Ok,
+        Ok(Err(e)) => {
+            set_last_error(e.message());
+            e.status()
+        }
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "Unknown panic.".into());
+            set_last_error(message);
+            FeosStatus::Panic
+        }
+    }
+}
+
+/// Message of the last error that occurred in the calling thread.
+///
+/// Returns null if no error occurred so far. The string is owned by the
+/// library and valid until the next failing call in the same thread.
+#[no_mangle]
+pub extern "C" fn feos_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|e| e.borrow().as_ref().map_or(ptr::null(), |e| e.as_ptr()))
+}
+
+unsafe fn deref<'a, T>(ptr: *const T, name: &'static str) -> FfiResult<&'a T> {
+    ptr.as_ref().ok_or(FfiError::NullPointer(name))
+}
+
+unsafe fn write<T>(ptr: *mut T, value: T, name: &'static str) -> FfiResult<()> {
+    if ptr.is_null() {
+        return Err(FfiError::NullPointer(name));
+    }
+    ptr.write(value);
+    Ok(())
+}
+
+unsafe fn write_handle<T>(ptr: *mut *mut T, value: T, name: &'static str) -> FfiResult<()> {
+    write(ptr, Box::into_raw(Box::new(value)), name)
+}
+
+unsafe fn free_handle<T>(ptr: *mut T) {
+    if !ptr.is_null() {
+        drop(Box::from_raw(ptr));
+    }
+}
+
+unsafe fn to_str<'a>(ptr: *const c_char, name: &'static str) -> FfiResult<&'a str> {
+    to_optional_str(ptr, name)?.ok_or(FfiError::NullPointer(name))
+}
+
+unsafe fn to_optional_str<'a>(
+    ptr: *const c_char,
+    name: &'static str,
+) -> FfiResult<Option<&'a str>> {
+    if ptr.is_null() {
+        return Ok(None);
+    }
+    CStr::from_ptr(ptr)
+        .to_str()
+        .map(Some)
+        .map_err(|_| FfiError::InvalidArgument(format!("Argument `{name}` is not valid UTF-8.")))
+}
+
+unsafe fn to_slice<'a>(ptr: *const f64, len: usize, name: &'static str) -> FfiResult<&'a [f64]> {
+    if ptr.is_null() {
+        return Err(FfiError::NullPointer(name));
+    }
+    Ok(std::slice::from_raw_parts(ptr, len))
+}
+
+unsafe fn write_array(ptr: *mut f64, values: &Array1<f64>, name: &'static str) -> FfiResult<()> {
+    if ptr.is_null() {
+        return Err(FfiError::NullPointer(name));
+    }
+    let slice = std::slice::from_raw_parts_mut(ptr, values.len());
+    slice.iter_mut().zip(values).for_each(|(s, &v)| *s = v);
+    Ok(())
+}
@@ -0,0 +1,173 @@
+use super::*;
+use feos_core::{Components, PhaseEquilibrium, SolverOptions};
+use quantity::{KELVIN, MOL, PASCAL};
+
+/// Opaque handle of a vapor-liquid equilibrium.
+pub struct FeosPhaseEquilibrium(PhaseEquilibrium<Eos, 2>);
+
+/// Variable that is specified in the calculation of bubble and dew points.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeosSpecification {
+    /// Temperature in K.
+    Temperature = 0,
+    /// Pressure in Pa.
+    Pressure = 1,
+}
+
+unsafe fn read_molefracs(
+    eos: &Eos,
+    molefracs: *const f64,
+    name: &'static str,
+) -> FfiResult<Array1<f64>> {
+    let molefracs = to_slice(molefracs, eos.components(), name)?;
+    Ok(Array1::from_vec(molefracs.to_vec()))
+}
+
+/// Perform a Tp-flash for the given temperature (K), pressure (Pa) and feed (mol).
+///
+/// # Safety
+/// `eos` has to be a valid handle, `feed` has to point to an array with one
+/// element per component and `vle` has to be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn feos_tp_flash(
+    eos: *const FeosEos,
+    temperature: f64,
+    pressure: f64,
+    feed: *const f64,
+    vle: *mut *mut FeosPhaseEquilibrium,
+) -> FeosStatus {
+    ffi_call(|| {
+        let eos = &deref(eos, "eos")?.0;
+        let feed = read_molefracs(eos, feed, "feed")? * MOL;
+        let e = PhaseEquilibrium::tp_flash(
+            eos,
+            temperature * KELVIN,
+            pressure * PASCAL,
+            &feed,
+            None,
+            SolverOptions::default(),
+            None,
+        )?;
+        write_handle(vle, FeosPhaseEquilibrium(e), "vle")
+    })
+}
+
+/// Calculate the bubble point of a liquid at given temperature (K) or pressure (Pa).
+///
+/// `tp_init` is an initial value for the pressure (Pa) or temperature (K). It
+/// can be null for a given temperature but is required for a given pressure.
+///
+/// # Safety
+/// `eos` has to be a valid handle, `liquid_molefracs` has to point to an array
+/// with one element per component and `vle` has to be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn feos_bubble_point(
+    eos: *const FeosEos,
+    specification: FeosSpecification,
+    temperature_or_pressure: f64,
+    liquid_molefracs: *const f64,
+    tp_init: *const f64,
+    vle: *mut *mut FeosPhaseEquilibrium,
+) -> FeosStatus {
+    ffi_call(|| {
+        let eos = &deref(eos, "eos")?.0;
+        let x = read_molefracs(eos, liquid_molefracs, "liquid_molefracs")?;
+        let tp_init = tp_init.as_ref().copied();
+        let options = Default::default();
+        let e = match specification {
+            FeosSpecification::Temperature => {
+                let t = temperature_or_pressure * KELVIN;
+                let p_init = tp_init.map(|p| p * PASCAL);
+                PhaseEquilibrium::bubble_point(eos, t, &x, p_init, None, options)
+            }
+            FeosSpecification::Pressure => {
+                let p = temperature_or_pressure * PASCAL;
+                let t_init = tp_init.ok_or(FfiError::NullPointer("tp_init"))? * KELVIN;
+                PhaseEquilibrium::bubble_point(eos, p, &x, Some(t_init), None, options)
+            }
+        }?;
+        write_handle(vle, FeosPhaseEquilibrium(e), "vle")
+    })
+}
+
+/// Calculate the dew point of a vapor at given temperature (K) or pressure (Pa).
+///
+/// `tp_init` is an initial value for the pressure (Pa) or temperature (K). It
+/// can be null for a given temperature but is required for a given pressure.
+///
+/// # Safety
+/// `eos` has to be a valid handle, `vapor_molefracs` has to point to an array
+/// with one element per component and `vle` has to be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn feos_dew_point(
+    eos: *const FeosEos,
+    specification: FeosSpecification,
+    temperature_or_pressure: f64,
+    vapor_molefracs: *const f64,
+    tp_init: *const f64,
+    vle: *mut *mut FeosPhaseEquilibrium,
+) -> FeosStatus {
+    ffi_call(|| {
+        let eos = &deref(eos, "eos")?.0;
+        let y = read_molefracs(eos, vapor_molefracs, "vapor_molefracs")?;
+        let tp_init = tp_init.as_ref().copied();
+        let options = Default::default();
+        let e = match specification {
+            FeosSpecification::Temperature => {
+                let t = temperature_or_pressure * KELVIN;
+                let p_init = tp_init.map(|p| p * PASCAL);
+                PhaseEquilibrium::dew_point(eos, t, &y, p_init, None, options)
+            }
+            FeosSpecification::Pressure => {
+                let p = temperature_or_pressure * PASCAL;
+                let t_init = tp_init.ok_or(FfiError::NullPointer("tp_init"))? * KELVIN;
+                PhaseEquilibrium::dew_point(eos, p, &y, Some(t_init), None, options)
+            }
+        }?;
+        write_handle(vle, FeosPhaseEquilibrium(e), "vle")
+    })
+}
+
+/// Release a phase equilibrium.
+///
+/// # Safety
+/// `vle` has to be null or a handle created by this library that is not used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn feos_phase_equilibrium_free(vle: *mut FeosPhaseEquilibrium) {
+    free_handle(vle)
+}
+
+/// Copy of the vapor phase of a phase equilibrium.
+///
+/// The returned state has to be released with [feos_state_free].
+///
+/// # Safety
+/// `vle` has to be a valid handle and `state` a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn feos_phase_equilibrium_vapor(
+    vle: *const FeosPhaseEquilibrium,
+    state: *mut *mut FeosState,
+) -> FeosStatus {
+    ffi_call(|| {
+        let vle = &deref(vle, "vle")?.0;
+        write_handle(state, FeosState(vle.vapor().clone()), "state")
+    })
+}
+
+/// Copy of the liquid phase of a phase equilibrium.
+///
+/// The returned state has to be released with [feos_state_free].
+///
+/// # Safety
+/// `vle` has to be a valid handle and `state` a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn feos_phase_equilibrium_liquid(
+    vle: *const FeosPhaseEquilibrium,
+    state: *mut *mut FeosState,
+) -> FeosStatus {
+    ffi_call(|| {
+        let vle = &deref(vle, "vle")?.0;
+        write_handle(state, FeosState(vle.liquid().clone()), "state")
+    })
+}
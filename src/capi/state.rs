@@ -0,0 +1,290 @@
+use super::*;
+use feos_core::{Components, Contributions, DensityInitialization, State};
+use quantity::{Moles, JOULE, KELVIN, METER, MOL, PASCAL, SECOND};
+use typenum::P3;
+
+/// Opaque handle of a thermodynamic state.
+pub struct FeosState(pub(super) State<Eos>);
+
+/// Initialization of the density iteration for states at given pressure.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeosDensityInitialization {
+    /// Calculate the most stable phase.
+    None = 0,
+    /// Calculate a vapor phase.
+    Vapor = 1,
+    /// Calculate a liquid phase.
+    Liquid = 2,
+}
+
+impl From<FeosDensityInitialization> for DensityInitialization {
+    fn from(density_initialization: FeosDensityInitialization) -> Self {
+        match density_initialization {
+            FeosDensityInitialization::None => Self::None,
+            FeosDensityInitialization::Vapor => Self::Vapor,
+            FeosDensityInitialization::Liquid => Self::Liquid,
+        }
+    }
+}
+
+/// Contributions to the Helmholtz energy considered in the calculation of properties.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeosContributions {
+    IdealGas = 0,
+    Residual = 1,
+    Total = 2,
+}
+
+impl From<FeosContributions> for Contributions {
+    fn from(contributions: FeosContributions) -> Self {
+        match contributions {
+            FeosContributions::IdealGas => Self::IdealGas,
+            FeosContributions::Residual => Self::Residual,
+            FeosContributions::Total => Self::Total,
+        }
+    }
+}
+
+/// Scalar properties of a state that can be evaluated with [feos_state_property].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeosProperty {
+    /// Temperature in K.
+    Temperature = 0,
+    /// Volume in m³.
+    Volume = 1,
+    /// Total amount of substance in mol.
+    TotalMoles = 2,
+    /// Molar density in mol/m³.
+    Density = 3,
+    /// Pressure in Pa.
+    Pressure = 4,
+    /// Compressibility factor.
+    Compressibility = 5,
+    /// Molar enthalpy in J/mol.
+    MolarEnthalpy = 6,
+    /// Molar entropy in J/(mol K).
+    MolarEntropy = 7,
+    /// Molar internal energy in J/mol.
+    MolarInternalEnergy = 8,
+    /// Molar Helmholtz energy in J/mol.
+    MolarHelmholtzEnergy = 9,
+    /// Molar Gibbs energy in J/mol.
+    MolarGibbsEnergy = 10,
+    /// Molar isochoric heat capacity in J/(mol K).
+    MolarIsochoricHeatCapacity = 11,
+    /// Molar isobaric heat capacity in J/(mol K).
+    MolarIsobaricHeatCapacity = 12,
+    /// Speed of sound in m/s. Requires molar weights.
+    SpeedOfSound = 13,
+    /// Isothermal compressibility in 1/Pa.
+    IsothermalCompressibility = 14,
+    /// Joule-Thomson coefficient in K/Pa.
+    JouleThomson = 15,
+}
+
+unsafe fn read_moles(eos: &Eos, moles: *const f64) -> FfiResult<Moles<Array1<f64>>> {
+    let moles = to_slice(moles, eos.components(), "moles")?;
+    Ok(Array1::from_vec(moles.to_vec()) * MOL)
+}
+
+/// Create a state at given temperature (K), volume (m³) and amount of substance (mol).
+///
+/// # Safety
+/// `eos` has to be a valid handle, `moles` has to point to an array with one
+/// element per component and `state` has to be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn feos_state_new_nvt(
+    eos: *const FeosEos,
+    temperature: f64,
+    volume: f64,
+    moles: *const f64,
+    state: *mut *mut FeosState,
+) -> FeosStatus {
+    ffi_call(|| {
+        let eos = &deref(eos, "eos")?.0;
+        let moles = read_moles(eos, moles)?;
+        let volume = volume * METER.powi::<P3>();
+        let s = State::new_nvt(eos, temperature * KELVIN, volume, &moles)?;
+        write_handle(state, FeosState(s), "state")
+    })
+}
+
+/// Create a state at given temperature (K), pressure (Pa) and amount of substance (mol).
+///
+/// # Safety
+/// `eos` has to be a valid handle, `moles` has to point to an array with one
+/// element per component and `state` has to be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn feos_state_new_npt(
+    eos: *const FeosEos,
+    temperature: f64,
+    pressure: f64,
+    moles: *const f64,
+    density_initialization: FeosDensityInitialization,
+    state: *mut *mut FeosState,
+) -> FeosStatus {
+    ffi_call(|| {
+        let eos = &deref(eos, "eos")?.0;
+        let moles = read_moles(eos, moles)?;
+        let s = State::new_npt(
+            eos,
+            temperature * KELVIN,
+            pressure * PASCAL,
+            &moles,
+            density_initialization.into(),
+        )?;
+        write_handle(state, FeosState(s), "state")
+    })
+}
+
+/// Create a state at given pressure (Pa), molar enthalpy (J/mol) and amount of substance (mol).
+///
+/// `initial_temperature` (K) is optional and can be null.
+///
+/// # Safety
+/// `eos` has to be a valid handle, `moles` has to point to an array with one
+/// element per component and `state` has to be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn feos_state_new_nph(
+    eos: *const FeosEos,
+    pressure: f64,
+    molar_enthalpy: f64,
+    moles: *const f64,
+    density_initialization: FeosDensityInitialization,
+    initial_temperature: *const f64,
+    state: *mut *mut FeosState,
+) -> FeosStatus {
+    ffi_call(|| {
+        let eos = &deref(eos, "eos")?.0;
+        let moles = read_moles(eos, moles)?;
+        let s = State::new_nph(
+            eos,
+            pressure * PASCAL,
+            molar_enthalpy * JOULE / MOL,
+            &moles,
+            density_initialization.into(),
+            initial_temperature.as_ref().map(|&t| t * KELVIN),
+        )?;
+        write_handle(state, FeosState(s), "state")
+    })
+}
+
+/// Release a state.
+///
+/// # Safety
+/// `state` has to be null or a handle created by this library that is not used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn feos_state_free(state: *mut FeosState) {
+    free_handle(state)
+}
+
+/// Evaluate a scalar property of a state in SI units.
+///
+/// The contributions are ignored for properties that do not depend on them
+/// (e.g. temperature or density).
+///
+/// # Safety
+/// `state` has to be a valid handle and `value` a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn feos_state_property(
+    state: *const FeosState,
+    property: FeosProperty,
+    contributions: FeosContributions,
+    value: *mut f64,
+) -> FeosStatus {
+    ffi_call(|| {
+        let s = &deref(state, "state")?.0;
+        let c = contributions.into();
+        let molar_energy = JOULE / MOL;
+        let molar_entropy = JOULE / MOL / KELVIN;
+        let v = match property {
+            FeosProperty::Temperature => s.temperature.convert_into(KELVIN),
+            FeosProperty::Volume => s.volume.convert_into(METER.powi::<P3>()),
+            FeosProperty::TotalMoles => s.total_moles.convert_into(MOL),
+            FeosProperty::Density => s.density.convert_into(MOL / METER.powi::<P3>()),
+            FeosProperty::Pressure => s.pressure(c).convert_into(PASCAL),
+            FeosProperty::Compressibility => s.compressibility(c),
+            FeosProperty::MolarEnthalpy => s.molar_enthalpy(c).convert_into(molar_energy),
+            FeosProperty::MolarEntropy => s.molar_entropy(c).convert_into(molar_entropy),
+            FeosProperty::MolarInternalEnergy => {
+                s.molar_internal_energy(c).convert_into(molar_energy)
+            }
+            FeosProperty::MolarHelmholtzEnergy => {
+                s.molar_helmholtz_energy(c).convert_into(molar_energy)
+            }
+            FeosProperty::MolarGibbsEnergy => s.molar_gibbs_energy(c).convert_into(molar_energy),
+            FeosProperty::MolarIsochoricHeatCapacity => s
+                .molar_isochoric_heat_capacity(c)
+                .convert_into(molar_entropy),
+            FeosProperty::MolarIsobaricHeatCapacity => s
+                .molar_isobaric_heat_capacity(c)
+                .convert_into(molar_entropy),
+            FeosProperty::SpeedOfSound => {
+                if !s.eos.residual.has_molar_weight() {
+                    return Err(FfiError::InvalidArgument(
+                        "The equation of state does not provide molar weights.".into(),
+                    ));
+                }
+                s.speed_of_sound().convert_into(METER / SECOND)
+            }
+            FeosProperty::IsothermalCompressibility => {
+                s.isothermal_compressibility().convert_into(1.0 / PASCAL)
+            }
+            FeosProperty::JouleThomson => s.joule_thomson().convert_into(KELVIN / PASCAL),
+        };
+        write(value, v, "value")
+    })
+}
+
+/// Mole fractions of all components.
+///
+/// # Safety
+/// `state` has to be a valid handle and `molefracs` has to point to an array
+/// with one element per component.
+#[no_mangle]
+pub unsafe extern "C" fn feos_state_molefracs(
+    state: *const FeosState,
+    molefracs: *mut f64,
+) -> FeosStatus {
+    ffi_call(|| {
+        let s = &deref(state, "state")?.0;
+        write_array(molefracs, &s.molefracs, "molefracs")
+    })
+}
+
+/// Logarithms of the fugacity coefficients of all components.
+///
+/// # Safety
+/// `state` has to be a valid handle and `ln_phi` has to point to an array
+/// with one element per component.
+#[no_mangle]
+pub unsafe extern "C" fn feos_state_ln_phi(
+    state: *const FeosState,
+    ln_phi: *mut f64,
+) -> FeosStatus {
+    ffi_call(|| {
+        let s = &deref(state, "state")?.0;
+        write_array(ln_phi, &s.ln_phi(), "ln_phi")
+    })
+}
+
+/// Fugacities $f_i=\varphi_ix_ip$ of all components in Pa.
+///
+/// # Safety
+/// `state` has to be a valid handle and `fugacity` has to point to an array
+/// with one element per component.
+#[no_mangle]
+pub unsafe extern "C" fn feos_state_fugacity(
+    state: *const FeosState,
+    fugacity: *mut f64,
+) -> FeosStatus {
+    ffi_call(|| {
+        let s = &deref(state, "state")?.0;
+        let p = s.pressure(Contributions::Total).convert_into(PASCAL);
+        let f = s.ln_phi().mapv(f64::exp) * &s.molefracs * p;
+        write_array(fugacity, &f, "fugacity")
+    })
+}
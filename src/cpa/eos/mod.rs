@@ -0,0 +1,194 @@
+use super::parameters::CpaParameters;
+use crate::association::Association;
+use crate::hard_sphere::HardSphereProperties;
+use feos_core::parameter::Parameter;
+use feos_core::{Components, Molarweight, Residual, StateHD};
+use ndarray::Array1;
+use num_dual::DualNum;
+use quantity::{MolarWeight, GRAM, MOL};
+use std::fmt;
+use std::sync::Arc;
+
+/// Configuration options for the CPA equation of state.
+#[derive(Copy, Clone)]
+pub struct CpaOptions {
+    /// maximum reduced density $b\rho$
+    pub max_b_rho: f64,
+    /// maximum number of iterations for cross association
+    pub max_iter_cross_assoc: usize,
+    /// tolerance for cross association
+    pub tol_cross_assoc: f64,
+}
+
+impl Default for CpaOptions {
+    fn default() -> Self {
+        Self {
+            max_b_rho: 0.9,
+            max_iter_cross_assoc: 50,
+            tol_cross_assoc: 1e-10,
+        }
+    }
+}
+
+/// Cubic-Plus-Association equation of state.
+pub struct Cpa {
+    parameters: Arc<CpaParameters>,
+    options: CpaOptions,
+    cubic: Srk,
+    association: Option<Association<CpaParameters>>,
+}
+
+impl Cpa {
+    /// CPA equation of state with default options.
+    pub fn new(parameters: Arc<CpaParameters>) -> Self {
+        Self::with_options(parameters, CpaOptions::default())
+    }
+
+    /// CPA equation of state with provided options.
+    pub fn with_options(parameters: Arc<CpaParameters>, options: CpaOptions) -> Self {
+        let cubic = Srk {
+            parameters: parameters.clone(),
+        };
+        let association = if !parameters.association.is_empty() {
+            Some(Association::new(
+                &parameters,
+                &parameters.association,
+                options.max_iter_cross_assoc,
+                options.tol_cross_assoc,
+            ))
+        } else {
+            None
+        };
+        Self {
+            parameters,
+            options,
+            cubic,
+            association,
+        }
+    }
+}
+
+impl fmt::Display for Cpa {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CPA")
+    }
+}
+
+impl Components for Cpa {
+    fn components(&self) -> usize {
+        self.parameters.pure_records.len()
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        Self::with_options(
+            Arc::new(self.parameters.subset(component_list)),
+            self.options,
+        )
+    }
+}
+
+impl Residual for Cpa {
+    fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
+        self.options.max_b_rho * moles.sum() / (&self.parameters.b * moles).sum()
+    }
+
+    fn residual_helmholtz_energy_contributions<D: DualNum<f64> + Copy>(
+        &self,
+        state: &StateHD<D>,
+    ) -> Vec<(String, D)> {
+        let mut v = Vec::with_capacity(2);
+        v.push((self.cubic.to_string(), self.cubic.helmholtz_energy(state)));
+        if let Some(association) = self.association.as_ref() {
+            let d = self.parameters.hs_diameter(state.temperature);
+            v.push((
+                association.to_string(),
+                association.helmholtz_energy(state, &d),
+            ))
+        }
+        v
+    }
+}
+
+impl Molarweight for Cpa {
+    fn molar_weight(&self) -> MolarWeight<Array1<f64>> {
+        self.parameters.molarweight.clone() * GRAM / MOL
+    }
+}
+
+/// Soave-Redlich-Kwong contribution of the CPA equation of state.
+struct Srk {
+    parameters: Arc<CpaParameters>,
+}
+
+impl Srk {
+    fn helmholtz_energy<D: DualNum<f64> + Copy>(&self, state: &StateHD<D>) -> D {
+        let p = &self.parameters;
+        let t = state.temperature;
+        let x = &state.molefracs;
+
+        // temperature dependent energy parameters
+        let ai = Array1::from_shape_fn(p.tc.len(), |i| {
+            let alpha = (-(t / p.tc[i]).sqrt() + 1.0) * p.c1[i] + 1.0;
+            alpha * alpha * p.a0[i]
+        });
+
+        // mixing rules
+        let mut a = D::zero();
+        for i in 0..ai.len() {
+            for j in 0..ai.len() {
+                a += (ai[i] * ai[j]).sqrt() * x[i] * x[j] * (1.0 - p.k_ij[(i, j)]);
+            }
+        }
+        let b = (x * &p.b).sum();
+
+        let rho = state.partial_density.sum();
+        let b_rho = rho * b;
+        state.moles.sum() * (-(-b_rho + 1.0).ln() - a / (t * b) * b_rho.ln_1p())
+    }
+}
+
+impl fmt::Display for Srk {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SRK")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpa::parameters::utils::{methane_parameters, water_parameters};
+    use approx::assert_relative_eq;
+    use feos_core::{Contributions, State};
+    use ndarray::arr1;
+    use quantity::{KELVIN, METER, MOL, PASCAL};
+    use typenum::P3;
+
+    #[test]
+    fn srk_pressure() {
+        let parameters = methane_parameters();
+        let cpa = Arc::new(Cpa::new(parameters));
+        let t = 250.0 * KELVIN;
+        let v = 1e-3 * METER.powi::<P3>();
+        let n = 0.2 * MOL;
+        let s = State::new_nvt(&cpa, t, v, &(arr1(&[1.0]) * n)).unwrap();
+        let p = s.pressure(Contributions::Total);
+
+        let (r, a0, b, c1, tc) = (8.31446261815324, 0.23204, 2.91e-5, 0.4472, 190.56);
+        let alpha = (1.0 + c1 * (1.0 - (250.0f64 / tc).sqrt())).powi(2);
+        let vm = 1e-3 / 0.2;
+        let p_srk = r * 250.0 / (vm - b) - a0 * alpha / (vm * (vm + b));
+        assert_relative_eq!(p.convert_into(PASCAL), p_srk, max_relative = 1e-8);
+    }
+
+    #[test]
+    fn water_association() {
+        let parameters = water_parameters();
+        let cpa = Cpa::new(parameters);
+        assert!(cpa.association.is_some());
+        let t = 350.0;
+        let rho = 0.03;
+        let s = StateHD::new(t, 1.0 / rho, arr1(&[1.0]));
+        let a_assoc = cpa.residual_helmholtz_energy_contributions(&s)[1].1;
+        assert!(a_assoc < 0.0);
+    }
+}
@@ -0,0 +1,14 @@
+//! Cubic-Plus-Association (CPA) equation of state
+//!
+//! [Kontogeorgis et al. (1996)](https://doi.org/10.1021/ie9600203)
+//!
+//! CPA combines the Soave-Redlich-Kwong equation of state with the
+//! association term of Wertheim's theory, using the simplified radial
+//! distribution function of [Kontogeorgis et al. (1999)](https://doi.org/10.1016/S0378-3812(99)00060-6).
+mod eos;
+mod parameters;
+#[cfg(feature = "python")]
+pub mod python;
+
+pub use eos::{Cpa, CpaOptions};
+pub use parameters::{CpaAssociationRecord, CpaBinaryRecord, CpaParameters, CpaRecord};
@@ -0,0 +1,445 @@
+use crate::association::{
+    AssociationParameters, AssociationRecord, AssociationStrength, BinaryAssociationRecord,
+};
+use crate::hard_sphere::{HardSphereProperties, MonomerShape};
+use feos_core::parameter::{Parameter, ParameterError, PureRecord};
+use ndarray::{Array, Array1, Array2};
+use num_dual::DualNum;
+use num_traits::Zero;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::FRAC_PI_6;
+use std::fmt::Write;
+use std::sync::Arc;
+
+/// Avogadro's number in 1/mol.
+const NAV: f64 = 6.02214076e23;
+/// Boltzmann constant in J/K.
+const KB: f64 = 1.380649e-23;
+
+/// CPA pure-component parameters.
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct CpaRecord {
+    /// Critical temperature in units of Kelvin
+    pub tc: f64,
+    /// Energy parameter of the cubic term in units of Pa m⁶/mol²
+    pub a0: f64,
+    /// Co-volume in units of m³/mol
+    pub b: f64,
+    /// Parameter of the Soave temperature function
+    pub c1: f64,
+    /// Association parameters
+    #[serde(flatten)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub association_record: Option<AssociationRecord<CpaAssociationRecord>>,
+}
+
+impl CpaRecord {
+    #[expect(clippy::too_many_arguments)]
+    pub fn new(
+        tc: f64,
+        a0: f64,
+        b: f64,
+        c1: f64,
+        epsilon_k_ab: Option<f64>,
+        beta_ab: Option<f64>,
+        na: Option<f64>,
+        nb: Option<f64>,
+        nc: Option<f64>,
+    ) -> Self {
+        let association_record = if na.is_none() && nb.is_none() && nc.is_none() {
+            None
+        } else {
+            Some(AssociationRecord::new(
+                CpaAssociationRecord::new(epsilon_k_ab, beta_ab),
+                na.unwrap_or_default(),
+                nb.unwrap_or_default(),
+                nc.unwrap_or_default(),
+            ))
+        };
+        Self {
+            tc,
+            a0,
+            b,
+            c1,
+            association_record,
+        }
+    }
+}
+
+impl std::fmt::Display for CpaRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CpaRecord(tc={}", self.tc)?;
+        write!(f, ", a0={}", self.a0)?;
+        write!(f, ", b={}", self.b)?;
+        write!(f, ", c1={}", self.c1)?;
+        if let Some(n) = &self.association_record {
+            write!(f, ", association_record={}", n)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// CPA association parameters.
+#[derive(Serialize, Deserialize, Clone, Copy, Default)]
+pub struct CpaAssociationRecord {
+    /// Association energy parameter in units of Kelvin
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub epsilon_k_ab: Option<f64>,
+    /// Association volume parameter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub beta_ab: Option<f64>,
+}
+
+impl CpaAssociationRecord {
+    pub fn new(epsilon_k_ab: Option<f64>, beta_ab: Option<f64>) -> Self {
+        Self {
+            epsilon_k_ab,
+            beta_ab,
+        }
+    }
+}
+
+impl std::fmt::Display for CpaAssociationRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut params = vec![];
+        if let Some(epsilon_k_ab) = self.epsilon_k_ab {
+            params.push(format!("epsilon_k_ab={}", epsilon_k_ab));
+        }
+        if let Some(beta_ab) = self.beta_ab {
+            params.push(format!("beta_ab={}", beta_ab));
+        }
+        write!(f, "CpaAssociationRecord({})", params.join(", "))
+    }
+}
+
+/// CPA binary interaction parameters.
+#[derive(Serialize, Deserialize, Clone, Copy, Default)]
+pub struct CpaBinaryRecord {
+    /// Binary interaction parameter of the cubic term
+    #[serde(skip_serializing_if = "f64::is_zero")]
+    #[serde(default)]
+    pub k_ij: f64,
+    /// Binary association parameters
+    #[serde(flatten)]
+    association: Option<BinaryAssociationRecord<CpaAssociationRecord>>,
+}
+
+impl From<f64> for CpaBinaryRecord {
+    fn from(k_ij: f64) -> Self {
+        Self {
+            k_ij,
+            association: None,
+        }
+    }
+}
+
+impl From<CpaBinaryRecord> for f64 {
+    fn from(binary_record: CpaBinaryRecord) -> Self {
+        binary_record.k_ij
+    }
+}
+
+impl CpaBinaryRecord {
+    pub fn new(k_ij: Option<f64>, epsilon_k_ab: Option<f64>, beta_ab: Option<f64>) -> Self {
+        let k_ij = k_ij.unwrap_or_default();
+        let association = if epsilon_k_ab.is_none() && beta_ab.is_none() {
+            None
+        } else {
+            Some(BinaryAssociationRecord::new(
+                CpaAssociationRecord::new(epsilon_k_ab, beta_ab),
+                None,
+            ))
+        };
+        Self { k_ij, association }
+    }
+}
+
+impl std::fmt::Display for CpaBinaryRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut tokens = vec![];
+        if !self.k_ij.is_zero() {
+            tokens.push(format!("k_ij={}", self.k_ij));
+        }
+        if let Some(association) = self.association {
+            if let Some(epsilon_k_ab) = association.parameters.epsilon_k_ab {
+                tokens.push(format!("epsilon_k_ab={}", epsilon_k_ab));
+            }
+            if let Some(beta_ab) = association.parameters.beta_ab {
+                tokens.push(format!("beta_ab={}", beta_ab));
+            }
+        }
+        write!(f, "CpaBinaryRecord({})", tokens.join(", "))
+    }
+}
+
+/// Parameter set required for the CPA equation of state.
+///
+/// Internally, the energy parameter `a0` is stored in units of K Å³
+/// and the co-volume `b` in units of Å³ (per molecule).
+pub struct CpaParameters {
+    pub molarweight: Array1<f64>,
+    pub tc: Array1<f64>,
+    pub a0: Array1<f64>,
+    pub b: Array1<f64>,
+    pub c1: Array1<f64>,
+    pub k_ij: Array2<f64>,
+    pub association: Arc<AssociationParameters<Self>>,
+    pub pure_records: Vec<PureRecord<CpaRecord>>,
+    pub binary_records: Option<Array2<CpaBinaryRecord>>,
+}
+
+impl Parameter for CpaParameters {
+    type Pure = CpaRecord;
+    type Binary = CpaBinaryRecord;
+
+    fn from_records(
+        pure_records: Vec<PureRecord<Self::Pure>>,
+        binary_records: Option<Array2<Self::Binary>>,
+    ) -> Result<Self, ParameterError> {
+        let n = pure_records.len();
+
+        let mut molarweight = Array::zeros(n);
+        let mut tc = Array::zeros(n);
+        let mut a0 = Array::zeros(n);
+        let mut b = Array::zeros(n);
+        let mut c1 = Array::zeros(n);
+        let mut association_records = Vec::with_capacity(n);
+
+        for (i, record) in pure_records.iter().enumerate() {
+            let r = &record.model_record;
+            molarweight[i] = record.molarweight;
+            tc[i] = r.tc;
+            a0[i] = r.a0 / (NAV * NAV * KB) * 1e30;
+            b[i] = r.b / NAV * 1e30;
+            c1[i] = r.c1;
+            association_records.push(r.association_record.clone().into_iter().collect());
+        }
+
+        let binary_association: Vec<_> = binary_records
+            .iter()
+            .flat_map(|r| {
+                r.indexed_iter()
+                    .filter_map(|((i, j), record)| record.association.map(|r| ([i, j], r)))
+            })
+            .collect();
+        let association =
+            AssociationParameters::new(&association_records, &binary_association, None);
+
+        let k_ij = binary_records
+            .as_ref()
+            .map_or_else(|| Array2::zeros([n; 2]), |br| br.map(|br| br.k_ij));
+
+        Ok(Self {
+            molarweight,
+            tc,
+            a0,
+            b,
+            c1,
+            k_ij,
+            association: Arc::new(association),
+            pure_records,
+            binary_records,
+        })
+    }
+
+    fn records(&self) -> (&[PureRecord<CpaRecord>], Option<&Array2<CpaBinaryRecord>>) {
+        (&self.pure_records, self.binary_records.as_ref())
+    }
+}
+
+/// The co-volume is mapped onto a hard-sphere diameter, so that the
+/// packing fraction $\zeta_3$ corresponds to the reduced density
+/// $\eta=\frac{b\rho}{4}$ of CPA.
+impl HardSphereProperties for CpaParameters {
+    fn monomer_shape<N: DualNum<f64>>(&self, _: N) -> MonomerShape<N> {
+        MonomerShape::Spherical(self.b.len())
+    }
+
+    fn hs_diameter<D: DualNum<f64> + Copy>(&self, _: D) -> Array1<D> {
+        self.b.mapv(|b| D::from((0.25 * b / FRAC_PI_6).cbrt()))
+    }
+}
+
+impl AssociationStrength for CpaParameters {
+    type Record = CpaAssociationRecord;
+    type BinaryRecord = CpaAssociationRecord;
+
+    fn association_strength<D: DualNum<f64> + Copy>(
+        &self,
+        temperature: D,
+        comp_i: usize,
+        comp_j: usize,
+        assoc_ij: Self::Record,
+    ) -> D {
+        if let (Some(epsilon_k_ab), Some(beta_ab)) = (assoc_ij.epsilon_k_ab, assoc_ij.beta_ab) {
+            let b_ij = 0.5 * (self.b[comp_i] + self.b[comp_j]);
+            (temperature.recip() * epsilon_k_ab).exp_m1() * beta_ab * b_ij
+        } else {
+            D::zero()
+        }
+    }
+
+    /// CR-1 combining rule: arithmetic mean of the association energies
+    /// and geometric mean of the association volumes.
+    fn combining_rule(parameters_i: Self::Record, parameters_j: Self::Record) -> Self::Record {
+        let epsilon_k_ab = if let (Some(epsilon_k_ab_i), Some(epsilon_k_ab_j)) =
+            (parameters_i.epsilon_k_ab, parameters_j.epsilon_k_ab)
+        {
+            Some(0.5 * (epsilon_k_ab_i + epsilon_k_ab_j))
+        } else {
+            None
+        };
+        let beta_ab = if let (Some(beta_ab_i), Some(beta_ab_j)) =
+            (parameters_i.beta_ab, parameters_j.beta_ab)
+        {
+            Some((beta_ab_i * beta_ab_j).sqrt())
+        } else {
+            None
+        };
+        Self::Record {
+            epsilon_k_ab,
+            beta_ab,
+        }
+    }
+
+    fn update_binary(parameters_ij: &mut Self::Record, binary_parameters: Self::BinaryRecord) {
+        if let Some(epsilon_k_ab) = binary_parameters.epsilon_k_ab {
+            parameters_ij.epsilon_k_ab = Some(epsilon_k_ab)
+        }
+        if let Some(beta_ab) = binary_parameters.beta_ab {
+            parameters_ij.beta_ab = Some(beta_ab)
+        }
+    }
+
+    /// Simplified radial distribution function $g=\frac{1}{1-1.9\eta}$
+    /// of Kontogeorgis et al. (1999).
+    fn contact_value<D: DualNum<f64> + Copy>(&self, _: D, _: D, _: D, n3i: D, _: D) -> D {
+        let eta = -n3i.recip() + 1.0;
+        (-eta * 1.9 + 1.0).recip()
+    }
+}
+
+impl CpaParameters {
+    pub fn to_markdown(&self) -> String {
+        let mut output = String::new();
+        let o = &mut output;
+        write!(
+            o,
+            "|component|molarweight|$T_c$|$a_0$|$b$|$c_1$|$\\varepsilon_{{AB}}$|$\\beta_{{AB}}$|$N_A$|$N_B$|$N_C$|\n|-|-|-|-|-|-|-|-|-|-|-|"
+        )
+        .unwrap();
+        for (i, record) in self.pure_records.iter().enumerate() {
+            let component = record.identifier.name.clone();
+            let component = component.unwrap_or(format!("Component {}", i + 1));
+            let r = &record.model_record;
+            let (epsilon_k_ab, beta_ab, na, nb, nc) = r.association_record.as_ref().map_or(
+                (
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                ),
+                |a| {
+                    (
+                        a.parameters
+                            .epsilon_k_ab
+                            .map_or(String::new(), |e| e.to_string()),
+                        a.parameters
+                            .beta_ab
+                            .map_or(String::new(), |b| b.to_string()),
+                        a.na.to_string(),
+                        a.nb.to_string(),
+                        a.nc.to_string(),
+                    )
+                },
+            );
+            write!(
+                o,
+                "\n|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|",
+                component,
+                record.molarweight,
+                r.tc,
+                r.a0,
+                r.b,
+                r.c1,
+                epsilon_k_ab,
+                beta_ab,
+                na,
+                nb,
+                nc
+            )
+            .unwrap();
+        }
+
+        output
+    }
+}
+
+impl std::fmt::Display for CpaParameters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CpaParameters(")?;
+        write!(f, "\n\tmolarweight={}", self.molarweight)?;
+        write!(f, "\n\ttc={}", self.tc)?;
+        write!(f, "\n\tc1={}", self.c1)?;
+        write!(f, "\n\tk_ij=\n{}", self.k_ij)?;
+        write!(f, "\n)")
+    }
+}
+
+#[cfg(test)]
+pub mod utils {
+    use super::*;
+
+    pub fn water_parameters() -> Arc<CpaParameters> {
+        let water_json = r#"
+            {
+                "identifier": {
+                    "cas": "7732-18-5",
+                    "name": "water",
+                    "iupac_name": "oxidane",
+                    "smiles": "O",
+                    "inchi": "InChI=1/H2O/h1H2",
+                    "formula": "H2O"
+                },
+                "model_record": {
+                    "tc": 647.29,
+                    "a0": 0.12277,
+                    "b": 1.4515e-5,
+                    "c1": 0.67359,
+                    "epsilon_k_ab": 2003.1,
+                    "beta_ab": 0.0692,
+                    "na": 2.0,
+                    "nb": 2.0
+                },
+                "molarweight": 18.0153
+            }"#;
+        let water_record: PureRecord<CpaRecord> =
+            serde_json::from_str(water_json).expect("Unable to parse json.");
+        Arc::new(CpaParameters::new_pure(water_record).unwrap())
+    }
+
+    pub fn methane_parameters() -> Arc<CpaParameters> {
+        let methane_json = r#"
+            {
+                "identifier": {
+                    "cas": "74-82-8",
+                    "name": "methane",
+                    "iupac_name": "methane",
+                    "smiles": "C",
+                    "inchi": "InChI=1/CH4/h1H4",
+                    "formula": "CH4"
+                },
+                "model_record": {
+                    "tc": 190.56,
+                    "a0": 0.23204,
+                    "b": 2.91e-5,
+                    "c1": 0.4472
+                },
+                "molarweight": 16.0425
+            }"#;
+        let methane_record: PureRecord<CpaRecord> =
+            serde_json::from_str(methane_json).expect("Unable to parse json.");
+        Arc::new(CpaParameters::new_pure(methane_record).unwrap())
+    }
+}
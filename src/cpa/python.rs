@@ -0,0 +1,189 @@
+use super::parameters::{CpaBinaryRecord, CpaParameters, CpaRecord};
+use feos_core::parameter::{
+    BinaryRecord, Identifier, IdentifierOption, Parameter, ParameterError, PureRecord,
+};
+use feos_core::python::parameter::*;
+use feos_core::*;
+use numpy::{PyArray2, PyReadonlyArray2, ToPyArray};
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use std::convert::{TryFrom, TryInto};
+use std::sync::Arc;
+
+/// Pure-substance parameters for the CPA equation of state.
+///
+/// Parameters
+/// ----------
+/// tc : float
+///     Critical temperature in units of Kelvin.
+/// a0 : float
+///     Energy parameter of the cubic term in units of Pa m^6/mol^2.
+/// b : float
+///     Co-volume in units of m^3/mol.
+/// c1 : float
+///     Parameter of the Soave temperature function.
+/// epsilon_k_ab : float, optional
+///     Association energy parameter in units of Kelvin.
+/// beta_ab : float, optional
+///     Association volume parameter.
+/// na : float, optional
+///     Number of association sites of type A.
+/// nb : float, optional
+///     Number of association sites of type B.
+/// nc : float, optional
+///     Number of association sites of type C.
+#[pyclass(name = "CpaRecord")]
+#[derive(Clone)]
+pub struct PyCpaRecord(CpaRecord);
+
+#[pymethods]
+impl PyCpaRecord {
+    #[new]
+    #[pyo3(
+        text_signature = "(tc, a0, b, c1, epsilon_k_ab=None, beta_ab=None, na=None, nb=None, nc=None)",
+        signature = (tc, a0, b, c1, epsilon_k_ab=None, beta_ab=None, na=None, nb=None, nc=None)
+    )]
+    #[expect(clippy::too_many_arguments)]
+    fn new(
+        tc: f64,
+        a0: f64,
+        b: f64,
+        c1: f64,
+        epsilon_k_ab: Option<f64>,
+        beta_ab: Option<f64>,
+        na: Option<f64>,
+        nb: Option<f64>,
+        nc: Option<f64>,
+    ) -> Self {
+        Self(CpaRecord::new(
+            tc,
+            a0,
+            b,
+            c1,
+            epsilon_k_ab,
+            beta_ab,
+            na,
+            nb,
+            nc,
+        ))
+    }
+
+    #[getter]
+    fn get_tc(&self) -> f64 {
+        self.0.tc
+    }
+
+    #[getter]
+    fn get_a0(&self) -> f64 {
+        self.0.a0
+    }
+
+    #[getter]
+    fn get_b(&self) -> f64 {
+        self.0.b
+    }
+
+    #[getter]
+    fn get_c1(&self) -> f64 {
+        self.0.c1
+    }
+
+    #[getter]
+    fn get_epsilon_k_ab(&self) -> Option<f64> {
+        self.0
+            .association_record
+            .as_ref()
+            .and_then(|a| a.parameters.epsilon_k_ab)
+    }
+
+    #[getter]
+    fn get_beta_ab(&self) -> Option<f64> {
+        self.0
+            .association_record
+            .as_ref()
+            .and_then(|a| a.parameters.beta_ab)
+    }
+
+    #[getter]
+    fn get_na(&self) -> Option<f64> {
+        self.0.association_record.as_ref().map(|a| a.na)
+    }
+
+    #[getter]
+    fn get_nb(&self) -> Option<f64> {
+        self.0.association_record.as_ref().map(|a| a.nb)
+    }
+
+    #[getter]
+    fn get_nc(&self) -> Option<f64> {
+        self.0.association_record.as_ref().map(|a| a.nc)
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+impl_json_handling!(PyCpaRecord);
+
+impl_pure_record!(CpaRecord, PyCpaRecord);
+
+/// Create a record for a binary interaction parameter.
+#[pyclass(name = "CpaBinaryRecord")]
+#[derive(Clone)]
+pub struct PyCpaBinaryRecord(CpaBinaryRecord);
+
+#[pymethods]
+impl PyCpaBinaryRecord {
+    #[new]
+    #[pyo3(text_signature = "(k_ij=None, epsilon_k_ab=None, beta_ab=None)")]
+    #[pyo3(signature = (k_ij=None, epsilon_k_ab=None, beta_ab=None))]
+    fn new(k_ij: Option<f64>, epsilon_k_ab: Option<f64>, beta_ab: Option<f64>) -> Self {
+        Self(CpaBinaryRecord::new(k_ij, epsilon_k_ab, beta_ab))
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+impl_json_handling!(PyCpaBinaryRecord);
+
+impl_binary_record!(CpaBinaryRecord, PyCpaBinaryRecord);
+
+#[pyclass(name = "CpaParameters")]
+#[derive(Clone)]
+pub struct PyCpaParameters(pub Arc<CpaParameters>);
+
+impl_parameter!(
+    CpaParameters,
+    PyCpaParameters,
+    PyCpaRecord,
+    PyCpaBinaryRecord
+);
+
+#[pymethods]
+impl PyCpaParameters {
+    #[getter]
+    fn get_k_ij<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<f64>> {
+        self.0.k_ij.view().to_pyarray(py)
+    }
+
+    fn _repr_markdown_(&self) -> String {
+        self.0.to_markdown()
+    }
+}
+
+#[pymodule]
+pub fn cpa(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyIdentifier>()?;
+    m.add_class::<IdentifierOption>()?;
+    m.add_class::<PyChemicalRecord>()?;
+
+    m.add_class::<PyCpaRecord>()?;
+    m.add_class::<PyCpaBinaryRecord>()?;
+    m.add_class::<PyPureRecord>()?;
+    m.add_class::<PyBinaryRecord>()?;
+    m.add_class::<PyCpaParameters>()?;
+    Ok(())
+}
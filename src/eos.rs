@@ -19,7 +19,7 @@ pub enum ResidualModel {
     // Equations of state
     NoResidual(NoResidual),
     #[cfg(feature = "pcsaft")]
-    #[implement(entropy_scaling, molar_weight)]
+    #[implement(entropy_scaling, molar_weight, pair_potential)]
     PcSaft(crate::pcsaft::PcSaft),
 
     #[cfg(feature = "epcsaft")]
@@ -27,12 +27,20 @@ pub enum ResidualModel {
     ElectrolytePcSaft(crate::epcsaft::ElectrolytePcSaft),
 
     #[cfg(feature = "gc_pcsaft")]
-    #[implement(molar_weight)]
+    #[implement(entropy_scaling, molar_weight)]
     GcPcSaft(crate::gc_pcsaft::GcPcSaft),
 
     #[implement(molar_weight)]
     PengRobinson(PengRobinson),
 
+    #[cfg(feature = "cpa")]
+    #[implement(molar_weight)]
+    Cpa(crate::cpa::Cpa),
+
+    #[cfg(feature = "multiparameter")]
+    #[implement(molar_weight)]
+    Multiparameter(crate::multiparameter::Multiparameter),
+
     #[cfg(feature = "python")]
     #[implement(molar_weight)]
     Python(feos_core::python::user_defined::PyResidual),
@@ -42,15 +50,19 @@ pub enum ResidualModel {
     SaftVRQMie(crate::saftvrqmie::SaftVRQMie),
 
     #[cfg(feature = "saftvrmie")]
-    #[implement(molar_weight)]
+    #[implement(entropy_scaling, molar_weight, pair_potential)]
     SaftVRMie(crate::saftvrmie::SaftVRMie),
 
-    #[cfg(feature = "pets")]
+    #[cfg(feature = "saftgammamie")]
     #[implement(molar_weight)]
+    SaftGammaMie(crate::saftgammamie::SaftGammaMie),
+
+    #[cfg(feature = "pets")]
+    #[implement(entropy_scaling, molar_weight, pair_potential)]
     Pets(crate::pets::Pets),
 
     #[cfg(feature = "uvtheory")]
-    #[implement(molar_weight)]
+    #[implement(molar_weight, pair_potential)]
     UVTheory(crate::uvtheory::UVTheory),
 
     // Helmholtz energy functionals
@@ -66,6 +78,10 @@ pub enum ResidualModel {
     #[implement(molar_weight, functional, fluid_parameters, pair_potential)]
     PetsFunctional(crate::pets::PetsFunctional),
 
+    #[cfg(all(feature = "dft", feature = "uvtheory"))]
+    #[implement(molar_weight, functional, fluid_parameters, pair_potential)]
+    UVTheoryFunctional(crate::uvtheory::UVTheoryFunctional),
+
     #[cfg(feature = "dft")]
     #[implement(functional, fluid_parameters, pair_potential)]
     FmtFunctional(crate::hard_sphere::FMTFunctional),
@@ -84,7 +100,9 @@ pub enum FunctionalContributionVariant {
     GcPcSaftFunctional(crate::gc_pcsaft::GcPcSaftFunctionalContribution),
     #[cfg(feature = "pets")]
     PetsFunctional(crate::pets::PetsFunctionalContribution),
-    Fmt(crate::hard_sphere::FMTContribution<crate::hard_sphere::HardSphereParameters>),
+    #[cfg(feature = "uvtheory")]
+    UVTheoryFunctional(crate::uvtheory::UVTheoryFunctionalContribution),
+    Fmt(crate::hard_sphere::FMTFunctionalContribution),
     #[cfg(feature = "saftvrqmie")]
     SaftVRQMieFunctional(crate::saftvrqmie::SaftVRQMieFunctionalContribution),
 }
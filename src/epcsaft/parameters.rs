@@ -1,5 +1,6 @@
 use crate::association::{
-    AssociationParameters, AssociationRecord, AssociationStrength, BinaryAssociationRecord,
+    AssociationParameters, AssociationRecord, AssociationSiteRecord, AssociationStrength,
+    BinaryAssociationRecord,
 };
 use crate::hard_sphere::{HardSphereProperties, MonomerShape};
 use feos_core::parameter::{FromSegments, Parameter, ParameterError, PureRecord};
@@ -47,7 +48,7 @@ impl FromSegments<f64> for ElectrolytePcSaftRecord {
             z += s.z.unwrap_or(0.0);
         });
 
-        let association_record = segments
+        let mut association_record = segments
             .iter()
             .filter_map(|(s, n)| {
                 s.association_record.as_ref().map(|record| {
@@ -77,6 +78,18 @@ impl FromSegments<f64> for ElectrolytePcSaftRecord {
                     nc,
                 )
             });
+        if let Some(record) = association_record.as_mut() {
+            record.sites = segments
+                .iter()
+                .filter_map(|(s, n)| s.association_record.as_ref().map(|r| (&r.sites, n)))
+                .flat_map(|(sites, &n)| {
+                    sites.iter().map(move |site| AssociationSiteRecord {
+                        n: site.n * n,
+                        ..site.clone()
+                    })
+                })
+                .collect();
+        }
 
         Ok(Self {
             m,
@@ -357,11 +370,11 @@ impl Parameter for ElectrolytePcSaftParameters {
             sigma[i] = r.sigma;
             epsilon_k[i] = r.epsilon_k;
             z[i] = r.z.unwrap_or(0.0);
-            association_records.push(r.association_record.into_iter().collect());
+            association_records.push(r.association_record.clone().into_iter().collect());
             molarweight[i] = record.molarweight;
             // check if component i is water with temperature-dependent sigma
             if (m[i] * 1000.0).round() / 1000.0 == 1.205 && epsilon_k[i].round() == 354.0 {
-                if let Some(record) = r.association_record {
+                if let Some(record) = &r.association_record {
                     if (record.parameters.kappa_ab * 1000.0).round() / 1000.0 == 0.045
                         && record.parameters.epsilon_k_ab.round() == 2426.0
                     {
@@ -611,14 +624,18 @@ impl ElectrolytePcSaftParameters {
         for (i, record) in self.pure_records.iter().enumerate() {
             let component = record.identifier.name.clone();
             let component = component.unwrap_or(format!("Component {}", i + 1));
-            let association = record.model_record.association_record.unwrap_or_else(|| {
-                AssociationRecord::new(
-                    ElectrolytePcSaftAssociationRecord::new(0.0, 0.0),
-                    0.0,
-                    0.0,
-                    0.0,
-                )
-            });
+            let association = record
+                .model_record
+                .association_record
+                .clone()
+                .unwrap_or_else(|| {
+                    AssociationRecord::new(
+                        ElectrolytePcSaftAssociationRecord::new(0.0, 0.0),
+                        0.0,
+                        0.0,
+                        0.0,
+                    )
+                });
             write!(
                 o,
                 "\n|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|",
@@ -854,6 +871,7 @@ pub mod utils {
             &binary_records,
             feos_core::parameter::IdentifierOption::Name,
         )
+        .unwrap()
         .unwrap();
         Arc::new(
             ElectrolytePcSaftParameters::from_records(pure_records, Some(binary_matrix)).unwrap(),
@@ -1028,6 +1046,7 @@ pub mod utils {
             &binary_records,
             feos_core::parameter::IdentifierOption::Name,
         )
+        .unwrap()
         .unwrap();
         Arc::new(
             ElectrolytePcSaftParameters::from_records(pure_records, Some(binary_matrix)).unwrap(),
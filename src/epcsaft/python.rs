@@ -94,12 +94,18 @@ impl PyElectrolytePcSaftRecord {
 
     #[getter]
     fn get_kappa_ab(&self) -> Option<f64> {
-        self.0.association_record.map(|a| a.parameters.kappa_ab)
+        self.0
+            .association_record
+            .as_ref()
+            .map(|a| a.parameters.kappa_ab)
     }
 
     #[getter]
     fn get_epsilon_k_ab(&self) -> Option<f64> {
-        self.0.association_record.map(|a| a.parameters.epsilon_k_ab)
+        self.0
+            .association_record
+            .as_ref()
+            .map(|a| a.parameters.epsilon_k_ab)
     }
 
     #[getter]
@@ -109,17 +115,17 @@ impl PyElectrolytePcSaftRecord {
 
     #[getter]
     fn get_na(&self) -> Option<f64> {
-        self.0.association_record.map(|a| a.na)
+        self.0.association_record.as_ref().map(|a| a.na)
     }
 
     #[getter]
     fn get_nb(&self) -> Option<f64> {
-        self.0.association_record.map(|a| a.nb)
+        self.0.association_record.as_ref().map(|a| a.nb)
     }
 
     #[getter]
     fn get_nc(&self) -> Option<f64> {
-        self.0.association_record.map(|a| a.nc)
+        self.0.association_record.as_ref().map(|a| a.nc)
     }
 
     fn __repr__(&self) -> PyResult<String> {
@@ -83,6 +83,7 @@ impl ParameterHetero for GcPcSaftFunctionalParameters {
                     segment
                         .model_record
                         .association_record
+                        .clone()
                         .into_iter()
                         .collect(),
                 );
@@ -5,10 +5,10 @@ use feos_core::parameter::{
     BinaryRecord, ChemicalRecord, Identifier, ParameterError, ParameterHetero, SegmentCount,
     SegmentRecord,
 };
-use quantity::{JOULE, KB, KELVIN};
 use indexmap::IndexMap;
 use ndarray::{Array1, Array2};
 use num_dual::DualNum;
+use quantity::{JOULE, KB, KELVIN};
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt::Write;
@@ -88,6 +88,15 @@ pub struct GcPcSaftEosParameters {
     pub sigma_ij: Array2<f64>,
     pub epsilon_k_ij: Array2<f64>,
 
+    /// Molecular averages of the segment parameters used in the
+    /// reference models for entropy scaling.
+    pub m_mol: Array1<f64>,
+    pub sigma_mol: Array1<f64>,
+    pub epsilon_k_mol: Array1<f64>,
+    pub viscosity: Option<Array2<f64>>,
+    pub diffusion: Option<Array2<f64>>,
+    pub thermal_conductivity: Option<Array2<f64>>,
+
     pub chemical_records: Vec<GcPcSaftChemicalRecord>,
     segment_records: Vec<SegmentRecord<GcPcSaftRecord>>,
     binary_segment_records: Option<Vec<BinaryRecord<String, f64>>>,
@@ -124,6 +133,14 @@ impl ParameterHetero for GcPcSaftEosParameters {
 
         let mut phi = Vec::new();
 
+        let n_comp = chemical_records.len();
+        let mut m_mol = Array1::zeros(n_comp);
+        let mut sigma_mol = Array1::zeros(n_comp);
+        let mut epsilon_k_mol = Array1::zeros(n_comp);
+        let mut viscosity = Some(Array2::zeros((4, n_comp)));
+        let mut diffusion = Some(Array2::zeros((5, n_comp)));
+        let mut thermal_conductivity = Some(Array2::zeros((4, n_comp)));
+
         for (i, chemical_record) in chemical_records.iter().cloned().enumerate() {
             let mut segment_indices = IndexMap::with_capacity(segment_records.len());
             let segment_map = chemical_record.segment_map(&segment_records)?;
@@ -146,10 +163,11 @@ impl ParameterHetero for GcPcSaftEosParameters {
                 sigma.push(segment.model_record.sigma);
                 epsilon_k.push(segment.model_record.epsilon_k);
 
-                let mut assoc = segment.model_record.association_record;
+                let mut assoc = segment.model_record.association_record.clone();
                 if let Some(assoc) = assoc.as_mut() {
                     assoc.na *= count;
                     assoc.nb *= count;
+                    assoc.sites.iter_mut().for_each(|s| s.n *= count);
                 };
                 association_records.push(assoc.into_iter().collect());
 
@@ -161,6 +179,62 @@ impl ParameterHetero for GcPcSaftEosParameters {
                 }
             }
 
+            m_mol[i] = m_i;
+            sigma_mol[i] = (sigma_i / m_i).cbrt();
+            epsilon_k_mol[i] = epsilon_k_i / m_i;
+
+            // group contribution method for the entropy scaling coefficients
+            // (Lötgering-Lin and Gross, 2015)
+            let n_t: f64 = segment_map.values().sum();
+            let segments = || segment_map.iter().map(|(s, &n)| (&s.model_record, n));
+            if let Some(v) = viscosity.as_mut() {
+                if segments().all(|(s, _)| s.viscosity.is_some()) {
+                    let mut v = v.column_mut(i);
+                    for (s, n) in segments() {
+                        let [a, b, c, d] = s.viscosity.unwrap();
+                        let s3 = s.m * s.sigma.powi(3) * n;
+                        v[0] += s3 * a;
+                        v[1] += s3 * b / sigma_i.powf(0.45);
+                        v[2] += n * c;
+                        v[3] += n * d;
+                    }
+                    // correction due to difference in Chapman-Enskog reference between GC and regular formulation.
+                    v[0] -= 0.5 * m_i.ln();
+                } else {
+                    viscosity = None;
+                }
+            }
+            if let Some(v) = diffusion.as_mut() {
+                if segments().all(|(s, _)| s.diffusion.is_some()) {
+                    let mut v = v.column_mut(i);
+                    for (s, n) in segments() {
+                        let [a, b, c, d, e] = s.diffusion.unwrap();
+                        let s3 = s.m * s.sigma.powi(3) * n;
+                        v[0] += s3 * a;
+                        v[1] += s3 * b / sigma_i.powf(0.45);
+                        v[2] += n * c;
+                        v[3] += n * d;
+                        v[4] += n * e;
+                    }
+                } else {
+                    diffusion = None;
+                }
+            }
+            if let Some(v) = thermal_conductivity.as_mut() {
+                if segments().all(|(s, _)| s.thermal_conductivity.is_some()) {
+                    let mut v = v.column_mut(i);
+                    for (s, n) in segments() {
+                        let [a, b, c, d] = s.thermal_conductivity.unwrap();
+                        v[0] += n * a;
+                        v[1] += n * b;
+                        v[2] += n * c;
+                        v[3] += n_t * d;
+                    }
+                } else {
+                    thermal_conductivity = None;
+                }
+            }
+
             if mu2_i > 0.0 {
                 dipole_comp.push(i);
                 mu.push(mu2_i.sqrt());
@@ -246,6 +320,12 @@ impl ParameterHetero for GcPcSaftEosParameters {
             k_ij,
             sigma_ij,
             epsilon_k_ij,
+            m_mol,
+            sigma_mol,
+            epsilon_k_mol,
+            viscosity,
+            diffusion,
+            thermal_conductivity,
             chemical_records,
             segment_records,
             binary_segment_records,
@@ -353,7 +433,7 @@ impl GcPcSaftEosParameters {
                 )
             };
             let record = gorup_dict[&self.identifiers[i]];
-            let association = if let Some(a) = record.association_record {
+            let association = if let Some(a) = &record.association_record {
                 format!(
                     "{}|{}|{}|{}|{}",
                     a.parameters.kappa_ab, a.parameters.epsilon_k_ab, a.na, a.nb, a.nc
@@ -442,7 +522,7 @@ pub mod test {
             "CH3".into(),
             15.0,
             GcPcSaftRecord::new(
-                0.77247, 3.6937, 181.49, None, None, None, None, None, None, None,
+                0.77247, 3.6937, 181.49, None, None, None, None, None, None, None, None, None, None,
             ),
         )
     }
@@ -452,7 +532,7 @@ pub mod test {
             "CH2".into(),
             14.0,
             GcPcSaftRecord::new(
-                0.7912, 3.0207, 157.23, None, None, None, None, None, None, None,
+                0.7912, 3.0207, 157.23, None, None, None, None, None, None, None, None, None, None,
             ),
         )
     }
@@ -472,6 +552,9 @@ pub mod test {
                 Some(1.0),
                 None,
                 None,
+                None,
+                None,
+                None,
             ),
         )
     }
@@ -25,8 +25,8 @@ pub struct PyGcPcSaftRecord(GcPcSaftRecord);
 impl PyGcPcSaftRecord {
     #[new]
     #[pyo3(
-        text_signature = "(m, sigma, epsilon_k, mu=None, kappa_ab=None, epsilon_k_ab=None, na=None, nb=None, nc=None, psi_dft=None)",
-        signature = (m, sigma, epsilon_k, mu=None, kappa_ab=None, epsilon_k_ab=None, na=None, nb=None, nc=None, psi_dft=None)
+        text_signature = "(m, sigma, epsilon_k, mu=None, kappa_ab=None, epsilon_k_ab=None, na=None, nb=None, nc=None, psi_dft=None, viscosity=None, diffusion=None, thermal_conductivity=None)",
+        signature = (m, sigma, epsilon_k, mu=None, kappa_ab=None, epsilon_k_ab=None, na=None, nb=None, nc=None, psi_dft=None, viscosity=None, diffusion=None, thermal_conductivity=None)
     )]
     #[expect(clippy::too_many_arguments)]
     fn new(
@@ -40,6 +40,9 @@ impl PyGcPcSaftRecord {
         nb: Option<f64>,
         nc: Option<f64>,
         psi_dft: Option<f64>,
+        viscosity: Option<[f64; 4]>,
+        diffusion: Option<[f64; 5]>,
+        thermal_conductivity: Option<[f64; 4]>,
     ) -> Self {
         Self(GcPcSaftRecord::new(
             m,
@@ -52,6 +55,9 @@ impl PyGcPcSaftRecord {
             nb,
             nc,
             psi_dft,
+            viscosity,
+            diffusion,
+            thermal_conductivity,
         ))
     }
 
@@ -77,27 +83,48 @@ impl PyGcPcSaftRecord {
 
     #[getter]
     fn get_kappa_ab(&self) -> Option<f64> {
-        self.0.association_record.map(|a| a.parameters.kappa_ab)
+        self.0
+            .association_record
+            .as_ref()
+            .map(|a| a.parameters.kappa_ab)
     }
 
     #[getter]
     fn get_epsilon_k_ab(&self) -> Option<f64> {
-        self.0.association_record.map(|a| a.parameters.epsilon_k_ab)
+        self.0
+            .association_record
+            .as_ref()
+            .map(|a| a.parameters.epsilon_k_ab)
     }
 
     #[getter]
     fn get_na(&self) -> Option<f64> {
-        self.0.association_record.map(|a| a.na)
+        self.0.association_record.as_ref().map(|a| a.na)
     }
 
     #[getter]
     fn get_nb(&self) -> Option<f64> {
-        self.0.association_record.map(|a| a.nb)
+        self.0.association_record.as_ref().map(|a| a.nb)
     }
 
     #[getter]
     fn get_nc(&self) -> Option<f64> {
-        self.0.association_record.map(|a| a.nc)
+        self.0.association_record.as_ref().map(|a| a.nc)
+    }
+
+    #[getter]
+    fn get_viscosity(&self) -> Option<[f64; 4]> {
+        self.0.viscosity
+    }
+
+    #[getter]
+    fn get_diffusion(&self) -> Option<[f64; 5]> {
+        self.0.diffusion
+    }
+
+    #[getter]
+    fn get_thermal_conductivity(&self) -> Option<[f64; 4]> {
+        self.0.thermal_conductivity
     }
 
     fn __repr__(&self) -> PyResult<String> {
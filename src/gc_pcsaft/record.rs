@@ -21,6 +21,15 @@ pub struct GcPcSaftRecord {
     /// interaction range parameter for the dispersion functional
     #[serde(skip_serializing_if = "Option::is_none")]
     pub psi_dft: Option<f64>,
+    /// Group contribution to the entropy scaling coefficients for the viscosity
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub viscosity: Option<[f64; 4]>,
+    /// Group contribution to the entropy scaling coefficients for the diffusion coefficient
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diffusion: Option<[f64; 5]>,
+    /// Group contribution to the entropy scaling coefficients for the thermal conductivity
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thermal_conductivity: Option<[f64; 4]>,
 }
 
 impl GcPcSaftRecord {
@@ -36,6 +45,9 @@ impl GcPcSaftRecord {
         nb: Option<f64>,
         nc: Option<f64>,
         psi_dft: Option<f64>,
+        viscosity: Option<[f64; 4]>,
+        diffusion: Option<[f64; 5]>,
+        thermal_conductivity: Option<[f64; 4]>,
     ) -> Self {
         let association_record = if kappa_ab.is_none()
             && epsilon_k_ab.is_none()
@@ -62,6 +74,9 @@ impl GcPcSaftRecord {
             mu,
             association_record,
             psi_dft,
+            viscosity,
+            diffusion,
+            thermal_conductivity,
         }
     }
 }
@@ -77,6 +92,15 @@ impl std::fmt::Display for GcPcSaftRecord {
         if let Some(n) = &self.association_record {
             write!(f, ", association_record={}", n)?;
         }
+        if let Some(n) = &self.viscosity {
+            write!(f, ", viscosity={:?}", n)?;
+        }
+        if let Some(n) = &self.diffusion {
+            write!(f, ", diffusion={:?}", n)?;
+        }
+        if let Some(n) = &self.thermal_conductivity {
+            write!(f, ", thermal_conductivity={:?}", n)?;
+        }
         write!(f, ")")
     }
 }
@@ -1,4 +1,5 @@
 use feos_core::{Components, EosResult, Residual, StateHD};
+use feos_derive::FunctionalContribution;
 use feos_dft::adsorption::FluidParameters;
 use feos_dft::solvation::PairPotential;
 use feos_dft::{
@@ -11,10 +12,10 @@ use std::f64::consts::PI;
 use std::fmt;
 use std::sync::Arc;
 
-use super::{HardSphereProperties, MonomerShape};
+use super::NonAddHardSphereFunctional;
+use super::{phi2, phi3, HardSphereProperties, HardSphereVersion, MonomerShape, N3_CUTOFF};
 
 const PI36M1: f64 = 1.0 / (36.0 * PI);
-const N3_CUTOFF: f64 = 1e-5;
 
 /// Different versions of fundamental measure theory.
 #[derive(Clone, Copy, PartialEq)]
@@ -26,32 +27,53 @@ pub enum FMTVersion {
     KierlikRosinberg,
     /// Anti-symmetric White Bear fundamental measure theory ([Rosenfeld et al., 1997](https://doi.org/10.1103/PhysRevE.55.4245)) and SI of ([Kessler et al., 2021](https://doi.org/10.1016/j.micromeso.2021.111263))
     AntiSymWhiteBear,
+    /// White Bear Mark II fundamental measure theory ([Hansen-Goos and Roth, 2006](https://doi.org/10.1088/0953-8984/18/37/002))
+    WhiteBearMarkII,
+    /// Original fundamental measure theory by [Rosenfeld, 1989](https://doi.org/10.1103/PhysRevLett.63.980)
+    Rosenfeld,
+}
+
+impl From<FMTVersion> for HardSphereVersion {
+    /// The equation of state for hard-sphere mixtures that is obtained from the
+    /// respective [FMTVersion] in the homogeneous limit.
+    fn from(version: FMTVersion) -> Self {
+        match version {
+            FMTVersion::WhiteBear | FMTVersion::KierlikRosinberg | FMTVersion::AntiSymWhiteBear => {
+                Self::Bmcsl
+            }
+            FMTVersion::WhiteBearMarkII => Self::HansenGoosRoth,
+            FMTVersion::Rosenfeld => Self::PercusYevick,
+        }
+    }
 }
 
 /// The [FunctionalContribution] for the hard sphere functional.
 ///
-/// The struct provides an implementation of different variants of fundamental measure theory ([Rosenfeld, 1989](https://doi.org/10.1103/PhysRevLett.63.980)). Currently, only variants that are consistent with the BMCSL equation of state for hard-sphere mixtures are considered (exluding, e.g., the original Rosenfeld and White-Bear II variants).
+/// The struct provides an implementation of different variants of fundamental measure theory ([Rosenfeld, 1989](https://doi.org/10.1103/PhysRevLett.63.980)). The White Bear, Kierlik-Rosinberg and anti-symmetric White Bear variants are consistent with the BMCSL equation of state for hard-sphere mixtures, the White Bear Mark II variant with the equation of state by Hansen-Goos and Roth and the original Rosenfeld variant with the Percus-Yevick equation of state (cf. [HardSphereVersion]).
 ///
 /// The Helmholtz energy density is calculated according to
-/// $$\beta f=-n_0\ln\left(1-n_3\right)+\frac{n_{12}}{1-n_3}+\frac{1}{36\pi}n_2n_{22}f_3(n_3)$$
-/// The expressions for $n_{12}$ and $n_{22}$ depend on the [FMTVersion].
+/// $$\beta f=-n_0\ln\left(1-n_3\right)+\frac{n_{12}}{1-n_3}f_2(n_3)+\frac{1}{36\pi}n_2n_{22}f_3(n_3)$$
+/// The expressions for $n_{12}$, $n_{22}$, $f_2$ and $f_3$ depend on the [FMTVersion].
 ///
-/// |[FMTVersion]|$n_{12}$|$n_{22}$|
-/// |-|:-:|:-:|
-/// |WhiteBear|$n_1n_2-\vec n_1\cdot\vec n_2$|$n_2^2-3\vec n_2\cdot\vec n_2$|
-/// |KierlikRosinberg|$n_1n_2$|$n_2^2$|
-/// |AntiSymWhiteBear|$n_1n_2-\vec n_1\cdot\vec n_2$|$n_2^2\left(1-\frac{\vec n_2\cdot\vec n_2}{n_2^2}\right)^3$|
+/// |[FMTVersion]|$n_{12}$|$n_{22}$|$f_2$|$f_3$|
+/// |-|:-:|:-:|:-:|:-:|
+/// |WhiteBear|$n_1n_2-\vec n_1\cdot\vec n_2$|$n_2^2-3\vec n_2\cdot\vec n_2$|$1$|$f_3^\mathrm{WB}$|
+/// |KierlikRosinberg|$n_1n_2$|$n_2^2$|$1$|$f_3^\mathrm{WB}$|
+/// |AntiSymWhiteBear|$n_1n_2-\vec n_1\cdot\vec n_2$|$n_2^2\left(1-\frac{\vec n_2\cdot\vec n_2}{n_2^2}\right)^3$|$1$|$f_3^\mathrm{WB}$|
+/// |WhiteBearMarkII|$n_1n_2-\vec n_1\cdot\vec n_2$|$n_2^2-3\vec n_2\cdot\vec n_2$|$1+\frac{\phi_2}{3}$|$\frac{3}{2\left(1-n_3\right)^2}\left(1-\frac{\phi_3}{3}\right)$|
+/// |Rosenfeld|$n_1n_2-\vec n_1\cdot\vec n_2$|$n_2^2-3\vec n_2\cdot\vec n_2$|$1$|$\frac{3}{2\left(1-n_3\right)^2}$|
 ///
-/// The value of $f(n_3)$ numerically diverges for small $n_3$. Therefore, it is approximated with a Taylor expansion.
-/// $$f_3=\begin{cases}\frac{n_3+\left(1-n_3\right)^2\ln\left(1-n_3\right)}{n_3^2\left(1-n_3\right)^2}&\text{if }n_3>10^{-5}\\\\
+/// The value of $f_3^\mathrm{WB}(n_3)$ numerically diverges for small $n_3$. Therefore, it is approximated with a Taylor expansion.
+/// $$f_3^\mathrm{WB}=\begin{cases}\frac{n_3+\left(1-n_3\right)^2\ln\left(1-n_3\right)}{n_3^2\left(1-n_3\right)^2}&\text{if }n_3>10^{-5}\\\\
 /// \frac{3}{2}+\frac{8}{3}n_3+\frac{15}{4}n_3^2+\frac{24}{5}n_3^3+\frac{35}{6}n_3^4&\text{else}\end{cases}$$
+/// The functions $\phi_2(n_3)$ and $\phi_3(n_3)$ are given in the documentation of [HardSphere](super::HardSphere).
 ///
 /// The weighted densities $n_k(\mathbf{r})$ are calculated by convolving the density profiles $\rho_\alpha(\mathbf{r})$ with weight functions $\omega_k^\alpha(\mathbf{r})$
 /// $$n_k(\mathbf{r})=\sum_\alpha\int\rho_\alpha(\mathbf{r}\')\omega_k^\alpha(\mathbf{r}-\mathbf{r}\')\mathrm{d}\mathbf{r}\'$$
 ///
 /// The weight functions differ between the different [FMTVersion]s.
 ///
-/// ||WhiteBear/AntiSymWhiteBear|KierlikRosinberg|
+/// ||WhiteBear/AntiSymWhiteBear/WhiteBearMarkII/Rosenfeld|KierlikRosinberg|
 /// |-|:-:|:-:|
 /// |$\omega_0^\alpha(\mathbf{r})$|$\frac{C_{0,\alpha}}{\pi\sigma_\alpha^2}\\,\delta\\!\left(\frac{d_\alpha}{2}-\|\mathbf{r}\|\right)$|$C_{0,\alpha}\left(-\frac{1}{8\pi}\\,\delta\'\'\\!\left(\frac{d_\alpha}{2}-\|\mathbf{r}\|\right)+\frac{1}{2\pi\|\mathbf{r}\|}\\,\delta\'\\!\left(\frac{d_\alpha}{2}-\|\mathbf{r}\|\right)\right)$|
 /// |$\omega_1^\alpha(\mathbf{r})$|$\frac{C_{1,\alpha}}{2\pi\sigma_\alpha}\\,\delta\\!\left(\frac{d_\alpha}{2}-\|\mathbf{r}\|\right)$|$\frac{C_{1,\alpha}}{8\pi}\\,\delta\'\\!\left(\frac{d_\alpha}{2}-\|\mathbf{r}\|\right)$|
@@ -89,82 +111,90 @@ impl<P: HardSphereProperties + Send + Sync> FunctionalContribution for FMTContri
         let r = self.properties.hs_diameter(temperature) * 0.5;
         let [c0, c1, c2, c3] = self.properties.geometry_coefficients(temperature);
         match (self.version, r.len()) {
-            (FMTVersion::WhiteBear | FMTVersion::AntiSymWhiteBear, 1) => {
-                WeightFunctionInfo::new(self.properties.component_index().into_owned(), false)
-                    .extend(
-                        vec![
-                            WeightFunctionShape::Delta,
-                            WeightFunctionShape::Theta,
-                            WeightFunctionShape::DeltaVec,
-                        ]
-                        .into_iter()
-                        .zip([c2, c3.clone(), c3])
-                        .map(|(s, c)| WeightFunction {
-                            prefactor: c,
-                            kernel_radius: r.clone(),
-                            shape: s,
-                        })
-                        .collect(),
-                        false,
-                    )
-            }
-            (FMTVersion::WhiteBear | FMTVersion::AntiSymWhiteBear, _) => {
-                WeightFunctionInfo::new(self.properties.component_index().into_owned(), false)
-                    .add(
-                        WeightFunction {
-                            prefactor: Zip::from(&c0)
-                                .and(&r)
-                                .map_collect(|&c, &r| r.powi(-2) * c / (4.0 * PI)),
-                            kernel_radius: r.clone(),
-                            shape: WeightFunctionShape::Delta,
-                        },
-                        true,
-                    )
-                    .add(
-                        WeightFunction {
-                            prefactor: Zip::from(&c1)
-                                .and(&r)
-                                .map_collect(|&c, &r| r.recip() * c / (4.0 * PI)),
-                            kernel_radius: r.clone(),
-                            shape: WeightFunctionShape::Delta,
-                        },
-                        true,
-                    )
-                    .add(
-                        WeightFunction {
-                            prefactor: c2,
-                            kernel_radius: r.clone(),
-                            shape: WeightFunctionShape::Delta,
-                        },
-                        true,
-                    )
-                    .add(
-                        WeightFunction {
-                            prefactor: c3.clone(),
-                            kernel_radius: r.clone(),
-                            shape: WeightFunctionShape::Theta,
-                        },
-                        true,
-                    )
-                    .add(
-                        WeightFunction {
-                            prefactor: Zip::from(&c3)
-                                .and(&r)
-                                .map_collect(|&c, &r| r.recip() * c / (4.0 * PI)),
-                            kernel_radius: r.clone(),
-                            shape: WeightFunctionShape::DeltaVec,
-                        },
-                        true,
-                    )
-                    .add(
-                        WeightFunction {
-                            prefactor: c3,
-                            kernel_radius: r,
-                            shape: WeightFunctionShape::DeltaVec,
-                        },
-                        true,
-                    )
-            }
+            (
+                FMTVersion::WhiteBear
+                | FMTVersion::AntiSymWhiteBear
+                | FMTVersion::WhiteBearMarkII
+                | FMTVersion::Rosenfeld,
+                1,
+            ) => WeightFunctionInfo::new(self.properties.component_index().into_owned(), false)
+                .extend(
+                    vec![
+                        WeightFunctionShape::Delta,
+                        WeightFunctionShape::Theta,
+                        WeightFunctionShape::DeltaVec,
+                    ]
+                    .into_iter()
+                    .zip([c2, c3.clone(), c3])
+                    .map(|(s, c)| WeightFunction {
+                        prefactor: c,
+                        kernel_radius: r.clone(),
+                        shape: s,
+                    })
+                    .collect(),
+                    false,
+                ),
+            (
+                FMTVersion::WhiteBear
+                | FMTVersion::AntiSymWhiteBear
+                | FMTVersion::WhiteBearMarkII
+                | FMTVersion::Rosenfeld,
+                _,
+            ) => WeightFunctionInfo::new(self.properties.component_index().into_owned(), false)
+                .add(
+                    WeightFunction {
+                        prefactor: Zip::from(&c0)
+                            .and(&r)
+                            .map_collect(|&c, &r| r.powi(-2) * c / (4.0 * PI)),
+                        kernel_radius: r.clone(),
+                        shape: WeightFunctionShape::Delta,
+                    },
+                    true,
+                )
+                .add(
+                    WeightFunction {
+                        prefactor: Zip::from(&c1)
+                            .and(&r)
+                            .map_collect(|&c, &r| r.recip() * c / (4.0 * PI)),
+                        kernel_radius: r.clone(),
+                        shape: WeightFunctionShape::Delta,
+                    },
+                    true,
+                )
+                .add(
+                    WeightFunction {
+                        prefactor: c2,
+                        kernel_radius: r.clone(),
+                        shape: WeightFunctionShape::Delta,
+                    },
+                    true,
+                )
+                .add(
+                    WeightFunction {
+                        prefactor: c3.clone(),
+                        kernel_radius: r.clone(),
+                        shape: WeightFunctionShape::Theta,
+                    },
+                    true,
+                )
+                .add(
+                    WeightFunction {
+                        prefactor: Zip::from(&c3)
+                            .and(&r)
+                            .map_collect(|&c, &r| r.recip() * c / (4.0 * PI)),
+                        kernel_radius: r.clone(),
+                        shape: WeightFunctionShape::DeltaVec,
+                    },
+                    true,
+                )
+                .add(
+                    WeightFunction {
+                        prefactor: c3,
+                        kernel_radius: r,
+                        shape: WeightFunctionShape::DeltaVec,
+                    },
+                    true,
+                ),
             (FMTVersion::KierlikRosinberg, _) => {
                 WeightFunctionInfo::new(self.properties.component_index().into_owned(), false)
                     .extend(
@@ -193,10 +223,9 @@ impl<P: HardSphereProperties + Send + Sync> FunctionalContribution for FMTContri
         temperature: N,
         weighted_densities: ArrayView2<N>,
     ) -> EosResult<Array1<N>> {
-        let pure_component_weighted_densities = matches!(
-            self.version,
-            FMTVersion::WhiteBear | FMTVersion::AntiSymWhiteBear
-        ) && self.properties.component_index().len() == 1;
+        let pure_component_weighted_densities =
+            !matches!(self.version, FMTVersion::KierlikRosinberg)
+                && self.properties.component_index().len() == 1;
 
         // scalar weighted densities
         let (n2, n3) = if pure_component_weighted_densities {
@@ -226,7 +255,10 @@ impl<P: HardSphereProperties + Send + Sync> FunctionalContribution for FMTContri
 
         // vector weighted densities
         let (n1n2, n2n2) = match self.version {
-            FMTVersion::WhiteBear | FMTVersion::AntiSymWhiteBear => {
+            FMTVersion::WhiteBear
+            | FMTVersion::AntiSymWhiteBear
+            | FMTVersion::WhiteBearMarkII
+            | FMTVersion::Rosenfeld => {
                 let (n1v, n2v) = if pure_component_weighted_densities {
                     let r = self.properties.hs_diameter(temperature)[0] * 0.5;
                     let n2v = weighted_densities.slice_axis(Axis(0), Slice::new(2, None, 1));
@@ -242,10 +274,12 @@ impl<P: HardSphereProperties + Send + Sync> FunctionalContribution for FMTContri
                     )
                 };
                 match self.version {
-                    FMTVersion::WhiteBear => (
-                        &n1 * &n2 - (&n1v * &n2v).sum_axis(Axis(0)),
-                        &n2 * &n2 - (&n2v * &n2v).sum_axis(Axis(0)) * 3.0,
-                    ),
+                    FMTVersion::WhiteBear | FMTVersion::WhiteBearMarkII | FMTVersion::Rosenfeld => {
+                        (
+                            &n1 * &n2 - (&n1v * &n2v).sum_axis(Axis(0)),
+                            &n2 * &n2 - (&n2v * &n2v).sum_axis(Axis(0)) * 3.0,
+                        )
+                    }
                     FMTVersion::AntiSymWhiteBear => {
                         let mut xi2 = (&n2v * &n2v).sum_axis(Axis(0)) / n2.map(|n| n.powi(2));
                         xi2.iter_mut().for_each(|x| {
@@ -270,14 +304,24 @@ impl<P: HardSphereProperties + Send + Sync> FunctionalContribution for FMTContri
         let n3m1 = n3.mapv(|n3| -n3 + 1.0);
         let n3m1rec = n3m1.mapv(|n3m1| n3m1.recip());
 
-        // use Taylor expansion for f3 at low densities to avoid numerical issues
-        let mut f3 = (&n3m1 * &n3m1 * &ln31 + n3) * &n3rec * n3rec * &n3m1rec * &n3m1rec;
-        f3.iter_mut().zip(n3).for_each(|(f3, &n3)| {
-            if n3.re() < N3_CUTOFF {
-                *f3 = (((n3 * 35.0 / 6.0 + 4.8) * n3 + 3.75) * n3 + 8.0 / 3.0) * n3 + 1.5;
+        let (f2, f3) = match self.version {
+            FMTVersion::WhiteBearMarkII => (
+                n3.mapv(|n3| phi2(n3) / 3.0 + 1.0),
+                n3.mapv(|n3| (-phi3(n3) / 3.0 + 1.0) * 1.5) * &n3m1rec * &n3m1rec,
+            ),
+            FMTVersion::Rosenfeld => (Array::ones(n3.raw_dim()), &n3m1rec * &n3m1rec * 1.5),
+            _ => {
+                // use Taylor expansion for f3 at low densities to avoid numerical issues
+                let mut f3 = (&n3m1 * &n3m1 * &ln31 + n3) * &n3rec * n3rec * &n3m1rec * &n3m1rec;
+                f3.iter_mut().zip(n3).for_each(|(f3, &n3)| {
+                    if n3.re() < N3_CUTOFF {
+                        *f3 = (((n3 * 35.0 / 6.0 + 4.8) * n3 + 3.75) * n3 + 8.0 / 3.0) * n3 + 1.5;
+                    }
+                });
+                (Array::ones(n3.raw_dim()), f3)
             }
-        });
-        Ok(-(&n0 * &ln31) + n1n2 * &n3m1rec + n2n2 * n2 * PI36M1 * f3)
+        };
+        Ok(-(&n0 * &ln31) + n1n2 * &n3m1rec * f2 + n2n2 * n2 * PI36M1 * f3)
     }
 }
 
@@ -287,13 +331,27 @@ impl<P: HardSphereProperties> fmt::Display for FMTContribution<P> {
             FMTVersion::WhiteBear => "WB",
             FMTVersion::KierlikRosinberg => "KR",
             FMTVersion::AntiSymWhiteBear => "AntiSymWB",
+            FMTVersion::WhiteBearMarkII => "WBII",
+            FMTVersion::Rosenfeld => "RF",
         };
         write!(f, "FMT functional ({})", ver)
     }
 }
 
 pub struct HardSphereParameters {
-    sigma: Array1<f64>,
+    pub(super) sigma: Array1<f64>,
+    nonadditivity: Option<Array2<f64>>,
+}
+
+impl HardSphereParameters {
+    /// Cross diameters $d_{\alpha\beta}=\frac{1}{2}\left(d_\alpha+d_\beta\right)\left(1+\Delta_{\alpha\beta}\right)$.
+    pub(super) fn sigma_ij(&self) -> Array2<f64> {
+        let n = self.sigma.len();
+        Array2::from_shape_fn((n, n), |(i, j)| {
+            let delta = self.nonadditivity.as_ref().map_or(0.0, |d| d[[i, j]]);
+            0.5 * (self.sigma[i] + self.sigma[j]) * (1.0 + delta)
+        })
+    }
 }
 
 impl HardSphereProperties for HardSphereParameters {
@@ -307,6 +365,10 @@ impl HardSphereProperties for HardSphereParameters {
 }
 
 /// [HelmholtzEnergyFunctional] for hard sphere systems.
+///
+/// For non-additive hard-sphere mixtures, the cross diameters are
+/// $d_{\alpha\beta}=\frac{1}{2}\left(d_\alpha+d_\beta\right)\left(1+\Delta_{\alpha\beta}\right)$
+/// and the [NonAddHardSphereFunctional] is used in addition to the FMT functional.
 pub struct FMTFunctional {
     properties: Arc<HardSphereParameters>,
     version: FMTVersion,
@@ -314,8 +376,27 @@ pub struct FMTFunctional {
 
 impl FMTFunctional {
     pub fn new(sigma: &Array1<f64>, version: FMTVersion) -> Self {
+        Self::from_properties(sigma.clone(), None, version)
+    }
+
+    /// Hard-sphere functional for non-additive mixtures with the
+    /// nonadditivity parameters $\Delta_{\alpha\beta}$.
+    pub fn with_nonadditivity(
+        sigma: &Array1<f64>,
+        nonadditivity: &Array2<f64>,
+        version: FMTVersion,
+    ) -> Self {
+        Self::from_properties(sigma.clone(), Some(nonadditivity.clone()), version)
+    }
+
+    fn from_properties(
+        sigma: Array1<f64>,
+        nonadditivity: Option<Array2<f64>>,
+        version: FMTVersion,
+    ) -> Self {
         let properties = Arc::new(HardSphereParameters {
-            sigma: sigma.clone(),
+            sigma,
+            nonadditivity,
         });
         Self {
             properties,
@@ -334,7 +415,11 @@ impl Components for FMTFunctional {
             .iter()
             .map(|&c| self.properties.sigma[c])
             .collect();
-        Self::new(&sigma, self.version)
+        let nonadditivity = self.properties.nonadditivity.as_ref().map(|d| {
+            d.select(Axis(0), component_list)
+                .select(Axis(1), component_list)
+        });
+        Self::from_properties(sigma, nonadditivity, self.version)
     }
 }
 
@@ -352,13 +437,22 @@ impl Residual for FMTFunctional {
 }
 
 impl HelmholtzEnergyFunctional for FMTFunctional {
-    type Contribution = FMTContribution<HardSphereParameters>;
+    type Contribution = FMTFunctionalContribution;
 
-    fn contributions(&self) -> Box<dyn Iterator<Item = FMTContribution<HardSphereParameters>>> {
-        Box::new(std::iter::once(FMTContribution::new(
-            &self.properties,
-            self.version,
-        )))
+    fn contributions(&self) -> Box<dyn Iterator<Item = FMTFunctionalContribution>> {
+        let mut contributions = Vec::with_capacity(2);
+
+        // Hard-sphere contribution
+        let fmt = FMTContribution::new(&self.properties, self.version);
+        contributions.push(fmt.into());
+
+        // Non-additive hard-sphere contribution
+        if self.properties.nonadditivity.is_some() {
+            let non_add_hs = NonAddHardSphereFunctional::new(self.properties.clone());
+            contributions.push(non_add_hs.into());
+        }
+
+        Box::new(contributions.into_iter())
     }
 
     fn molecule_shape(&self) -> MoleculeShape {
@@ -368,9 +462,9 @@ impl HelmholtzEnergyFunctional for FMTFunctional {
 
 impl PairPotential for FMTFunctional {
     fn pair_potential(&self, i: usize, r: &Array1<f64>, _: f64) -> Array2<f64> {
-        let s = &self.properties.sigma;
-        Array::from_shape_fn((s.len(), r.len()), |(j, k)| {
-            if r[k] > 0.5 * (s[i] + s[j]) {
+        let s = self.properties.sigma_ij();
+        Array::from_shape_fn((s.nrows(), r.len()), |(j, k)| {
+            if r[k] > s[[i, j]] {
                 0.0
             } else {
                 f64::INFINITY
@@ -388,3 +482,122 @@ impl FluidParameters for FMTFunctional {
         &self.properties.sigma
     }
 }
+
+#[derive(FunctionalContribution)]
+pub enum FMTFunctionalContribution {
+    Fmt(FMTContribution<HardSphereParameters>),
+    NonAddHardSphere(NonAddHardSphereFunctional),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::HardSphere;
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::{arr1, arr2};
+    use num_dual::Dual64;
+    use std::f64::consts::FRAC_PI_6;
+
+    fn residual_helmholtz_energy<D: DualNum<f64> + Copy + ScalarOperand>(
+        func: &FMTFunctional,
+        state: &StateHD<D>,
+    ) -> D {
+        func.residual_helmholtz_energy_contributions(state)
+            .into_iter()
+            .fold(D::zero(), |acc, (_, a)| acc + a)
+    }
+
+    #[test]
+    fn test_bulk_versions_pure() {
+        let eta: f64 = 0.35;
+        let density = eta / FRAC_PI_6;
+        let compressibility = [
+            (
+                FMTVersion::WhiteBear,
+                (1.0 + eta + eta.powi(2) - eta.powi(3)) / (1.0 - eta).powi(3),
+            ),
+            (
+                FMTVersion::WhiteBearMarkII,
+                (1.0 + eta + eta.powi(2) - eta.powi(3)) / (1.0 - eta).powi(3),
+            ),
+            (
+                FMTVersion::Rosenfeld,
+                (1.0 + eta + eta.powi(2)) / (1.0 - eta).powi(3),
+            ),
+        ];
+        for (version, z) in compressibility {
+            let func = FMTFunctional::new(&arr1(&[1.0]), version);
+            let state = StateHD::new(
+                Dual64::from(1.0),
+                Dual64::from(1.0).derivative(),
+                arr1(&[Dual64::from(density)]),
+            );
+            let a = residual_helmholtz_energy(&func, &state);
+            assert_relative_eq!(1.0 - a.eps / density, z, max_relative = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_bulk_versions_mixture() {
+        let sigma = arr1(&[1.0, 1.8]);
+        let state = StateHD::new(1.0, 1.0, arr1(&[0.3, 0.1]));
+        for version in [
+            FMTVersion::WhiteBear,
+            FMTVersion::KierlikRosinberg,
+            FMTVersion::AntiSymWhiteBear,
+            FMTVersion::WhiteBearMarkII,
+            FMTVersion::Rosenfeld,
+        ] {
+            let func = FMTFunctional::new(&sigma, version);
+            let hs = HardSphere::with_version(&func.properties, version.into());
+            assert_relative_eq!(
+                residual_helmholtz_energy(&func, &state),
+                hs.helmholtz_energy(&state),
+                max_relative = 1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn test_nonadditive_bulk() {
+        let sigma = arr1(&[1.0, 1.5]);
+        let nonadditivity = arr2(&[[0.0, 0.1], [0.1, 0.0]]);
+        let rho = arr1(&[0.2, 0.1]);
+        let state = StateHD::new(1.0, 1.0, rho.clone());
+
+        // vanishing nonadditivity
+        let func = FMTFunctional::new(&sigma, FMTVersion::WhiteBear);
+        let func_zero = FMTFunctional::with_nonadditivity(
+            &sigma,
+            &Array2::zeros((2, 2)),
+            FMTVersion::WhiteBear,
+        );
+        let a_add = residual_helmholtz_energy(&func, &state);
+        assert_relative_eq!(
+            residual_helmholtz_energy(&func_zero, &state),
+            a_add,
+            max_relative = 1e-14
+        );
+
+        // first order correction with the BMCSL contact values
+        let func_nonadd =
+            FMTFunctional::with_nonadditivity(&sigma, &nonadditivity, FMTVersion::WhiteBear);
+        let zeta2 = FRAC_PI_6 * (&rho * &sigma.mapv(|s| s.powi(2))).sum();
+        let zeta3 = FRAC_PI_6 * (&rho * &sigma.mapv(|s| s.powi(3))).sum();
+        let mut delta_a = 0.0;
+        for i in 0..2 {
+            for j in 0..2 {
+                let d_add = 0.5 * (sigma[i] + sigma[j]);
+                let mu = sigma[i] * sigma[j] / (sigma[i] + sigma[j]);
+                let g = 1.0 / (1.0 - zeta3)
+                    + 3.0 * mu * zeta2 / (1.0 - zeta3).powi(2)
+                    + 2.0 * (mu * zeta2).powi(2) / (1.0 - zeta3).powi(3);
+                delta_a +=
+                    2.0 * PI * rho[i] * rho[j] * d_add.powi(2) * g * d_add * nonadditivity[[i, j]];
+            }
+        }
+        let a_nonadd = residual_helmholtz_energy(&func_nonadd, &state);
+        assert!(a_nonadd > a_add);
+        assert_relative_eq!(a_nonadd, a_add + delta_a, max_relative = 1e-12);
+    }
+}
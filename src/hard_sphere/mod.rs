@@ -10,9 +10,15 @@ use std::{borrow::Cow, sync::Arc};
 #[cfg(feature = "dft")]
 mod dft;
 #[cfg(feature = "dft")]
+mod non_additive_hs;
+#[cfg(feature = "dft")]
 pub(crate) use dft::HardSphereParameters;
 #[cfg(feature = "dft")]
-pub use dft::{FMTContribution, FMTFunctional, FMTVersion};
+pub use dft::{FMTContribution, FMTFunctional, FMTFunctionalContribution, FMTVersion};
+#[cfg(feature = "dft")]
+pub use non_additive_hs::NonAddHardSphereFunctional;
+
+const N3_CUTOFF: f64 = 1e-5;
 
 /// Different monomer shapes for FMT and BMCSL.
 pub enum MonomerShape<'a, D> {
@@ -79,6 +85,18 @@ pub trait HardSphereProperties {
     }
 }
 
+/// Different versions of the equation of state for hard-sphere mixtures.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum HardSphereVersion {
+    /// Boublík-Mansoori-Carnahan-Starling-Leland equation of state ([Boublík, 1970](https://doi.org/10.1063/1.1673824), [Mansoori et al., 1971](https://doi.org/10.1063/1.1675048))
+    #[default]
+    Bmcsl,
+    /// Generalization of the Carnahan-Starling equation of state to mixtures by Hansen-Goos and Roth (J. Chem. Phys. 124, 154506, 2006)
+    HansenGoosRoth,
+    /// Percus-Yevick (compressibility route) or scaled particle theory equation of state
+    PercusYevick,
+}
+
 /// Implementation of the BMCSL equation of state for hard-sphere mixtures.
 ///
 /// This structure provides an implementation of the Boublík-Mansoori-Carnahan-Starling-Leland (BMCSL) equation of state ([Boublík, 1970](https://doi.org/10.1063/1.1673824), [Mansoori et al., 1971](https://doi.org/10.1063/1.1675048)) that is often used as reference contribution in SAFT equations of state. The implementation is generalized to allow the description of non-sperical or fused-sphere reference fluids.
@@ -88,15 +106,27 @@ pub trait HardSphereProperties {
 /// with the packing fractions
 /// $$\zeta_k=\frac{\pi}{6}\sum_\alpha C_{k,\alpha}\rho_\alpha d_\alpha^k,~~~~~~~~k=0\ldots 3.$$
 ///
+/// Alternatively, the equation of state by Hansen-Goos and Roth, which reduces to the Carnahan-Starling equation of state for pure fluids, and the Percus-Yevick equation of state, which are the bulk limits of the White Bear Mark II and the original Rosenfeld fundamental measure theories, can be selected via [HardSphereVersion]. Their reduced Helmholtz energies are
+/// $$\frac{\beta A}{V}=\frac{6}{\pi}\left(-\zeta_0\ln\left(1-\zeta_3\right)+\frac{3\zeta_1\zeta_2}{1-\zeta_3}\left(1+\frac{\phi_2}{3}\right)+\frac{3\zeta_2^3}{2\left(1-\zeta_3\right)^2}\left(1-\frac{\phi_3}{3}\right)\right)$$
+/// with $\phi_2=\phi_3=0$ for Percus-Yevick and
+/// $$\phi_2=\frac{2\zeta_3-\zeta_3^2+2\left(1-\zeta_3\right)\ln\left(1-\zeta_3\right)}{\zeta_3},~~~~\phi_3=\frac{2\zeta_3-3\zeta_3^2+2\zeta_3^3+2\left(1-\zeta_3\right)^2\ln\left(1-\zeta_3\right)}{\zeta_3^2}$$
+/// for the equation of state by Hansen-Goos and Roth.
+///
 /// The geometry coefficients $C_{k,\alpha}$ and the segment diameters $d_\alpha$ are specified via the [HardSphereProperties] trait.
 pub struct HardSphere<P> {
     parameters: Arc<P>,
+    version: HardSphereVersion,
 }
 
 impl<P> HardSphere<P> {
     pub fn new(parameters: &Arc<P>) -> Self {
+        Self::with_version(parameters, HardSphereVersion::Bmcsl)
+    }
+
+    pub fn with_version(parameters: &Arc<P>, version: HardSphereVersion) -> Self {
         Self {
             parameters: parameters.clone(),
+            version,
         }
     }
 }
@@ -125,10 +155,24 @@ impl<P: HardSphereProperties> HardSphere<P> {
         let density = state.partial_density.sum();
         zeta.iter_mut().for_each(|z| *z *= density);
         let frac_1mz3 = -(zeta[3] - 1.0).recip();
-        let a = state.volume / std::f64::consts::FRAC_PI_6
-            * (zeta[1] * zeta[2] * frac_1mz3 * 3.0
-                + zeta[2].powi(2) * frac_1mz3.powi(2) * zeta_23
-                + (zeta[2] * zeta_23.powi(2) - zeta[0]) * (zeta[3] * (-1.0)).ln_1p());
+        let ln31 = (zeta[3] * (-1.0)).ln_1p();
+        let a = match self.version {
+            HardSphereVersion::Bmcsl => {
+                zeta[1] * zeta[2] * frac_1mz3 * 3.0
+                    + zeta[2].powi(2) * frac_1mz3.powi(2) * zeta_23
+                    + (zeta[2] * zeta_23.powi(2) - zeta[0]) * ln31
+            }
+            HardSphereVersion::HansenGoosRoth => {
+                zeta[1] * zeta[2] * frac_1mz3 * (phi2(zeta[3]) + 3.0)
+                    + zeta[2].powi(3) * frac_1mz3.powi(2) * (-phi3(zeta[3]) + 3.0) * 0.5
+                    - zeta[0] * ln31
+            }
+            HardSphereVersion::PercusYevick => {
+                zeta[1] * zeta[2] * frac_1mz3 * 3.0 + zeta[2].powi(3) * frac_1mz3.powi(2) * 1.5
+                    - zeta[0] * ln31
+            }
+        } * state.volume
+            / std::f64::consts::FRAC_PI_6;
         (a, zeta, diameter)
     }
 
@@ -143,3 +187,26 @@ impl<P> fmt::Display for HardSphere<P> {
         write!(f, "Hard Sphere")
     }
 }
+
+/// The function $\phi_2(n_3)$ of the White Bear Mark II functional and the Hansen-Goos-Roth equation of state.
+///
+/// For small $n_3$, a Taylor expansion is used to avoid numerical issues.
+pub(crate) fn phi2<D: DualNum<f64> + Copy>(n3: D) -> D {
+    if n3.re() < N3_CUTOFF {
+        (((n3 / 15.0 + 0.1) * n3 + 1.0 / 6.0) * n3 + 1.0 / 3.0) * n3 * n3
+    } else {
+        (n3 * 2.0 - n3 * n3 + (-n3 + 1.0) * (-n3).ln_1p() * 2.0) / n3
+    }
+}
+
+/// The function $\phi_3(n_3)$ of the White Bear Mark II functional and the Hansen-Goos-Roth equation of state.
+///
+/// For small $n_3$, a Taylor expansion is used to avoid numerical issues.
+pub(crate) fn phi3<D: DualNum<f64> + Copy>(n3: D) -> D {
+    if n3.re() < N3_CUTOFF {
+        (((-n3 / 30.0 - 1.0 / 15.0) * n3 - 1.0 / 6.0) * n3 + 4.0 / 3.0) * n3
+    } else {
+        (n3 * 2.0 - n3 * n3 * 3.0 + n3.powi(3) * 2.0 + (-n3 + 1.0).powi(2) * (-n3).ln_1p() * 2.0)
+            / (n3 * n3)
+    }
+}
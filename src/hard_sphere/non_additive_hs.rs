@@ -0,0 +1,178 @@
+use super::HardSphereParameters;
+use feos_core::EosResult;
+use feos_dft::{FunctionalContribution, WeightFunction, WeightFunctionInfo, WeightFunctionShape};
+use ndarray::*;
+use num_dual::DualNum;
+use std::f64::consts::PI;
+use std::fmt;
+use std::sync::Arc;
+
+const N0_CUTOFF: f64 = 1e-9;
+
+/// Correction of the FMT functional for non-additive hard-sphere mixtures.
+///
+/// The Helmholtz energy is expanded to first order in the nonadditivity
+/// around the additive mixture, i.e.,
+/// $$\beta f=2\pi\sum_{\alpha\beta}\rho_{0,\alpha}\rho_{0,\beta}d_{\alpha\beta}^{\mathrm{add}\\,2}g_{\alpha\beta}\left(d_{\alpha\beta}-d_{\alpha\beta}^\mathrm{add}\right)$$
+/// with the contact values $g_{\alpha\beta}$ of the BMCSL radial distribution function that
+/// are evaluated with the local weighted densities of FMT.
+#[derive(Clone)]
+pub struct NonAddHardSphereFunctional {
+    parameters: Arc<HardSphereParameters>,
+}
+
+impl NonAddHardSphereFunctional {
+    pub fn new(parameters: Arc<HardSphereParameters>) -> Self {
+        Self { parameters }
+    }
+}
+
+impl FunctionalContribution for NonAddHardSphereFunctional {
+    fn weight_functions<N: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        _: N,
+    ) -> WeightFunctionInfo<N> {
+        let p = &self.parameters;
+        let r = p.sigma.mapv(|s| N::from(s * 0.5));
+        WeightFunctionInfo::new(Array1::from_shape_fn(r.len(), |i| i), false)
+            .add(
+                WeightFunction::new_scaled(r.clone(), WeightFunctionShape::Delta),
+                false,
+            )
+            .add(
+                WeightFunction {
+                    prefactor: Array::ones(r.len()),
+                    kernel_radius: r.clone(),
+                    shape: WeightFunctionShape::DeltaVec,
+                },
+                false,
+            )
+            .add(
+                WeightFunction {
+                    prefactor: Array::ones(r.len()),
+                    kernel_radius: r,
+                    shape: WeightFunctionShape::Theta,
+                },
+                true,
+            )
+    }
+
+    fn helmholtz_energy_density<N: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        _: N,
+        weighted_densities: ArrayView2<N>,
+    ) -> EosResult<Array1<N>> {
+        let p = &self.parameters;
+        // number of components
+        let n = p.sigma.len();
+        // number of dimensions
+        let dim = (weighted_densities.shape()[0] - 1) / n - 1;
+
+        // weighted densities
+        let n0i = weighted_densities.slice_axis(Axis(0), Slice::new(0, Some(n as isize), 1));
+        let n2vi: Vec<_> = (0..dim)
+            .map(|i| {
+                weighted_densities.slice_axis(
+                    Axis(0),
+                    Slice::new((n * (i + 1)) as isize, Some((n * (i + 2)) as isize), 1),
+                )
+            })
+            .collect();
+        let n3 = weighted_densities.index_axis(Axis(0), n * (dim + 1));
+
+        // calculate rho0
+        let r = &p.sigma * 0.5;
+        let mut n2i = Array::zeros(n0i.raw_dim());
+        for i in 0..n {
+            n2i.index_axis_mut(Axis(0), i)
+                .assign(&(&n0i.index_axis(Axis(0), i) * (r[i].powi(2) * 4.0 * PI)));
+        }
+        let mut rho0: Array2<N> = (n2vi
+            .iter()
+            .map(|n2vi| n2vi * n2vi)
+            .fold(Array::zeros(n0i.raw_dim()), |acc, x| acc + x)
+            / -(&n2i * &n2i)
+            + 1.0)
+            * n0i;
+        rho0.iter_mut().zip(&n0i).for_each(|(rho0, &n0i)| {
+            if n0i.re() < N0_CUTOFF {
+                *rho0 = n0i;
+            }
+        });
+
+        // calculate xi
+        let n2v: Vec<_> = n2vi.iter().map(|n2vi| n2vi.sum_axis(Axis(0))).collect();
+        let n2 = n2i.sum_axis(Axis(0));
+        let mut xi = n2v
+            .iter()
+            .map(|n2v| n2v * n2v)
+            .fold(Array::zeros(n3.raw_dim()), |acc, x| acc + x)
+            / -(&n2 * &n2)
+            + 1.0;
+        xi.iter_mut()
+            .zip(&n0i.sum_axis(Axis(0)))
+            .for_each(|(xi, &n0i)| {
+                if n0i.re() < N0_CUTOFF {
+                    *xi = N::one();
+                }
+            });
+
+        // auxiliary variables
+        let n3i = n3.mapv(|n3| (-n3 + 1.0).recip());
+
+        // non-additive and additive hard-sphere diameters
+        let d_ij = p.sigma_ij();
+        let d_add_ij = Array2::from_shape_fn((n, n), |(i, j)| 0.5 * (p.sigma[i] + p.sigma[j]));
+
+        Ok(rho0
+            .view()
+            .into_shape_with_order([n, rho0.len() / n])
+            .unwrap()
+            .axis_iter(Axis(1))
+            .zip(n2.iter())
+            .zip(n3i.iter())
+            .zip(xi.iter())
+            .map(|(((rho0, &n2), &n3i), &xi)| {
+                non_additive_hs_energy_density(&p.sigma, &d_ij, &d_add_ij, &rho0, n2, n3i, xi)
+            })
+            .collect::<Array1<N>>()
+            .into_shape_with_order(n2.raw_dim())
+            .unwrap())
+    }
+}
+
+fn non_additive_hs_energy_density<S, N: DualNum<f64> + Copy + ScalarOperand>(
+    d: &Array1<f64>,
+    d_ij: &Array2<f64>,
+    d_add_ij: &Array2<f64>,
+    rho0: &ArrayBase<S, Ix1>,
+    n2: N,
+    n3i: N,
+    xi: N,
+) -> N
+where
+    S: Data<Elem = N>,
+{
+    let n = rho0.len();
+    let g_hs_ij = Array2::from_shape_fn((n, n), |(i, j)| {
+        let mu = d[i] * d[j] / (d[i] + d[j]);
+        n3i + n2 * xi * n3i.powi(2) * mu / 2.0 + (n2 * xi * mu).powi(2) * n3i.powi(3) / 18.0
+    });
+
+    Array2::from_shape_fn((n, n), |(i, j)| {
+        rho0[i]
+            * rho0[j]
+            * g_hs_ij[[i, j]]
+            * d_add_ij[[i, j]].powi(2)
+            * (d_ij[[i, j]] - d_add_ij[[i, j]])
+            * 2.0
+            * PI
+    })
+    .sum()
+}
+
+impl fmt::Display for NonAddHardSphereFunctional {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Non-additive hard-sphere functional")
+    }
+}
@@ -9,8 +9,12 @@ use std::sync::Arc;
 
 mod dippr;
 mod joback;
+mod nasa;
+mod rrho;
 pub use dippr::{Dippr, DipprRecord};
 pub use joback::{Joback, JobackRecord};
+pub use nasa::{Nasa, Nasa9Range, NasaRecord};
+pub use rrho::{Rrho, RrhoRecord};
 
 /// Collection of different [IdealGas] implementations.
 ///
@@ -21,6 +25,8 @@ pub enum IdealGasModel {
     NoModel(usize),
     Joback(Arc<Joback>),
     Dippr(Arc<Dippr>),
+    Nasa(Arc<Nasa>),
+    Rrho(Arc<Rrho>),
     #[cfg(feature = "python")]
     Python(PyIdealGas),
 }
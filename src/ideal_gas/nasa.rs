@@ -0,0 +1,402 @@
+//! Ideal gas model based on the NASA 7- and 9-coefficient polynomials
+//! as used in combustion databases
+//! ([McBride et al., 2002](https://ntrs.nasa.gov/citations/20020085330)).
+use feos_core::parameter::{NoBinaryModelRecord, Parameter, ParameterError, PureRecord};
+use feos_core::{Components, EosResult, IdealGas};
+use ndarray::{Array1, Array2};
+use num_dual::DualNum;
+use quantity::{MolarEntropy, Temperature, KELVIN, RGAS};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Coefficients of a NASA 9-coefficient polynomial that are
+/// valid in the temperature range from `t_min` to `t_max`.
+///
+/// The coefficients are ordered as $a_1,\ldots,a_7,b_1,b_2$.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Nasa9Range {
+    pub t_min: f64,
+    pub t_max: f64,
+    pub coefs: [f64; 9],
+}
+
+impl Nasa9Range {
+    /// Creates a new `Nasa9Range`.
+    pub fn new(t_min: f64, t_max: f64, coefs: [f64; 9]) -> Self {
+        Self {
+            t_min,
+            t_max,
+            coefs,
+        }
+    }
+}
+
+/// Parameters of NASA polynomials for the ideal gas heat capacity,
+/// enthalpy and entropy.
+///
+/// All polynomials use $\[T\]=\text{K}$. The enthalpies include the enthalpy
+/// of formation and the entropies are absolute entropies at $p^\circ=1\\,\text{bar}$.
+/// Outside of the given temperature ranges, the polynomials of the closest
+/// range are extrapolated.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum NasaRecord {
+    /// 7-coefficient polynomials for a low and a high temperature range,
+    /// separated by the common temperature `t_mid`.
+    /// $$\frac{c_p^\circ}{R} = a_1 + a_2T + a_3T^2 + a_4T^3 + a_5T^4$$
+    /// $$\frac{h^\circ}{RT} = a_1 + \frac{a_2}{2}T + \frac{a_3}{3}T^2 + \frac{a_4}{4}T^3 + \frac{a_5}{5}T^4 + \frac{a_6}{T}$$
+    /// $$\frac{s^\circ}{R} = a_1\ln T + a_2T + \frac{a_3}{2}T^2 + \frac{a_4}{3}T^3 + \frac{a_5}{4}T^4 + a_7$$
+    NASA7 {
+        t_mid: f64,
+        low: [f64; 7],
+        high: [f64; 7],
+    },
+    /// 9-coefficient polynomials for an arbitrary number of temperature ranges.
+    /// $$\frac{c_p^\circ}{R} = a_1T^{-2} + a_2T^{-1} + a_3 + a_4T + a_5T^2 + a_6T^3 + a_7T^4$$
+    /// $$\frac{h^\circ}{RT} = -a_1T^{-2} + a_2\frac{\ln T}{T} + a_3 + \frac{a_4}{2}T + \frac{a_5}{3}T^2 + \frac{a_6}{4}T^3 + \frac{a_7}{5}T^4 + \frac{b_1}{T}$$
+    /// $$\frac{s^\circ}{R} = -\frac{a_1}{2}T^{-2} - a_2T^{-1} + a_3\ln T + a_4T + \frac{a_5}{2}T^2 + \frac{a_6}{3}T^3 + \frac{a_7}{4}T^4 + b_2$$
+    NASA9(Vec<Nasa9Range>),
+}
+
+impl NasaRecord {
+    /// Create parameters for 7-coefficient polynomials.
+    pub fn nasa7(t_mid: f64, low: [f64; 7], high: [f64; 7]) -> Self {
+        Self::NASA7 { t_mid, low, high }
+    }
+
+    /// Create parameters for 9-coefficient polynomials.
+    pub fn nasa9(ranges: Vec<Nasa9Range>) -> Self {
+        Self::NASA9(ranges)
+    }
+
+    /// Coefficients of the range that contains `t` or, outside of all ranges,
+    /// of the closest range.
+    fn nasa9_coefs(ranges: &[Nasa9Range], t: f64) -> &[f64; 9] {
+        let distance = |r: &Nasa9Range| (r.t_min - t).max(t - r.t_max).max(0.0);
+        let mut coefs = &ranges[0].coefs;
+        let mut d_min = f64::INFINITY;
+        for r in ranges {
+            let d = distance(r);
+            if d < d_min {
+                coefs = &r.coefs;
+                d_min = d;
+            }
+        }
+        coefs
+    }
+
+    fn validate(&self) -> Result<(), ParameterError> {
+        match self {
+            Self::NASA7 { .. } => Ok(()),
+            Self::NASA9(ranges) if ranges.is_empty() => {
+                Err(ParameterError::IncompatibleParameters(
+                    "No temperature range for NASA 9-coefficient polynomials given!".into(),
+                ))
+            }
+            Self::NASA9(ranges) => ranges.iter().try_for_each(|r| {
+                if r.t_min < r.t_max {
+                    Ok(())
+                } else {
+                    Err(ParameterError::IncompatibleParameters(format!(
+                        "Invalid temperature range [{}, {}] for NASA 9-coefficient polynomials!",
+                        r.t_min, r.t_max
+                    )))
+                }
+            }),
+        }
+    }
+
+    fn c_p(&self, t: f64) -> f64 {
+        match self {
+            Self::NASA7 { t_mid, low, high } => {
+                let a = if t < *t_mid { low } else { high };
+                a[0] + t * (a[1] + t * (a[2] + t * (a[3] + t * a[4])))
+            }
+            Self::NASA9(ranges) => {
+                let a = Self::nasa9_coefs(ranges, t);
+                a[0] / (t * t) + a[1] / t + a[2] + t * (a[3] + t * (a[4] + t * (a[5] + t * a[6])))
+            }
+        }
+    }
+
+    /// Dimensionless standard Gibbs energy $\frac{g^\circ}{RT}=\frac{h^\circ}{RT}-\frac{s^\circ}{R}$.
+    fn g_reduced<D: DualNum<f64> + Copy>(&self, t: D) -> D {
+        let t_inv = t.recip();
+        let ln_t = t.ln();
+        match self {
+            Self::NASA7 { t_mid, low, high } => {
+                let a = if t.re() < *t_mid { low } else { high };
+                let h = t * (t * (t * (t * a[4] / 5.0 + a[3] / 4.0) + a[2] / 3.0) + a[1] / 2.0)
+                    + t_inv * a[5]
+                    + a[0];
+                let s = t * (t * (t * (t * a[4] / 4.0 + a[3] / 3.0) + a[2] / 2.0) + a[1])
+                    + ln_t * a[0]
+                    + a[6];
+                h - s
+            }
+            Self::NASA9(ranges) => {
+                let a = Self::nasa9_coefs(ranges, t.re());
+                let t_inv2 = t_inv * t_inv;
+                let h = -t_inv2 * a[0]
+                    + ln_t * t_inv * a[1]
+                    + t * (t * (t * (t * a[6] / 5.0 + a[5] / 4.0) + a[4] / 3.0) + a[3] / 2.0)
+                    + t_inv * a[7]
+                    + a[2];
+                let s = -t_inv2 * (a[0] / 2.0) - t_inv * a[1]
+                    + ln_t * a[2]
+                    + t * (t * (t * (t * a[6] / 4.0 + a[5] / 3.0) + a[4] / 2.0) + a[3])
+                    + a[8];
+                h - s
+            }
+        }
+    }
+}
+
+impl fmt::Display for NasaRecord {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NASA7 { t_mid, low, high } => write!(
+                fmt,
+                "NasaRecord(NASA7, t_mid={t_mid}, low={low:?}, high={high:?})"
+            ),
+            Self::NASA9(ranges) => {
+                write!(fmt, "NasaRecord(NASA9")?;
+                for r in ranges {
+                    write!(fmt, ", [{}, {}]: {:?}", r.t_min, r.t_max, r.coefs)?;
+                }
+                write!(fmt, ")")
+            }
+        }
+    }
+}
+
+/// Ideal gas equations of state based on NASA polynomials.
+///
+/// In contrast to other ideal gas models, the resulting enthalpies
+/// and entropies are absolute values that include the enthalpy
+/// of formation.
+pub struct Nasa(Vec<PureRecord<NasaRecord>>);
+
+impl Parameter for Nasa {
+    type Pure = NasaRecord;
+    type Binary = NoBinaryModelRecord;
+
+    fn from_records(
+        pure_records: Vec<PureRecord<Self::Pure>>,
+        _binary_records: Option<Array2<Self::Binary>>,
+    ) -> Result<Self, ParameterError> {
+        for record in &pure_records {
+            record.model_record.validate()?;
+        }
+        Ok(Self(pure_records))
+    }
+
+    fn records(&self) -> (&[PureRecord<Self::Pure>], Option<&Array2<Self::Binary>>) {
+        (&self.0, None)
+    }
+}
+
+impl Nasa {
+    /// Directly calculates the molar ideal gas heat capacity from the NASA polynomials.
+    pub fn molar_isobaric_heat_capacity(
+        &self,
+        temperature: Temperature,
+        molefracs: &Array1<f64>,
+    ) -> EosResult<MolarEntropy> {
+        let t = temperature.convert_to(KELVIN);
+        let c_p: f64 = molefracs
+            .iter()
+            .zip(&self.0)
+            .map(|(x, r)| x * r.model_record.c_p(t))
+            .sum();
+        Ok(c_p * RGAS)
+    }
+}
+
+impl Components for Nasa {
+    fn components(&self) -> usize {
+        self.0.len()
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        let mut records = Vec::with_capacity(component_list.len());
+        component_list
+            .iter()
+            .for_each(|&i| records.push(self.0[i].clone()));
+        Self::from_records(records, None).unwrap()
+    }
+}
+
+const P0: f64 = 1.0e5;
+const A3: f64 = 1e-30;
+const KB: f64 = 1.380649e-23;
+
+impl IdealGas for Nasa {
+    fn ln_lambda3<D: DualNum<f64> + Copy>(&self, temperature: D) -> Array1<D> {
+        let f = (temperature * KB / (P0 * A3)).ln();
+        self.0
+            .iter()
+            .map(|r| r.model_record.g_reduced(temperature) + f)
+            .collect()
+    }
+
+    fn ideal_gas_model(&self) -> String {
+        "Ideal gas (NASA)".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use feos_core::parameter::Identifier;
+    use feos_core::{Contributions, DensityInitialization, EquationOfState, NoResidual, State};
+    use ndarray::arr1;
+    use quantity::{BAR, JOULE, MOL};
+    use std::sync::Arc;
+
+    fn nitrogen_nasa7() -> NasaRecord {
+        NasaRecord::nasa7(
+            1000.0,
+            [
+                3.53100528,
+                -1.23660988e-4,
+                -5.02999433e-7,
+                2.43530612e-9,
+                -1.40881235e-12,
+                -1046.97628,
+                2.96747038,
+            ],
+            [
+                2.95257637,
+                1.3969004e-3,
+                -4.92631603e-7,
+                7.86010195e-11,
+                -4.60755204e-15,
+                -923.948688,
+                5.87188762,
+            ],
+        )
+    }
+
+    fn nitrogen_nasa9() -> NasaRecord {
+        NasaRecord::nasa9(vec![
+            Nasa9Range::new(
+                200.0,
+                1000.0,
+                [
+                    2.210371497e4,
+                    -3.818461820e2,
+                    6.082738360,
+                    -8.530914410e-3,
+                    1.384646189e-5,
+                    -9.625793620e-9,
+                    2.519705809e-12,
+                    7.108460860e2,
+                    -1.076003744e1,
+                ],
+            ),
+            Nasa9Range::new(
+                1000.0,
+                6000.0,
+                [
+                    5.877124060e5,
+                    -2.239249073e3,
+                    6.066949220,
+                    -6.139685500e-4,
+                    1.491806679e-7,
+                    -1.923105485e-11,
+                    1.061954386e-15,
+                    1.283210415e4,
+                    -1.586640027e1,
+                ],
+            ),
+        ])
+    }
+
+    fn standard_state(record: NasaRecord) -> EosResult<State<EquationOfState<Nasa, NoResidual>>> {
+        let nasa = Arc::new(Nasa::new_pure(PureRecord::new(
+            Identifier::default(),
+            28.0134,
+            record,
+        ))?);
+        let eos = Arc::new(EquationOfState::ideal_gas(nasa));
+        State::new_npt(
+            &eos,
+            298.15 * KELVIN,
+            BAR,
+            &(arr1(&[1.0]) * MOL),
+            DensityInitialization::Vapor,
+        )
+    }
+
+    #[test]
+    fn nitrogen() -> EosResult<()> {
+        // JANAF: s = 191.609 J/mol/K, c_p = 29.124 J/mol/K, h = 0
+        for record in [nitrogen_nasa7(), nitrogen_nasa9()] {
+            let state = standard_state(record)?;
+            assert_relative_eq!(
+                state.molar_entropy(Contributions::IdealGas),
+                191.609 * JOULE / MOL / KELVIN,
+                max_relative = 1e-4
+            );
+            assert_relative_eq!(
+                state.molar_isobaric_heat_capacity(Contributions::IdealGas),
+                29.124 * JOULE / MOL / KELVIN,
+                max_relative = 1e-3
+            );
+            assert_relative_eq!(
+                state.molar_enthalpy(Contributions::IdealGas),
+                0.0 * JOULE / MOL,
+                epsilon = 1e-1
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn heat_capacity() -> EosResult<()> {
+        let nasa = Nasa::new_pure(PureRecord::new(
+            Identifier::default(),
+            28.0134,
+            nitrogen_nasa9(),
+        ))?;
+        let eos = Arc::new(EquationOfState::ideal_gas(Arc::new(nasa)));
+        for t in [300.0, 1500.0] {
+            let state = State::new_npt(
+                &eos,
+                t * KELVIN,
+                BAR,
+                &(arr1(&[1.0]) * MOL),
+                DensityInitialization::Vapor,
+            )?;
+            assert_relative_eq!(
+                eos.ideal_gas
+                    .molar_isobaric_heat_capacity(state.temperature, &state.molefracs)?,
+                state.molar_isobaric_heat_capacity(Contributions::IdealGas),
+                max_relative = 1e-10
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn range_selection() {
+        let NasaRecord::NASA9(ranges) = nitrogen_nasa9() else {
+            unreachable!()
+        };
+        for (t, i) in [(100.0, 0), (500.0, 0), (1500.0, 1), (8000.0, 1)] {
+            assert_eq!(NasaRecord::nasa9_coefs(&ranges, t), &ranges[i].coefs);
+        }
+    }
+
+    #[test]
+    fn invalid_records() {
+        for record in [
+            NasaRecord::nasa9(vec![]),
+            NasaRecord::nasa9(vec![Nasa9Range::new(1000.0, 200.0, [0.0; 9])]),
+        ] {
+            let record = PureRecord::new(Identifier::default(), 28.0134, record);
+            assert!(Nasa::new_pure(record).is_err());
+        }
+    }
+}
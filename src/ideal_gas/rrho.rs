@@ -0,0 +1,250 @@
+//! Ideal gas model of a rigid rotor and harmonic oscillator
+//! based on molecular constants.
+use feos_core::parameter::{NoBinaryModelRecord, Parameter, ParameterError, PureRecord};
+use feos_core::{Components, IdealGas};
+use ndarray::{Array1, Array2};
+use num_dual::DualNum;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+use std::fmt;
+
+/// Molecular constants for the rigid rotor and harmonic oscillator model.
+///
+/// Rotational constants and vibrational wavenumbers are given in $\text{cm}^{-1}$,
+/// e.g., as reported by quantum chemistry software or spectroscopic databases.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RrhoRecord {
+    /// Rotational constants (none for atoms, one for linear and three for nonlinear molecules)
+    pub rotational_constants: Vec<f64>,
+    /// Wavenumbers of the vibrational modes
+    pub vibrational_wavenumbers: Vec<f64>,
+    /// Rotational symmetry number
+    #[serde(default = "one")]
+    pub symmetry_number: f64,
+    /// Degeneracy of the electronic ground state
+    #[serde(default = "one")]
+    pub electronic_degeneracy: f64,
+}
+
+fn one() -> f64 {
+    1.0
+}
+
+impl RrhoRecord {
+    /// Creates a new `RrhoRecord`.
+    pub fn new(
+        rotational_constants: Vec<f64>,
+        vibrational_wavenumbers: Vec<f64>,
+        symmetry_number: f64,
+        electronic_degeneracy: f64,
+    ) -> Self {
+        Self {
+            rotational_constants,
+            vibrational_wavenumbers,
+            symmetry_number,
+            electronic_degeneracy,
+        }
+    }
+
+    /// Logarithm of the internal (rotational, vibrational and electronic) partition function.
+    fn ln_q_int<D: DualNum<f64> + Copy>(&self, temperature: D) -> D {
+        let ln_q_rot = match self.rotational_constants.as_slice() {
+            [] => D::zero(),
+            [b] => temperature.ln() - (self.symmetry_number * C2 * b).ln(),
+            [a, b, c] => {
+                temperature.ln() * 1.5 + 0.5 * PI.ln()
+                    - self.symmetry_number.ln()
+                    - 0.5 * (C2 * a * C2 * b * C2 * c).ln()
+            }
+            // the number of rotational constants is checked in `from_records`
+            _ => unreachable!(),
+        };
+        let t_inv = temperature.recip();
+        let ln_q_vib = self
+            .vibrational_wavenumbers
+            .iter()
+            .fold(D::zero(), |acc, &nu| {
+                acc - (-(-t_inv * (C2 * nu)).exp() + 1.0).ln()
+            });
+        ln_q_rot + ln_q_vib + self.electronic_degeneracy.ln()
+    }
+}
+
+impl fmt::Display for RrhoRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "RrhoRecord(rotational_constants={:?}, vibrational_wavenumbers={:?}, symmetry_number={}, electronic_degeneracy={})",
+            self.rotational_constants,
+            self.vibrational_wavenumbers,
+            self.symmetry_number,
+            self.electronic_degeneracy
+        )
+    }
+}
+
+/// Ideal gas equations of state of rigid rotors and harmonic oscillators.
+///
+/// The thermal de Broglie wavelength is obtained from the partition functions
+/// of the translational, rotational, vibrational and electronic degrees of freedom.
+/// The energies are measured relative to the vibrational ground state, i.e., the
+/// enthalpy does not include the zero point energy.
+pub struct Rrho(Vec<PureRecord<RrhoRecord>>);
+
+impl Parameter for Rrho {
+    type Pure = RrhoRecord;
+    type Binary = NoBinaryModelRecord;
+
+    fn from_records(
+        pure_records: Vec<PureRecord<Self::Pure>>,
+        _binary_records: Option<Array2<Self::Binary>>,
+    ) -> Result<Self, ParameterError> {
+        for record in &pure_records {
+            let n = record.model_record.rotational_constants.len();
+            if ![0, 1, 3].contains(&n) {
+                return Err(ParameterError::IncompatibleParameters(format!(
+                    "Either 0, 1 or 3 rotational constants have to be provided, got {n}!"
+                )));
+            }
+        }
+        Ok(Self(pure_records))
+    }
+
+    fn records(&self) -> (&[PureRecord<Self::Pure>], Option<&Array2<Self::Binary>>) {
+        (&self.0, None)
+    }
+}
+
+impl Components for Rrho {
+    fn components(&self) -> usize {
+        self.0.len()
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        let mut records = Vec::with_capacity(component_list.len());
+        component_list
+            .iter()
+            .for_each(|&i| records.push(self.0[i].clone()));
+        Self::from_records(records, None).unwrap()
+    }
+}
+
+/// Planck constant in J s
+const H: f64 = 6.62607015e-34;
+/// Boltzmann constant in J/K
+const KB: f64 = 1.380649e-23;
+/// Avogadro constant in 1/mol
+const NAV: f64 = 6.02214076e23;
+/// Second radiation constant hc/k in cm K
+const C2: f64 = 1.438776877;
+
+impl IdealGas for Rrho {
+    fn ln_lambda3<D: DualNum<f64> + Copy>(&self, temperature: D) -> Array1<D> {
+        self.0
+            .iter()
+            .map(|r| {
+                // translational contribution in units of Å³
+                let m = r.molarweight * 1e-3 / NAV;
+                let ln_lambda3_trans =
+                    (temperature * (2.0 * PI * m * KB)).ln() * -1.5 + 3.0 * (H * 1e10).ln();
+                ln_lambda3_trans - r.model_record.ln_q_int(temperature)
+            })
+            .collect()
+    }
+
+    fn ideal_gas_model(&self) -> String {
+        "Ideal gas (RRHO)".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use feos_core::parameter::Identifier;
+    use feos_core::{Contributions, DensityInitialization, EosResult, EquationOfState, State};
+    use ndarray::arr1;
+    use quantity::{BAR, JOULE, KELVIN, MOL, RGAS};
+    use std::sync::Arc;
+
+    fn standard_molar_entropy(molarweight: f64, record: RrhoRecord) -> EosResult<f64> {
+        let rrho = Rrho::new_pure(PureRecord::new(Identifier::default(), molarweight, record))?;
+        let eos = Arc::new(EquationOfState::ideal_gas(Arc::new(rrho)));
+        let state = State::new_npt(
+            &eos,
+            298.15 * KELVIN,
+            BAR,
+            &(arr1(&[1.0]) * MOL),
+            DensityInitialization::Vapor,
+        )?;
+        Ok(state
+            .molar_entropy(Contributions::IdealGas)
+            .convert_to(JOULE / MOL / KELVIN))
+    }
+
+    #[test]
+    fn argon() -> EosResult<()> {
+        // Sackur-Tetrode equation
+        let record = RrhoRecord::new(vec![], vec![], 1.0, 1.0);
+        assert_relative_eq!(
+            standard_molar_entropy(39.948, record)?,
+            154.846,
+            max_relative = 1e-4
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn nitrogen() -> EosResult<()> {
+        let record = RrhoRecord::new(vec![1.99824], vec![2358.57], 2.0, 1.0);
+        assert_relative_eq!(
+            standard_molar_entropy(28.0134, record)?,
+            191.609,
+            max_relative = 1e-3
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn water() -> EosResult<()> {
+        let record = RrhoRecord::new(
+            vec![27.877, 14.512, 9.285],
+            vec![1594.7, 3657.1, 3755.9],
+            2.0,
+            1.0,
+        );
+        let rrho = Arc::new(Rrho::new_pure(PureRecord::new(
+            Identifier::default(),
+            18.0153,
+            record,
+        ))?);
+        let eos = Arc::new(EquationOfState::ideal_gas(rrho));
+        let state = State::new_npt(
+            &eos,
+            298.15 * KELVIN,
+            BAR,
+            &(arr1(&[1.0]) * MOL),
+            DensityInitialization::Vapor,
+        )?;
+        // JANAF: s = 188.834 J/mol/K, c_p = 33.590 J/mol/K
+        assert_relative_eq!(
+            state.molar_entropy(Contributions::IdealGas),
+            188.834 * JOULE / MOL / KELVIN,
+            max_relative = 1e-3
+        );
+        // the rigid rotor contributes 3R to the heat capacity at high temperatures
+        assert_relative_eq!(
+            state.molar_isobaric_heat_capacity(Contributions::IdealGas),
+            4.0 * RGAS,
+            max_relative = 1e-2
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_rotational_constants() {
+        let record = RrhoRecord::new(vec![1.0, 2.0], vec![], 1.0, 1.0);
+        let record = PureRecord::new(Identifier::default(), 10.0, record);
+        assert!(Rrho::new_pure(record).is_err());
+    }
+}
@@ -46,14 +46,20 @@ pub mod association;
 pub mod hard_sphere;
 
 // models
+#[cfg(feature = "cpa")]
+pub mod cpa;
 #[cfg(feature = "epcsaft")]
 pub mod epcsaft;
 #[cfg(feature = "gc_pcsaft")]
 pub mod gc_pcsaft;
+#[cfg(feature = "multiparameter")]
+pub mod multiparameter;
 #[cfg(feature = "pcsaft")]
 pub mod pcsaft;
 #[cfg(feature = "pets")]
 pub mod pets;
+#[cfg(feature = "saftgammamie")]
+pub mod saftgammamie;
 #[cfg(feature = "saftvrmie")]
 pub mod saftvrmie;
 #[cfg(feature = "saftvrqmie")]
@@ -66,6 +72,9 @@ pub mod ideal_gas;
 #[cfg(feature = "python")]
 mod python;
 
+#[cfg(feature = "capi")]
+pub mod capi;
+
 pub mod core {
     //! Re-export of all functionalities in [feos_core].
     pub use feos_core::*;
@@ -0,0 +1,390 @@
+use super::parameters::{MultiparameterParameters, MultiparameterTerm};
+use feos_core::parameter::Parameter;
+use feos_core::{Components, Molarweight, Residual, StateHD};
+use ndarray::Array1;
+use num_dual::DualNum;
+use quantity::{MolarWeight, GRAM, MOL};
+use std::fmt;
+use std::sync::Arc;
+
+/// Configuration options for the multiparameter equation of state.
+#[derive(Copy, Clone)]
+pub struct MultiparameterOptions {
+    /// maximum reduced density $\delta=\rho/\rho_r$
+    pub max_delta: f64,
+}
+
+impl Default for MultiparameterOptions {
+    fn default() -> Self {
+        Self { max_delta: 4.0 }
+    }
+}
+
+/// Multiparameter equation of state in the form of Span and Wagner
+/// with the reducing and departure functions of GERG-2008 for mixtures.
+pub struct Multiparameter {
+    parameters: Arc<MultiparameterParameters>,
+    options: MultiparameterOptions,
+}
+
+impl Multiparameter {
+    /// Multiparameter equation of state with default options.
+    pub fn new(parameters: Arc<MultiparameterParameters>) -> Self {
+        Self::with_options(parameters, MultiparameterOptions::default())
+    }
+
+    /// Multiparameter equation of state with provided options.
+    pub fn with_options(
+        parameters: Arc<MultiparameterParameters>,
+        options: MultiparameterOptions,
+    ) -> Self {
+        Self {
+            parameters,
+            options,
+        }
+    }
+
+    /// Reducing density (in units of 1/Å³) and reducing temperature
+    /// (in units of K) of a mixture with composition `x`.
+    fn reducing_functions<D: DualNum<f64> + Copy>(&self, x: &Array1<D>) -> (D, D) {
+        let p = &self.parameters;
+        let mut v_r = D::zero();
+        let mut t_r = D::zero();
+        for i in 0..x.len() {
+            v_r += x[i] * x[i] / p.rhoc[i];
+            t_r += x[i] * x[i] * p.tc[i];
+            for j in i + 1..x.len() {
+                let x_ij = x[i] + x[j];
+                if x_ij.re() == 0.0 {
+                    continue;
+                }
+                let v_c = (p.rhoc[i].cbrt().recip() + p.rhoc[j].cbrt().recip()).powi(3) / 8.0;
+                let t_c = (p.tc[i] * p.tc[j]).sqrt();
+                let (beta_v, beta_t) = (p.beta_v[(i, j)], p.beta_t[(i, j)]);
+                v_r += x[i] * x[j] * x_ij / (x[i] * beta_v.powi(2) + x[j])
+                    * (2.0 * beta_v * p.gamma_v[(i, j)] * v_c);
+                t_r += x[i] * x[j] * x_ij / (x[i] * beta_t.powi(2) + x[j])
+                    * (2.0 * beta_t * p.gamma_t[(i, j)] * t_c);
+            }
+        }
+        (v_r.recip(), t_r)
+    }
+
+    /// Reduced density and inverse reduced temperature of a state.
+    fn reduced_variables<D: DualNum<f64> + Copy>(&self, state: &StateHD<D>) -> (D, D) {
+        let (rho_r, t_r) = self.reducing_functions(&state.molefracs);
+        (state.partial_density.sum() / rho_r, t_r / state.temperature)
+    }
+
+    fn pure_helmholtz_energy<D: DualNum<f64> + Copy>(&self, state: &StateHD<D>) -> D {
+        let p = &self.parameters;
+        let (delta, tau) = self.reduced_variables(state);
+        let x = &state.molefracs;
+        let alpha: D = (0..x.len())
+            .map(|i| {
+                p.terms[i]
+                    .iter()
+                    .map(|term| term.alpha(delta, tau))
+                    .sum::<D>()
+                    * x[i]
+            })
+            .sum();
+        alpha * state.moles.sum()
+    }
+
+    fn departure_helmholtz_energy<D: DualNum<f64> + Copy>(&self, state: &StateHD<D>) -> D {
+        let p = &self.parameters;
+        let (delta, tau) = self.reduced_variables(state);
+        let x = &state.molefracs;
+        let mut alpha = D::zero();
+        for i in 0..x.len() {
+            for j in i + 1..x.len() {
+                if p.f[(i, j)] == 0.0 {
+                    continue;
+                }
+                alpha += p.departure[(i, j)]
+                    .iter()
+                    .map(|term| term.alpha(delta, tau))
+                    .sum::<D>()
+                    * x[i]
+                    * x[j]
+                    * p.f[(i, j)];
+            }
+        }
+        alpha * state.moles.sum()
+    }
+
+    fn has_departure(&self) -> bool {
+        let p = &self.parameters;
+        p.f.indexed_iter()
+            .any(|((i, j), &f)| i < j && f != 0.0 && !p.departure[(i, j)].is_empty())
+    }
+}
+
+impl fmt::Display for Multiparameter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Multiparameter")
+    }
+}
+
+impl Components for Multiparameter {
+    fn components(&self) -> usize {
+        self.parameters.pure_records.len()
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        Self::with_options(
+            Arc::new(self.parameters.subset(component_list)),
+            self.options,
+        )
+    }
+}
+
+impl Residual for Multiparameter {
+    fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
+        let x = moles / moles.sum();
+        self.options.max_delta * self.reducing_functions(&x).0
+    }
+
+    fn residual_helmholtz_energy_contributions<D: DualNum<f64> + Copy>(
+        &self,
+        state: &StateHD<D>,
+    ) -> Vec<(String, D)> {
+        let mut v = Vec::with_capacity(2);
+        v.push(("Pure fluids".to_string(), self.pure_helmholtz_energy(state)));
+        if self.has_departure() {
+            v.push((
+                "Departure".to_string(),
+                self.departure_helmholtz_energy(state),
+            ));
+        }
+        v
+    }
+}
+
+impl Molarweight for Multiparameter {
+    fn molar_weight(&self) -> MolarWeight<Array1<f64>> {
+        self.parameters.molarweight.clone() * GRAM / MOL
+    }
+}
+
+impl MultiparameterTerm {
+    /// Contribution of the terms to the reduced residual Helmholtz energy.
+    fn alpha<D: DualNum<f64> + Copy>(&self, delta: D, tau: D) -> D {
+        let mut res = D::zero();
+        match self {
+            Self::Polynomial { n, d, t } => {
+                for k in 0..n.len() {
+                    res += delta.powi(d[k]) * tau.powf(t[k]) * n[k];
+                }
+            }
+            Self::Exponential { n, d, t, c } => {
+                for k in 0..n.len() {
+                    res += delta.powi(d[k]) * tau.powf(t[k]) * (-delta.powi(c[k])).exp() * n[k];
+                }
+            }
+            Self::Gaussian {
+                n,
+                d,
+                t,
+                eta,
+                beta,
+                gamma,
+                epsilon,
+            } => {
+                for k in 0..n.len() {
+                    let de = delta - epsilon[k];
+                    let tg = tau - gamma[k];
+                    res += delta.powi(d[k])
+                        * tau.powf(t[k])
+                        * (-de * de * eta[k] - tg * tg * beta[k]).exp()
+                        * n[k];
+                }
+            }
+            Self::GergExponential {
+                n,
+                d,
+                t,
+                eta,
+                beta,
+                gamma,
+                epsilon,
+            } => {
+                for k in 0..n.len() {
+                    let de = delta - epsilon[k];
+                    res += delta.powi(d[k])
+                        * tau.powf(t[k])
+                        * (-de * de * eta[k] - (delta - gamma[k]) * beta[k]).exp()
+                        * n[k];
+                }
+            }
+            Self::NonAnalytic {
+                n,
+                a,
+                b,
+                beta,
+                big_a,
+                big_b,
+                big_c,
+                big_d,
+            } => {
+                let dm1 = delta - 1.0;
+                let dm1sq = dm1 * dm1;
+                let tm1 = tau - 1.0;
+                for k in 0..n.len() {
+                    // the powers of (δ-1)² vanish (including their first
+                    // derivatives) at the critical density
+                    let big_delta = if dm1sq.re() == 0.0 {
+                        tm1 * tm1
+                    } else {
+                        let theta = -tm1 + dm1sq.powf(0.5 / beta[k]) * big_a[k];
+                        theta * theta + dm1sq.powf(a[k]) * big_b[k]
+                    };
+                    let psi = (-dm1sq * big_c[k] - tm1 * tm1 * big_d[k]).exp();
+                    res += big_delta.powf(b[k]) * delta * psi * n[k];
+                }
+            }
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::multiparameter::parameters::utils::{iapws95, polynomial_fluid};
+    use crate::multiparameter::MultiparameterBinaryRecord;
+    use approx::assert_relative_eq;
+    use num_dual::{first_derivative, Dual64};
+
+    const NAV: f64 = 6.02214076e23;
+
+    fn water_state(temperature: f64, density: f64) -> StateHD<f64> {
+        // density in kg/m³
+        let rho = density / 18.015268e-3 * NAV * 1e-30;
+        StateHD::new(temperature, 1.0 / rho, ndarray::arr1(&[1.0]))
+    }
+
+    #[test]
+    fn iapws95_residual_helmholtz_energy() {
+        // IAPWS-95, Table 6
+        let eos = Multiparameter::new(iapws95());
+        let state = water_state(500.0, 838.025);
+        let phi_r = eos.residual_helmholtz_energy(&state);
+        assert_relative_eq!(phi_r, -0.342693206e1, max_relative = 1e-8);
+
+        let state = water_state(647.0, 358.0);
+        let phi_r = eos.residual_helmholtz_energy(&state);
+        assert_relative_eq!(phi_r, -0.121202657e1, max_relative = 1e-8);
+    }
+
+    #[test]
+    fn iapws95_derivatives() {
+        // IAPWS-95, Table 6
+        let eos = Multiparameter::new(iapws95());
+        let rhoc = eos.parameters.rhoc[0];
+        let tc = eos.parameters.tc[0];
+        let (delta, tau) = (838.025 / 322.0, 647.096 / 500.0);
+        let (_, phi_r_delta) = first_derivative(
+            |delta: Dual64| {
+                let s = StateHD::new(
+                    Dual64::from(tc / tau),
+                    (delta * rhoc).recip(),
+                    ndarray::arr1(&[Dual64::from(1.0)]),
+                );
+                eos.residual_helmholtz_energy(&s)
+            },
+            delta,
+        );
+        let (_, phi_r_tau) = first_derivative(
+            |tau: Dual64| {
+                let s = StateHD::new(
+                    tau.recip() * tc,
+                    Dual64::from(1.0 / (delta * rhoc)),
+                    ndarray::arr1(&[Dual64::from(1.0)]),
+                );
+                eos.residual_helmholtz_energy(&s)
+            },
+            tau,
+        );
+        assert_relative_eq!(phi_r_delta, -0.364366650, max_relative = 1e-8);
+        assert_relative_eq!(phi_r_tau, -0.581403435e1, max_relative = 1e-8);
+    }
+
+    #[test]
+    fn critical_density() {
+        let eos = Multiparameter::new(iapws95());
+        let rhoc = eos.parameters.rhoc[0];
+        let state = StateHD::new(600.0, 1.0 / rhoc, ndarray::arr1(&[1.0]));
+        assert!(eos.residual_helmholtz_energy(&state).is_finite());
+    }
+
+    #[test]
+    fn departure_function() {
+        let binary = serde_json::from_str::<MultiparameterBinaryRecord>(
+            r#"{
+                "beta_v": 1.1,
+                "gamma_v": 0.9,
+                "beta_t": 0.95,
+                "gamma_t": 1.05,
+                "f": 0.5,
+                "departure": [{"type": "polynomial", "n": [0.2], "d": [1], "t": [1.5]}]
+            }"#,
+        )
+        .unwrap();
+        let params = MultiparameterParameters::new_binary(
+            vec![
+                polynomial_fluid(300.0, 10000.0),
+                polynomial_fluid(400.0, 8000.0),
+            ],
+            Some(binary.clone()),
+        )
+        .unwrap();
+        let eos = Multiparameter::new(Arc::new(params));
+        let x = ndarray::arr1(&[0.3, 0.7]);
+        let state = StateHD::new(350.0, 1e5, x.clone());
+        let (delta, tau) = eos.reduced_variables(&state);
+        let a = eos.residual_helmholtz_energy_contributions(&state);
+        assert_relative_eq!(
+            a[1].1,
+            0.3 * 0.7 * 0.5 * 0.2 * delta * tau.powf(1.5),
+            max_relative = 1e-14
+        );
+
+        // the asymmetric reducing functions are independent of the order of the components
+        let params = MultiparameterParameters::new_binary(
+            vec![
+                polynomial_fluid(400.0, 8000.0),
+                polynomial_fluid(300.0, 10000.0),
+            ],
+            Some(binary.transposed()),
+        )
+        .unwrap();
+        let eos_rev = Multiparameter::new(Arc::new(params));
+        let state_rev = StateHD::new(350.0, 1e5, ndarray::arr1(&[0.7, 0.3]));
+        let (delta_rev, tau_rev) = eos_rev.reduced_variables(&state_rev);
+        assert_relative_eq!(delta, delta_rev, max_relative = 1e-14);
+        assert_relative_eq!(tau, tau_rev, max_relative = 1e-14);
+        assert_relative_eq!(
+            eos.residual_helmholtz_energy(&state),
+            eos_rev.residual_helmholtz_energy(&state_rev),
+            max_relative = 1e-14
+        );
+    }
+
+    #[test]
+    fn reducing_functions_pure_limit() {
+        let params = MultiparameterParameters::new_binary(
+            vec![
+                polynomial_fluid(300.0, 10000.0),
+                polynomial_fluid(400.0, 8000.0),
+            ],
+            None,
+        )
+        .unwrap();
+        let eos = Multiparameter::new(Arc::new(params));
+        let (rho_r, t_r) = eos.reducing_functions(&ndarray::arr1(&[1.0, 0.0]));
+        assert_relative_eq!(rho_r, eos.parameters.rhoc[0], max_relative = 1e-14);
+        assert_relative_eq!(t_r, 300.0, max_relative = 1e-14);
+    }
+}
@@ -0,0 +1,14 @@
+//! Multiparameter reference equations of state
+//!
+//! Empirical residual Helmholtz energies in the form of
+//! [Span and Wagner (2003)](https://doi.org/10.1023/A:1022310214958)
+//! as used, e.g., in [IAPWS-95](https://doi.org/10.1063/1.1461829). Mixtures
+//! are described with the reducing and departure functions of
+//! [GERG-2008](https://doi.org/10.1021/je300655b).
+mod eos;
+mod parameters;
+
+pub use eos::{Multiparameter, MultiparameterOptions};
+pub use parameters::{
+    MultiparameterBinaryRecord, MultiparameterParameters, MultiparameterRecord, MultiparameterTerm,
+};
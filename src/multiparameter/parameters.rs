@@ -0,0 +1,428 @@
+use feos_core::parameter::{
+    BinaryRecord, Identifier, IdentifierOption, Parameter, ParameterError, PureRecord,
+};
+use ndarray::{Array, Array1, Array2};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Avogadro's number in 1/mol.
+const NAV: f64 = 6.02214076e23;
+
+/// A group of terms of an empirical residual Helmholtz energy in
+/// reduced density $\delta$ and inverse reduced temperature $\tau$.
+///
+/// Each group stores one coefficient vector per parameter, in the
+/// same way as the coefficient tables of the original publications.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MultiparameterTerm {
+    /// $n\delta^d\tau^t$
+    Polynomial {
+        n: Vec<f64>,
+        d: Vec<i32>,
+        t: Vec<f64>,
+    },
+    /// $n\delta^d\tau^t\exp\left(-\delta^c\right)$
+    Exponential {
+        n: Vec<f64>,
+        d: Vec<i32>,
+        t: Vec<f64>,
+        c: Vec<i32>,
+    },
+    /// $n\delta^d\tau^t\exp\left(-\eta(\delta-\varepsilon)^2-\beta(\tau-\gamma)^2\right)$
+    Gaussian {
+        n: Vec<f64>,
+        d: Vec<i32>,
+        t: Vec<f64>,
+        eta: Vec<f64>,
+        beta: Vec<f64>,
+        gamma: Vec<f64>,
+        epsilon: Vec<f64>,
+    },
+    /// $n\delta^d\tau^t\exp\left(-\eta(\delta-\varepsilon)^2-\beta(\delta-\gamma)\right)$
+    /// as used in the departure functions of GERG-2008.
+    GergExponential {
+        n: Vec<f64>,
+        d: Vec<i32>,
+        t: Vec<f64>,
+        eta: Vec<f64>,
+        beta: Vec<f64>,
+        gamma: Vec<f64>,
+        epsilon: Vec<f64>,
+    },
+    /// $n\Delta^b\delta\psi$ with $\Delta=\theta^2+B\left[(\delta-1)^2\right]^a$,
+    /// $\theta=(1-\tau)+A\left[(\delta-1)^2\right]^\frac{1}{2\beta}$ and
+    /// $\psi=\exp\left(-C(\delta-1)^2-D(\tau-1)^2\right)$ as used in IAPWS-95.
+    NonAnalytic {
+        n: Vec<f64>,
+        a: Vec<f64>,
+        b: Vec<f64>,
+        beta: Vec<f64>,
+        #[serde(rename = "A")]
+        big_a: Vec<f64>,
+        #[serde(rename = "B")]
+        big_b: Vec<f64>,
+        #[serde(rename = "C")]
+        big_c: Vec<f64>,
+        #[serde(rename = "D")]
+        big_d: Vec<f64>,
+    },
+}
+
+impl MultiparameterTerm {
+    fn len(&self) -> usize {
+        match self {
+            Self::Polynomial { n, .. }
+            | Self::Exponential { n, .. }
+            | Self::Gaussian { n, .. }
+            | Self::GergExponential { n, .. }
+            | Self::NonAnalytic { n, .. } => n.len(),
+        }
+    }
+
+    fn check(&self) -> Result<(), ParameterError> {
+        let l = self.len();
+        let lengths = match self {
+            Self::Polynomial { d, t, .. } => vec![d.len(), t.len()],
+            Self::Exponential { d, t, c, .. } => vec![d.len(), t.len(), c.len()],
+            Self::Gaussian {
+                d,
+                t,
+                eta,
+                beta,
+                gamma,
+                epsilon,
+                ..
+            }
+            | Self::GergExponential {
+                d,
+                t,
+                eta,
+                beta,
+                gamma,
+                epsilon,
+                ..
+            } => vec![
+                d.len(),
+                t.len(),
+                eta.len(),
+                beta.len(),
+                gamma.len(),
+                epsilon.len(),
+            ],
+            Self::NonAnalytic {
+                a,
+                b,
+                beta,
+                big_a,
+                big_b,
+                big_c,
+                big_d,
+                ..
+            } => vec![
+                a.len(),
+                b.len(),
+                beta.len(),
+                big_a.len(),
+                big_b.len(),
+                big_c.len(),
+                big_d.len(),
+            ],
+        };
+        if lengths.iter().any(|&k| k != l) {
+            return Err(ParameterError::IncompatibleParameters(format!(
+                "All coefficient vectors of a multiparameter term must have length {l}."
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Pure-component parameters of a multiparameter equation of state.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct MultiparameterRecord {
+    /// Critical (or reducing) temperature in units of Kelvin
+    pub tc: f64,
+    /// Critical (or reducing) density in units of mol/m³
+    pub rhoc: f64,
+    /// Terms of the residual Helmholtz energy
+    pub terms: Vec<MultiparameterTerm>,
+}
+
+impl std::fmt::Display for MultiparameterRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MultiparameterRecord(tc={}", self.tc)?;
+        write!(f, ", rhoc={}", self.rhoc)?;
+        let n: usize = self.terms.iter().map(|t| t.len()).sum();
+        write!(f, ", terms={n})")
+    }
+}
+
+/// Binary parameters of the GERG-2008 reducing and departure functions.
+///
+/// The parameters $\beta_v$ and $\beta_T$ are asymmetric. They are
+/// defined for the order of the components given in the binary record.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct MultiparameterBinaryRecord {
+    /// Asymmetry parameter of the reducing density
+    #[serde(default = "one")]
+    pub beta_v: f64,
+    /// Interaction parameter of the reducing density
+    #[serde(default = "one")]
+    pub gamma_v: f64,
+    /// Asymmetry parameter of the reducing temperature
+    #[serde(default = "one")]
+    pub beta_t: f64,
+    /// Interaction parameter of the reducing temperature
+    #[serde(default = "one")]
+    pub gamma_t: f64,
+    /// Weighting factor of the departure function
+    #[serde(default)]
+    pub f: f64,
+    /// Terms of the departure function
+    #[serde(default)]
+    pub departure: Vec<MultiparameterTerm>,
+}
+
+fn one() -> f64 {
+    1.0
+}
+
+impl Default for MultiparameterBinaryRecord {
+    fn default() -> Self {
+        Self {
+            beta_v: 1.0,
+            gamma_v: 1.0,
+            beta_t: 1.0,
+            gamma_t: 1.0,
+            f: 0.0,
+            departure: Vec::new(),
+        }
+    }
+}
+
+impl MultiparameterBinaryRecord {
+    /// Binary record for the reversed order of the components.
+    pub fn transposed(&self) -> Self {
+        Self {
+            beta_v: self.beta_v.recip(),
+            beta_t: self.beta_t.recip(),
+            ..self.clone()
+        }
+    }
+}
+
+impl std::fmt::Display for MultiparameterBinaryRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "MultiparameterBinaryRecord(beta_v={}, gamma_v={}, beta_t={}, gamma_t={}",
+            self.beta_v, self.gamma_v, self.beta_t, self.gamma_t
+        )?;
+        if !self.departure.is_empty() {
+            write!(f, ", f={}", self.f)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Parameter set required for the multiparameter equation of state.
+///
+/// Internally, the critical densities are stored in units of 1/Å³.
+/// The equations are evaluated with the gas constant of `feos` instead
+/// of the one used in the original publications. Phase equilibrium
+/// densities are therefore not affected, while pressures and energies
+/// differ by the ratio of the gas constants (typically about 1e-5).
+pub struct MultiparameterParameters {
+    pub molarweight: Array1<f64>,
+    pub tc: Array1<f64>,
+    pub rhoc: Array1<f64>,
+    pub terms: Vec<Vec<MultiparameterTerm>>,
+    pub beta_v: Array2<f64>,
+    pub gamma_v: Array2<f64>,
+    pub beta_t: Array2<f64>,
+    pub gamma_t: Array2<f64>,
+    pub f: Array2<f64>,
+    pub departure: Array2<Vec<MultiparameterTerm>>,
+    pub pure_records: Vec<PureRecord<MultiparameterRecord>>,
+    pub binary_records: Option<Array2<MultiparameterBinaryRecord>>,
+}
+
+impl Parameter for MultiparameterParameters {
+    type Pure = MultiparameterRecord;
+    type Binary = MultiparameterBinaryRecord;
+
+    /// Creates parameters from records for pure substances and binary parameters.
+    ///
+    /// Only the upper triangle (`i < j`) of the binary records is used, because
+    /// the reducing functions are asymmetric.
+    fn from_records(
+        pure_records: Vec<PureRecord<Self::Pure>>,
+        binary_records: Option<Array2<Self::Binary>>,
+    ) -> Result<Self, ParameterError> {
+        let n = pure_records.len();
+
+        let mut molarweight = Array::zeros(n);
+        let mut tc = Array::zeros(n);
+        let mut rhoc = Array::zeros(n);
+        let mut terms = Vec::with_capacity(n);
+
+        for (i, record) in pure_records.iter().enumerate() {
+            let r = &record.model_record;
+            molarweight[i] = record.molarweight;
+            tc[i] = r.tc;
+            rhoc[i] = r.rhoc * NAV * 1e-30;
+            for term in r.terms.iter() {
+                term.check()?;
+            }
+            terms.push(r.terms.clone());
+        }
+
+        let binary = Array2::from_shape_fn([n, n], |(i, j)| match &binary_records {
+            Some(br) if i < j => br[(i, j)].clone(),
+            Some(br) if i > j => br[(j, i)].transposed(),
+            _ => MultiparameterBinaryRecord::default(),
+        });
+        for br in binary.iter() {
+            for term in br.departure.iter() {
+                term.check()?;
+            }
+        }
+
+        Ok(Self {
+            molarweight,
+            tc,
+            rhoc,
+            terms,
+            beta_v: binary.map(|br| br.beta_v),
+            gamma_v: binary.map(|br| br.gamma_v),
+            beta_t: binary.map(|br| br.beta_t),
+            gamma_t: binary.map(|br| br.gamma_t),
+            f: binary.map(|br| br.f),
+            departure: binary.map(|br| br.departure.clone()),
+            pure_records,
+            binary_records,
+        })
+    }
+
+    fn records(
+        &self,
+    ) -> (
+        &[PureRecord<MultiparameterRecord>],
+        Option<&Array2<MultiparameterBinaryRecord>>,
+    ) {
+        (&self.pure_records, self.binary_records.as_ref())
+    }
+
+    /// Builds the matrix of binary records while keeping track of the
+    /// order of the components in the binary records.
+    fn binary_matrix_from_records(
+        pure_records: &[PureRecord<Self::Pure>],
+        binary_records: &[BinaryRecord<Identifier, Self::Binary>],
+        identifier_option: IdentifierOption,
+    ) -> Result<Option<Array2<Self::Binary>>, ParameterError> {
+        if binary_records.is_empty() {
+            return Ok(None);
+        }
+
+        let binary_map: HashMap<(String, String), Self::Binary> = binary_records
+            .iter()
+            .filter_map(|br| {
+                let id1 = br.id1.as_string(identifier_option);
+                let id2 = br.id2.as_string(identifier_option);
+                id1.and_then(|id1| id2.map(|id2| ((id1, id2), br.model_record.clone())))
+            })
+            .collect();
+        let ids = pure_records
+            .iter()
+            .enumerate()
+            .map(|(i, pr)| {
+                pr.identifier.as_string(identifier_option).ok_or_else(|| {
+                    ParameterError::IncompatibleParameters(format!(
+                        "No identifier for given identifier_option for pure record {i}."
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let n = pure_records.len();
+        Ok(Some(Array2::from_shape_fn([n, n], |(i, j)| {
+            let (id1, id2) = (ids[i].clone(), ids[j].clone());
+            binary_map
+                .get(&(id1.clone(), id2.clone()))
+                .cloned()
+                .or_else(|| binary_map.get(&(id2, id1)).map(|br| br.transposed()))
+                .unwrap_or_default()
+        })))
+    }
+}
+
+impl std::fmt::Display for MultiparameterParameters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MultiparameterParameters(")?;
+        write!(f, "\n\tmolarweight={}", self.molarweight)?;
+        write!(f, "\n\ttc={}", self.tc)?;
+        write!(f, "\n\trhoc={}", self.rhoc)?;
+        write!(f, "\n\tbeta_v=\n{}", self.beta_v)?;
+        write!(f, "\n\tgamma_v=\n{}", self.gamma_v)?;
+        write!(f, "\n\tbeta_t=\n{}", self.beta_t)?;
+        write!(f, "\n\tgamma_t=\n{}", self.gamma_t)?;
+        write!(f, "\n\tf=\n{}", self.f)?;
+        write!(f, "\n)")
+    }
+}
+
+#[cfg(test)]
+pub mod utils {
+    use super::*;
+    use std::sync::Arc;
+
+    pub fn iapws95() -> Arc<MultiparameterParameters> {
+        Arc::new(
+            MultiparameterParameters::from_json(
+                vec!["water"],
+                "parameters/multiparameter/wagner2002.json",
+                None,
+                IdentifierOption::Name,
+            )
+            .unwrap(),
+        )
+    }
+
+    pub fn polynomial_fluid(tc: f64, rhoc: f64) -> PureRecord<MultiparameterRecord> {
+        let record = MultiparameterRecord {
+            tc,
+            rhoc,
+            terms: vec![MultiparameterTerm::Polynomial {
+                n: vec![0.5, -1.5],
+                d: vec![1, 1],
+                t: vec![0.0, 1.0],
+            }],
+        };
+        PureRecord::new(Identifier::default(), 16.0, record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::utils::polynomial_fluid;
+    use super::*;
+
+    #[test]
+    fn binary_matrix_missing_identifier() {
+        let pure_records = vec![
+            polynomial_fluid(300.0, 10000.0),
+            polynomial_fluid(400.0, 8000.0),
+        ];
+        let binary_records = vec![BinaryRecord::new(
+            Identifier::new(None, Some("a"), None, None, None, None),
+            Identifier::new(None, Some("b"), None, None, None, None),
+            MultiparameterBinaryRecord::default(),
+        )];
+        assert!(MultiparameterParameters::binary_matrix_from_records(
+            &pure_records,
+            &binary_records,
+            IdentifierOption::Name,
+        )
+        .is_err());
+    }
+}
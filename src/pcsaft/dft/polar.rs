@@ -1,7 +1,8 @@
 use super::PcSaftParameters;
 use crate::hard_sphere::HardSphereProperties;
 use crate::pcsaft::eos::polar::{
-    MeanSegmentNumbers, Multipole, AD, ADQ, ALPHA, AQ, BD, BDQ, BQ, CD, CDQ, CQ, PI_SQ_43,
+    mean_segment_numbers_ij, MeanSegmentNumbers, Multipole, AD, ADQ, ALPHA, AQ, BD, BDQ, BQ, CD,
+    CDQ, CQ, PI_SQ_43,
 };
 use feos_core::EosError;
 use ndarray::*;
@@ -170,6 +171,18 @@ fn phi_polar_dipole<N: DualNum<f64> + Copy + ScalarOperand>(
             }
         }
     }
+
+    // induction
+    for i in 0..p.ndipole {
+        let di = p.dipole_comp[i];
+        for &j in p.polarizable_comp.iter() {
+            let (mij1, mij2) = mean_segment_numbers_ij(p.m[di], p.m[j]);
+            phi2 -= &(&density.index_axis(Axis(0), di)
+                * &density.index_axis(Axis(0), j)
+                * pair_integral_ij(mij1, mij2, eta, &AD, &BD, eps_ij_t[[di, j]])
+                * (mu2_term[i] * (6.0 * p.alpha[j] / p.m[j]) / sig_ij_3[[di, j]]));
+        }
+    }
     phi2 = phi2 * PI;
     phi3 = phi3 * PI_SQ_43;
     let mut result = &phi2 * &phi2 / (&phi2 - &phi3);
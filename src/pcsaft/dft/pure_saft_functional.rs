@@ -279,7 +279,7 @@ impl FunctionalContribution for PureAttFunctional {
 
             let phi2 = -(&rho * &rho)
                 * pair_integral_ij(m1, m2, &eta, &AD, &BD, e)
-                * (mu2_term * mu2_term / s3 * PI);
+                * ((mu2_term * mu2_term + mu2_term * (6.0 * p.alpha[0] / p.m[0])) / s3 * PI);
             let phi3 = -(&rho * &rho * rho)
                 * triplet_integral_ijk(m1, m2, &eta, &CD)
                 * (mu2_term * mu2_term * mu2_term / s3 * PI_SQ_43);
@@ -6,7 +6,11 @@ use feos_core::{
     Components, EntropyScaling, EosError, EosResult, Molarweight, ReferenceSystem, Residual, State,
     StateHD,
 };
+#[cfg(feature = "dft")]
+use feos_dft::solvation::PairPotential;
 use ndarray::Array1;
+#[cfg(feature = "dft")]
+use ndarray::Array2;
 use num_dual::DualNum;
 use quantity::*;
 use std::f64::consts::{FRAC_PI_6, PI};
@@ -189,6 +193,18 @@ impl Molarweight for PcSaft {
     }
 }
 
+#[cfg(feature = "dft")]
+impl PairPotential for PcSaft {
+    fn pair_potential(&self, i: usize, r: &Array1<f64>, _: f64) -> Array2<f64> {
+        let sigma_ij = &self.parameters.sigma_ij;
+        let eps_ij_4 = 4.0 * &self.parameters.epsilon_k_ij;
+        Array2::from_shape_fn((self.parameters.m.len(), r.len()), |(j, k)| {
+            let att = (sigma_ij[[i, j]] / r[k]).powi(6);
+            eps_ij_4[[i, j]] * att * (att - 1.0)
+        })
+    }
+}
+
 impl fmt::Display for PcSaft {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "PC-SAFT")
@@ -580,4 +596,45 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn maxwell_stefan_fick_diffusion() -> EosResult<()> {
+        let e = Arc::new(PcSaft::new(propane_butane_parameters()));
+        let t = 300.0 * KELVIN;
+        let p = BAR;
+        let n = arr1(&[0.3, 0.7]) * MOL;
+        let s = State::new_npt(&e, t, p, &n, DensityInitialization::Vapor)?;
+
+        // the generalized Darken relation reduces to the binary Darken relation
+        let d = s.tracer_diffusion()?;
+        let d_ms = s.maxwell_stefan_diffusion(DiffusionMixingRule::Darken)?;
+        assert_relative_eq!(
+            d_ms.get((0, 1)),
+            d.get(0) * 0.7 + d.get(1) * 0.3,
+            max_relative = 1e-12
+        );
+
+        // binary Fick diffusion coefficient: D = Đ Γ
+        let d_ms = s.maxwell_stefan_diffusion(DiffusionMixingRule::Vignes)?;
+        let d_fick = s.fick_diffusion(DiffusionMixingRule::Vignes)?;
+        assert_relative_eq!(d_ms.get((0, 1)), d_ms.get((1, 0)), max_relative = 1e-12);
+        assert_relative_eq!(
+            d_fick.get((0, 0)),
+            d_ms.get((0, 1)) * s.thermodynamic_factor()[[0, 0]],
+            max_relative = 1e-12
+        );
+
+        // the self-diffusion of the pure solvents is recovered at infinite dilution
+        let d_inf = s.infinite_dilution_diffusion()?;
+        let propane = Arc::new(PcSaft::new(propane_parameters()));
+        let pure = State::new_npt(
+            &propane,
+            t,
+            p,
+            &(arr1(&[1.0]) * MOL),
+            DensityInitialization::Vapor,
+        )?;
+        assert_relative_eq!(d_inf.get((0, 0)), pure.diffusion()?, max_relative = 1e-8);
+        Ok(())
+    }
 }
@@ -126,6 +126,16 @@ impl MeanSegmentNumbers {
     }
 }
 
+/// Mean segment number terms of the pair integral of two components.
+///
+/// Used for the induction contributions, in which one partner
+/// is only polarizable and not necessarily polar.
+pub fn mean_segment_numbers_ij(mi: f64, mj: f64) -> (f64, f64) {
+    let mij = (mi.min(2.0) * mj.min(2.0)).sqrt();
+    let mij1 = (mij - 1.0) / mij;
+    (mij1, mij1 * (mij - 2.0) / mij)
+}
+
 fn pair_integral_ij<D: DualNum<f64> + Copy>(
     mij1: f64,
     mij2: f64,
@@ -226,6 +236,21 @@ impl Dipole {
                 }
             }
         }
+
+        // induction: the squared dipole moments of each pair are replaced by
+        // mu_i^2 mu_j^2 + 3kT (alpha_j mu_i^2 + alpha_i mu_j^2)
+        for i in 0..p.ndipole {
+            let di = p.dipole_comp[i];
+            for &j in p.polarizable_comp.iter() {
+                let (mij1, mij2) = mean_segment_numbers_ij(p.m[di], p.m[j]);
+                phi2 -= rho[di]
+                    * rho[j]
+                    * mu2_term[i]
+                    * (6.0 * p.alpha[j] / p.m[j])
+                    * pair_integral_ij(mij1, mij2, &etas, &AD, &BD, eps_ij_t[[di, j]])
+                    / sig_ij_3[[di, j]];
+            }
+        }
         phi2 *= PI;
         phi3 *= PI_SQ_43;
         let mut result = phi2 * phi2 / (phi2 - phi3) * state.volume;
@@ -497,6 +522,35 @@ mod tests {
         assert_relative_eq!(a, -1.4126308106201688, epsilon = 1e-10);
     }
 
+    #[test]
+    fn test_induced_dipole_contribution() {
+        let dme = dme_parameters();
+        let mut record = dme.pure_records[0].clone();
+        record.model_record.alpha = Some(5.24);
+        let polarizable = Dipole {
+            parameters: Arc::new(PcSaftParameters::new_pure(record).unwrap()),
+        };
+        let dp = Dipole {
+            parameters: Arc::new(dme),
+        };
+
+        // in the low-density limit, the ratio of the Keesom and Debye contributions
+        // is 6 kT alpha / mu^2
+        let t = 350.0;
+        let s = StateHD::new(t, 1e9, arr1(&[1.0]));
+        let d = dp.parameters.hs_diameter(t);
+        let mu2 = 1.3f64.powi(2) * 1e-19 / 1.380649e-23;
+        assert_relative_eq!(
+            polarizable.helmholtz_energy(&s, &d) / dp.helmholtz_energy(&s, &d),
+            1.0 + 6.0 * t * 5.24 / mu2,
+            max_relative = 1e-6
+        );
+
+        // dense state
+        let s = StateHD::new(t, 1000.0, arr1(&[1.0]));
+        assert!(polarizable.helmholtz_energy(&s, &d) < dp.helmholtz_energy(&s, &d));
+    }
+
     #[test]
     fn test_quadrupolar_contribution() {
         let qp = Quadrupole {
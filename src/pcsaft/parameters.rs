@@ -1,5 +1,6 @@
 use crate::association::{
-    AssociationParameters, AssociationRecord, AssociationStrength, BinaryAssociationRecord,
+    AssociationParameters, AssociationRecord, AssociationSiteRecord, AssociationStrength,
+    BinaryAssociationRecord,
 };
 use crate::hard_sphere::{HardSphereProperties, MonomerShape};
 use conv::ValueInto;
@@ -30,6 +31,9 @@ pub struct PcSaftRecord {
     /// Quadrupole moment in units of Debye * Angstrom
     #[serde(skip_serializing_if = "Option::is_none")]
     pub q: Option<f64>,
+    /// Polarizability (volume) in units of Angstrom^3
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alpha: Option<f64>,
     /// Association parameters
     #[serde(flatten)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -65,7 +69,11 @@ impl FromSegments<f64> for PcSaftRecord {
             .iter()
             .filter_map(|(s, n)| s.mu.map(|mu| mu * n))
             .reduce(|a, b| a + b);
-        let association_record = segments
+        let alpha = segments
+            .iter()
+            .filter_map(|(s, n)| s.alpha.map(|alpha| alpha * n))
+            .reduce(|a, b| a + b);
+        let mut association_record = segments
             .iter()
             .filter_map(|(s, n)| {
                 s.association_record.as_ref().map(|record| {
@@ -95,6 +103,18 @@ impl FromSegments<f64> for PcSaftRecord {
                     nc,
                 )
             });
+        if let Some(record) = association_record.as_mut() {
+            record.sites = segments
+                .iter()
+                .filter_map(|(s, n)| s.association_record.as_ref().map(|r| (&r.sites, n)))
+                .flat_map(|(sites, &n)| {
+                    sites.iter().map(move |site| AssociationSiteRecord {
+                        n: site.n * n,
+                        ..site.clone()
+                    })
+                })
+                .collect();
+        }
 
         // entropy scaling
         let mut viscosity = if segments
@@ -156,6 +176,7 @@ impl FromSegments<f64> for PcSaftRecord {
             epsilon_k: epsilon_k / m,
             mu,
             q,
+            alpha,
             association_record,
             viscosity,
             diffusion,
@@ -173,7 +194,8 @@ impl FromSegments<usize> for PcSaftRecord {
                 if s.q.is_some()
                     || s.mu.is_some()
                     || s.association_record
-                        .is_some_and(|r| r.na + r.nb + r.nc > 0.0)
+                        .as_ref()
+                        .is_some_and(|r| r.n_sites() > 0.0)
                 {
                     Some(n)
                 } else {
@@ -187,7 +209,8 @@ impl FromSegments<usize> for PcSaftRecord {
             .iter()
             .filter_map(|(s, n)| {
                 s.association_record
-                    .map(|r| (r.na * r.nb + r.nc) as usize * n)
+                    .as_ref()
+                    .and_then(|r| (r.n_sites() > 0.0).then_some(n))
             })
             .sum();
         if polar_segments > 1 {
@@ -215,6 +238,9 @@ impl std::fmt::Display for PcSaftRecord {
         if let Some(n) = &self.q {
             write!(f, ", q={}", n)?;
         }
+        if let Some(n) = &self.alpha {
+            write!(f, ", alpha={}", n)?;
+        }
         if let Some(n) = &self.association_record {
             write!(f, ", association_record={}", n)?;
         }
@@ -247,6 +273,7 @@ impl PcSaftRecord {
         viscosity: Option<[f64; 4]>,
         diffusion: Option<[f64; 5]>,
         thermal_conductivity: Option<[f64; 4]>,
+        alpha: Option<f64>,
     ) -> PcSaftRecord {
         let association_record = Some(AssociationRecord::new(
             PcSaftAssociationRecord::new(kappa_ab, epsilon_k_ab),
@@ -260,6 +287,7 @@ impl PcSaftRecord {
             epsilon_k,
             mu,
             q,
+            alpha,
             association_record,
             viscosity,
             diffusion,
@@ -383,6 +411,7 @@ pub struct PcSaftParameters {
     pub q: Array1<f64>,
     pub mu2: Array1<f64>,
     pub q2: Array1<f64>,
+    pub alpha: Array1<f64>,
     pub association: Arc<AssociationParameters<Self>>,
     pub sigma_ij: Array2<f64>,
     pub epsilon_k_ij: Array2<f64>,
@@ -391,6 +420,7 @@ pub struct PcSaftParameters {
     pub nquadpole: usize,
     pub dipole_comp: Array1<usize>,
     pub quadpole_comp: Array1<usize>,
+    pub polarizable_comp: Array1<usize>,
     pub viscosity: Option<Array2<f64>>,
     pub diffusion: Option<Array2<f64>>,
     pub thermal_conductivity: Option<Array2<f64>>,
@@ -414,6 +444,7 @@ impl Parameter for PcSaftParameters {
         let mut epsilon_k = Array::zeros(n);
         let mut mu = Array::zeros(n);
         let mut q = Array::zeros(n);
+        let mut alpha = Array::zeros(n);
         let mut association_records = Vec::with_capacity(n);
         let mut viscosity = Vec::with_capacity(n);
         let mut diffusion = Vec::with_capacity(n);
@@ -429,7 +460,8 @@ impl Parameter for PcSaftParameters {
             epsilon_k[i] = r.epsilon_k;
             mu[i] = r.mu.unwrap_or(0.0);
             q[i] = r.q.unwrap_or(0.0);
-            association_records.push(r.association_record.into_iter().collect());
+            alpha[i] = r.alpha.unwrap_or(0.0);
+            association_records.push(r.association_record.clone().into_iter().collect());
             viscosity.push(r.viscosity);
             diffusion.push(r.diffusion);
             thermal_conductivity.push(r.thermal_conductivity);
@@ -454,6 +486,11 @@ impl Parameter for PcSaftParameters {
             .filter_map(|(i, &q2)| (q2.abs() > 0.0).then_some(i))
             .collect();
         let nquadpole = quadpole_comp.len();
+        let polarizable_comp: Array1<usize> = alpha
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &alpha)| (alpha > 0.0).then_some(i))
+            .collect();
 
         let binary_association: Vec<_> = binary_records
             .iter()
@@ -519,6 +556,7 @@ impl Parameter for PcSaftParameters {
             q,
             mu2,
             q2,
+            alpha,
             association: Arc::new(association),
             sigma_ij,
             epsilon_k_ij,
@@ -527,6 +565,7 @@ impl Parameter for PcSaftParameters {
             nquadpole,
             dipole_comp,
             quadpole_comp,
+            polarizable_comp,
             viscosity: viscosity_coefficients,
             diffusion: diffusion_coefficients,
             thermal_conductivity: thermal_conductivity_coefficients,
@@ -615,18 +654,22 @@ impl PcSaftParameters {
         let o = &mut output;
         write!(
             o,
-            "|component|molarweight|$m$|$\\sigma$|$\\varepsilon$|$\\mu$|$Q$|$\\kappa_{{AB}}$|$\\varepsilon_{{AB}}$|$N_A$|$N_B$|$N_C$|\n|-|-|-|-|-|-|-|-|-|-|-|-|"
+            "|component|molarweight|$m$|$\\sigma$|$\\varepsilon$|$\\mu$|$Q$|$\\alpha$|$\\kappa_{{AB}}$|$\\varepsilon_{{AB}}$|$N_A$|$N_B$|$N_C$|\n|-|-|-|-|-|-|-|-|-|-|-|-|-|"
         )
         .unwrap();
         for (i, record) in self.pure_records.iter().enumerate() {
             let component = record.identifier.name.clone();
             let component = component.unwrap_or(format!("Component {}", i + 1));
-            let association = record.model_record.association_record.unwrap_or_else(|| {
-                AssociationRecord::new(PcSaftAssociationRecord::new(None, None), 0.0, 0.0, 0.0)
-            });
+            let association = record
+                .model_record
+                .association_record
+                .clone()
+                .unwrap_or_else(|| {
+                    AssociationRecord::new(PcSaftAssociationRecord::new(None, None), 0.0, 0.0, 0.0)
+                });
             write!(
                 o,
-                "\n|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|",
+                "\n|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|",
                 component,
                 record.molarweight,
                 record.model_record.m,
@@ -634,6 +677,7 @@ impl PcSaftParameters {
                 record.model_record.epsilon_k,
                 format_option(record.model_record.mu),
                 format_option(record.model_record.q),
+                format_option(record.model_record.alpha),
                 format_option(association.parameters.kappa_ab),
                 format_option(association.parameters.epsilon_k_ab),
                 association.na,
@@ -903,4 +947,30 @@ pub mod utils {
 
         Ok(())
     }
+
+    #[test]
+    pub fn test_from_segments_sites() {
+        let ch3 = PcSaftRecord::new(
+            0.61198, 3.7202, 229.9, None, None, None, None, None, None, None, None, None, None,
+            None,
+        );
+        let mut nh2 = PcSaftRecord::new(
+            1.0, 3.3, 300.0, None, None, None, None, None, None, None, None, None, None, None,
+        );
+        let site = |site_type: &str, n, bonds_with: &str| {
+            let bonds_with = vec![bonds_with.to_string()];
+            AssociationSiteRecord::new(site_type.into(), n, bonds_with, None, Default::default())
+        };
+        nh2.association_record = Some(AssociationRecord::with_sites(
+            PcSaftAssociationRecord::new(Some(0.02), Some(1000.0)),
+            vec![site("H", 2.0, "N"), site("N", 1.0, "H")],
+        ));
+        let record =
+            PcSaftRecord::from_segments(&[(ch3.clone(), 1usize), (nh2.clone(), 1)]).unwrap();
+        let sites = &record.association_record.unwrap().sites;
+        assert_eq!(sites.len(), 2);
+        assert_eq!(sites[0].n, 2.0);
+        assert_eq!(sites[1].n, 1.0);
+        assert!(PcSaftRecord::from_segments(&[(ch3, 1usize), (nh2, 2)]).is_err());
+    }
 }
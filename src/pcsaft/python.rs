@@ -1,5 +1,8 @@
-use super::parameters::{PcSaftBinaryRecord, PcSaftParameters, PcSaftRecord};
+use super::parameters::{
+    PcSaftAssociationRecord, PcSaftBinaryRecord, PcSaftParameters, PcSaftRecord,
+};
 use super::DQVariants;
+use crate::association::AssociationSiteRecord;
 use feos_core::parameter::{
     BinaryRecord, Identifier, IdentifierOption, Parameter, ParameterError, PureRecord,
     SegmentRecord,
@@ -9,9 +12,90 @@ use feos_core::*;
 use numpy::{PyArray2, PyReadonlyArray2, ToPyArray};
 use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
+use std::collections::HashMap;
 use std::convert::{TryFrom, TryInto};
 use std::sync::Arc;
 
+/// Association sites of a user-defined type.
+///
+/// Two sites can bond if either of them lists the type of the other
+/// in `bonds_with` or `bond_parameters`.
+///
+/// Parameters
+/// ----------
+/// site_type : str
+///     Name of the site type.
+/// n : float
+///     Number of association sites of this type.
+/// bonds_with : List[str], optional
+///     Site types that this site bonds to.
+/// kappa_ab : float, optional
+///     Association volume parameter of this site type.
+/// epsilon_k_ab : float, optional
+///     Association energy parameter of this site type in units of Kelvin.
+/// bond_parameters : Dict[str, Tuple[float, float]], optional
+///     Association volume and energy parameters of bonds with the given
+///     site types, which replace the combining rule.
+#[pyclass(name = "AssociationSiteRecord")]
+#[derive(Clone)]
+pub struct PyAssociationSiteRecord(AssociationSiteRecord<PcSaftAssociationRecord>);
+
+#[pymethods]
+impl PyAssociationSiteRecord {
+    #[new]
+    #[pyo3(
+        text_signature = "(site_type, n, bonds_with=None, kappa_ab=None, epsilon_k_ab=None, bond_parameters=None)",
+        signature = (site_type, n, bonds_with=None, kappa_ab=None, epsilon_k_ab=None, bond_parameters=None)
+    )]
+    fn new(
+        site_type: String,
+        n: f64,
+        bonds_with: Option<Vec<String>>,
+        kappa_ab: Option<f64>,
+        epsilon_k_ab: Option<f64>,
+        bond_parameters: Option<HashMap<String, (f64, f64)>>,
+    ) -> Self {
+        let parameters = (kappa_ab.is_some() || epsilon_k_ab.is_some())
+            .then(|| PcSaftAssociationRecord::new(kappa_ab, epsilon_k_ab));
+        let bond_parameters = bond_parameters
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(site_type, (kappa_ab, epsilon_k_ab))| {
+                let parameters = PcSaftAssociationRecord::new(Some(kappa_ab), Some(epsilon_k_ab));
+                (site_type, parameters)
+            })
+            .collect();
+        Self(AssociationSiteRecord::new(
+            site_type,
+            n,
+            bonds_with.unwrap_or_default(),
+            parameters,
+            bond_parameters,
+        ))
+    }
+
+    #[getter]
+    fn get_site_type(&self) -> String {
+        self.0.site_type.clone()
+    }
+
+    #[getter]
+    fn get_n(&self) -> f64 {
+        self.0.n
+    }
+
+    #[getter]
+    fn get_bonds_with(&self) -> Vec<String> {
+        self.0.bonds_with.clone()
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+impl_json_handling!(PyAssociationSiteRecord);
+
 /// Pure-substance parameters for the PC-Saft equation of state.
 ///
 /// Parameters
@@ -42,6 +126,10 @@ use std::sync::Arc;
 ///     Entropy-scaling parameters for diffusion. Defaults to `None`.
 /// thermal_conductivity : List[float], optional
 ///     Entropy-scaling parameters for thermal_conductivity. Defaults to `None`.
+/// sites : List[AssociationSiteRecord], optional
+///     Association sites of user-defined types.
+/// alpha : float, optional
+///     Polarizability (volume) in units of Angstrom^3.
 #[pyclass(name = "PcSaftRecord")]
 #[derive(Clone)]
 pub struct PyPcSaftRecord(PcSaftRecord);
@@ -50,8 +138,8 @@ pub struct PyPcSaftRecord(PcSaftRecord);
 impl PyPcSaftRecord {
     #[new]
     #[pyo3(
-        text_signature = "(m, sigma, epsilon_k, mu=None, q=None, kappa_ab=None, epsilon_k_ab=None, na=None, nb=None, nc=None, viscosity=None, diffusion=None, thermal_conductivity=None)",
-        signature = (m, sigma, epsilon_k, mu=None, q=None, kappa_ab=None, epsilon_k_ab=None, na=None, nb=None, nc=None, viscosity=None, diffusion=None, thermal_conductivity=None)
+        text_signature = "(m, sigma, epsilon_k, mu=None, q=None, kappa_ab=None, epsilon_k_ab=None, na=None, nb=None, nc=None, viscosity=None, diffusion=None, thermal_conductivity=None, sites=None, alpha=None)",
+        signature = (m, sigma, epsilon_k, mu=None, q=None, kappa_ab=None, epsilon_k_ab=None, na=None, nb=None, nc=None, viscosity=None, diffusion=None, thermal_conductivity=None, sites=None, alpha=None)
     )]
     #[expect(clippy::too_many_arguments)]
     fn new(
@@ -68,8 +156,10 @@ impl PyPcSaftRecord {
         viscosity: Option<[f64; 4]>,
         diffusion: Option<[f64; 5]>,
         thermal_conductivity: Option<[f64; 4]>,
+        sites: Option<Vec<PyAssociationSiteRecord>>,
+        alpha: Option<f64>,
     ) -> Self {
-        Self(PcSaftRecord::new(
+        let mut record = PcSaftRecord::new(
             m,
             sigma,
             epsilon_k,
@@ -83,7 +173,13 @@ impl PyPcSaftRecord {
             viscosity,
             diffusion,
             thermal_conductivity,
-        ))
+            alpha,
+        );
+        if let (Some(sites), Some(association_record)) = (sites, record.association_record.as_mut())
+        {
+            association_record.sites = sites.into_iter().map(|s| s.0).collect();
+        }
+        Self(record)
     }
 
     #[getter]
@@ -111,10 +207,16 @@ impl PyPcSaftRecord {
         self.0.q
     }
 
+    #[getter]
+    fn get_alpha(&self) -> Option<f64> {
+        self.0.alpha
+    }
+
     #[getter]
     fn get_kappa_ab(&self) -> Option<f64> {
         self.0
             .association_record
+            .as_ref()
             .and_then(|a| a.parameters.kappa_ab)
     }
 
@@ -122,22 +224,38 @@ impl PyPcSaftRecord {
     fn get_epsilon_k_ab(&self) -> Option<f64> {
         self.0
             .association_record
+            .as_ref()
             .and_then(|a| a.parameters.epsilon_k_ab)
     }
 
     #[getter]
     fn get_na(&self) -> Option<f64> {
-        self.0.association_record.map(|a| a.na)
+        self.0.association_record.as_ref().map(|a| a.na)
     }
 
     #[getter]
     fn get_nb(&self) -> Option<f64> {
-        self.0.association_record.map(|a| a.nb)
+        self.0.association_record.as_ref().map(|a| a.nb)
     }
 
     #[getter]
     fn get_nc(&self) -> Option<f64> {
-        self.0.association_record.map(|a| a.nc)
+        self.0.association_record.as_ref().map(|a| a.nc)
+    }
+
+    #[getter]
+    fn get_sites(&self) -> Vec<PyAssociationSiteRecord> {
+        self.0
+            .association_record
+            .as_ref()
+            .map(|a| {
+                a.sites
+                    .iter()
+                    .cloned()
+                    .map(PyAssociationSiteRecord)
+                    .collect()
+            })
+            .unwrap_or_default()
     }
 
     #[getter]
@@ -224,6 +342,7 @@ pub fn pcsaft(m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     m.add_class::<DQVariants>()?;
     m.add_class::<PyPcSaftRecord>()?;
+    m.add_class::<PyAssociationSiteRecord>()?;
     m.add_class::<PyPcSaftBinaryRecord>()?;
     m.add_class::<PyPureRecord>()?;
     m.add_class::<PySegmentRecord>()?;
@@ -1,11 +1,18 @@
 use super::parameters::PetsParameters;
 use crate::hard_sphere::HardSphere;
 use feos_core::parameter::Parameter;
-use feos_core::{Components, Molarweight, Residual};
+use feos_core::{
+    Components, EntropyScaling, EosError, EosResult, Molarweight, ReferenceSystem, Residual, State,
+};
+#[cfg(feature = "dft")]
+use feos_dft::solvation::PairPotential;
 use ndarray::Array1;
-use quantity::{MolarWeight, GRAM, MOL};
-use std::f64::consts::FRAC_PI_6;
+#[cfg(feature = "dft")]
+use ndarray::Array2;
+use quantity::*;
+use std::f64::consts::{FRAC_PI_6, PI};
 use std::sync::Arc;
+use typenum::P2;
 
 pub(crate) mod dispersion;
 use dispersion::Dispersion;
@@ -97,217 +104,181 @@ impl Molarweight for Pets {
     }
 }
 
-// fn omega11(t: f64) -> f64 {
-//     1.06036 * t.powf(-0.15610)
-//         + 0.19300 * (-0.47635 * t).exp()
-//         + 1.03587 * (-1.52996 * t).exp()
-//         + 1.76474 * (-3.89411 * t).exp()
-// }
+#[cfg(feature = "dft")]
+impl PairPotential for Pets {
+    fn pair_potential(&self, i: usize, r: &Array1<f64>, _: f64) -> Array2<f64> {
+        let eps_ij_4 = 4.0 * self.parameters.epsilon_k_ij.clone();
+        let shift_ij = &eps_ij_4 * (2.5f64.powi(-12) - 2.5f64.powi(-6));
+        let rc_ij = 2.5 * &self.parameters.sigma_ij;
+        Array2::from_shape_fn((self.parameters.sigma.len(), r.len()), |(j, k)| {
+            if r[k] > rc_ij[[i, j]] {
+                0.0
+            } else {
+                let att = (self.parameters.sigma_ij[[i, j]] / r[k]).powi(6);
+                eps_ij_4[[i, j]] * att * (att - 1.0) - shift_ij[[i, j]]
+            }
+        })
+    }
+}
 
-// fn omega22(t: f64) -> f64 {
-//     1.16145 * t.powf(-0.14874) + 0.52487 * (-0.77320 * t).exp() + 2.16178 * (-2.43787 * t).exp()
-//         - 6.435e-4 * t.powf(0.14874) * (18.0323 * t.powf(-0.76830) - 7.27371).sin()
-// }
+fn omega11(t: f64) -> f64 {
+    1.06036 * t.powf(-0.15610)
+        + 0.19300 * (-0.47635 * t).exp()
+        + 1.03587 * (-1.52996 * t).exp()
+        + 1.76474 * (-3.89411 * t).exp()
+}
 
-// impl EntropyScaling for Pets {
-//     fn viscosity_reference(
-//         &self,
-//         temperature: SINumber,
-//         _: SINumber,
-//         moles: &SIArray1,
-//     ) -> EosResult<SINumber> {
-//         let x = moles.to_reduced(moles.sum())?;
-//         let p = &self.parameters;
-//         let mw = &p.molarweight;
-//         let ce: Array1<SINumber> = (0..self.components())
-//             .map(|i| {
-//                 let tr = (temperature / p.epsilon_k[i] / KELVIN)
-//                     .into_value()
-//                     .unwrap();
-//                 5.0 / 16.0
-//                     * (mw[i] * GRAM / MOL * KB / NAV * temperature / PI)
-//                         .sqrt()
-//                         .unwrap()
-//                     / omega22(tr)
-//                     / (p.sigma[i] * ANGSTROM).powi(2)
-//             })
-//             .collect();
-//         let mut ce_mix = 0.0 * MILLI * PASCAL * SECOND;
-//         for i in 0..self.components() {
-//             let denom: f64 = (0..self.components())
-//                 .map(|j| {
-//                     x[j] * (1.0
-//                         + (ce[i] / ce[j]).into_value().unwrap().sqrt()
-//                             * (mw[j] / mw[i]).powf(1.0 / 4.0))
-//                     .powi(2)
-//                         / (8.0 * (1.0 + mw[i] / mw[j])).sqrt()
-//                 })
-//                 .sum();
-//             ce_mix += ce[i] * x[i] / denom
-//         }
-//         Ok(ce_mix)
-//     }
+fn omega22(t: f64) -> f64 {
+    1.16145 * t.powf(-0.14874) + 0.52487 * (-0.77320 * t).exp() + 2.16178 * (-2.43787 * t).exp()
+        - 6.435e-4 * t.powf(0.14874) * (18.0323 * t.powf(-0.76830) - 7.27371).sin()
+}
 
-//     fn viscosity_correlation(&self, s_res: f64, x: &Array1<f64>) -> EosResult<f64> {
-//         let coefficients = self
-//             .parameters
-//             .viscosity
-//             .as_ref()
-//             .expect("Missing viscosity coefficients.");
-//         let a: f64 = (&coefficients.row(0) * x).sum();
-//         let b: f64 = (&coefficients.row(1) * x).sum();
-//         let c: f64 = (&coefficients.row(2) * x).sum();
-//         let d: f64 = (&coefficients.row(3) * x).sum();
-//         Ok(a + b * s_res + c * s_res.powi(2) + d * s_res.powi(3))
-//     }
+#[inline]
+fn chapman_enskog_thermal_conductivity(
+    temperature: Temperature,
+    molarweight: MolarWeight,
+    sigma: f64,
+    epsilon_k: f64,
+) -> ThermalConductivity {
+    let t = temperature.to_reduced();
+    0.083235 * (t / molarweight.convert_to(GRAM / MOL)).sqrt()
+        / sigma.powi(2)
+        / omega22(t / epsilon_k)
+        * WATT
+        / METER
+        / KELVIN
+}
 
-//     fn diffusion_reference(
-//         &self,
-//         temperature: SINumber,
-//         volume: SINumber,
-//         moles: &SIArray1,
-//     ) -> EosResult<SINumber> {
-//         if self.components() != 1 {
-//             return Err(EosError::IncompatibleComponents(self.components(), 1));
-//         }
-//         let p = &self.parameters;
-//         let density = moles.sum() / volume;
-//         let res: Array1<SINumber> = (0..self.components())
-//             .map(|i| {
-//                 let tr = (temperature / p.epsilon_k[i] / KELVIN)
-//                     .into_value()
-//                     .unwrap();
-//                 3.0 / 8.0 / (p.sigma[i] * ANGSTROM).powi(2) / omega11(tr) / (density * NAV)
-//                     * (temperature * RGAS / PI / (p.molarweight[i] * GRAM / MOL))
-//                         .sqrt()
-//                         .unwrap()
-//             })
-//             .collect();
-//         Ok(res[0])
-//     }
+impl EntropyScaling for Pets {
+    fn viscosity_reference(
+        &self,
+        temperature: Temperature,
+        _: Volume,
+        moles: &Moles<Array1<f64>>,
+    ) -> EosResult<Viscosity> {
+        let p = &self.parameters;
+        let mw = &p.molarweight;
+        let x = (moles / moles.sum()).into_value();
+        let ce: Array1<_> = (0..self.components())
+            .map(|i| {
+                let tr = (temperature / p.epsilon_k[i] / KELVIN).into_value();
+                5.0 / 16.0 * (mw[i] * GRAM / MOL * KB / NAV * temperature / PI).sqrt()
+                    / omega22(tr)
+                    / (p.sigma[i] * ANGSTROM).powi::<P2>()
+            })
+            .collect();
+        let mut ce_mix = 0.0 * MILLI * PASCAL * SECOND;
+        for i in 0..self.components() {
+            let denom: f64 = (0..self.components())
+                .map(|j| {
+                    x[j] * (1.0
+                        + (ce[i] / ce[j]).into_value().sqrt() * (mw[j] / mw[i]).powf(1.0 / 4.0))
+                    .powi(2)
+                        / (8.0 * (1.0 + mw[i] / mw[j])).sqrt()
+                })
+                .sum();
+            ce_mix += ce[i] * x[i] / denom
+        }
+        Ok(ce_mix)
+    }
 
-//     fn diffusion_correlation(&self, s_res: f64, x: &Array1<f64>) -> EosResult<f64> {
-//         if self.components() != 1 {
-//             return Err(EosError::IncompatibleComponents(self.components(), 1));
-//         }
-//         let coefficients = self
-//             .parameters
-//             .diffusion
-//             .as_ref()
-//             .expect("Missing diffusion coefficients.");
-//         let a: f64 = (&coefficients.row(0) * x).sum();
-//         let b: f64 = (&coefficients.row(1) * x).sum();
-//         let c: f64 = (&coefficients.row(2) * x).sum();
-//         let d: f64 = (&coefficients.row(3) * x).sum();
-//         let e: f64 = (&coefficients.row(4) * x).sum();
-//         Ok(a + b * s_res
-//             - c * (1.0 - s_res.exp()) * s_res.powi(2)
-//             - d * s_res.powi(4)
-//             - e * s_res.powi(8))
-//     }
+    fn viscosity_correlation(&self, s_res: f64, x: &Array1<f64>) -> EosResult<f64> {
+        let coefficients = self
+            .parameters
+            .viscosity
+            .as_ref()
+            .expect("Missing viscosity coefficients.");
+        let a: f64 = (&coefficients.row(0) * x).sum();
+        let b: f64 = (&coefficients.row(1) * x).sum();
+        let c: f64 = (&coefficients.row(2) * x).sum();
+        let d: f64 = (&coefficients.row(3) * x).sum();
+        Ok(a + b * s_res + c * s_res.powi(2) + d * s_res.powi(3))
+    }
 
-//     // fn thermal_conductivity_reference(
-//     //     &self,
-//     //     state: &State<E>,
-//     // ) -> EosResult<SINumber> {
-//     //     if self.components() != 1 {
-//     //         return Err(EosError::IncompatibleComponents(self.components(), 1));
-//     //     }
-//     //     let p = &self.parameters;
-//     //     let res: Array1<SINumber> = (0..self.components())
-//     //         .map(|i| {
-//     //             let tr = (state.temperature / p.epsilon_k[i] / KELVIN)
-//     //                 .into_value()
-//     //                 .unwrap();
-//     //             let cp = State::critical_point_pure(&state.eos, Some(state.temperature)).unwrap();
-//     //             let s_res_cp_reduced = cp
-//     //                 .entropy(Contributions::Residual)
-//     //                 .to_reduced(SIUnit::reference_entropy())
-//     //                 .unwrap();
-//     //             let s_res_reduced = cp
-//     //                 .entropy(Contributions::Residual)
-//     //                 .to_reduced(SIUnit::reference_entropy())
-//     //                 .unwrap();
-//     //             let ref_ce = 0.083235
-//     //                 * ((state.temperature / KELVIN).into_value().unwrap()
-//     //                     / (p.molarweight[0]))
-//     //                     .sqrt()
-//     //                 / p.sigma[0]
-//     //                 / p.sigma[0]
-//     //                 / omega22(tr);
-//     //             let alpha_visc = (-s_res_reduced / s_res_cp_reduced).exp();
-//     //             let ref_ts = (-0.0167141 * tr + 0.0470581 * (tr).powi(2))
-//     //                 * (p.sigma[i].powi(3) * p.epsilon_k[0])
-//     //                 / 100000.0;
-//     //             (ref_ce + ref_ts * alpha_visc) * WATT / METER / KELVIN
-//     //         })
-//     //         .collect();
-//     //     Ok(res[0])
-//     // }
+    fn diffusion_reference(
+        &self,
+        temperature: Temperature,
+        volume: Volume,
+        moles: &Moles<Array1<f64>>,
+    ) -> EosResult<Diffusivity> {
+        if self.components() != 1 {
+            return Err(EosError::IncompatibleComponents(self.components(), 1));
+        }
+        let p = &self.parameters;
+        let density = moles.sum() / volume;
+        let tr = (temperature / p.epsilon_k[0] / KELVIN).into_value();
+        Ok(
+            3.0 / 8.0 / (p.sigma[0] * ANGSTROM).powi::<P2>() / omega11(tr) / (density * NAV)
+                * (temperature * RGAS / PI / (p.molarweight[0] * GRAM / MOL)).sqrt(),
+        )
+    }
 
-//     // Equation 11 of DOI: 10.1021/acs.iecr.9b03998
-//     fn thermal_conductivity_reference(
-//         &self,
-//         temperature: SINumber,
-//         volume: SINumber,
-//         moles: &SIArray1,
-//     ) -> EosResult<SINumber> {
-//         if self.components() != 1 {
-//             return Err(EosError::IncompatibleComponents(self.components(), 1));
-//         }
-//         let p = &self.parameters;
-//         let state = State::new_nvt(
-//             &Arc::new(Self::new(self.parameters.clone())),
-//             temperature,
-//             volume,
-//             moles,
-//         )?;
-//         let res: Array1<SINumber> = (0..self.components())
-//             .map(|i| {
-//                 let tr = (temperature / p.epsilon_k[i] / KELVIN)
-//                     .into_value()
-//                     .unwrap();
-//                 let ce = 83.235
-//                     * f64::powf(10.0, -1.5)
-//                     * ((temperature / KELVIN).into_value().unwrap() / p.molarweight[0]).sqrt()
-//                     / (p.sigma[0] * p.sigma[0])
-//                     / omega22(tr);
-//                 ce * WATT / METER / KELVIN
-//                     + state.density
-//                         * self
-//                             .diffusion_reference(temperature, volume, moles)
-//                             .unwrap()
-//                         * self
-//                             .diffusion_correlation(
-//                                 state
-//                                     .residual_molar_entropy()
-//                                     .to_reduced(SIUnit::reference_molar_entropy())
-//                                     .unwrap(),
-//                                 &state.molefracs,
-//                             )
-//                             .unwrap()
-//                         * (state.c_v(Contributions::Total) - 1.5 * RGAS)
-//             })
-//             .collect();
-//         Ok(res[0])
-//     }
+    fn diffusion_correlation(&self, s_res: f64, x: &Array1<f64>) -> EosResult<f64> {
+        if self.components() != 1 {
+            return Err(EosError::IncompatibleComponents(self.components(), 1));
+        }
+        let coefficients = self
+            .parameters
+            .diffusion
+            .as_ref()
+            .expect("Missing diffusion coefficients.");
+        let a: f64 = (&coefficients.row(0) * x).sum();
+        let b: f64 = (&coefficients.row(1) * x).sum();
+        let c: f64 = (&coefficients.row(2) * x).sum();
+        let d: f64 = (&coefficients.row(3) * x).sum();
+        let e: f64 = (&coefficients.row(4) * x).sum();
+        Ok(a + b * s_res
+            - c * (1.0 - s_res.exp()) * s_res.powi(2)
+            - d * s_res.powi(4)
+            - e * s_res.powi(8))
+    }
 
-//     fn thermal_conductivity_correlation(&self, s_res: f64, x: &Array1<f64>) -> EosResult<f64> {
-//         if self.components() != 1 {
-//             return Err(EosError::IncompatibleComponents(self.components(), 1));
-//         }
-//         let coefficients = self
-//             .parameters
-//             .thermal_conductivity
-//             .as_ref()
-//             .expect("Missing thermal conductivity coefficients");
-//         let a: f64 = (&coefficients.row(0) * x).sum();
-//         let b: f64 = (&coefficients.row(1) * x).sum();
-//         let c: f64 = (&coefficients.row(2) * x).sum();
-//         let d: f64 = (&coefficients.row(3) * x).sum();
-//         Ok(a + b * s_res + c * (1.0 - s_res.exp()) + d * s_res.powi(2))
-//     }
-// }
+    // Equation 4 of DOI: 10.1021/acs.iecr.9b04289
+    fn thermal_conductivity_reference(
+        &self,
+        temperature: Temperature,
+        volume: Volume,
+        moles: &Moles<Array1<f64>>,
+    ) -> EosResult<ThermalConductivity> {
+        if self.components() != 1 {
+            return Err(EosError::IncompatibleComponents(self.components(), 1));
+        }
+        let p = &self.parameters;
+        let state = State::new_nvt(&Arc::new(Self::new(p.clone())), temperature, volume, moles)?;
+        let tr = (temperature / p.epsilon_k[0] / KELVIN).into_value();
+        let s_res_reduced = state.residual_molar_entropy().to_reduced();
+        let ref_ce = chapman_enskog_thermal_conductivity(
+            temperature,
+            self.molar_weight().get(0),
+            p.sigma[0],
+            p.epsilon_k[0],
+        );
+        let alpha_visc = (-s_res_reduced / -0.5).exp();
+        let ref_ts = (-0.0167141 * tr + 0.0470581 * tr.powi(2))
+            * (p.sigma[0].powi(3) * p.epsilon_k[0])
+            * 1e-5
+            * WATT
+            / METER
+            / KELVIN;
+        Ok(ref_ce + ref_ts * alpha_visc)
+    }
+
+    fn thermal_conductivity_correlation(&self, s_res: f64, x: &Array1<f64>) -> EosResult<f64> {
+        if self.components() != 1 {
+            return Err(EosError::IncompatibleComponents(self.components(), 1));
+        }
+        let coefficients = self
+            .parameters
+            .thermal_conductivity
+            .as_ref()
+            .expect("Missing thermal conductivity coefficients");
+        let a: f64 = (&coefficients.row(0) * x).sum();
+        let b: f64 = (&coefficients.row(1) * x).sum();
+        let c: f64 = (&coefficients.row(2) * x).sum();
+        let d: f64 = (&coefficients.row(3) * x).sum();
+        Ok(a + b * s_res + c * (1.0 - s_res.exp()) + d * s_res.powi(2))
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -318,8 +289,8 @@ mod tests {
     use approx::assert_relative_eq;
     use feos_core::{Contributions, DensityInitialization, PhaseEquilibrium, State, StateHD};
     use ndarray::arr1;
-    use quantity::{BAR, KELVIN, METER, PASCAL, RGAS};
-    use typenum::P3;
+    use quantity::{BAR, CENTI, KELVIN, METER, MILLI, PASCAL, RGAS, SECOND};
+    use typenum::{P2, P3};
 
     #[test]
     fn ideal_gas_pressure() {
@@ -435,49 +406,47 @@ mod tests {
         )
     }
 
-    // #[test]
-    // fn viscosity() -> EosResult<()> {
-    //     let e = Arc::new(Pets::new(argon_parameters()));
-    //     let t = 300.0 * KELVIN;
-    //     let p = BAR;
-    //     let n = arr1(&[1.0]) * MOL;
-    //     let s = State::new_npt(&e, t, p, &n, DensityInitialization::None).unwrap();
-    //     assert_relative_eq!(
-    //         s.viscosity()?,
-    //         0.00797 * MILLI * PASCAL * SECOND,
-    //         epsilon = 1e-5
-    //     );
-    //     assert_relative_eq!(
-    //         s.ln_viscosity_reduced()?,
-    //         (s.viscosity()? / e.viscosity_reference(s.temperature, s.volume, &s.moles)?)
-    //             .into_value()
-    //             .unwrap()
-    //             .ln(),
-    //         epsilon = 1e-15
-    //     );
-    //     Ok(())
-    // }
+    #[test]
+    fn viscosity() -> EosResult<()> {
+        let e = Arc::new(Pets::new(argon_parameters()));
+        let t = 300.0 * KELVIN;
+        let p = BAR;
+        let n = arr1(&[1.0]) * MOL;
+        let s = State::new_npt(&e, t, p, &n, DensityInitialization::None).unwrap();
+        assert_relative_eq!(
+            s.viscosity()?,
+            0.02306 * MILLI * PASCAL * SECOND,
+            epsilon = 1e-5
+        );
+        assert_relative_eq!(
+            s.ln_viscosity_reduced()?,
+            (s.viscosity()? / e.viscosity_reference(s.temperature, s.volume, &s.moles)?)
+                .into_value()
+                .ln(),
+            epsilon = 1e-15
+        );
+        Ok(())
+    }
 
-    // #[test]
-    // fn diffusion() -> EosResult<()> {
-    //     let e = Arc::new(Pets::new(argon_parameters()));
-    //     let t = 300.0 * KELVIN;
-    //     let p = BAR;
-    //     let n = arr1(&[1.0]) * MOL;
-    //     let s = State::new_npt(&e, t, p, &n, DensityInitialization::None).unwrap();
-    //     assert_relative_eq!(
-    //         s.diffusion()?,
-    //         0.01505 * (CENTI * METER).powi(2) / SECOND,
-    //         epsilon = 1e-5
-    //     );
-    //     assert_relative_eq!(
-    //         s.ln_diffusion_reduced()?,
-    //         (s.diffusion()? / e.diffusion_reference(s.temperature, s.volume, &s.moles)?)
-    //             .into_value()
-    //             .unwrap()
-    //             .ln(),
-    //         epsilon = 1e-15
-    //     );
-    //     Ok(())
-    // }
+    #[test]
+    fn diffusion() -> EosResult<()> {
+        let e = Arc::new(Pets::new(argon_parameters()));
+        let t = 300.0 * KELVIN;
+        let p = BAR;
+        let n = arr1(&[1.0]) * MOL;
+        let s = State::new_npt(&e, t, p, &n, DensityInitialization::None).unwrap();
+        assert_relative_eq!(
+            s.diffusion()?,
+            0.18885 * (CENTI * METER).powi::<P2>() / SECOND,
+            epsilon = 1e-5
+        );
+        assert_relative_eq!(
+            s.ln_diffusion_reduced()?,
+            (s.diffusion()? / e.diffusion_reference(s.temperature, s.volume, &s.moles)?)
+                .into_value()
+                .ln(),
+            epsilon = 1e-15
+        );
+        Ok(())
+    }
 }
@@ -16,6 +16,10 @@ use crate::pets::{PetsFunctional, PetsOptions};
 use crate::saftvrqmie::python::PySaftVRQMieParameters;
 #[cfg(feature = "saftvrqmie")]
 use crate::saftvrqmie::{SaftVRQMieFunctional, SaftVRQMieOptions};
+#[cfg(feature = "uvtheory")]
+use crate::uvtheory::python::PyUVTheoryParameters;
+#[cfg(feature = "uvtheory")]
+use crate::uvtheory::{Perturbation, UVTheoryFunctional, UVTheoryOptions};
 use crate::ResidualModel;
 
 use super::eos::{PyEquationOfState, PyPhaseEquilibrium, PyState, PyStateVec};
@@ -28,6 +32,7 @@ use feos_dft::*;
 use ndarray::{Array1, Array2, Array3, Array4};
 use numpy::prelude::*;
 use numpy::{PyArray1, PyArray2, PyArray3, PyArray4};
+use pyo3::exceptions::{PyIOError, PyValueError};
 use pyo3::prelude::*;
 use quantity::*;
 use std::convert::TryInto;
@@ -168,6 +173,45 @@ impl PyHelmholtzEnergyFunctional {
         PyEquationOfState(Arc::new(EquationOfState::new(ideal_gas, func)))
     }
 
+    /// uv-theory Helmholtz energy functional.
+    ///
+    /// Parameters
+    /// ----------
+    /// parameters : UVTheoryParameters
+    ///     The set of uv-theory parameters.
+    /// fmt_version: FMTVersion, optional
+    ///     The specific variant of the FMT term. Defaults to FMTVersion.WhiteBear
+    /// max_eta : float, optional
+    ///     Maximum packing fraction. Defaults to 0.5.
+    /// perturbation : Perturbation, optional
+    ///     Division type of the Mie potential. Defaults to WCA division.
+    ///
+    /// Returns
+    /// -------
+    /// HelmholtzEnergyFunctional
+    #[cfg(feature = "uvtheory")]
+    #[staticmethod]
+    #[pyo3(
+        signature = (parameters, fmt_version=FMTVersion::WhiteBear, max_eta=0.5, perturbation=Perturbation::WeeksChandlerAndersen),
+        text_signature = "(parameters, fmt_version, max_eta=0.5, perturbation)"
+    )]
+    fn uvtheory(
+        parameters: PyUVTheoryParameters,
+        fmt_version: FMTVersion,
+        max_eta: f64,
+        perturbation: Perturbation,
+    ) -> PyEquationOfState {
+        let options = UVTheoryOptions {
+            max_eta,
+            perturbation,
+        };
+        let func = Arc::new(ResidualModel::UVTheoryFunctional(
+            UVTheoryFunctional::with_options(parameters.0, fmt_version, options),
+        ));
+        let ideal_gas = Arc::new(IdealGasModel::NoModel(func.components()));
+        PyEquationOfState(Arc::new(EquationOfState::new(ideal_gas, func)))
+    }
+
     /// Helmholtz energy functional for hard sphere systems.
     ///
     /// Parameters
@@ -176,16 +220,30 @@ impl PyHelmholtzEnergyFunctional {
     ///     The diameters of the hard spheres in Angstrom.
     /// fmt_version : FMTVersion
     ///     The specific variant of the FMT term.
+    /// nonadditivity : numpy.ndarray[float], optional
+    ///     The nonadditivity parameters of the cross diameters
+    ///     for non-additive hard-sphere mixtures.
     ///
     /// Returns
     /// -------
     /// HelmholtzEnergyFunctional
     #[staticmethod]
-    fn fmt(sigma: &Bound<'_, PyArray1<f64>>, fmt_version: FMTVersion) -> PyEquationOfState {
-        let func = Arc::new(ResidualModel::FmtFunctional(FMTFunctional::new(
-            &sigma.to_owned_array(),
-            fmt_version,
-        )));
+    #[pyo3(
+        signature = (sigma, fmt_version, nonadditivity=None),
+        text_signature = "(sigma, fmt_version, nonadditivity=None)"
+    )]
+    fn fmt(
+        sigma: &Bound<'_, PyArray1<f64>>,
+        fmt_version: FMTVersion,
+        nonadditivity: Option<&Bound<'_, PyArray2<f64>>>,
+    ) -> PyEquationOfState {
+        let sigma = sigma.to_owned_array();
+        let func = Arc::new(ResidualModel::FmtFunctional(match nonadditivity {
+            Some(delta) => {
+                FMTFunctional::with_nonadditivity(&sigma, &delta.to_owned_array(), fmt_version)
+            }
+            None => FMTFunctional::new(&sigma, fmt_version),
+        }));
         let ideal_gas = Arc::new(IdealGasModel::NoModel(func.components()));
         PyEquationOfState(Arc::new(EquationOfState::new(ideal_gas, func)))
     }
@@ -230,6 +288,65 @@ impl PyHelmholtzEnergyFunctional {
     }
 }
 
+#[pymethods]
+impl PyEquationOfState {
+    /// Write tables of the pair potential and the force for all pure
+    /// components and all unique pairs of components.
+    ///
+    /// Only available for models based on a well-defined pair potential
+    /// (PC-SAFT, PeTS, SAFT-VR Mie, uv-theory and the corresponding
+    /// Helmholtz energy functionals).
+    ///
+    /// Parameters
+    /// ----------
+    /// temperature : SINumber
+    ///     The temperature.
+    /// r : SIArray1
+    ///     The distances at which the pair potential is tabulated.
+    /// format : PairPotentialTableFormat, optional
+    ///     The file format. Defaults to PairPotentialTableFormat.Lammps.
+    /// names : [str], optional
+    ///     The names of the components used in the file names.
+    ///     Defaults to the component indices.
+    /// directory : str, optional
+    ///     The directory in which the files are created. Defaults to ".".
+    ///
+    /// Example
+    /// -------
+    ///
+    /// For a methane-ethane mixture at 150 K, three LAMMPS tables are created:
+    /// "methane_150K.table", "ethane_150K.table" and "methane_ethane_150K.table".
+    #[pyo3(signature = (temperature, r, format=PairPotentialTableFormat::Lammps, names=None, directory="."))]
+    fn pair_potential_tables(
+        &self,
+        temperature: Temperature,
+        r: Length<Array1<f64>>,
+        format: PairPotentialTableFormat,
+        names: Option<Vec<String>>,
+        directory: &str,
+    ) -> PyResult<()> {
+        let names =
+            names.unwrap_or_else(|| (0..self.0.components()).map(|i| i.to_string()).collect());
+        if names.len() != self.0.components() {
+            return Err(PyValueError::new_err(format!(
+                "{} names were given for {} components!",
+                names.len(),
+                self.0.components()
+            )));
+        }
+        self.0
+            .residual
+            .pair_potential_tables(
+                temperature,
+                &r,
+                format,
+                &names,
+                std::path::Path::new(directory),
+            )
+            .map_err(PyIOError::new_err)
+    }
+}
+
 impl_planar_interface!(EquationOfState<IdealGasModel, ResidualModel>);
 impl_surface_tension_diagram!(EquationOfState<IdealGasModel, ResidualModel>);
 
@@ -248,14 +365,18 @@ pub fn dft(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Geometry>()?;
     m.add_class::<PyPore1D>()?;
     m.add_class::<PyPore2D>()?;
+    m.add_class::<PyPoreAxisymmetric>()?;
+    m.add_class::<PyPoreShape>()?;
     m.add_class::<PyPore3D>()?;
     m.add_class::<PyPairCorrelation>()?;
     m.add_class::<PyExternalPotential>()?;
     m.add_class::<PyAdsorption1D>()?;
+    m.add_class::<PyAdsorptionAxisymmetric>()?;
     m.add_class::<PyAdsorption3D>()?;
     m.add_class::<PySurfaceTensionDiagram>()?;
     m.add_class::<PyDFTSolver>()?;
     m.add_class::<PySolvationProfile>()?;
+    m.add_class::<PairPotentialTableFormat>()?;
 
     Ok(())
 }
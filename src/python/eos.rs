@@ -1,3 +1,7 @@
+#[cfg(feature = "cpa")]
+use crate::cpa::python::PyCpaParameters;
+#[cfg(feature = "cpa")]
+use crate::cpa::{Cpa, CpaOptions};
 #[cfg(feature = "epcsaft")]
 use crate::epcsaft::python::PyElectrolytePcSaftParameters;
 #[cfg(feature = "epcsaft")]
@@ -37,6 +41,8 @@ use crate::ResidualModel;
 
 use super::dippr::PyDippr;
 use super::joback::PyJoback;
+use super::nasa::PyNasa;
+use super::rrho::PyRrho;
 use feos_core::cubic::PengRobinson;
 use feos_core::python::cubic::PyPengRobinsonParameters;
 use feos_core::python::user_defined::{PyIdealGas, PyResidual};
@@ -259,6 +265,46 @@ impl PyEquationOfState {
         Self(Arc::new(EquationOfState::new(ideal_gas, residual)))
     }
 
+    /// Cubic-Plus-Association equation of state.
+    ///
+    /// Parameters
+    /// ----------
+    /// parameters : CpaParameters
+    ///     The parameters of the CPA equation of state to use.
+    /// max_b_rho : float, optional
+    ///     Maximum reduced density. Defaults to 0.9.
+    /// max_iter_cross_assoc : unsigned integer, optional
+    ///     Maximum number of iterations for cross association. Defaults to 50.
+    /// tol_cross_assoc : float
+    ///     Tolerance for convergence of cross association. Defaults to 1e-10.
+    ///
+    /// Returns
+    /// -------
+    /// EquationOfState
+    ///     The CPA equation of state that can be used to compute thermodynamic
+    ///     states.
+    #[cfg(feature = "cpa")]
+    #[staticmethod]
+    #[pyo3(
+        signature = (parameters, max_b_rho=0.9, max_iter_cross_assoc=50, tol_cross_assoc=1e-10),
+        text_signature = "(parameters, max_b_rho=0.9, max_iter_cross_assoc=50, tol_cross_assoc=1e-10)"
+    )]
+    pub fn cpa(
+        parameters: PyCpaParameters,
+        max_b_rho: f64,
+        max_iter_cross_assoc: usize,
+        tol_cross_assoc: f64,
+    ) -> Self {
+        let options = CpaOptions {
+            max_b_rho,
+            max_iter_cross_assoc,
+            tol_cross_assoc,
+        };
+        let residual = Arc::new(ResidualModel::Cpa(Cpa::with_options(parameters.0, options)));
+        let ideal_gas = Arc::new(IdealGasModel::NoModel(residual.components()));
+        Self(Arc::new(EquationOfState::new(ideal_gas, residual)))
+    }
+
     /// Residual Helmholtz energy model from a Python class.
     ///
     /// Parameters
@@ -433,6 +479,75 @@ impl PyEquationOfState {
     fn dippr(&self, dippr: PyDippr) -> Self {
         self.add_ideal_gas(IdealGasModel::Dippr(dippr.0))
     }
+
+    /// Ideal gas model based on NASA polynomials.
+    ///
+    /// Parameters
+    /// ----------
+    /// nasa : Nasa
+    ///     The parametrized Nasa model.
+    ///
+    /// Returns
+    /// -------
+    /// EquationOfState
+    fn nasa(&self, nasa: PyNasa) -> Self {
+        self.add_ideal_gas(IdealGasModel::Nasa(nasa.0))
+    }
+
+    /// Ideal gas model of rigid rotors and harmonic oscillators.
+    ///
+    /// Parameters
+    /// ----------
+    /// rrho : Rrho
+    ///     The parametrized Rrho model.
+    ///
+    /// Returns
+    /// -------
+    /// EquationOfState
+    fn rrho(&self, rrho: PyRrho) -> Self {
+        self.add_ideal_gas(IdealGasModel::Rrho(rrho.0))
+    }
+
+    /// Set the reference state for enthalpies and entropies.
+    ///
+    /// Parameters
+    /// ----------
+    /// reference_state : str
+    ///     One of 'IIR', 'ASHRAE', 'NBP' or 'formation'.
+    /// enthalpy : SIArray1, optional
+    ///     Molar enthalpies of the ideal gases at 298.15 K and 1 bar.
+    ///     Required for the 'formation' reference state.
+    /// entropy : SIArray1, optional
+    ///     Molar entropies of the ideal gases at 298.15 K and 1 bar.
+    ///     Required for the 'formation' reference state.
+    ///
+    /// Returns
+    /// -------
+    /// EquationOfState
+    #[pyo3(signature = (reference_state, enthalpy=None, entropy=None))]
+    fn with_reference_state(
+        &self,
+        reference_state: &str,
+        enthalpy: Option<MolarEnergy<Array1<f64>>>,
+        entropy: Option<MolarEntropy<Array1<f64>>>,
+    ) -> PyResult<Self> {
+        let reference_state = match (reference_state, enthalpy, entropy) {
+            ("IIR", None, None) => ReferenceState::IIR,
+            ("ASHRAE", None, None) => ReferenceState::ASHRAE,
+            ("NBP", None, None) => ReferenceState::NBP,
+            ("formation", Some(enthalpy), Some(entropy)) => {
+                ReferenceState::Formation { enthalpy, entropy }
+            }
+            _ => {
+                return Err(PyErr::new::<PyValueError, _>(
+                    "`reference_state` must be 'IIR', 'ASHRAE', 'NBP' or 'formation'. Enthalpies and entropies have to be provided only for 'formation'.".to_string(),
+                ))
+            }
+        };
+        Ok(Self(Arc::new(
+            (*self.0).clone().with_reference_state(reference_state)?,
+        )))
+    }
 }
 
 impl PyEquationOfState {
@@ -464,6 +579,7 @@ impl_estimator_entropy_scaling!(EquationOfState<IdealGasModel, ResidualModel>, P
 #[pymodule]
 pub fn eos(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Contributions>()?;
+    m.add_class::<DiffusionMixingRule>()?;
     m.add_class::<Verbosity>()?;
 
     m.add_class::<PyEquationOfState>()?;
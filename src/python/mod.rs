@@ -1,3 +1,5 @@
+#[cfg(feature = "cpa")]
+use crate::cpa::python::cpa as cpa_module;
 #[cfg(feature = "epcsaft")]
 use crate::epcsaft::python::epcsaft as epcsaft_module;
 #[cfg(feature = "gc_pcsaft")]
@@ -21,10 +23,14 @@ mod cubic;
 mod dippr;
 mod eos;
 mod joback;
+mod nasa;
+mod rrho;
 use cubic::cubic as cubic_module;
 use dippr::dippr as dippr_module;
 use eos::eos as eos_module;
 use joback::joback as joback_module;
+use nasa::nasa as nasa_module;
+use rrho::rrho as rrho_module;
 
 #[cfg(feature = "dft")]
 mod dft;
@@ -40,6 +46,8 @@ pub fn feos(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pymodule!(dft_module))?;
     m.add_wrapped(wrap_pymodule!(joback_module))?;
     m.add_wrapped(wrap_pymodule!(dippr_module))?;
+    m.add_wrapped(wrap_pymodule!(nasa_module))?;
+    m.add_wrapped(wrap_pymodule!(rrho_module))?;
     m.add_wrapped(wrap_pymodule!(cubic_module))?;
     #[cfg(feature = "pcsaft")]
     m.add_wrapped(wrap_pymodule!(pcsaft_module))?;
@@ -55,6 +63,8 @@ pub fn feos(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pymodule!(saftvrqmie_module))?;
     #[cfg(feature = "saftvrmie")]
     m.add_wrapped(wrap_pymodule!(saftvrmie_module))?;
+    #[cfg(feature = "cpa")]
+    m.add_wrapped(wrap_pymodule!(cpa_module))?;
 
     set_path(m, "feos.eos", "eos")?;
     #[cfg(feature = "estimator")]
@@ -63,6 +73,8 @@ pub fn feos(m: &Bound<'_, PyModule>) -> PyResult<()> {
     set_path(m, "feos.dft", "dft")?;
     set_path(m, "feos.joback", "joback")?;
     set_path(m, "feos.dippr", "dippr")?;
+    set_path(m, "feos.nasa", "nasa")?;
+    set_path(m, "feos.rrho", "rrho")?;
     set_path(m, "feos.cubic", "cubic")?;
     #[cfg(feature = "pcsaft")]
     set_path(m, "feos.pcsaft", "pcsaft")?;
@@ -78,6 +90,8 @@ pub fn feos(m: &Bound<'_, PyModule>) -> PyResult<()> {
     set_path(m, "feos.saftvrqmie", "saftvrqmie")?;
     #[cfg(feature = "saftvrmie")]
     set_path(m, "feos.saftvrmie", "saftvrmie")?;
+    #[cfg(feature = "cpa")]
+    set_path(m, "feos.cpa", "cpa")?;
     Ok(())
 }
 
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use crate::ideal_gas::{Nasa, Nasa9Range, NasaRecord};
+use feos_core::parameter::*;
+use feos_core::python::parameter::*;
+use feos_core::{impl_json_handling, impl_parameter, impl_pure_record};
+use numpy::{PyArray2, PyReadonlyArray2, ToPyArray};
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use std::convert::{TryFrom, TryInto};
+
+/// NASA polynomial parameters for a pure component.
+#[pyclass(name = "NasaRecord")]
+#[derive(Clone)]
+pub struct PyNasaRecord(pub NasaRecord);
+
+#[pymethods]
+impl PyNasaRecord {
+    /// Create a set of parameters for NASA 7-coefficient polynomials.
+    ///
+    /// Parameters
+    /// ----------
+    /// t_mid : float
+    ///     Common temperature of the low and high temperature ranges in K.
+    /// low : list[float]
+    ///     The 7 coefficients of the low temperature range.
+    /// high : list[float]
+    ///     The 7 coefficients of the high temperature range.
+    ///
+    /// Returns
+    /// -------
+    /// NasaRecord
+    #[staticmethod]
+    fn nasa7(t_mid: f64, low: [f64; 7], high: [f64; 7]) -> Self {
+        Self(NasaRecord::nasa7(t_mid, low, high))
+    }
+
+    /// Create a set of parameters for NASA 9-coefficient polynomials.
+    ///
+    /// Parameters
+    /// ----------
+    /// ranges : list[tuple[float, float, list[float]]]
+    ///     Lower and upper temperature in K and the 9 coefficients
+    ///     for each temperature range.
+    ///
+    /// Returns
+    /// -------
+    /// NasaRecord
+    #[staticmethod]
+    fn nasa9(ranges: Vec<(f64, f64, [f64; 9])>) -> Self {
+        Self(NasaRecord::nasa9(
+            ranges
+                .into_iter()
+                .map(|(t_min, t_max, coefs)| Nasa9Range::new(t_min, t_max, coefs))
+                .collect(),
+        ))
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+impl_json_handling!(PyNasaRecord);
+impl_pure_record!(NasaRecord, PyNasaRecord);
+
+/// Ideal gas model based on NASA polynomials.
+#[pyclass(name = "Nasa")]
+#[derive(Clone)]
+pub struct PyNasa(pub Arc<Nasa>);
+
+impl_parameter!(Nasa, PyNasa, PyNasaRecord);
+
+#[pymodule]
+pub fn nasa(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyIdentifier>()?;
+    m.add_class::<IdentifierOption>()?;
+    m.add_class::<PyNasaRecord>()?;
+    m.add_class::<PyPureRecord>()?;
+    m.add_class::<PyNasa>()
+}
@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use crate::ideal_gas::{Rrho, RrhoRecord};
+use feos_core::parameter::*;
+use feos_core::python::parameter::*;
+use feos_core::{impl_json_handling, impl_parameter, impl_pure_record};
+use numpy::{PyArray2, PyReadonlyArray2, ToPyArray};
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use std::convert::{TryFrom, TryInto};
+
+/// Molecular constants of a rigid rotor and harmonic oscillator.
+///
+/// Parameters
+/// ----------
+/// rotational_constants : list[float]
+///     Rotational constants in 1/cm (none for atoms, one for
+///     linear and three for nonlinear molecules).
+/// vibrational_wavenumbers : list[float]
+///     Wavenumbers of the vibrational modes in 1/cm.
+/// symmetry_number : float, optional
+///     Rotational symmetry number. Defaults to 1.
+/// electronic_degeneracy : float, optional
+///     Degeneracy of the electronic ground state. Defaults to 1.
+///
+/// Returns
+/// -------
+/// RrhoRecord
+#[pyclass(name = "RrhoRecord")]
+#[derive(Clone)]
+pub struct PyRrhoRecord(pub RrhoRecord);
+
+#[pymethods]
+impl PyRrhoRecord {
+    #[new]
+    #[pyo3(
+        signature = (rotational_constants, vibrational_wavenumbers, symmetry_number=1.0, electronic_degeneracy=1.0),
+        text_signature = "(rotational_constants, vibrational_wavenumbers, symmetry_number=1.0, electronic_degeneracy=1.0)"
+    )]
+    fn new(
+        rotational_constants: Vec<f64>,
+        vibrational_wavenumbers: Vec<f64>,
+        symmetry_number: f64,
+        electronic_degeneracy: f64,
+    ) -> Self {
+        Self(RrhoRecord::new(
+            rotational_constants,
+            vibrational_wavenumbers,
+            symmetry_number,
+            electronic_degeneracy,
+        ))
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+impl_json_handling!(PyRrhoRecord);
+impl_pure_record!(RrhoRecord, PyRrhoRecord);
+
+/// Ideal gas model of rigid rotors and harmonic oscillators.
+#[pyclass(name = "Rrho")]
+#[derive(Clone)]
+pub struct PyRrho(pub Arc<Rrho>);
+
+impl_parameter!(Rrho, PyRrho, PyRrhoRecord);
+
+#[pymodule]
+pub fn rrho(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyIdentifier>()?;
+    m.add_class::<IdentifierOption>()?;
+    m.add_class::<PyRrhoRecord>()?;
+    m.add_class::<PyPureRecord>()?;
+    m.add_class::<PyRrho>()
+}
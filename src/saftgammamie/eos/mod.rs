@@ -0,0 +1,277 @@
+use super::SaftGammaMieParameters;
+use crate::hard_sphere::HardSphere;
+use crate::saftvrmie::eos::association::Association;
+use crate::saftvrmie::eos::dispersion::{a_disp, ln_g_mie, Properties};
+use feos_core::parameter::ParameterHetero;
+use feos_core::{Components, Molarweight, Residual, StateHD};
+use ndarray::{Array1, ScalarOperand};
+use num_dual::DualNum;
+use quantity::{MolarWeight, GRAM, MOL};
+use std::{f64::consts::FRAC_PI_6, sync::Arc};
+
+/// Customization options for the SAFT-$\gamma$ Mie equation of state.
+#[derive(Copy, Clone)]
+pub struct SaftGammaMieOptions {
+    /// maximum packing fraction
+    pub max_eta: f64,
+    /// maximum number of iterations for cross association calculation
+    pub max_iter_cross_assoc: usize,
+    /// tolerance for cross association calculation
+    pub tol_cross_assoc: f64,
+}
+
+impl Default for SaftGammaMieOptions {
+    fn default() -> Self {
+        Self {
+            max_eta: 0.5,
+            max_iter_cross_assoc: 50,
+            tol_cross_assoc: 1e-10,
+        }
+    }
+}
+
+/// SAFT-$\gamma$ Mie equation of state.
+pub struct SaftGammaMie {
+    pub parameters: Arc<SaftGammaMieParameters>,
+    options: SaftGammaMieOptions,
+    hard_sphere: HardSphere<SaftGammaMieParameters>,
+    chain: bool,
+    association: Option<Association<SaftGammaMieParameters>>,
+}
+
+impl SaftGammaMie {
+    pub fn new(parameters: Arc<SaftGammaMieParameters>) -> Self {
+        Self::with_options(parameters, SaftGammaMieOptions::default())
+    }
+
+    pub fn with_options(
+        parameters: Arc<SaftGammaMieParameters>,
+        options: SaftGammaMieOptions,
+    ) -> Self {
+        let association = if !parameters.association.is_empty() {
+            Some(Association::new(
+                &parameters,
+                &parameters.association,
+                options.max_iter_cross_assoc,
+                options.tol_cross_assoc,
+            ))
+        } else {
+            None
+        };
+        Self {
+            parameters: parameters.clone(),
+            options,
+            hard_sphere: HardSphere::new(&parameters),
+            chain: parameters.m_mix.iter().any(|&m| m > 1.0),
+            association,
+        }
+    }
+}
+
+impl Components for SaftGammaMie {
+    fn components(&self) -> usize {
+        self.parameters.molarweight.len()
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        Self::with_options(
+            Arc::new(self.parameters.subset(component_list)),
+            self.options,
+        )
+    }
+}
+
+impl Residual for SaftGammaMie {
+    fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
+        let p = &self.parameters;
+        let moles_segments: Array1<f64> = p.component_index.iter().map(|&i| moles[i]).collect();
+        self.options.max_eta * moles.sum()
+            / (FRAC_PI_6 * &p.m * p.sigma.mapv(|v| v.powi(3)) * moles_segments).sum()
+    }
+
+    fn residual_helmholtz_energy_contributions<D: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        state: &StateHD<D>,
+    ) -> Vec<(String, D)> {
+        let mut a = Vec::with_capacity(7);
+
+        let (a_hs, _, d) = self.hard_sphere.helmholtz_energy_and_properties(state);
+        a.push(("Hard Sphere".to_string(), a_hs));
+
+        let properties = Properties::new(&*self.parameters, state, &d);
+        a.push((
+            "Dispersion".to_string(),
+            a_disp(&*self.parameters, &properties, state),
+        ));
+        if self.chain {
+            a.push(("Chain".to_string(), self.a_chain(&properties, state, &d)));
+        }
+        if let Some(assoc) = self.association.as_ref() {
+            a.push(("Association".to_string(), assoc.helmholtz_energy(state, &d)));
+        }
+        a
+    }
+}
+
+impl SaftGammaMie {
+    /// Chain contribution with the molecular averages of the group parameters.
+    fn a_chain<D: DualNum<f64> + Copy>(
+        &self,
+        properties: &Properties<D>,
+        state: &StateHD<D>,
+        diameter: &Array1<D>,
+    ) -> D {
+        let p = &self.parameters;
+        let n = p.m_mix.len();
+        let t_inv = state.temperature.recip();
+
+        // molecular averages of the hard-sphere diameters
+        let mut d3_mix = Array1::from_elem(n, D::zero());
+        for (k, &i) in p.component_index.iter().enumerate() {
+            for (l, &j) in p.component_index.iter().enumerate() {
+                if i == j {
+                    let d_kl = (diameter[k] + diameter[l]) * 0.5;
+                    d3_mix[i] += d_kl.powi(3) * (p.z_k[k] * p.z_k[l]);
+                }
+            }
+        }
+
+        let mut a_chain = D::zero();
+        for i in 0..n {
+            let ln_g = ln_g_mie(
+                properties,
+                t_inv,
+                d3_mix[i].cbrt(),
+                p.sigma_mix[i],
+                p.epsilon_k_mix[i],
+                p.lr_mix[i],
+                p.la_mix[i],
+            );
+            a_chain -= state.moles[i] * (p.m_mix[i] - 1.0) * ln_g;
+        }
+        a_chain
+    }
+}
+
+impl Molarweight for SaftGammaMie {
+    fn molar_weight(&self) -> MolarWeight<Array1<f64>> {
+        self.parameters.molarweight.clone() * GRAM / MOL
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::saftgammamie::parameters::utils::*;
+    use crate::saftgammamie::SaftGammaMieRecord;
+    use crate::saftvrmie::{test_utils::test_parameters, SaftVRMie};
+    use approx::assert_relative_eq;
+    use feos_core::parameter::{ChemicalRecord, Identifier, SegmentRecord};
+    use ndarray::arr1;
+
+    fn single_group(name: &str, molarweight: f64, record: SaftGammaMieRecord) -> SaftGammaMie {
+        let chemical_record = ChemicalRecord::new(
+            Identifier::new(None, Some(name), None, None, None, None),
+            vec![name.into()],
+            None,
+        );
+        let segment_record = SegmentRecord::new(name.into(), molarweight, record);
+        let parameters = SaftGammaMieParameters::from_segments(
+            vec![chemical_record],
+            vec![segment_record],
+            None,
+        )
+        .unwrap();
+        SaftGammaMie::new(Arc::new(parameters))
+    }
+
+    fn assert_same_helmholtz_energy(saftvrmie: &SaftVRMie, saftgammamie: &SaftGammaMie) {
+        let t = 250.0;
+        let v = 1000.0;
+        let n = 1.5;
+        let s = StateHD::new(t, v, arr1(&[n]));
+        let a_vr: f64 = saftvrmie
+            .residual_helmholtz_energy_contributions(&s)
+            .iter()
+            .map(|(_, a)| a)
+            .sum();
+        let a_gamma: f64 = saftgammamie
+            .residual_helmholtz_energy_contributions(&s)
+            .iter()
+            .map(|(_, a)| a)
+            .sum();
+        assert_relative_eq!(a_vr, a_gamma, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn single_group_is_saftvrmie() {
+        let saftvrmie = SaftVRMie::new(Arc::new(test_parameters().remove("ethane").unwrap()));
+        let saftgammamie = single_group(
+            "ethane",
+            30.07,
+            SaftGammaMieRecord::new_simple(1.0, 1.4373, 3.7257, 206.12, 12.4, 6.0),
+        );
+        assert_same_helmholtz_energy(&saftvrmie, &saftgammamie);
+    }
+
+    #[test]
+    fn single_associating_group_is_saftvrmie() {
+        let saftvrmie = SaftVRMie::new(Arc::new(test_parameters().remove("methanol").unwrap()));
+        let saftgammamie = single_group(
+            "methanol",
+            32.026,
+            SaftGammaMieRecord::new(
+                1.0,
+                1.5283,
+                3.3063,
+                167.72,
+                8.6556,
+                6.0,
+                Some(0.41314),
+                Some(2904.7),
+                Some(1.0),
+                Some(1.0),
+                None,
+            ),
+        );
+        assert_same_helmholtz_energy(&saftvrmie, &saftgammamie);
+    }
+
+    #[test]
+    fn identical_segments() {
+        // two identical segments in a group are equivalent to a group that appears twice
+        let (mut groups, _) = alkane_groups();
+        let ch2 = groups.pop().unwrap();
+        let mut double_ch2 = ch2.clone();
+        double_ch2.identifier = "(CH2)2".into();
+        double_ch2.model_record.vk = 2.0;
+        let cr = |segments: Vec<&str>| {
+            ChemicalRecord::new(
+                Identifier::default(),
+                segments.into_iter().map(String::from).collect(),
+                None,
+            )
+        };
+        let p1 = SaftGammaMieParameters::from_segments(
+            vec![cr(vec!["CH2", "CH2"])],
+            vec![ch2.clone()],
+            None,
+        )
+        .unwrap();
+        let p2 =
+            SaftGammaMieParameters::from_segments(vec![cr(vec!["(CH2)2"])], vec![double_ch2], None)
+                .unwrap();
+        let s = StateHD::new(300.0, 500.0, arr1(&[1.0]));
+        let a1: f64 = SaftGammaMie::new(Arc::new(p1))
+            .residual_helmholtz_energy_contributions(&s)
+            .iter()
+            .map(|(_, a)| a)
+            .sum();
+        let a2: f64 = SaftGammaMie::new(Arc::new(p2))
+            .residual_helmholtz_energy_contributions(&s)
+            .iter()
+            .map(|(_, a)| a)
+            .sum();
+        assert_relative_eq!(a1, a2, max_relative = 1e-12);
+    }
+}
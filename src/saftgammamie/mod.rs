@@ -0,0 +1,15 @@
+//! Statistical Associating Fluid Theory for Mie potentials of variable range
+//! in a group-contribution formulation (SAFT-$\gamma$ Mie)
+//!
+//! Molecules are built from heteronuclear groups of fused Mie segments.
+//! The dispersion and association contributions are evaluated on the group level
+//! using the implementation of [SAFT-VR Mie](crate::saftvrmie); the chain contribution
+//! uses molecular averages of the group parameters.
+//!
+//! - [Papaioannou et al. (2014)](https://doi.org/10.1063/1.4851455)
+//! - [Dufal et al. (2014)](https://doi.org/10.1021/je500248h)
+mod eos;
+mod parameters;
+
+pub use eos::{SaftGammaMie, SaftGammaMieOptions};
+pub use parameters::{SaftGammaMieBinaryRecord, SaftGammaMieParameters, SaftGammaMieRecord};
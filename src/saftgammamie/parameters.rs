@@ -0,0 +1,610 @@
+use crate::hard_sphere::{HardSphereProperties, MonomerShape};
+use crate::saftvrmie::eos::association::{
+    AssociationParameters, AssociationRecord, BinaryAssociationRecord,
+};
+use crate::saftvrmie::eos::dispersion::MieParameters;
+use crate::saftvrmie::parameters::hs_diameter_mie;
+use feos_core::parameter::{
+    BinaryRecord, ChemicalRecord, ParameterError, ParameterHetero, SegmentCount, SegmentRecord,
+};
+use indexmap::IndexMap;
+use ndarray::{Array1, Array2};
+use num_dual::DualNum;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+fn one() -> f64 {
+    1.0
+}
+
+fn is_one(x: &f64) -> bool {
+    *x == 1.0
+}
+
+/// SAFT-$\gamma$ Mie group parameters.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SaftGammaMieRecord {
+    /// Number of identical spherical segments in the group
+    #[serde(default = "one")]
+    #[serde(skip_serializing_if = "is_one")]
+    pub vk: f64,
+    /// Shape factor of the segments
+    pub shape_factor: f64,
+    /// Segment diameter in units of Angstrom
+    pub sigma: f64,
+    /// Energetic parameter in units of Kelvin
+    pub epsilon_k: f64,
+    /// Repulsive Mie exponent
+    pub lr: f64,
+    /// Attractive Mie exponent
+    pub la: f64,
+    /// Association
+    #[serde(flatten)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub association_record: Option<AssociationRecord>,
+}
+
+impl SaftGammaMieRecord {
+    #[expect(clippy::too_many_arguments)]
+    pub fn new(
+        vk: f64,
+        shape_factor: f64,
+        sigma: f64,
+        epsilon_k: f64,
+        lr: f64,
+        la: f64,
+        rc_ab: Option<f64>,
+        epsilon_k_ab: Option<f64>,
+        na: Option<f64>,
+        nb: Option<f64>,
+        nc: Option<f64>,
+    ) -> Self {
+        let association_record = if rc_ab.is_none()
+            && epsilon_k_ab.is_none()
+            && na.is_none()
+            && nb.is_none()
+            && nc.is_none()
+        {
+            None
+        } else {
+            Some(AssociationRecord::new(
+                rc_ab.unwrap_or_default(),
+                epsilon_k_ab.unwrap_or_default(),
+                na.unwrap_or_default(),
+                nb.unwrap_or_default(),
+                nc.unwrap_or_default(),
+            ))
+        };
+        Self {
+            vk,
+            shape_factor,
+            sigma,
+            epsilon_k,
+            lr,
+            la,
+            association_record,
+        }
+    }
+
+    /// Create a record for a non-associating group.
+    pub fn new_simple(
+        vk: f64,
+        shape_factor: f64,
+        sigma: f64,
+        epsilon_k: f64,
+        lr: f64,
+        la: f64,
+    ) -> Self {
+        Self::new(
+            vk,
+            shape_factor,
+            sigma,
+            epsilon_k,
+            lr,
+            la,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+}
+
+impl std::fmt::Display for SaftGammaMieRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SaftGammaMieRecord(vk={}", self.vk)?;
+        write!(f, ", shape_factor={}", self.shape_factor)?;
+        write!(f, ", sigma={}", self.sigma)?;
+        write!(f, ", epsilon_k={}", self.epsilon_k)?;
+        write!(f, ", lr={}", self.lr)?;
+        write!(f, ", la={}", self.la)?;
+        if let Some(n) = &self.association_record {
+            write!(f, ", association_record={}", n)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// SAFT-$\gamma$ Mie group-group interaction parameters.
+///
+/// Parameters that are not specified are obtained from the
+/// combining rules.
+#[derive(Serialize, Deserialize, Clone, Copy, Default)]
+pub struct SaftGammaMieBinaryRecord {
+    /// Unlike dispersion energy in units of Kelvin
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub epsilon_k: Option<f64>,
+    /// Unlike repulsive Mie exponent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lr: Option<f64>,
+    /// Binary association parameters
+    #[serde(flatten)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub association: Option<BinaryAssociationRecord>,
+}
+
+impl SaftGammaMieBinaryRecord {
+    pub fn new(
+        epsilon_k: Option<f64>,
+        lr: Option<f64>,
+        rc_ab: Option<f64>,
+        epsilon_k_ab: Option<f64>,
+        site_indices: Option<[usize; 2]>,
+    ) -> Self {
+        let association = if rc_ab.is_none() && epsilon_k_ab.is_none() && site_indices.is_none() {
+            None
+        } else {
+            Some(BinaryAssociationRecord::new(
+                rc_ab,
+                epsilon_k_ab,
+                site_indices,
+            ))
+        };
+        Self {
+            epsilon_k,
+            lr,
+            association,
+        }
+    }
+}
+
+impl std::fmt::Display for SaftGammaMieBinaryRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut tokens = vec![];
+        if let Some(epsilon_k) = self.epsilon_k {
+            tokens.push(format!("epsilon_k={}", epsilon_k));
+        }
+        if let Some(lr) = self.lr {
+            tokens.push(format!("lr={}", lr));
+        }
+        if let Some(association) = self.association {
+            if let Some(rc_ab) = association.rc_ab {
+                tokens.push(format!("rc_ab={}", rc_ab));
+            }
+            if let Some(epsilon_k_ab) = association.epsilon_k_ab {
+                tokens.push(format!("epsilon_k_ab={}", epsilon_k_ab));
+            }
+        }
+        write!(f, "SaftGammaMieBinaryRecord({})", tokens.join(", "))
+    }
+}
+
+/// Parameter set required for the SAFT-$\gamma$ Mie equation of state.
+///
+/// Every group on every molecule is represented by a segment with
+/// the segment number $m_{ki}=\nu_{ki}\nu_k^*S_k$.
+pub struct SaftGammaMieParameters {
+    pub molarweight: Array1<f64>,
+    pub component_index: Array1<usize>,
+    identifiers: Vec<String>,
+    counts: Vec<f64>,
+
+    pub m: Array1<f64>,
+    pub sigma: Array1<f64>,
+    pub epsilon_k: Array1<f64>,
+    pub lr: Array1<f64>,
+    pub la: Array1<f64>,
+
+    pub sigma_ij: Array2<f64>,
+    pub epsilon_k_ij: Array2<f64>,
+    pub lr_ij: Array2<f64>,
+    pub la_ij: Array2<f64>,
+    pub c_ij: Array2<f64>,
+    pub alpha_ij: Array2<f64>,
+
+    /// Number of segments of every molecule
+    pub m_mix: Array1<f64>,
+    /// Fraction of the segments of a molecule that belong to a segment type
+    pub z_k: Array1<f64>,
+    /// Averaged molecular diameter used in the chain contribution
+    pub sigma_mix: Array1<f64>,
+    /// Averaged molecular energy parameter used in the chain contribution
+    pub epsilon_k_mix: Array1<f64>,
+    /// Averaged molecular repulsive exponent used in the chain contribution
+    pub lr_mix: Array1<f64>,
+    /// Averaged molecular attractive exponent used in the chain contribution
+    pub la_mix: Array1<f64>,
+
+    pub association: AssociationParameters,
+
+    pub chemical_records: Vec<ChemicalRecord>,
+    segment_records: Vec<SegmentRecord<SaftGammaMieRecord>>,
+    binary_segment_records: Option<Vec<BinaryRecord<String, SaftGammaMieBinaryRecord>>>,
+}
+
+impl ParameterHetero for SaftGammaMieParameters {
+    type Chemical = ChemicalRecord;
+    type Pure = SaftGammaMieRecord;
+    type Binary = SaftGammaMieBinaryRecord;
+
+    fn from_segments<C: Clone + Into<ChemicalRecord>>(
+        chemical_records: Vec<C>,
+        segment_records: Vec<SegmentRecord<SaftGammaMieRecord>>,
+        binary_segment_records: Option<Vec<BinaryRecord<String, SaftGammaMieBinaryRecord>>>,
+    ) -> Result<Self, ParameterError> {
+        let chemical_records: Vec<ChemicalRecord> =
+            chemical_records.into_iter().map(|c| c.into()).collect();
+
+        let mut molarweight = Array1::zeros(chemical_records.len());
+        let mut component_index = Vec::new();
+        let mut identifiers = Vec::new();
+        let mut counts = Vec::new();
+        let mut m = Vec::new();
+        let mut sigma = Vec::new();
+        let mut epsilon_k = Vec::new();
+        let mut lr = Vec::new();
+        let mut la = Vec::new();
+        let mut association_records = Vec::new();
+        let mut m_mix = Array1::zeros(chemical_records.len());
+
+        for (i, chemical_record) in chemical_records.iter().enumerate() {
+            let segment_map = chemical_record.segment_map(&segment_records)?;
+            for (segment, &count) in segment_map.iter() {
+                let count = count as f64;
+                let r = &segment.model_record;
+                molarweight[i] += segment.molarweight * count;
+
+                component_index.push(i);
+                identifiers.push(segment.identifier.clone());
+                counts.push(count);
+                m.push(count * r.vk * r.shape_factor);
+                sigma.push(r.sigma);
+                epsilon_k.push(r.epsilon_k);
+                lr.push(r.lr);
+                la.push(r.la);
+                m_mix[i] += count * r.vk * r.shape_factor;
+
+                let mut assoc = r.association_record;
+                if let Some(assoc) = assoc.as_mut() {
+                    assoc.na *= count;
+                    assoc.nb *= count;
+                    assoc.nc *= count;
+                };
+                association_records.push(assoc.into_iter().collect());
+            }
+        }
+        let n = m.len();
+
+        // Unlike group-group interactions
+        let mut binary_segment_records_map = IndexMap::new();
+        if let Some(binary_segment_records) = binary_segment_records.as_ref() {
+            for binary_record in binary_segment_records {
+                binary_segment_records_map.insert(
+                    (binary_record.id1.clone(), binary_record.id2.clone()),
+                    binary_record.model_record,
+                );
+                binary_segment_records_map.insert(
+                    (binary_record.id2.clone(), binary_record.id1.clone()),
+                    binary_record.model_record,
+                );
+            }
+        }
+        let binary: Array2<Option<SaftGammaMieBinaryRecord>> =
+            Array2::from_shape_fn([n; 2], |(i, j)| {
+                binary_segment_records_map
+                    .get(&(identifiers[i].clone(), identifiers[j].clone()))
+                    .copied()
+            });
+
+        // Combining rules dispersion
+        let sigma_ij = Array2::from_shape_fn([n; 2], |(i, j)| 0.5 * (sigma[i] + sigma[j]));
+        let epsilon_k_ij = Array2::from_shape_fn([n; 2], |(i, j)| {
+            binary[[i, j]].and_then(|b| b.epsilon_k).unwrap_or_else(|| {
+                (sigma[i].powi(3) * sigma[j].powi(3)).sqrt() / sigma_ij[[i, j]].powi(3)
+                    * (epsilon_k[i] * epsilon_k[j]).sqrt()
+            })
+        });
+        let lr_ij = Array2::from_shape_fn([n; 2], |(i, j)| {
+            binary[[i, j]]
+                .and_then(|b| b.lr)
+                .unwrap_or_else(|| ((lr[i] - 3.0) * (lr[j] - 3.0)).sqrt() + 3.0)
+        });
+        let la_ij = Array2::from_shape_fn([n; 2], |(i, j)| {
+            ((la[i] - 3.0) * (la[j] - 3.0)).sqrt() + 3.0
+        });
+        let c_ij = Array2::from_shape_fn([n; 2], |(i, j)| {
+            let (lr, la) = (lr_ij[[i, j]], la_ij[[i, j]]);
+            lr / (lr - la) * (lr / la).powf(la / (lr - la))
+        });
+        let alpha_ij = Array2::from_shape_fn([n; 2], |(i, j)| {
+            c_ij[[i, j]] * ((la_ij[[i, j]] - 3.0).recip() - (lr_ij[[i, j]] - 3.0).recip())
+        });
+
+        // Molecular averages for the chain contribution
+        let z_k = Array1::from_shape_fn(n, |k| m[k] / m_mix[component_index[k]]);
+        let mut sigma3_mix = Array1::zeros(chemical_records.len());
+        let mut epsilon_k_mix = Array1::zeros(chemical_records.len());
+        let mut lr_mix = Array1::zeros(chemical_records.len());
+        let mut la_mix = Array1::zeros(chemical_records.len());
+        for k in 0..n {
+            for l in 0..n {
+                let i = component_index[k];
+                if i == component_index[l] {
+                    let z = z_k[k] * z_k[l];
+                    sigma3_mix[i] += z * sigma_ij[[k, l]].powi(3);
+                    epsilon_k_mix[i] += z * epsilon_k_ij[[k, l]];
+                    lr_mix[i] += z * lr_ij[[k, l]];
+                    la_mix[i] += z * la_ij[[k, l]];
+                }
+            }
+        }
+
+        // Association
+        let binary_association: Vec<_> = binary
+            .indexed_iter()
+            .filter_map(|(ij, b)| b.and_then(|b| b.association).map(|a| (ij, a)))
+            .collect();
+        let sigma = Array1::from_vec(sigma);
+        let component_index = Array1::from_vec(component_index);
+        let association = AssociationParameters::new(
+            &association_records,
+            &sigma,
+            &binary_association,
+            Some(&component_index),
+        );
+
+        Ok(Self {
+            molarweight,
+            component_index,
+            identifiers,
+            counts,
+            m: Array1::from_vec(m),
+            sigma,
+            epsilon_k: Array1::from_vec(epsilon_k),
+            lr: Array1::from_vec(lr),
+            la: Array1::from_vec(la),
+            sigma_ij,
+            epsilon_k_ij,
+            lr_ij,
+            la_ij,
+            c_ij,
+            alpha_ij,
+            m_mix,
+            z_k,
+            sigma_mix: sigma3_mix.mapv(f64::cbrt),
+            epsilon_k_mix,
+            lr_mix,
+            la_mix,
+            association,
+            chemical_records,
+            segment_records,
+            binary_segment_records,
+        })
+    }
+
+    fn records(
+        &self,
+    ) -> (
+        &[Self::Chemical],
+        &[SegmentRecord<Self::Pure>],
+        &Option<Vec<BinaryRecord<String, Self::Binary>>>,
+    ) {
+        (
+            &self.chemical_records,
+            &self.segment_records,
+            &self.binary_segment_records,
+        )
+    }
+}
+
+impl SaftGammaMieParameters {
+    /// Temperature dependent hard-sphere diameters of all segment pairs.
+    pub fn hs_diameter_ij<D: DualNum<f64> + Copy>(
+        &self,
+        i: usize,
+        j: usize,
+        inverse_temperature: D,
+    ) -> D {
+        hs_diameter_mie(
+            self.sigma_ij[[i, j]],
+            self.epsilon_k_ij[[i, j]],
+            self.lr_ij[[i, j]],
+            self.la_ij[[i, j]],
+            self.c_ij[[i, j]],
+            inverse_temperature,
+        )
+    }
+}
+
+impl HardSphereProperties for SaftGammaMieParameters {
+    fn monomer_shape<N: DualNum<f64>>(&self, _: N) -> MonomerShape<N> {
+        let m = self.m.mapv(N::from);
+        MonomerShape::Heterosegmented([m.clone(), m.clone(), m.clone(), m], &self.component_index)
+    }
+
+    fn hs_diameter<D: DualNum<f64> + Copy>(&self, temperature: D) -> Array1<D> {
+        let t_inv = temperature.recip();
+        Array1::from_shape_fn(self.m.len(), |i| self.hs_diameter_ij(i, i, t_inv))
+    }
+}
+
+impl MieParameters for SaftGammaMieParameters {
+    fn segment_number(&self) -> &Array1<f64> {
+        &self.m
+    }
+
+    fn sigma_ij(&self) -> &Array2<f64> {
+        &self.sigma_ij
+    }
+
+    fn epsilon_k_ij(&self) -> &Array2<f64> {
+        &self.epsilon_k_ij
+    }
+
+    fn lr_ij(&self) -> &Array2<f64> {
+        &self.lr_ij
+    }
+
+    fn la_ij(&self) -> &Array2<f64> {
+        &self.la_ij
+    }
+
+    fn c_ij(&self) -> &Array2<f64> {
+        &self.c_ij
+    }
+
+    fn alpha_ij(&self) -> &Array2<f64> {
+        &self.alpha_ij
+    }
+}
+
+impl SaftGammaMieParameters {
+    pub fn to_markdown(&self) -> String {
+        let group_dict: HashMap<&String, &SaftGammaMieRecord> = self
+            .segment_records
+            .iter()
+            .map(|r| (&r.identifier, &r.model_record))
+            .collect();
+
+        let mut output = String::new();
+        let o = &mut output;
+        write!(
+            o,
+            "|component|molarweight|group|count|$\\nu^*$|$S$|$\\sigma$|$\\varepsilon$|$\\lambda_r$|$\\lambda_a$|$r^c_{{AB}}$|$\\varepsilon_{{AB}}$|$N_A$|$N_B$|$N_C$|\n|-|-|-|-|-|-|-|-|-|-|-|-|-|-|-|"
+        )
+        .unwrap();
+        for i in 0..self.m.len() {
+            let component = if i > 0 && self.component_index[i] == self.component_index[i - 1] {
+                "|".to_string()
+            } else {
+                let pure = &self.chemical_records[self.component_index[i]].identifier;
+                format!(
+                    "{}|{}",
+                    pure.name
+                        .as_ref()
+                        .unwrap_or(&format!("Component {}", self.component_index[i] + 1)),
+                    self.molarweight[self.component_index[i]],
+                )
+            };
+            let record = group_dict[&self.identifiers[i]];
+            let association = if let Some(a) = record.association_record {
+                format!("{}|{}|{}|{}|{}", a.rc_ab, a.epsilon_k_ab, a.na, a.nb, a.nc)
+            } else {
+                "||||".to_string()
+            };
+            write!(
+                o,
+                "\n|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|",
+                component,
+                self.identifiers[i],
+                self.counts[i],
+                record.vk,
+                record.shape_factor,
+                record.sigma,
+                record.epsilon_k,
+                record.lr,
+                record.la,
+                association
+            )
+            .unwrap();
+        }
+        output
+    }
+}
+
+impl std::fmt::Display for SaftGammaMieParameters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SaftGammaMieParameters(")?;
+        write!(f, "\n\tmolarweight={}", self.molarweight)?;
+        write!(f, "\n\tcomponent_index={}", self.component_index)?;
+        write!(f, "\n\tm={}", self.m)?;
+        write!(f, "\n\tsigma={}", self.sigma)?;
+        write!(f, "\n\tepsilon_k={}", self.epsilon_k)?;
+        write!(f, "\n\tlr={}", self.lr)?;
+        write!(f, "\n\tla={}", self.la)?;
+        write!(f, "\n)")
+    }
+}
+
+#[cfg(test)]
+pub mod utils {
+    use super::*;
+    use feos_core::parameter::Identifier;
+
+    /// CH3 and CH2 groups from Papaioannou et al. (2014).
+    pub fn alkane_groups() -> (
+        Vec<SegmentRecord<SaftGammaMieRecord>>,
+        Vec<BinaryRecord<String, SaftGammaMieBinaryRecord>>,
+    ) {
+        let ch3 = SegmentRecord::new(
+            "CH3".into(),
+            15.035,
+            SaftGammaMieRecord::new_simple(1.0, 0.57255, 4.0772, 256.77, 15.050, 6.0),
+        );
+        let ch2 = SegmentRecord::new(
+            "CH2".into(),
+            14.027,
+            SaftGammaMieRecord::new_simple(1.0, 0.22932, 4.8801, 473.39, 19.871, 6.0),
+        );
+        let binary = BinaryRecord::new(
+            "CH3".into(),
+            "CH2".into(),
+            SaftGammaMieBinaryRecord::new(Some(350.77), None, None, None, None),
+        );
+        (vec![ch3, ch2], vec![binary])
+    }
+
+    pub fn alkane(name: &str, n_ch2: usize) -> ChemicalRecord {
+        let mut segments = vec!["CH3".to_string()];
+        segments.extend(std::iter::repeat_n("CH2".to_string(), n_ch2));
+        segments.push("CH3".to_string());
+        ChemicalRecord::new(
+            Identifier::new(None, Some(name), None, None, None, None),
+            segments,
+            None,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::utils::*;
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn molecular_averages() {
+        let (groups, binary) = alkane_groups();
+        let p = SaftGammaMieParameters::from_segments(
+            vec![alkane("n-hexane", 4)],
+            groups,
+            Some(binary),
+        )
+        .unwrap();
+        let m = 2.0 * 0.57255 + 4.0 * 0.22932;
+        assert_relative_eq!(p.m_mix[0], m);
+        assert_relative_eq!(p.molarweight[0], 86.178, epsilon = 1e-10);
+        assert_relative_eq!(p.z_k.sum(), 1.0, epsilon = 1e-14);
+
+        let z3 = 2.0 * 0.57255 / m;
+        let z2 = 4.0 * 0.22932 / m;
+        let epsilon_k = z3 * z3 * 256.77 + z2 * z2 * 473.39 + 2.0 * z2 * z3 * 350.77;
+        assert_relative_eq!(p.epsilon_k_mix[0], epsilon_k, epsilon = 1e-10);
+        let sigma3 = z3 * z3 * 4.0772f64.powi(3)
+            + z2 * z2 * 4.8801f64.powi(3)
+            + 2.0 * z2 * z3 * (0.5 * (4.0772 + 4.8801f64)).powi(3);
+        assert_relative_eq!(p.sigma_mix[0], sigma3.cbrt(), epsilon = 1e-10);
+    }
+}
@@ -6,6 +6,54 @@ use num_dual::{Dual, DualNum};
 use num_traits::Zero;
 
 use super::SaftVRMieParameters;
+use crate::hard_sphere::HardSphereProperties;
+
+/// Segment parameters required for the dispersion contribution of
+/// Mie fluids.
+///
+/// Segments are either whole molecules (SAFT-VR Mie) or groups
+/// on a molecule (SAFT-$\gamma$ Mie). The index of the molecule a segment
+/// belongs to is provided by [HardSphereProperties::component_index].
+pub(crate) trait MieParameters: HardSphereProperties {
+    /// Number of (fused) spherical segments for every segment type.
+    fn segment_number(&self) -> &Array1<f64>;
+    fn sigma_ij(&self) -> &Array2<f64>;
+    fn epsilon_k_ij(&self) -> &Array2<f64>;
+    fn lr_ij(&self) -> &Array2<f64>;
+    fn la_ij(&self) -> &Array2<f64>;
+    fn c_ij(&self) -> &Array2<f64>;
+    fn alpha_ij(&self) -> &Array2<f64>;
+}
+
+impl MieParameters for SaftVRMieParameters {
+    fn segment_number(&self) -> &Array1<f64> {
+        &self.m
+    }
+
+    fn sigma_ij(&self) -> &Array2<f64> {
+        &self.sigma_ij
+    }
+
+    fn epsilon_k_ij(&self) -> &Array2<f64> {
+        &self.epsilon_k_ij
+    }
+
+    fn lr_ij(&self) -> &Array2<f64> {
+        &self.lr_ij
+    }
+
+    fn la_ij(&self) -> &Array2<f64> {
+        &self.la_ij
+    }
+
+    fn c_ij(&self) -> &Array2<f64> {
+        &self.c_ij
+    }
+
+    fn alpha_ij(&self) -> &Array2<f64> {
+        &self.alpha_ij
+    }
+}
 
 #[derive(Debug)]
 pub struct Properties<D> {
@@ -26,19 +74,23 @@ pub struct Properties<D> {
 }
 
 impl<D: DualNum<f64> + Copy + Zero + ScalarOperand> Properties<D> {
-    pub(super) fn new(
-        parameters: &SaftVRMieParameters,
+    pub(crate) fn new<P: MieParameters>(
+        parameters: &P,
         state: &StateHD<D>,
         diameter: &Array1<D>,
     ) -> Self {
-        let n = parameters.m.len();
-        let x = &state.molefracs;
+        let m = parameters.segment_number();
+        let sigma_ij = parameters.sigma_ij();
+        let component_index = parameters.component_index();
+        let n = m.len();
+        let x = Array1::from_shape_fn(n, |i| state.molefracs[component_index[i]]);
+        let rho = Array1::from_shape_fn(n, |i| state.partial_density[component_index[i]]);
 
-        let mean_segment_number = (x * &parameters.m).sum();
-        let xs = x * &parameters.m / mean_segment_number;
+        let mean_segment_number = (&x * m).sum();
+        let xs = x * m / mean_segment_number;
 
         // Set eps to one -> get partial derivatives w.r.t segment density
-        let segment_density = (&state.partial_density * &parameters.m).sum();
+        let segment_density = (rho * m).sum();
 
         // diameter
         let d_ij = Array2::from_shape_fn((n, n), |(i, j)| (diameter[i] + diameter[j]) * 0.5);
@@ -49,10 +101,10 @@ impl<D: DualNum<f64> + Copy + Zero + ScalarOperand> Properties<D> {
         let mut zeta_x_bar = D::zero();
         for i in 0..n {
             zeta_x += xs[i].powi(2) * d3_ij[[i, i]];
-            zeta_x_bar += xs[i].powi(2) * parameters.sigma_ij[[i, i]].powi(3);
+            zeta_x_bar += xs[i].powi(2) * sigma_ij[[i, i]].powi(3);
             for j in i + 1..n {
                 zeta_x += xs[i] * xs[j] * d3_ij[[i, j]] * 2.0;
-                zeta_x_bar += xs[i] * xs[j] * parameters.sigma_ij[[i, j]].powi(3) * 2.0;
+                zeta_x_bar += xs[i] * xs[j] * sigma_ij[[i, j]].powi(3) * 2.0;
             }
         }
         zeta_x *= segment_density * FRAC_PI_6;
@@ -98,13 +150,15 @@ pub(super) const PHI: [[f64; 7]; 6] = [
 ];
 
 /// First, second and third order perturbations for dispersive interactions
-pub fn a_disp<D: DualNum<f64> + Copy + ScalarOperand>(
-    parameters: &SaftVRMieParameters,
+pub(crate) fn a_disp<D: DualNum<f64> + Copy + ScalarOperand, P: MieParameters>(
+    parameters: &P,
     properties: &Properties<D>,
     state: &StateHD<D>,
 ) -> D {
-    let p = &parameters;
-    let n = p.sigma.len();
+    let (sigma_ij, epsilon_k_ij) = (parameters.sigma_ij(), parameters.epsilon_k_ij());
+    let (lr_ij, la_ij) = (parameters.lr_ij(), parameters.la_ij());
+    let (c_ij, alpha_ij) = (parameters.c_ij(), parameters.alpha_ij());
+    let n = sigma_ij.nrows();
     let xs = &properties.segment_molefracs;
     let t_inv = state.temperature.inv();
     let zeta_x = properties.zeta_x;
@@ -120,11 +174,11 @@ pub fn a_disp<D: DualNum<f64> + Copy + ScalarOperand>(
 
     for i in 0..n {
         // parameters
-        let eps_k = p.epsilon_k[i];
-        let sig = p.sigma[i];
-        let la = p.la[i];
-        let lr = p.lr[i];
-        let c = p.c_ij[[i, i]];
+        let eps_k = epsilon_k_ij[[i, i]];
+        let sig = sigma_ij[[i, i]];
+        let la = la_ij[[i, i]];
+        let lr = lr_ij[[i, i]];
+        let c = c_ij[[i, i]];
 
         let di = properties.diameter[i];
         let d3 = di.powi(3);
@@ -139,7 +193,7 @@ pub fn a_disp<D: DualNum<f64> + Copy + ScalarOperand>(
         let a2_ii = pref * eps_k * c * k_hs * 0.5 * (a1s_b_2la - a1s_b_lalr * 2.0 + a1s_b_2lr);
 
         // note indices of f(i, alpha) are shifted due to 0-indexing.
-        let alpha = parameters.alpha_ij[[i, i]];
+        let alpha = alpha_ij[[i, i]];
         let a3_ii = -zeta_x_bar
             * f(3, alpha)
             * (zeta_x_bar * (zeta_x_bar * f(5, alpha) + f(4, alpha))).exp()
@@ -155,11 +209,11 @@ pub fn a_disp<D: DualNum<f64> + Copy + ScalarOperand>(
 
         for j in i + 1..n {
             // parameters
-            let eps_k = p.epsilon_k_ij[[i, j]];
-            let sig = p.sigma_ij[[i, j]];
-            let la = p.la_ij[[i, j]];
-            let lr = p.lr_ij[[i, j]];
-            let c = p.c_ij[[i, j]];
+            let eps_k = epsilon_k_ij[[i, j]];
+            let sig = sigma_ij[[i, j]];
+            let la = la_ij[[i, j]];
+            let lr = lr_ij[[i, j]];
+            let c = c_ij[[i, j]];
 
             let dij = (di + properties.diameter[j]) * 0.5;
             let d3 = dij.powi(3);
@@ -174,7 +228,7 @@ pub fn a_disp<D: DualNum<f64> + Copy + ScalarOperand>(
             let a2_ij = pref * eps_k * c * k_hs * 0.5 * (a1s_b_2la - a1s_b_lalr * 2.0 + a1s_b_2lr);
 
             // note indices of f(i, alpha) are shifted due to 0-indexing.
-            let alpha = parameters.alpha_ij[[i, j]];
+            let alpha = alpha_ij[[i, j]];
             let a3_ij = -zeta_x_bar
                 * f(3, alpha)
                 * (zeta_x_bar * (zeta_x_bar * f(5, alpha) + f(4, alpha))).exp()
@@ -194,7 +248,7 @@ pub fn a_disp<D: DualNum<f64> + Copy + ScalarOperand>(
 }
 
 /// Combine dispersion and chain contributions
-pub fn a_disp_chain<D: DualNum<f64> + Copy + ScalarOperand>(
+pub(crate) fn a_disp_chain<D: DualNum<f64> + Copy + ScalarOperand>(
     parameters: &SaftVRMieParameters,
     properties: &Properties<D>,
     state: &StateHD<D>,
@@ -332,6 +386,68 @@ pub fn a_disp_chain<D: DualNum<f64> + Copy + ScalarOperand>(
             + a_chain)
 }
 
+/// Logarithm of the pair correlation function of Mie segments at contact,
+/// $\ln g^\mathrm{Mie}(\sigma)$, for a (pseudo) segment with the given diameter and
+/// potential parameters.
+///
+/// Used in the chain contribution of heteronuclear molecules, for which
+/// the parameters are averages over the groups of a molecule.
+pub(crate) fn ln_g_mie<D: DualNum<f64> + Copy>(
+    properties: &Properties<D>,
+    t_inv: D,
+    diameter: D,
+    sigma: f64,
+    epsilon_k: f64,
+    lr: f64,
+    la: f64,
+) -> D {
+    let k = &properties.k0;
+    let c = lr / (lr - la) * (lr / la).powf(la / (lr - la));
+    let alpha = c * ((la - 3.0).recip() - (lr - 3.0).recip());
+
+    // wrap rho_s in Dual to calculate da1/drho_s and da2/drho_s
+    let rho_s_dual = Dual::from_re(properties.segment_density).derivative();
+    let zeta_x_dual = if properties.segment_density.is_zero() {
+        rho_s_dual * 0.0
+    } else {
+        Dual::from_re(properties.zeta_x / properties.segment_density) * rho_s_dual
+    };
+    let k_hs_dual = (zeta_x_dual - 1.0).powi(4)
+        / ((zeta_x_dual + zeta_x_dual.powi(2) - zeta_x_dual.powi(3)) * 4.0
+            + zeta_x_dual.powi(4)
+            + 1.0);
+    let k_hs = k_hs_dual.re;
+    let zeta_x_bar = properties.zeta_x_bar;
+
+    let d3 = Dual::from_re(diameter.powi(3));
+    let x0 = Dual::from_re(diameter.recip() * sigma);
+    let pref = rho_s_dual * d3 * epsilon_k * 2.0 * PI * c;
+    let a1s_b_la = a1s_b_ij(zeta_x_dual, x0, la);
+    let a1s_b_lr = a1s_b_ij(zeta_x_dual, x0, lr);
+    let a1s_b_2la = a1s_b_ij(zeta_x_dual, x0, 2.0 * la);
+    let a1s_b_lalr = a1s_b_ij(zeta_x_dual, x0, la + lr);
+    let a1s_b_2lr = a1s_b_ij(zeta_x_dual, x0, 2.0 * lr);
+    let a1 = pref * (a1s_b_la - a1s_b_lr);
+    let a2 = pref * epsilon_k * c * k_hs_dual * 0.5 * (a1s_b_2la - a1s_b_lalr * 2.0 + a1s_b_2lr);
+
+    // use dual-parts of a1 and a2 for derivatives
+    // and real-parts of a1s_b-terms.
+    let x0 = x0.re;
+    let pref = d3.re * epsilon_k * 2.0 * PI;
+    let g_hs = (k[0] + k[1] * x0 + k[2] * x0.powi(2) + k[3] * x0.powi(3)).exp();
+    let g1 = a1.eps * 3.0 / pref - (a1s_b_la.re * la - a1s_b_lr.re * lr) * c;
+    let g2_mca = a2.eps * 3.0 / pref / epsilon_k
+        - (a1s_b_2lr.re * lr - a1s_b_lalr.re * (la + lr) + a1s_b_2la.re * la) * k_hs * c.powi(2);
+    let beta_eps = t_inv * epsilon_k;
+    let gamma = zeta_x_bar
+        * beta_eps.exp_m1()
+        * 10.0
+        * (-(10.0 * (0.57 - alpha)).tanh() + 1.0)
+        * (-zeta_x_bar * 6.7 - zeta_x_bar.powi(2) * 8.0).exp();
+    let g2 = g2_mca * (gamma + 1.0);
+    g_hs.ln() + (beta_eps * g1 + beta_eps.powi(2) * g2) / g_hs
+}
+
 #[inline]
 pub(super) fn zeta_eff<D: DualNum<f64> + Copy>(zeta: D, lambda: f64) -> D {
     let li = 1. / lambda;
@@ -3,13 +3,22 @@ use crate::hard_sphere::HardSphere;
 use super::SaftVRMieParameters;
 use association::Association;
 use feos_core::parameter::Parameter;
-use feos_core::{Components, Molarweight, Residual, StateHD};
+use feos_core::{
+    Components, EntropyScaling, EosError, EosResult, Molarweight, ReferenceSystem, Residual, State,
+    StateHD,
+};
+#[cfg(feature = "dft")]
+use feos_dft::solvation::PairPotential;
+#[cfg(feature = "dft")]
+use ndarray::Array2;
 use ndarray::{Array1, ScalarOperand};
 use num_dual::DualNum;
-use quantity::{MolarWeight, GRAM, MOL};
-use std::{f64::consts::FRAC_PI_6, sync::Arc};
+use quantity::*;
+use std::f64::consts::{FRAC_PI_6, PI};
+use std::sync::Arc;
+use typenum::P2;
 
-pub(super) mod association;
+pub(crate) mod association;
 pub(crate) mod dispersion;
 use dispersion::{a_disp, a_disp_chain, Properties};
 
@@ -92,12 +101,12 @@ impl Residual for SaftVRMie {
         let (a_hs, _, d) = self.hard_sphere.helmholtz_energy_and_properties(state);
         a.push(("Hard Sphere".to_string(), a_hs));
 
-        let properties = Properties::new(&self.parameters, state, &d);
+        let properties = Properties::new(&*self.parameters, state, &d);
         if self.chain {
             let a_disp_chain = a_disp_chain(&self.parameters, &properties, state);
             a.push(("Dispersion + Chain".to_string(), a_disp_chain));
         } else {
-            let a_disp = a_disp(&self.parameters, &properties, state);
+            let a_disp = a_disp(&*self.parameters, &properties, state);
             a.push(("Dispersion".to_string(), a_disp));
         }
         if let Some(assoc) = self.association.as_ref() {
@@ -112,3 +121,311 @@ impl Molarweight for SaftVRMie {
         self.parameters.molarweight.clone() * GRAM / MOL
     }
 }
+
+#[cfg(feature = "dft")]
+impl PairPotential for SaftVRMie {
+    fn pair_potential(&self, i: usize, r: &Array1<f64>, _: f64) -> Array2<f64> {
+        let p = &self.parameters;
+        Array2::from_shape_fn((p.m.len(), r.len()), |(j, k)| {
+            let s = p.sigma_ij[[i, j]] / r[k];
+            p.c_ij[[i, j]]
+                * p.epsilon_k_ij[[i, j]]
+                * (s.powf(p.lr_ij[[i, j]]) - s.powf(p.la_ij[[i, j]]))
+        })
+    }
+}
+
+fn omega11(t: f64) -> f64 {
+    1.06036 * t.powf(-0.15610)
+        + 0.19300 * (-0.47635 * t).exp()
+        + 1.03587 * (-1.52996 * t).exp()
+        + 1.76474 * (-3.89411 * t).exp()
+}
+
+fn omega22(t: f64) -> f64 {
+    1.16145 * t.powf(-0.14874) + 0.52487 * (-0.77320 * t).exp() + 2.16178 * (-2.43787 * t).exp()
+        - 6.435e-4 * t.powf(0.14874) * (18.0323 * t.powf(-0.76830) - 7.27371).sin()
+}
+
+#[inline]
+fn chapman_enskog_thermal_conductivity(
+    temperature: Temperature,
+    molarweight: MolarWeight,
+    m: f64,
+    sigma: f64,
+    epsilon_k: f64,
+) -> ThermalConductivity {
+    let t = temperature.to_reduced();
+    0.083235 * (t * m / molarweight.convert_to(GRAM / MOL)).sqrt()
+        / sigma.powi(2)
+        / omega22(t / epsilon_k)
+        * WATT
+        / METER
+        / KELVIN
+}
+
+impl EntropyScaling for SaftVRMie {
+    fn viscosity_reference(
+        &self,
+        temperature: Temperature,
+        _: Volume,
+        moles: &Moles<Array1<f64>>,
+    ) -> EosResult<Viscosity> {
+        let p = &self.parameters;
+        let mw = &p.molarweight;
+        let x = (moles / moles.sum()).into_value();
+        let ce: Array1<_> = (0..self.components())
+            .map(|i| {
+                let tr = (temperature / p.epsilon_k[i] / KELVIN).into_value();
+                5.0 / 16.0 * (mw[i] * GRAM / MOL * KB / NAV * temperature / PI).sqrt()
+                    / omega22(tr)
+                    / (p.sigma[i] * ANGSTROM).powi::<P2>()
+            })
+            .collect();
+        let mut ce_mix = 0.0 * MILLI * PASCAL * SECOND;
+        for i in 0..self.components() {
+            let denom: f64 = (0..self.components())
+                .map(|j| {
+                    x[j] * (1.0
+                        + (ce[i] / ce[j]).into_value().sqrt() * (mw[j] / mw[i]).powf(1.0 / 4.0))
+                    .powi(2)
+                        / (8.0 * (1.0 + mw[i] / mw[j])).sqrt()
+                })
+                .sum();
+            ce_mix += ce[i] * x[i] / denom
+        }
+        Ok(ce_mix)
+    }
+
+    fn viscosity_correlation(&self, s_res: f64, x: &Array1<f64>) -> EosResult<f64> {
+        let coefficients = self
+            .parameters
+            .viscosity
+            .as_ref()
+            .expect("Missing viscosity coefficients.");
+        let m = (x * &self.parameters.m).sum();
+        let s = s_res / m;
+        let pref = (x * &self.parameters.m) / m;
+        let a: f64 = (&coefficients.row(0) * x).sum();
+        let b: f64 = (&coefficients.row(1) * &pref).sum();
+        let c: f64 = (&coefficients.row(2) * &pref).sum();
+        let d: f64 = (&coefficients.row(3) * &pref).sum();
+        Ok(a + b * s + c * s.powi(2) + d * s.powi(3))
+    }
+
+    fn diffusion_reference(
+        &self,
+        temperature: Temperature,
+        volume: Volume,
+        moles: &Moles<Array1<f64>>,
+    ) -> EosResult<Diffusivity> {
+        if self.components() != 1 {
+            return Err(EosError::IncompatibleComponents(self.components(), 1));
+        }
+        let p = &self.parameters;
+        let density = moles.sum() / volume;
+        let res: Array1<_> = (0..self.components())
+            .map(|i| {
+                let tr = (temperature / p.epsilon_k[i] / KELVIN).into_value();
+                3.0 / 8.0 / (p.sigma[i] * ANGSTROM).powi::<P2>() / omega11(tr) / (density * NAV)
+                    * (temperature * RGAS / PI / (p.molarweight[i] * GRAM / MOL) / p.m[i]).sqrt()
+            })
+            .collect();
+        Ok(res[0])
+    }
+
+    fn diffusion_correlation(&self, s_res: f64, x: &Array1<f64>) -> EosResult<f64> {
+        if self.components() != 1 {
+            return Err(EosError::IncompatibleComponents(self.components(), 1));
+        }
+        let coefficients = self
+            .parameters
+            .diffusion
+            .as_ref()
+            .expect("Missing diffusion coefficients.");
+        let m = (x * &self.parameters.m).sum();
+        let s = s_res / m;
+        let pref = (x * &self.parameters.m).mapv(|v| v / m);
+        let a: f64 = (&coefficients.row(0) * x).sum();
+        let b: f64 = (&coefficients.row(1) * &pref).sum();
+        let c: f64 = (&coefficients.row(2) * &pref).sum();
+        let d: f64 = (&coefficients.row(3) * &pref).sum();
+        let e: f64 = (&coefficients.row(4) * &pref).sum();
+        Ok(a + b * s - c * (1.0 - s.exp()) * s.powi(2) - d * s.powi(4) - e * s.powi(8))
+    }
+
+    // Equation 4 of DOI: 10.1021/acs.iecr.9b04289
+    fn thermal_conductivity_reference(
+        &self,
+        temperature: Temperature,
+        volume: Volume,
+        moles: &Moles<Array1<f64>>,
+    ) -> EosResult<ThermalConductivity> {
+        if self.components() != 1 {
+            return Err(EosError::IncompatibleComponents(self.components(), 1));
+        }
+        let p = &self.parameters;
+        let mws = self.molar_weight();
+        let state = State::new_nvt(&Arc::new(Self::new(p.clone())), temperature, volume, moles)?;
+        let res: Array1<_> = (0..self.components())
+            .map(|i| {
+                let tr = (temperature / p.epsilon_k[i] / KELVIN).into_value();
+                let s_res_reduced = state.residual_molar_entropy().to_reduced() / p.m[i];
+                let ref_ce = chapman_enskog_thermal_conductivity(
+                    temperature,
+                    mws.get(i),
+                    p.m[i],
+                    p.sigma[i],
+                    p.epsilon_k[i],
+                );
+                let alpha_visc = (-s_res_reduced / -0.5).exp();
+                let ref_ts = (-0.0167141 * tr / p.m[i] + 0.0470581 * (tr / p.m[i]).powi(2))
+                    * (p.m[i] * p.m[i] * p.sigma[i].powi(3) * p.epsilon_k[i])
+                    * 1e-5
+                    * WATT
+                    / METER
+                    / KELVIN;
+                ref_ce + ref_ts * alpha_visc
+            })
+            .collect();
+        Ok(res[0])
+    }
+
+    fn thermal_conductivity_correlation(&self, s_res: f64, x: &Array1<f64>) -> EosResult<f64> {
+        if self.components() != 1 {
+            return Err(EosError::IncompatibleComponents(self.components(), 1));
+        }
+        let coefficients = self
+            .parameters
+            .thermal_conductivity
+            .as_ref()
+            .expect("Missing thermal conductivity coefficients");
+        let m = (x * &self.parameters.m).sum();
+        let s = s_res / m;
+        let pref = (x * &self.parameters.m).mapv(|v| v / m);
+        let a: f64 = (&coefficients.row(0) * x).sum();
+        let b: f64 = (&coefficients.row(1) * &pref).sum();
+        let c: f64 = (&coefficients.row(2) * &pref).sum();
+        let d: f64 = (&coefficients.row(3) * &pref).sum();
+        Ok(a + b * s + c * (1.0 - s.exp()) + d * s.powi(2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::saftvrmie::{SaftVRMieParameters, SaftVRMieRecord};
+    use approx::assert_relative_eq;
+    use feos_core::parameter::{Identifier, PureRecord};
+    use feos_core::DensityInitialization;
+    use ndarray::arr1;
+    use typenum::P2;
+
+    /// Lafitte et al. (2013) parameters of propane with vanishing entropy
+    /// scaling coefficients, i.e., transport properties of the reference model.
+    fn propane() -> Arc<SaftVRMieParameters> {
+        let record = SaftVRMieRecord::new(
+            1.6845,
+            3.9056,
+            239.89,
+            13.006,
+            6.0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some([0.0; 4]),
+            Some([0.0; 5]),
+            Some([0.0; 4]),
+        );
+        let pure_record = PureRecord::new(Identifier::default(), 44.0962, record);
+        Arc::new(SaftVRMieParameters::new_pure(pure_record).unwrap())
+    }
+
+    #[test]
+    fn viscosity() -> EosResult<()> {
+        let e = Arc::new(SaftVRMie::new(propane()));
+        let t = 300.0 * KELVIN;
+        let p = BAR;
+        let n = arr1(&[1.0]) * MOL;
+        let s = State::new_npt(&e, t, p, &n, DensityInitialization::None)?;
+        assert_relative_eq!(
+            s.viscosity()?,
+            0.014127 * MILLI * PASCAL * SECOND,
+            max_relative = 1e-4
+        );
+        assert_relative_eq!(
+            s.ln_viscosity_reduced()?,
+            (s.viscosity()? / e.viscosity_reference(s.temperature, s.volume, &s.moles)?)
+                .into_value()
+                .ln(),
+            epsilon = 1e-15
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn diffusion() -> EosResult<()> {
+        let e = Arc::new(SaftVRMie::new(propane()));
+        let t = 300.0 * KELVIN;
+        let p = BAR;
+        let n = arr1(&[1.0]) * MOL;
+        let s = State::new_npt(&e, t, p, &n, DensityInitialization::None)?;
+        assert_relative_eq!(
+            s.diffusion()?,
+            0.080094 * (CENTI * METER).powi::<P2>() / SECOND,
+            max_relative = 1e-4
+        );
+        assert_relative_eq!(
+            s.ln_diffusion_reduced()?,
+            (s.diffusion()? / e.diffusion_reference(s.temperature, s.volume, &s.moles)?)
+                .into_value()
+                .ln(),
+            epsilon = 1e-15
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "dft")]
+    #[test]
+    fn pair_potential_tables() -> std::io::Result<()> {
+        use feos_dft::solvation::PairPotentialTableFormat;
+
+        let e = SaftVRMie::new(propane());
+        let (lr, la) = (13.006, 6.0);
+        let r_min = (lr / la).powf(1.0 / (lr - la)) * 3.9056;
+        let u = e.pair_potential(0, &arr1(&[r_min, r_min * 0.99, r_min * 1.01]), 300.0);
+        assert_relative_eq!(u[[0, 0]], -239.89, max_relative = 1e-12);
+        assert!(u[[0, 1]] > u[[0, 0]] && u[[0, 2]] > u[[0, 0]]);
+
+        let dir = std::env::temp_dir().join("feos_pair_potential_tables");
+        std::fs::create_dir_all(&dir)?;
+        let r = Array1::linspace(3.0, 12.0, 91) * ANGSTROM;
+        let names = vec!["propane".to_string()];
+        for format in [
+            PairPotentialTableFormat::Lammps,
+            PairPotentialTableFormat::Gromacs,
+            PairPotentialTableFormat::Csv,
+        ] {
+            e.pair_potential_tables(300.0 * KELVIN, &r, format, &names, &dir)?;
+        }
+        let lammps = std::fs::read_to_string(dir.join("propane_300K.table"))?;
+        assert_eq!(lammps.lines().count(), 5 + 91);
+        assert!(lammps.contains("\npropane\nN 91\n"));
+        let gromacs = std::fs::read_to_string(dir.join("table_propane_300K.xvg"))?;
+        assert_eq!(gromacs.lines().count(), 1 + 91);
+        let csv = std::fs::read_to_string(dir.join("propane_300K.csv"))?;
+        let values: Vec<f64> = csv
+            .lines()
+            .nth(31)
+            .unwrap()
+            .split(',')
+            .map(|x| x.parse().unwrap())
+            .collect();
+        let u = e.pair_potential(0, &arr1(&[6.0]), 300.0)[[0, 0]];
+        assert_relative_eq!(values[0], 6.0, max_relative = 1e-12);
+        assert_relative_eq!(values[1], u, max_relative = 1e-12);
+        std::fs::remove_dir_all(dir)
+    }
+}
@@ -421,23 +421,40 @@ impl SaftVRMieParameters {
         j: usize,
         inverse_temperature: D,
     ) -> D {
-        let lr = self.lr_ij[[i, j]];
-        let la = self.la_ij[[i, j]];
-        let c_eps_t = inverse_temperature * self.c_ij[[i, j]] * self.epsilon_k_ij[[i, j]];
-
-        // perform integration in reduced distances, then multiply sigma
-        // r0 is dimensionless
-        let r0 = lower_integratal_limit(la, lr, c_eps_t);
-        let width = (-r0 + 1.0) * 0.5;
-        GLQ10.iter().fold(r0, |d, &[x, w]| {
-            let r = width * x + width + r0;
-            let u = beta_u_mie(r, la, lr, 1.0, c_eps_t);
-            let f_u = -(-u).exp_m1();
-            d + width * f_u * w
-        }) * self.sigma_ij[[i, j]]
+        hs_diameter_mie(
+            self.sigma_ij[[i, j]],
+            self.epsilon_k_ij[[i, j]],
+            self.lr_ij[[i, j]],
+            self.la_ij[[i, j]],
+            self.c_ij[[i, j]],
+            inverse_temperature,
+        )
     }
 }
 
+/// Temperature dependent Barker-Henderson diameter of a Mie potential.
+pub(crate) fn hs_diameter_mie<D: DualNum<f64> + Copy>(
+    sigma: f64,
+    epsilon_k: f64,
+    lr: f64,
+    la: f64,
+    c: f64,
+    inverse_temperature: D,
+) -> D {
+    let c_eps_t = inverse_temperature * c * epsilon_k;
+
+    // perform integration in reduced distances, then multiply sigma
+    // r0 is dimensionless
+    let r0 = lower_integratal_limit(la, lr, c_eps_t);
+    let width = (-r0 + 1.0) * 0.5;
+    GLQ10.iter().fold(r0, |d, &[x, w]| {
+        let r = width * x + width + r0;
+        let u = beta_u_mie(r, la, lr, 1.0, c_eps_t);
+        let f_u = -(-u).exp_m1();
+        d + width * f_u * w
+    }) * sigma
+}
+
 /// Find lower limit for integration of the temperature dependent diameter
 ///
 /// Method of Aasen et al.
@@ -0,0 +1,143 @@
+use super::eos::{UVTheory, UVTheoryOptions};
+use super::parameters::UVTheoryParameters;
+use crate::hard_sphere::{FMTContribution, FMTVersion};
+use feos_core::parameter::Parameter;
+use feos_core::{Components, EosResult, Molarweight, Residual, StateHD};
+use feos_derive::FunctionalContribution;
+use feos_dft::adsorption::FluidParameters;
+use feos_dft::solvation::PairPotential;
+use feos_dft::{FunctionalContribution, HelmholtzEnergyFunctional, MoleculeShape};
+use ndarray::{Array1, Array2, ScalarOperand};
+use num_dual::DualNum;
+use perturbation::PerturbationFunctional;
+use quantity::{MolarWeight, GRAM, MOL};
+use std::f64::consts::FRAC_PI_6;
+use std::sync::Arc;
+
+mod perturbation;
+
+/// uv-theory Helmholtz energy functional.
+///
+/// The hard-sphere contribution is modeled with FMT using the
+/// temperature dependent diameters of the chosen perturbation
+/// scheme. The perturbation terms are evaluated with weighted
+/// densities so that the functional reduces to the uv-theory
+/// equation of state for homogeneous systems.
+pub struct UVTheoryFunctional {
+    /// uv-theory parameters of all substances in the system
+    pub parameters: Arc<UVTheoryParameters>,
+    fmt_version: FMTVersion,
+    options: UVTheoryOptions,
+    eos: Arc<UVTheory>,
+}
+
+impl UVTheoryFunctional {
+    /// uv-theory functional with default options.
+    ///
+    /// # Defaults
+    /// `FMTVersion`: `FMTVersion::WhiteBear`
+    pub fn new(parameters: Arc<UVTheoryParameters>) -> Self {
+        Self::with_options(
+            parameters,
+            FMTVersion::WhiteBear,
+            UVTheoryOptions::default(),
+        )
+    }
+
+    /// uv-theory functional with default options for and provided FMT version.
+    pub fn new_full(parameters: Arc<UVTheoryParameters>, fmt_version: FMTVersion) -> Self {
+        Self::with_options(parameters, fmt_version, UVTheoryOptions::default())
+    }
+
+    /// uv-theory functional with provided options for FMT and equation of state options.
+    pub fn with_options(
+        parameters: Arc<UVTheoryParameters>,
+        fmt_version: FMTVersion,
+        uvtheory_options: UVTheoryOptions,
+    ) -> Self {
+        let eos = Arc::new(UVTheory::with_options(
+            parameters.clone(),
+            uvtheory_options.clone(),
+        ));
+        Self {
+            parameters,
+            fmt_version,
+            options: uvtheory_options,
+            eos,
+        }
+    }
+}
+
+impl Components for UVTheoryFunctional {
+    fn components(&self) -> usize {
+        self.parameters.pure_records.len()
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        Self::with_options(
+            Arc::new(self.parameters.subset(component_list)),
+            self.fmt_version,
+            self.options.clone(),
+        )
+    }
+}
+
+impl Residual for UVTheoryFunctional {
+    fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
+        self.options.max_eta * moles.sum()
+            / (FRAC_PI_6 * self.parameters.sigma.mapv(|v| v.powi(3)) * moles).sum()
+    }
+
+    fn residual_helmholtz_energy_contributions<D: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        state: &StateHD<D>,
+    ) -> Vec<(String, D)> {
+        self.evaluate_bulk(state)
+    }
+}
+
+impl HelmholtzEnergyFunctional for UVTheoryFunctional {
+    type Contribution = UVTheoryFunctionalContribution;
+
+    fn molecule_shape(&self) -> MoleculeShape<'_> {
+        MoleculeShape::Spherical(self.parameters.ncomponents)
+    }
+
+    fn contributions(&self) -> Box<dyn Iterator<Item = UVTheoryFunctionalContribution>> {
+        // Hard-sphere contribution
+        let hs = FMTContribution::new(&self.eos, self.fmt_version);
+
+        // Reference and attractive perturbations
+        let pert = PerturbationFunctional::new(self.eos.clone());
+
+        Box::new([hs.into(), pert.into()].into_iter())
+    }
+}
+
+impl Molarweight for UVTheoryFunctional {
+    fn molar_weight(&self) -> MolarWeight<Array1<f64>> {
+        self.parameters.molarweight.clone() * GRAM / MOL
+    }
+}
+
+impl FluidParameters for UVTheoryFunctional {
+    fn epsilon_k_ff(&self) -> Array1<f64> {
+        self.parameters.epsilon_k.clone()
+    }
+
+    fn sigma_ff(&self) -> &Array1<f64> {
+        &self.parameters.sigma
+    }
+}
+
+impl PairPotential for UVTheoryFunctional {
+    fn pair_potential(&self, i: usize, r: &Array1<f64>, temperature: f64) -> Array2<f64> {
+        self.eos.pair_potential(i, r, temperature)
+    }
+}
+
+#[derive(FunctionalContribution)]
+pub enum UVTheoryFunctionalContribution {
+    Fmt(FMTContribution<UVTheory>),
+    Perturbation(PerturbationFunctional),
+}
@@ -0,0 +1,84 @@
+use crate::hard_sphere::HardSphereProperties;
+use crate::uvtheory::UVTheory;
+use feos_core::{EosError, StateHD};
+use feos_dft::{FunctionalContribution, WeightFunction, WeightFunctionInfo, WeightFunctionShape};
+use ndarray::*;
+use num_dual::DualNum;
+use std::fmt;
+use std::sync::Arc;
+
+/// psi Parameter for DFT (same as for PC-SAFT, not adjusted to uv-theory)
+const PSI_DFT: f64 = 1.3862;
+/// psi Parameter for pDGT (same as for PC-SAFT, not adjusted to uv-theory)
+const PSI_PDGT: f64 = 1.3286;
+
+/// Weighted density approximation of the reference and attractive
+/// perturbations of uv-theory.
+///
+/// The perturbation terms of the equation of state are evaluated
+/// locally with densities that are averaged over spheres with a
+/// diameter of $\psi d_i$.
+#[derive(Clone)]
+pub struct PerturbationFunctional {
+    eos: Arc<UVTheory>,
+}
+
+impl PerturbationFunctional {
+    pub fn new(eos: Arc<UVTheory>) -> Self {
+        Self { eos }
+    }
+}
+
+fn perturbation_weight_functions<N: DualNum<f64> + Copy + ScalarOperand>(
+    eos: &UVTheory,
+    psi: f64,
+    temperature: N,
+) -> WeightFunctionInfo<N> {
+    let d = eos.hs_diameter(temperature);
+    WeightFunctionInfo::new(Array1::from_shape_fn(d.len(), |i| i), false).add(
+        WeightFunction::new_scaled(d * psi, WeightFunctionShape::Theta),
+        false,
+    )
+}
+
+impl FunctionalContribution for PerturbationFunctional {
+    fn weight_functions<N: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        temperature: N,
+    ) -> WeightFunctionInfo<N> {
+        perturbation_weight_functions(&self.eos, PSI_DFT, temperature)
+    }
+
+    fn weight_functions_pdgt<N: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        temperature: N,
+    ) -> WeightFunctionInfo<N> {
+        perturbation_weight_functions(&self.eos, PSI_PDGT, temperature)
+    }
+
+    fn helmholtz_energy_density<N: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        temperature: N,
+        density: ArrayView2<N>,
+    ) -> Result<Array1<N>, EosError> {
+        // the perturbation terms are (at least) quadratic in the density,
+        // therefore, they and their derivatives vanish for an empty system
+        Ok(density
+            .axis_iter(Axis(1))
+            .map(|rho| {
+                if rho.sum().re() > f64::EPSILON {
+                    let state = StateHD::new(temperature, N::one(), rho.to_owned());
+                    self.eos.perturbation_helmholtz_energy(&state)
+                } else {
+                    N::zero()
+                }
+            })
+            .collect())
+    }
+}
+
+impl fmt::Display for PerturbationFunctional {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Perturbation functional")
+    }
+}
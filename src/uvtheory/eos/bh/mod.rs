@@ -7,6 +7,8 @@ use crate::uvtheory::UVTheoryParameters;
 use attractive_perturbation::AttractivePerturbation;
 use feos_core::StateHD;
 use hard_sphere::HardSphere;
+#[cfg(feature = "dft")]
+use ndarray::Array1;
 use num_dual::DualNum;
 use reference_perturbation::ReferencePerturbation;
 
@@ -56,3 +58,17 @@ impl BarkerHenderson {
         ]
     }
 }
+
+#[cfg(feature = "dft")]
+impl BarkerHenderson {
+    /// Temperature dependent hard-sphere diameters.
+    pub fn hs_diameter<D: DualNum<f64> + Copy>(&self, temperature: D) -> Array1<D> {
+        hard_sphere::diameter_bh(&self.hard_sphere.parameters, temperature)
+    }
+
+    /// Helmholtz energy of the reference and attractive perturbations.
+    pub fn perturbation_helmholtz_energy<D: DualNum<f64> + Copy>(&self, state: &StateHD<D>) -> D {
+        self.reference_perturbation.helmholtz_energy(state)
+            + self.attractive_perturbation.helmholtz_energy(state)
+    }
+}
@@ -1,9 +1,17 @@
 #![allow(clippy::excessive_precision)]
 #![allow(clippy::needless_range_loop)]
+#[cfg(feature = "dft")]
+use super::parameters::mie_prefactor;
 use super::parameters::UVTheoryParameters;
+#[cfg(feature = "dft")]
+use crate::hard_sphere::{HardSphereProperties, MonomerShape};
 use feos_core::parameter::Parameter;
 use feos_core::{Components, Molarweight, Residual};
+#[cfg(feature = "dft")]
+use feos_dft::solvation::PairPotential;
 use ndarray::Array1;
+#[cfg(feature = "dft")]
+use ndarray::Array2;
 use quantity::{MolarWeight, GRAM, MOL};
 use std::f64::consts::FRAC_PI_6;
 use std::sync::Arc;
@@ -125,6 +133,53 @@ impl Molarweight for UVTheory {
     }
 }
 
+#[cfg(feature = "dft")]
+impl UVTheory {
+    /// Helmholtz energy of the reference and attractive perturbations,
+    /// i.e., the residual Helmholtz energy without the hard-sphere contribution.
+    pub(crate) fn perturbation_helmholtz_energy<D: num_dual::DualNum<f64> + Copy>(
+        &self,
+        state: &feos_core::StateHD<D>,
+    ) -> D {
+        match &self.implementation {
+            UVTheoryImplementation::BarkerHenderson(a) => a.perturbation_helmholtz_energy(state),
+            UVTheoryImplementation::WeeksChandlerAndersen(a) => {
+                a.perturbation_helmholtz_energy(state)
+            }
+            UVTheoryImplementation::WeeksChandlerAndersenB3(a) => {
+                a.perturbation_helmholtz_energy(state)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "dft")]
+impl HardSphereProperties for UVTheory {
+    fn monomer_shape<D: num_dual::DualNum<f64>>(&self, _: D) -> MonomerShape<'_, D> {
+        MonomerShape::Spherical(self.parameters.ncomponents)
+    }
+
+    fn hs_diameter<D: num_dual::DualNum<f64> + Copy>(&self, temperature: D) -> Array1<D> {
+        match &self.implementation {
+            UVTheoryImplementation::BarkerHenderson(a) => a.hs_diameter(temperature),
+            UVTheoryImplementation::WeeksChandlerAndersen(a) => a.hs_diameter(temperature),
+            UVTheoryImplementation::WeeksChandlerAndersenB3(a) => a.hs_diameter(temperature),
+        }
+    }
+}
+
+#[cfg(feature = "dft")]
+impl PairPotential for UVTheory {
+    fn pair_potential(&self, i: usize, r: &Array1<f64>, _: f64) -> Array2<f64> {
+        let p = &self.parameters;
+        Array2::from_shape_fn((p.ncomponents, r.len()), |(j, k)| {
+            let (rep, att) = (p.rep_ij[[i, j]], p.att_ij[[i, j]]);
+            let s = p.sigma_ij[[i, j]] / r[k];
+            mie_prefactor(rep, att) * p.eps_k_ij[[i, j]] * (s.powf(rep) - s.powf(att))
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -10,6 +10,8 @@ use attractive_perturbation::AttractivePerturbation;
 use attractive_perturbation_uvb3::AttractivePerturbationB3;
 use feos_core::StateHD;
 use hard_sphere::HardSphere;
+#[cfg(feature = "dft")]
+use ndarray::Array1;
 use num_dual::DualNum;
 use reference_perturbation::ReferencePerturbation;
 use reference_perturbation_uvb3::ReferencePerturbationB3;
@@ -61,6 +63,20 @@ impl WeeksChandlerAndersen {
     }
 }
 
+#[cfg(feature = "dft")]
+impl WeeksChandlerAndersen {
+    /// Temperature dependent hard-sphere diameters.
+    pub fn hs_diameter<D: DualNum<f64> + Copy>(&self, temperature: D) -> Array1<D> {
+        hard_sphere::diameter_wca(&self.hard_sphere.parameters, temperature)
+    }
+
+    /// Helmholtz energy of the reference and attractive perturbations.
+    pub fn perturbation_helmholtz_energy<D: DualNum<f64> + Copy>(&self, state: &StateHD<D>) -> D {
+        self.reference_perturbation.helmholtz_energy(state)
+            + self.attractive_perturbation.helmholtz_energy(state)
+    }
+}
+
 pub(super) struct WeeksChandlerAndersenB3 {
     hard_sphere: HardSphere,
     reference_perturbation: ReferencePerturbationB3,
@@ -107,3 +123,17 @@ impl WeeksChandlerAndersenB3 {
         ]
     }
 }
+
+#[cfg(feature = "dft")]
+impl WeeksChandlerAndersenB3 {
+    /// Temperature dependent hard-sphere diameters.
+    pub fn hs_diameter<D: DualNum<f64> + Copy>(&self, temperature: D) -> Array1<D> {
+        hard_sphere::diameter_wca(&self.hard_sphere.parameters, temperature)
+    }
+
+    /// Helmholtz energy of the reference and attractive perturbations.
+    pub fn perturbation_helmholtz_energy<D: DualNum<f64> + Copy>(&self, state: &StateHD<D>) -> D {
+        self.reference_perturbation.helmholtz_energy(state)
+            + self.attractive_perturbation.helmholtz_energy(state)
+    }
+}
@@ -63,9 +63,13 @@
 //! );
 //! # Ok::<(), EosError>(())
 //! ```
+#[cfg(feature = "dft")]
+mod dft;
 mod eos;
 mod parameters;
 
+#[cfg(feature = "dft")]
+pub use dft::{UVTheoryFunctional, UVTheoryFunctionalContribution};
 pub use eos::{Perturbation, UVTheory, UVTheoryOptions};
 pub use parameters::{UVTheoryBinaryRecord, UVTheoryParameters, UVTheoryRecord};
 
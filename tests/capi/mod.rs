@@ -0,0 +1,239 @@
+use approx::assert_relative_eq;
+use feos::capi::*;
+use std::error::Error;
+use std::ffi::{CStr, CString};
+use std::ptr;
+
+fn eos(substances: &[&str], ideal_gas: FeosIdealGasModel) -> Result<*mut FeosEos, Box<dyn Error>> {
+    let substances: Vec<_> = substances
+        .iter()
+        .map(|&s| CString::new(s))
+        .collect::<Result<_, _>>()?;
+    let substances: Vec<_> = substances.iter().map(|s| s.as_ptr()).collect();
+    let parameters = CString::new("tests/pcsaft/test_parameters.json")?;
+    let joback = CString::new("tests/pcsaft/test_parameters_joback.json")?;
+    let mut eos = ptr::null_mut();
+    let status = unsafe {
+        feos_eos_from_json(
+            FeosResidualModel::PcSaft,
+            substances.as_ptr(),
+            substances.len(),
+            parameters.as_ptr(),
+            ptr::null(),
+            ideal_gas,
+            joback.as_ptr(),
+            FeosIdentifierOption::Name,
+            &mut eos,
+        )
+    };
+    assert_eq!(status, FeosStatus::Ok);
+    Ok(eos)
+}
+
+fn property(state: *const FeosState, property: FeosProperty) -> f64 {
+    let mut value = f64::NAN;
+    let status =
+        unsafe { feos_state_property(state, property, FeosContributions::Total, &mut value) };
+    assert_eq!(status, FeosStatus::Ok);
+    value
+}
+
+#[test]
+fn test_state_nvt_npt_nph() -> Result<(), Box<dyn Error>> {
+    let eos = eos(&["propane"], FeosIdealGasModel::Joback)?;
+    let mut n = 0;
+    assert_eq!(unsafe { feos_eos_components(eos, &mut n) }, FeosStatus::Ok);
+    assert_eq!(n, 1);
+
+    let moles = [2.0];
+    let (t, p) = (300.0, 1e5);
+    let mut npt = ptr::null_mut();
+    let status = unsafe {
+        feos_state_new_npt(
+            eos,
+            t,
+            p,
+            moles.as_ptr(),
+            FeosDensityInitialization::Vapor,
+            &mut npt,
+        )
+    };
+    assert_eq!(status, FeosStatus::Ok);
+    assert_relative_eq!(
+        property(npt, FeosProperty::Pressure),
+        p,
+        max_relative = 1e-10
+    );
+    assert_relative_eq!(property(npt, FeosProperty::TotalMoles), 2.0);
+
+    // the same state at given volume
+    let volume = property(npt, FeosProperty::Volume);
+    let mut nvt = ptr::null_mut();
+    let status = unsafe { feos_state_new_nvt(eos, t, volume, moles.as_ptr(), &mut nvt) };
+    assert_eq!(status, FeosStatus::Ok);
+    assert_relative_eq!(
+        property(nvt, FeosProperty::Pressure),
+        p,
+        max_relative = 1e-10
+    );
+
+    // the same state at given enthalpy
+    let h = property(npt, FeosProperty::MolarEnthalpy);
+    let mut nph = ptr::null_mut();
+    let status = unsafe {
+        feos_state_new_nph(
+            eos,
+            p,
+            h,
+            moles.as_ptr(),
+            FeosDensityInitialization::Vapor,
+            ptr::null(),
+            &mut nph,
+        )
+    };
+    assert_eq!(status, FeosStatus::Ok);
+    assert_relative_eq!(
+        property(nph, FeosProperty::Temperature),
+        t,
+        max_relative = 1e-8
+    );
+
+    // fugacity of a pure component
+    let (mut ln_phi, mut fugacity) = ([0.0], [0.0]);
+    unsafe {
+        assert_eq!(feos_state_ln_phi(npt, ln_phi.as_mut_ptr()), FeosStatus::Ok);
+        assert_eq!(
+            feos_state_fugacity(npt, fugacity.as_mut_ptr()),
+            FeosStatus::Ok
+        );
+    }
+    assert_relative_eq!(fugacity[0], ln_phi[0].exp() * p, max_relative = 1e-10);
+    assert!(property(npt, FeosProperty::SpeedOfSound) > 0.0);
+
+    unsafe {
+        feos_state_free(npt);
+        feos_state_free(nvt);
+        feos_state_free(nph);
+        feos_eos_free(eos);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_phase_equilibria() -> Result<(), Box<dyn Error>> {
+    let eos = eos(&["propane", "butane"], FeosIdealGasModel::NoModel)?;
+    let x = [0.5, 0.5];
+    let mut bubble = ptr::null_mut();
+    let status = unsafe {
+        feos_bubble_point(
+            eos,
+            FeosSpecification::Temperature,
+            300.0,
+            x.as_ptr(),
+            ptr::null(),
+            &mut bubble,
+        )
+    };
+    assert_eq!(status, FeosStatus::Ok);
+    let (mut liquid, mut vapor) = (ptr::null_mut(), ptr::null_mut());
+    let (mut f_liquid, mut f_vapor) = ([0.0; 2], [0.0; 2]);
+    unsafe {
+        assert_eq!(
+            feos_phase_equilibrium_liquid(bubble, &mut liquid),
+            FeosStatus::Ok
+        );
+        assert_eq!(
+            feos_phase_equilibrium_vapor(bubble, &mut vapor),
+            FeosStatus::Ok
+        );
+        assert_eq!(
+            feos_state_fugacity(liquid, f_liquid.as_mut_ptr()),
+            FeosStatus::Ok
+        );
+        assert_eq!(
+            feos_state_fugacity(vapor, f_vapor.as_mut_ptr()),
+            FeosStatus::Ok
+        );
+    }
+    assert_relative_eq!(f_liquid[0], f_vapor[0], max_relative = 1e-8);
+    assert_relative_eq!(f_liquid[1], f_vapor[1], max_relative = 1e-8);
+
+    // flash of a feed between the bubble and the dew point
+    let p = property(liquid, FeosProperty::Pressure);
+    let mut dew = ptr::null_mut();
+    let status = unsafe {
+        feos_dew_point(
+            eos,
+            FeosSpecification::Pressure,
+            p,
+            x.as_ptr(),
+            &300.0,
+            &mut dew,
+        )
+    };
+    assert_eq!(status, FeosStatus::Ok);
+    let mut flash = ptr::null_mut();
+    let status = unsafe { feos_tp_flash(eos, 300.0, 0.9 * p, x.as_ptr(), &mut flash) };
+    assert_eq!(status, FeosStatus::Ok);
+    let (mut flash_liquid, mut y) = (ptr::null_mut(), [0.0; 2]);
+    unsafe {
+        assert_eq!(
+            feos_phase_equilibrium_liquid(flash, &mut flash_liquid),
+            FeosStatus::Ok
+        );
+        assert_eq!(
+            feos_state_molefracs(flash_liquid, y.as_mut_ptr()),
+            FeosStatus::Ok
+        );
+    }
+    assert!(y[0] < x[0]);
+
+    unsafe {
+        feos_state_free(liquid);
+        feos_state_free(vapor);
+        feos_state_free(flash_liquid);
+        feos_phase_equilibrium_free(bubble);
+        feos_phase_equilibrium_free(dew);
+        feos_phase_equilibrium_free(flash);
+        feos_eos_free(eos);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_errors() -> Result<(), Box<dyn Error>> {
+    let substances = [CString::new("unobtainium")?];
+    let substances: Vec<_> = substances.iter().map(|s| s.as_ptr()).collect();
+    let parameters = CString::new("tests/pcsaft/test_parameters.json")?;
+    let mut eos = ptr::null_mut();
+    let status = unsafe {
+        feos_eos_from_json(
+            FeosResidualModel::PcSaft,
+            substances.as_ptr(),
+            1,
+            parameters.as_ptr(),
+            ptr::null(),
+            FeosIdealGasModel::NoModel,
+            ptr::null(),
+            FeosIdentifierOption::Name,
+            &mut eos,
+        )
+    };
+    assert_eq!(status, FeosStatus::ParameterError);
+    assert!(eos.is_null());
+    let message = unsafe { CStr::from_ptr(feos_last_error_message()) }.to_str()?;
+    assert!(message.contains("unobtainium"));
+
+    // missing handle
+    let mut n = 0;
+    let status = unsafe { feos_eos_components(ptr::null(), &mut n) };
+    assert_eq!(status, FeosStatus::NullPointer);
+
+    // invalid state
+    let eos = self::eos(&["propane"], FeosIdealGasModel::NoModel)?;
+    let mut state = ptr::null_mut();
+    let status = unsafe { feos_state_new_nvt(eos, -300.0, 1.0, [1.0].as_ptr(), &mut state) };
+    assert_eq!(status, FeosStatus::InvalidState);
+    unsafe { feos_eos_free(eos) };
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use approx::assert_relative_eq;
+use feos::cpa::{Cpa, CpaParameters};
+use feos_core::parameter::{IdentifierOption, Parameter};
+use feos_core::{Contributions, PhaseEquilibrium};
+use quantity::*;
+use std::error::Error;
+use std::sync::Arc;
+
+#[test]
+fn vapor_pressure_water() -> Result<(), Box<dyn Error>> {
+    let params = CpaParameters::from_json(
+        vec!["water"],
+        "tests/cpa/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let cpa = Arc::new(Cpa::new(Arc::new(params)));
+    let vle = PhaseEquilibrium::pure(&cpa, 373.15 * KELVIN, None, Default::default())?;
+    let p = vle.vapor().pressure(Contributions::Total);
+    assert_relative_eq!(p, 101325.0 * PASCAL, max_relative = 0.03);
+    Ok(())
+}
+
+#[test]
+fn vapor_pressure_methanol() -> Result<(), Box<dyn Error>> {
+    let params = CpaParameters::from_json(
+        vec!["methanol"],
+        "tests/cpa/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let cpa = Arc::new(Cpa::new(Arc::new(params)));
+    let vle = PhaseEquilibrium::pure(&cpa, 337.85 * KELVIN, None, Default::default())?;
+    let p = vle.vapor().pressure(Contributions::Total);
+    assert_relative_eq!(p, 101325.0 * PASCAL, max_relative = 0.03);
+    Ok(())
+}
+
+#[test]
+fn vle_binary_methane_water() -> Result<(), Box<dyn Error>> {
+    let params = CpaParameters::from_json(
+        vec!["methane", "water"],
+        "tests/cpa/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let cpa = Arc::new(Cpa::new(Arc::new(params)));
+    let vle = PhaseEquilibrium::bubble_point(
+        &cpa,
+        373.15 * KELVIN,
+        &ndarray::arr1(&[1e-4, 1.0 - 1e-4]),
+        None,
+        None,
+        Default::default(),
+    )?;
+    assert_relative_eq!(
+        vle.vapor().pressure(Contributions::Total),
+        vle.liquid().pressure(Contributions::Total),
+        max_relative = 1e-8
+    );
+    assert!(vle.vapor().molefracs[0] > vle.liquid().molefracs[0]);
+    Ok(())
+}
@@ -0,0 +1 @@
+mod tp_flash;
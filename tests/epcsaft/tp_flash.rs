@@ -0,0 +1,129 @@
+use approx::assert_relative_eq;
+use feos::epcsaft::{ElectrolytePcSaft, ElectrolytePcSaftParameters};
+use feos_core::parameter::{IdentifierOption, Parameter, ParameterError};
+use feos_core::{PhaseEquilibrium, SolverOptions};
+use ndarray::*;
+use quantity::*;
+use std::error::Error;
+use std::sync::Arc;
+
+fn read_params(components: Vec<&str>) -> Result<Arc<ElectrolytePcSaftParameters>, ParameterError> {
+    Ok(Arc::new(ElectrolytePcSaftParameters::from_json(
+        components,
+        "tests/epcsaft/test_parameters.json",
+        Some("tests/epcsaft/test_parameters_binary.json"),
+        IdentifierOption::Name,
+    )?))
+}
+
+#[test]
+fn test_tp_flash_electroneutral() -> Result<(), Box<dyn Error>> {
+    let parameters = read_params(vec!["water", "hexane", "sodium ion", "chloride ion"])?;
+    let charges = parameters.z.clone();
+    let saft = Arc::new(ElectrolytePcSaft::new(parameters));
+    let t = 298.15 * KELVIN;
+    let p = BAR;
+    let feed = arr1(&[0.6, 0.38, 0.01, 0.01]) * MOL;
+    let options = SolverOptions::new().tol(1e-9);
+    let lle =
+        PhaseEquilibrium::tp_flash_electroneutral(&saft, t, p, &feed, &charges, None, options)?;
+    let organic = lle.phase_equilibrium.vapor();
+    let aqueous = lle.phase_equilibrium.liquid();
+
+    // both phases are electroneutral
+    assert!((&charges * &organic.molefracs).sum().abs() < 1e-12);
+    assert!((&charges * &aqueous.molefracs).sum().abs() < 1e-12);
+
+    // material balance
+    assert_relative_eq!(
+        organic.moles.clone() + aqueous.moles.clone(),
+        feed,
+        max_relative = 1e-10
+    );
+    let beta = lle.phase_fraction();
+    assert_relative_eq!(
+        beta,
+        (organic.total_moles / (organic.total_moles + aqueous.total_moles)).into_value()
+    );
+
+    // phase equilibrium of the solvents and the salt
+    let ln_f = |s: &feos_core::State<_>| s.molefracs.mapv(f64::ln) + s.ln_phi();
+    let (ln_f_org, ln_f_aq) = (ln_f(organic), ln_f(aqueous));
+    assert_relative_eq!(ln_f_org[0], ln_f_aq[0], epsilon = 1e-8);
+    assert_relative_eq!(ln_f_org[1], ln_f_aq[1], epsilon = 1e-8);
+    assert_relative_eq!(
+        ln_f_org[2] + ln_f_org[3],
+        ln_f_aq[2] + ln_f_aq[3],
+        epsilon = 1e-8
+    );
+
+    // the salt stays in the aqueous phase
+    let k_salt = lle.salt_distribution_coefficient(2, 3)?;
+    let k = lle.distribution_coefficients();
+    assert_relative_eq!(k_salt, (k[2] * k[3]).sqrt(), max_relative = 1e-12);
+    assert!(k_salt < 1e-3);
+    assert!(lle.salt_distribution_coefficient(3, 2).is_err());
+
+    // salting-out of hexane from the aqueous phase
+    let solvents = Arc::new(ElectrolytePcSaft::new(read_params(vec![
+        "water", "hexane",
+    ])?));
+    let lle_salt_free = PhaseEquilibrium::tp_flash(
+        &solvents,
+        t,
+        p,
+        &(arr1(&[0.6, 0.38]) * MOL),
+        None,
+        options,
+        None,
+    )?;
+    let aqueous_salt_free = if lle_salt_free.vapor().molefracs[0] > 0.5 {
+        lle_salt_free.vapor()
+    } else {
+        lle_salt_free.liquid()
+    };
+    let x_hexane = aqueous.molefracs[1] / (aqueous.molefracs[0] + aqueous.molefracs[1]);
+    assert!(x_hexane < aqueous_salt_free.molefracs[1]);
+    Ok(())
+}
+
+#[test]
+fn test_tp_flash_electroneutral_without_ions() -> Result<(), Box<dyn Error>> {
+    let parameters = read_params(vec!["water", "hexane"])?;
+    let charges = parameters.z.clone();
+    let saft = Arc::new(ElectrolytePcSaft::new(parameters));
+    let t = 298.15 * KELVIN;
+    let p = BAR;
+    let feed = arr1(&[0.6, 0.4]) * MOL;
+    let options = SolverOptions::new().tol(1e-9);
+    let lle =
+        PhaseEquilibrium::tp_flash_electroneutral(&saft, t, p, &feed, &charges, None, options)?;
+    let reference = PhaseEquilibrium::tp_flash(&saft, t, p, &feed, None, options, None)?;
+    let (x1, x2) = if reference.vapor().density < reference.liquid().density {
+        (reference.vapor(), reference.liquid())
+    } else {
+        (reference.liquid(), reference.vapor())
+    };
+    assert_relative_eq!(
+        lle.phase_equilibrium.vapor().molefracs,
+        x1.molefracs,
+        max_relative = 1e-6
+    );
+    assert_relative_eq!(
+        lle.phase_equilibrium.liquid().molefracs,
+        x2.molefracs,
+        max_relative = 1e-6
+    );
+    assert_eq!(lle.galvani_potential(), 0.0 * VOLT);
+
+    // the feed has to be electroneutral
+    let parameters = read_params(vec!["water", "sodium ion", "chloride ion"])?;
+    let charges = parameters.z.clone();
+    let saft = Arc::new(ElectrolytePcSaft::new(parameters));
+    let feed = arr1(&[0.9, 0.06, 0.04]) * MOL;
+    assert!(
+        PhaseEquilibrium::tp_flash_electroneutral(&saft, t, p, &feed, &charges, None, options)
+            .is_err()
+    );
+    Ok(())
+}
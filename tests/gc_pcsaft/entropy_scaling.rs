@@ -0,0 +1,46 @@
+use approx::assert_relative_eq;
+use feos::gc_pcsaft::{GcPcSaft, GcPcSaftEosParameters};
+use feos::pcsaft::{PcSaft, PcSaftParameters};
+use feos_core::parameter::{IdentifierOption, Parameter, ParameterHetero};
+use feos_core::{EntropyScaling, EosResult};
+use ndarray::arr1;
+use quantity::{KELVIN, METER, MOL};
+use std::sync::Arc;
+use typenum::P3;
+
+#[test]
+fn test_viscosity_homosegmented() -> EosResult<()> {
+    let gc_parameters = GcPcSaftEosParameters::from_json_segments(
+        &["hexane"],
+        "parameters/pcsaft/gc_substances.json",
+        "parameters/pcsaft/loetgeringlin2015_homo.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let parameters = PcSaftParameters::from_json_segments(
+        &["hexane"],
+        "parameters/pcsaft/gc_substances.json",
+        "parameters/pcsaft/loetgeringlin2015_homo.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let gc_eos = Arc::new(GcPcSaft::new(Arc::new(gc_parameters)));
+    let eos = Arc::new(PcSaft::new(Arc::new(parameters)));
+
+    let t = 300.0 * KELVIN;
+    let volume = 1.5e-4 * METER.powi::<P3>();
+    let moles = arr1(&[1.0]) * MOL;
+    let x = arr1(&[1.0]);
+    assert_relative_eq!(
+        gc_eos.viscosity_reference(t, volume, &moles)?,
+        eos.viscosity_reference(t, volume, &moles)?,
+        max_relative = 1e-10
+    );
+    assert_relative_eq!(
+        gc_eos.viscosity_correlation(-1.5, &x)?,
+        eos.viscosity_correlation(-1.5, &x)?,
+        max_relative = 1e-10
+    );
+
+    Ok(())
+}
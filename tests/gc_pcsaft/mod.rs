@@ -1,2 +1,4 @@
 mod binary;
 mod dft;
+#[cfg(feature = "pcsaft")]
+mod entropy_scaling;
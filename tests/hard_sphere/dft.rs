@@ -0,0 +1,69 @@
+#![cfg(feature = "dft")]
+use approx::assert_relative_eq;
+use feos::hard_sphere::{FMTFunctional, FMTVersion};
+use feos_core::{Contributions, ReferenceSystem, State};
+use feos_dft::adsorption::{ExternalPotential, Pore1D, PoreSpecification};
+use feos_dft::Geometry;
+use ndarray::{arr1, arr2, Axis};
+use quantity::*;
+use std::error::Error;
+use std::sync::Arc;
+use typenum::P3;
+
+#[test]
+fn test_slit_pore_versions() -> Result<(), Box<dyn Error>> {
+    let t = 300.0 * KELVIN;
+    let density = 0.7 / (3.0 * ANGSTROM).powi::<P3>() / NAV;
+    for version in [
+        FMTVersion::WhiteBear,
+        FMTVersion::WhiteBearMarkII,
+        FMTVersion::Rosenfeld,
+    ] {
+        let func = Arc::new(FMTFunctional::new(&arr1(&[3.0]), version));
+        let bulk = State::new_pure(&func, t, density)?;
+        let pore = Pore1D::new(
+            Geometry::Cartesian,
+            60.0 * ANGSTROM,
+            ExternalPotential::HardWall { sigma_ss: 0.0 },
+            Some(2048),
+            None,
+        );
+        let profile = pore.initialize(&bulk, None, None)?.solve(None)?;
+        let rho = profile.profile.density.to_reduced();
+        let contact_density = rho.index_axis(Axis(0), 0).fold(0.0, |a: f64, &r| a.max(r));
+        let p = (bulk.pressure(Contributions::Total) / (RGAS * t)).to_reduced();
+        // contact theorem (deviations are caused by the discretization)
+        assert_relative_eq!(contact_density, p, max_relative = 0.03);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_slit_pore_nonadditive() -> Result<(), Box<dyn Error>> {
+    let t = 300.0 * KELVIN;
+    let func = Arc::new(FMTFunctional::with_nonadditivity(
+        &arr1(&[3.0, 3.5]),
+        &arr2(&[[0.0, 0.1], [0.1, 0.0]]),
+        FMTVersion::WhiteBear,
+    ));
+    let density = 0.4 / (3.0 * ANGSTROM).powi::<P3>() / NAV;
+    let moles = arr1(&[0.5, 0.5]) * MOL;
+    let bulk = State::new_nvt(&func, t, moles.sum() / density, &moles)?;
+    let pore = Pore1D::new(
+        Geometry::Cartesian,
+        40.0 * ANGSTROM,
+        ExternalPotential::HardWall { sigma_ss: 0.0 },
+        Some(2048),
+        None,
+    );
+    let profile = pore.initialize(&bulk, None, None)?.solve(None)?;
+    let rho = profile.profile.density.to_reduced();
+    let contact_density: f64 = rho
+        .axis_iter(Axis(0))
+        .map(|rho| rho.fold(0.0, |a: f64, &r| a.max(r)))
+        .sum();
+    let p = (bulk.pressure(Contributions::Total) / (RGAS * t)).to_reduced();
+    // contact theorem (deviations are caused by the discretization)
+    assert_relative_eq!(contact_density, p, max_relative = 0.03);
+    Ok(())
+}
@@ -1,6 +1,20 @@
+#[cfg(all(feature = "capi", feature = "pcsaft"))]
+mod capi;
+#[cfg(feature = "cpa")]
+mod cpa;
+#[cfg(feature = "epcsaft")]
+mod epcsaft;
 #[cfg(feature = "gc_pcsaft")]
 mod gc_pcsaft;
+#[cfg(feature = "multiparameter")]
+mod multiparameter;
 #[cfg(feature = "pcsaft")]
 mod pcsaft;
+#[cfg(feature = "saftgammamie")]
+mod saftgammamie;
 #[cfg(feature = "saftvrmie")]
 mod saftvrmie;
+#[cfg(feature = "uvtheory")]
+mod uvtheory;
+#[cfg(feature = "dft")]
+mod hard_sphere;
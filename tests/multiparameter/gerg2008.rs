@@ -0,0 +1,125 @@
+use approx::assert_relative_eq;
+use feos::multiparameter::{Multiparameter, MultiparameterParameters};
+use feos_core::parameter::{IdentifierOption, Parameter};
+use feos_core::{Contributions, DensityInitialization, PhaseEquilibrium, State};
+use ndarray::arr1;
+use quantity::*;
+use std::error::Error;
+use std::sync::Arc;
+use typenum::P3;
+
+fn gerg2008(components: &[&str]) -> Result<Arc<Multiparameter>, Box<dyn Error>> {
+    let params = MultiparameterParameters::from_json(
+        components.to_vec(),
+        "parameters/multiparameter/kunz2012.json",
+        Some("parameters/multiparameter/kunz2012_binary.json"),
+        IdentifierOption::Name,
+    )?;
+    Ok(Arc::new(Multiparameter::new(Arc::new(params))))
+}
+
+#[test]
+fn critical_points_gerg2008() -> Result<(), Box<dyn Error>> {
+    // Kunz & Wagner (2012), Table A3.5; the technical equation for propane
+    // reproduces the critical pressure only to about 0.1 %
+    for (component, tc, pc) in [
+        ("methane", 190.564, 4.5992),
+        ("nitrogen", 126.192, 3.3958),
+        ("carbon dioxide", 304.1282, 7.3773),
+        ("ethane", 305.322, 4.8722),
+        ("propane", 369.825, 4.2512),
+    ] {
+        let eos = gerg2008(&[component])?;
+        let cp = State::critical_point(&eos, None, Some(tc * KELVIN), Default::default())?;
+        assert_relative_eq!(cp.temperature, tc * KELVIN, max_relative = 1e-3);
+        assert_relative_eq!(
+            cp.pressure(Contributions::Total),
+            pc * MEGA * PASCAL,
+            max_relative = 2e-3
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn vle_pure_gerg2008_components() -> Result<(), Box<dyn Error>> {
+    // normal boiling points of the reference equations of state
+    for (component, t_nbp, tol) in [
+        ("nitrogen", 77.355, 1e-3),
+        ("ethane", 184.569, 1e-3),
+        ("propane", 231.036, 3e-3),
+    ] {
+        let eos = gerg2008(&[component])?;
+        let vle = PhaseEquilibrium::pure(&eos, t_nbp * KELVIN, None, Default::default())?;
+        assert_relative_eq!(
+            vle.vapor().pressure(Contributions::Total),
+            101325.0 * PASCAL,
+            max_relative = tol
+        );
+    }
+
+    // triple point of carbon dioxide (Span & Wagner, 1996)
+    let eos = gerg2008(&["carbon dioxide"])?;
+    let vle = PhaseEquilibrium::pure(&eos, 216.592 * KELVIN, None, Default::default())?;
+    assert_relative_eq!(
+        vle.vapor().pressure(Contributions::Total),
+        0.51795 * MEGA * PASCAL,
+        max_relative = 1e-3
+    );
+    assert_relative_eq!(
+        vle.liquid().mass_density(),
+        1178.46 * KILOGRAM / METER.powi::<P3>(),
+        max_relative = 1e-3
+    );
+    assert_relative_eq!(
+        vle.vapor().mass_density(),
+        13.761 * KILOGRAM / METER.powi::<P3>(),
+        max_relative = 1e-3
+    );
+    Ok(())
+}
+
+#[test]
+fn compressibility_natural_gas() -> Result<(), Box<dyn Error>> {
+    // compressibility factor at reference conditions (288.15 K, 101.325 kPa)
+    // from the summation factors of ISO 6976:1995
+    let eos = gerg2008(&["methane", "nitrogen", "carbon dioxide", "ethane", "propane"])?;
+    let x = arr1(&[0.9, 0.02, 0.01, 0.05, 0.02]);
+    let sqrt_b = arr1(&[0.0447, 0.0173, 0.0748, 0.0922, 0.1338]);
+    let z_iso = 1.0 - f64::powi(x.dot(&sqrt_b), 2);
+    let state = State::new_npt(
+        &eos,
+        288.15 * KELVIN,
+        101325.0 * PASCAL,
+        &(x * MOL),
+        DensityInitialization::Vapor,
+    )?;
+    assert_relative_eq!(
+        state.compressibility(Contributions::Total),
+        z_iso,
+        max_relative = 1e-4
+    );
+    Ok(())
+}
+
+#[test]
+fn azeotrope_carbon_dioxide_ethane() -> Result<(), Box<dyn Error>> {
+    // carbon dioxide + ethane forms a maximum pressure azeotrope at
+    // x_CO2 ≈ 0.7 (e.g., Fredenslund & Mollerup, 1974)
+    let t = 250.0 * KELVIN;
+    let eos = gerg2008(&["carbon dioxide", "ethane"])?;
+    let p_co2 =
+        PhaseEquilibrium::pure(&gerg2008(&["carbon dioxide"])?, t, None, Default::default())?
+            .vapor()
+            .pressure(Contributions::Total);
+    let p_c2h6 = PhaseEquilibrium::pure(&gerg2008(&["ethane"])?, t, None, Default::default())?
+        .vapor()
+        .pressure(Contributions::Total);
+    let x = arr1(&[0.7, 0.3]);
+    let vle =
+        PhaseEquilibrium::bubble_point(&eos, t, &x, Some(p_co2), Some(&x), Default::default())?;
+    let p = vle.vapor().pressure(Contributions::Total);
+    assert!(p > p_co2 && p > p_c2h6);
+    assert_relative_eq!(vle.vapor().molefracs[0], x[0], epsilon = 0.02);
+    Ok(())
+}
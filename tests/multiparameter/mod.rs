@@ -0,0 +1,2 @@
+mod gerg2008;
+mod vle;
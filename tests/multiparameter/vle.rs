@@ -0,0 +1,56 @@
+use approx::assert_relative_eq;
+use feos::multiparameter::{Multiparameter, MultiparameterParameters};
+use feos_core::parameter::{IdentifierOption, Parameter};
+use feos_core::{Contributions, PhaseEquilibrium};
+use quantity::*;
+use std::error::Error;
+use std::sync::Arc;
+use typenum::P3;
+
+#[test]
+fn vle_pure_iapws95() -> Result<(), Box<dyn Error>> {
+    let params = MultiparameterParameters::from_json(
+        vec!["water"],
+        "parameters/multiparameter/wagner2002.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let eos = Arc::new(Multiparameter::new(Arc::new(params)));
+
+    // IAPWS-95, Table 8 (the pressure differs by the ratio of the gas constants)
+    let vle = PhaseEquilibrium::pure(&eos, 450.0 * KELVIN, None, Default::default())?;
+    assert_relative_eq!(
+        vle.vapor().pressure(Contributions::Total),
+        0.932203564 * MEGA * PASCAL,
+        max_relative = 2e-5
+    );
+    assert_relative_eq!(
+        vle.liquid().mass_density(),
+        890.341250 * KILOGRAM / METER.powi::<P3>(),
+        max_relative = 1e-8
+    );
+    assert_relative_eq!(
+        vle.vapor().mass_density(),
+        4.81200360 * KILOGRAM / METER.powi::<P3>(),
+        max_relative = 1e-8
+    );
+    Ok(())
+}
+
+#[test]
+fn vle_pure_gerg2008() -> Result<(), Box<dyn Error>> {
+    let params = MultiparameterParameters::from_json(
+        vec!["methane"],
+        "parameters/multiparameter/kunz2012.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let eos = Arc::new(Multiparameter::new(Arc::new(params)));
+    let vle = PhaseEquilibrium::pure(&eos, 111.667 * KELVIN, None, Default::default())?;
+    assert_relative_eq!(
+        vle.vapor().pressure(Contributions::Total),
+        101325.0 * PASCAL,
+        max_relative = 1e-3
+    );
+    Ok(())
+}
@@ -1,15 +1,26 @@
 #![allow(clippy::excessive_precision)]
 #![cfg(feature = "dft")]
 use approx::assert_relative_eq;
+use feos::association::{AssociationRecord, AssociationSiteRecord};
 use feos::hard_sphere::FMTVersion;
 use feos::ideal_gas::Joback;
-use feos::pcsaft::{PcSaft, PcSaftFunctional, PcSaftParameters};
-use feos_core::parameter::{IdentifierOption, Parameter};
-use feos_core::{Contributions, EquationOfState, PhaseEquilibrium, State, Verbosity};
+use feos::pcsaft::{
+    PcSaft, PcSaftAssociationRecord, PcSaftFunctional, PcSaftParameters, PcSaftRecord,
+};
+use feos_core::parameter::{Identifier, IdentifierOption, Parameter, PureRecord};
+use feos_core::{
+    Contributions, EquationOfState, PhaseEquilibrium, ReferenceSystem, State, Verbosity,
+};
+use feos_dft::adsorption::{
+    Adsorption1D, ExternalPotential, IdealAdsorbedSolution, Pore1D, PoreAxisymmetric,
+    PoreProfile1D, PoreShape, PoreSpecification, PureComponentIsotherm, SolvationForceCurve,
+    WettingProfile, YoungContactAngle,
+};
 use feos_dft::interface::PlanarInterface;
-use feos_dft::{DFTSolver, PdgtFunctionalProperties};
-use ndarray::{arr1, Axis};
+use feos_dft::{DFTSolver, DFTSpecifications, Geometry, PdgtFunctionalProperties};
+use ndarray::{arr1, s, Array1, Axis, Zip};
 use quantity::*;
+use std::collections::HashMap;
 use std::error::Error;
 use std::sync::Arc;
 use typenum::P3;
@@ -89,6 +100,285 @@ fn test_bulk_implementations() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn test_bulk_polarizable() -> Result<(), Box<dyn Error>> {
+    let dme = PcSaftRecord {
+        m: 2.2634,
+        sigma: 3.2723,
+        epsilon_k: 210.29,
+        mu: Some(1.3),
+        alpha: Some(5.24),
+        ..Default::default()
+    };
+    let co2 = PcSaftRecord {
+        m: 1.5131,
+        sigma: 3.1869,
+        epsilon_k: 163.333,
+        q: Some(4.4),
+        alpha: Some(2.91),
+        ..Default::default()
+    };
+    let dme = PureRecord::new(Identifier::default(), 46.0688, dme);
+    let co2 = PureRecord::new(Identifier::default(), 44.0098, co2);
+    let t = 300.0 * KELVIN;
+    let v = 1e-4 * METER.powi::<P3>();
+
+    // pure components
+    for record in [&dme, &co2] {
+        let params = Arc::new(PcSaftParameters::new_pure(record.clone())?);
+        let eos = Arc::new(PcSaft::new(params.clone()));
+        let func_pure = Arc::new(PcSaftFunctional::new(params.clone()));
+        let func_full = Arc::new(PcSaftFunctional::new_full(params, FMTVersion::WhiteBear));
+        let n = arr1(&[1.5]) * MOL;
+        let a = State::new_nvt(&eos, t, v, &n)?.residual_helmholtz_energy();
+        let a_pure = State::new_nvt(&func_pure, t, v, &n)?.residual_helmholtz_energy();
+        let a_full = State::new_nvt(&func_full, t, v, &n)?.residual_helmholtz_energy();
+        assert_relative_eq!(a, a_pure, max_relative = 1e-12);
+        assert_relative_eq!(a, a_full, max_relative = 1e-12);
+    }
+
+    // mixture
+    let params = Arc::new(PcSaftParameters::new_binary(vec![dme, co2], None)?);
+    let eos = Arc::new(PcSaft::new(params.clone()));
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let n = arr1(&[0.5, 1.0]) * MOL;
+    let a = State::new_nvt(&eos, t, v, &n)?.residual_helmholtz_energy();
+    let a_func = State::new_nvt(&func, t, v, &n)?.residual_helmholtz_energy();
+    assert_relative_eq!(a, a_func, max_relative = 1e-12);
+    Ok(())
+}
+
+#[test]
+fn test_bulk_site_types() -> Result<(), Box<dyn Error>> {
+    let site = |site_type: &str, n: f64, bonds_with: &[&str]| {
+        let bonds_with = bonds_with.iter().map(|s| s.to_string()).collect();
+        AssociationSiteRecord::new(site_type.into(), n, bonds_with, None, HashMap::new())
+    };
+    let association = PcSaftAssociationRecord::new(Some(0.034867983), Some(2500.6706));
+    let sites = vec![
+        site("H", 2.0, &["O"]),
+        site("O", 1.0, &[]),
+        site("E", 1.0, &["E", "O"]),
+    ];
+    let water = PcSaftRecord {
+        m: 1.065587,
+        sigma: 3.000683,
+        epsilon_k: 366.5121,
+        association_record: Some(AssociationRecord::with_sites(association, sites)),
+        ..Default::default()
+    };
+    let methanol = PcSaftRecord {
+        m: 1.5255,
+        sigma: 3.23,
+        epsilon_k: 188.9,
+        association_record: Some(AssociationRecord::new(
+            PcSaftAssociationRecord::new(Some(0.035176), Some(2899.5)),
+            1.0,
+            1.0,
+            0.0,
+        )),
+        ..Default::default()
+    };
+    let water = PureRecord::new(Identifier::default(), 18.0152, water);
+    let methanol = PureRecord::new(Identifier::default(), 32.042, methanol);
+    let t = 300.0 * KELVIN;
+    let v = 1e-4 * METER.powi::<P3>();
+
+    // pure component
+    let params = Arc::new(PcSaftParameters::new_pure(water.clone())?);
+    let eos = Arc::new(PcSaft::new(params.clone()));
+    let func_pure = Arc::new(PcSaftFunctional::new(params.clone()));
+    let func_full = Arc::new(PcSaftFunctional::new_full(params, FMTVersion::WhiteBear));
+    let n = arr1(&[1.5]) * MOL;
+    let a = State::new_nvt(&eos, t, v, &n)?.residual_helmholtz_energy();
+    let a_pure = State::new_nvt(&func_pure, t, v, &n)?.residual_helmholtz_energy();
+    let a_full = State::new_nvt(&func_full, t, v, &n)?.residual_helmholtz_energy();
+    assert_relative_eq!(a, a_pure, max_relative = 1e-10);
+    assert_relative_eq!(a, a_full, max_relative = 1e-10);
+
+    // mixture with a component using sites of type A and B
+    let params = Arc::new(PcSaftParameters::new_binary(vec![water, methanol], None)?);
+    let eos = Arc::new(PcSaft::new(params.clone()));
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let n = arr1(&[0.5, 1.0]) * MOL;
+    let a = State::new_nvt(&eos, t, v, &n)?.residual_helmholtz_energy();
+    let a_func = State::new_nvt(&func, t, v, &n)?.residual_helmholtz_energy();
+    assert_relative_eq!(a, a_func, max_relative = 1e-10);
+    Ok(())
+}
+
+#[test]
+fn test_bulk_cross_association_c_sites() -> Result<(), Box<dyn Error>> {
+    // two components with sites of type C require the iterative solver
+    let record = |m, sigma, epsilon_k, kappa_ab, epsilon_k_ab| {
+        let association = AssociationRecord::new(
+            PcSaftAssociationRecord::new(Some(kappa_ab), Some(epsilon_k_ab)),
+            0.0,
+            0.0,
+            1.0,
+        );
+        let record = PcSaftRecord {
+            m,
+            sigma,
+            epsilon_k,
+            association_record: Some(association),
+            ..Default::default()
+        };
+        PureRecord::new(Identifier::default(), 60.0, record)
+    };
+    let acid1 = record(1.4, 3.6, 240.0, 0.05, 2500.0);
+    let acid2 = record(2.0, 3.8, 250.0, 0.02, 2200.0);
+    let params = Arc::new(PcSaftParameters::new_binary(vec![acid1, acid2], None)?);
+    let eos = Arc::new(PcSaft::new(params.clone()));
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let t = 350.0 * KELVIN;
+    let v = 1e-4 * METER.powi::<P3>();
+    let n = arr1(&[0.5, 1.0]) * MOL;
+    let a = State::new_nvt(&eos, t, v, &n)?.residual_helmholtz_energy();
+    let a_func = State::new_nvt(&func, t, v, &n)?.residual_helmholtz_energy();
+    assert_relative_eq!(a, a_func, max_relative = 1e-10);
+    Ok(())
+}
+
+#[test]
+fn test_pore_axisymmetric() -> Result<(), Box<dyn Error>> {
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let bulk = State::new_pure(&func, 500.0 * KELVIN, 6000.0 * MOL / METER.powi::<P3>())?;
+    let system_size = [16.0 * ANGSTROM, 64.0 * ANGSTROM];
+
+    // infinitely long cylindrical pore on the same radial grid, with the
+    // wall shifted from 16 A to 12 A
+    let potential = ExternalPotential::HardWall { sigma_ss: 11.0 };
+    let pore = Pore1D::new(
+        Geometry::Cylindrical,
+        system_size[0],
+        potential,
+        Some(64),
+        None,
+    );
+    let profile_1d = pore.initialize(&bulk, None, None)?.solve(None)?;
+
+    // the center of a long cylindrical pore is not affected by the pore mouth
+    let potential = ExternalPotential::HardWall { sigma_ss: 3.0 };
+    let shape = PoreShape::FiniteCylinder {
+        radius: 12.0 * ANGSTROM,
+        length: 80.0 * ANGSTROM,
+    };
+    let pore = PoreAxisymmetric::new(
+        shape,
+        system_size,
+        [64, 32],
+        potential.clone(),
+        None,
+        Some(1),
+    );
+    let profile = pore.initialize(&bulk, None, None)?.solve(None)?;
+    let rho_1d = profile_1d.profile.density.get((0, 0));
+    let rho_center = profile.profile.density.get((0, 0, 0));
+    assert_relative_eq!(rho_center, rho_1d, max_relative = 1e-3);
+
+    // far from the solid, the density approaches the bulk density
+    let rho_reservoir = profile.profile.density.get((0, 0, 31));
+    assert_relative_eq!(rho_reservoir, bulk.density, max_relative = 1e-2);
+
+    // the solid is excluded from the ink-bottle pore
+    let shape = PoreShape::InkBottle {
+        cavity_radius: 12.0 * ANGSTROM,
+        cavity_length: 30.0 * ANGSTROM,
+        neck_radius: 6.0 * ANGSTROM,
+        neck_length: 10.0 * ANGSTROM,
+    };
+    let pore = PoreAxisymmetric::new(
+        shape.clone(),
+        system_size,
+        [64, 32],
+        potential.clone(),
+        None,
+        Some(1),
+    );
+    let profile = pore.initialize(&bulk, None, None)?;
+    let external_potential = &profile.profile.external_potential;
+    let grids = profile.profile.grid.grids();
+    let ir = grids[0].iter().position(|&r| r > 8.0).unwrap();
+    let iz = grids[1].iter().position(|&z| z > 20.0).unwrap();
+    assert_eq!(external_potential[(0, ir, 0)], 0.0);
+    assert_eq!(external_potential[(0, ir, iz)], 50.0);
+
+    // the system has to include the reservoir
+    let system_size = [10.0 * ANGSTROM, 64.0 * ANGSTROM];
+    let pore = PoreAxisymmetric::new(shape, system_size, [64, 32], potential, None, Some(1));
+    assert!(pore.initialize(&bulk, None, None).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_pore_axisymmetric_lj93() -> Result<(), Box<dyn Error>> {
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let bulk = State::new_pure(&func, 300.0 * KELVIN, 100.0 * MOL / METER.powi::<P3>())?;
+    let potential = ExternalPotential::LJ93 {
+        sigma_ss: 3.0,
+        epsilon_k_ss: 100.0,
+        rho_s: 0.08,
+    };
+
+    // the center of a long cylindrical pore experiences the potential of an
+    // infinitely long cylindrical pore (reference values from a numerical
+    // integration of the Lennard-Jones interactions over the solid)
+    let shape = PoreShape::FiniteCylinder {
+        radius: 12.0 * ANGSTROM,
+        length: 400.0 * ANGSTROM,
+    };
+    let system_size = [20.0 * ANGSTROM, 240.0 * ANGSTROM];
+    let pore = PoreAxisymmetric::new(shape, system_size, [40, 16], potential.clone(), None, None);
+    let profile = pore.initialize(&bulk, None, None)?;
+    let grids = profile.profile.grid.grids();
+    for (i, r, v) in [
+        (3, 1.5938487899799723, -0.6172352782316),
+        (11, 2.775169855410077, -0.708187421925995),
+        (19, 4.832056701234227, -1.0989539531712),
+        (27, 8.413456898295847, -5.50237743159688),
+    ] {
+        assert_relative_eq!(grids[0][i], r, max_relative = 1e-10);
+        assert_relative_eq!(
+            profile.profile.external_potential[(0, i, 0)],
+            v,
+            max_relative = 1e-3
+        );
+    }
+
+    // the attraction is weaker at the pore mouth
+    let iz = grids[1].iter().position(|&z| z > 200.0).unwrap();
+    assert!(
+        profile.profile.external_potential[(0, 0, iz)]
+            > profile.profile.external_potential[(0, 0, 0)]
+    );
+
+    // other potentials are not available for axisymmetric pores
+    let potential = ExternalPotential::SimpleLJ93 {
+        sigma_ss: 3.0,
+        epsilon_k_ss: 100.0,
+    };
+    let shape = PoreShape::FiniteCylinder {
+        radius: 12.0 * ANGSTROM,
+        length: 40.0 * ANGSTROM,
+    };
+    let pore = PoreAxisymmetric::new(shape, system_size, [40, 16], potential, None, None);
+    assert!(pore.initialize(&bulk, None, None).is_err());
+    Ok(())
+}
+
 #[test]
 #[allow(non_snake_case)]
 fn test_dft_propane() -> Result<(), Box<dyn Error>> {
@@ -388,3 +678,469 @@ fn test_entropy_bulk_values() -> Result<(), Box<dyn Error>> {
     );
     Ok(())
 }
+
+#[test]
+fn test_pore_specified_moles() -> Result<(), Box<dyn Error>> {
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let bulk = State::new_pure(&func, 300.0 * KELVIN, 40.0 * MOL / METER.powi::<P3>())?;
+    let pore = Pore1D::new(
+        Geometry::Cartesian,
+        30.0 * ANGSTROM,
+        ExternalPotential::LJ93 {
+            sigma_ss: 3.0,
+            epsilon_k_ss: 10.0,
+            rho_s: 0.08,
+        },
+        Some(512),
+        None,
+    );
+    let solver = DFTSolver::new(None)
+        .picard_iteration(None, Some(50), None, None)
+        .anderson_mixing(None, None, None, None, None);
+    let mut profile = pore.initialize(&bulk, None, None)?.solve(Some(&solver))?;
+
+    // the bulk density is adjusted to obtain the specified number of particles
+    let moles = profile.profile.moles() * 0.8;
+    profile.profile.specification = Arc::new(DFTSpecifications::Moles {
+        moles: moles.to_reduced(),
+    });
+    let profile = profile.solve(None)?;
+    assert_relative_eq!(profile.profile.moles(), moles, max_relative = 1e-8);
+
+    // the resulting bulk state is in equilibrium with the pore
+    let reference = pore
+        .initialize(&profile.profile.bulk, None, None)?
+        .solve(Some(&solver))?;
+    assert_relative_eq!(reference.profile.moles(), moles, max_relative = 1e-6);
+    Ok(())
+}
+
+#[test]
+fn test_contact_angle_propane() -> Result<(), Box<dyn Error>> {
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let vle = PhaseEquilibrium::pure(&func, 230.0 * KELVIN, None, Default::default())?;
+    let potential = ExternalPotential::LJ93 {
+        sigma_ss: 3.0,
+        epsilon_k_ss: 10.0,
+        rho_s: 0.08,
+    };
+
+    // contact angle from Young's equation (converged with respect to the grid)
+    let young = |n| {
+        let pore = Pore1D::new(
+            Geometry::Cartesian,
+            100.0 * ANGSTROM,
+            potential.clone(),
+            Some(n),
+            None,
+        );
+        YoungContactAngle::new(&vle, &pore, None).map(|y| y.contact_angle().convert_into(DEGREES))
+    };
+    let theta_young = young(1024)?;
+    assert!((young(256)? - theta_young).abs() < 0.05);
+    assert!(theta_young > 90.0 && theta_young < 180.0);
+
+    // contact angle from the shape of the droplet
+    let solver = DFTSolver::new(None)
+        .picard_iteration(Some(true), Some(50), None, None)
+        .anderson_mixing(None, None, None, None, None);
+    let wetting = WettingProfile::new(
+        &vle,
+        &potential,
+        [50.0 * ANGSTROM, 100.0 * ANGSTROM],
+        [48, 96],
+        None,
+    )
+    .solve(Some(&solver))?;
+    let theta_profile = wetting.contact_angle.unwrap().convert_into(DEGREES);
+    // the angle obtained from the droplet shape depends on the grid
+    // (108.6°, 115.5° and 124.3° for 32x64, 48x96 and 64x128 grid points)
+    assert!((theta_profile - theta_young).abs() < 2.5);
+    Ok(())
+}
+
+#[test]
+fn test_solvation_force_propane() -> Result<(), Box<dyn Error>> {
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let vle = PhaseEquilibrium::pure(&func, 230.0 * KELVIN, None, Default::default())?;
+    let pore = Pore1D::new(
+        Geometry::Cartesian,
+        30.0 * ANGSTROM,
+        ExternalPotential::LJ93 {
+            sigma_ss: 3.0,
+            epsilon_k_ss: 100.0,
+            rho_s: 0.08,
+        },
+        Some(512),
+        None,
+    );
+    let solver = DFTSolver::new(None)
+        .picard_iteration(None, Some(50), None, None)
+        .anderson_mixing(None, None, None, None, None);
+    let pore_size = Length::linspace(30.0 * ANGSTROM, 10.0 * ANGSTROM, 41);
+    let curve = SolvationForceCurve::new(vle.liquid(), &pore, &pore_size, Some(&solver))?;
+    assert!(curve.profiles.iter().all(|p| p.is_ok()));
+
+    // warm-started profiles agree with independent calculations
+    let i = 30;
+    let reference = Pore1D::new(
+        Geometry::Cartesian,
+        pore_size.get(i),
+        pore.potential.clone(),
+        pore.n_grid,
+        None,
+    )
+    .initialize(vle.liquid(), None, None)?
+    .solve(Some(&solver))?;
+    assert_relative_eq!(
+        curve.grand_potential().get(i),
+        reference.grand_potential.unwrap(),
+        max_relative = 1e-6
+    );
+
+    // the disjoining pressure vanishes for wide pores
+    let pi = curve.disjoining_pressure();
+    assert!(pi.get(i).abs() > 10.0 * pi.get(0).abs());
+
+    // pressure sum rule: the disjoining pressure is the force per area that the
+    // fluid exerts on a wall minus the bulk pressure
+    let pore_size = Length::linspace(15.2 * ANGSTROM, 14.8 * ANGSTROM, 5);
+    let curve = SolvationForceCurve::new(vle.liquid(), &pore, &pore_size, Some(&solver))?;
+    let profile = curve.profiles[2].as_ref().unwrap();
+    let t = vle.liquid().temperature.to_reduced();
+    let h = 0.5 * pore_size.get(2).to_reduced();
+    let axis = profile.profile.grid.axes()[0];
+    let dz = &axis.edges.slice(s![1..]) - &axis.edges.slice(s![..-1]);
+    let dv = |z: Array1<f64>| {
+        let delta = 1e-6;
+        let v = |z| {
+            pore.potential
+                .calculate_cartesian_potential(&z, func.as_ref(), t)
+        };
+        (v(&z + delta) - v(&z - delta)) / (2.0 * delta)
+    };
+    let dv = dv(h - &axis.grid) + dv(h + &axis.grid);
+    let rho = profile.profile.density.to_reduced();
+    let external_potential = &profile.profile.external_potential;
+    let force: f64 = Zip::from(&rho)
+        .and(&dv)
+        .and(external_potential)
+        .and_broadcast(&dz)
+        .fold(0.0, |f, &rho, &dv, &v, &dz| {
+            // skip the points at which the potential is cut off
+            if v < 50.0 {
+                f - rho * dv * dz
+            } else {
+                f
+            }
+        });
+    let force = Pressure::from_reduced(force);
+    assert_relative_eq!(
+        curve.disjoining_pressure().get(2),
+        force - vle.liquid().pressure(Contributions::Total),
+        max_relative = 1e-2
+    );
+    Ok(())
+}
+
+#[test]
+fn test_iast_langmuir() -> Result<(), Box<dyn Error>> {
+    // IAST reduces to the extended Langmuir isotherm for equal saturation loadings
+    let n_sat = 2.0 * MOL;
+    let b = [0.5 / BAR, 0.1 / BAR];
+    let iast = IdealAdsorbedSolution::new(
+        b.iter()
+            .map(|&b| PureComponentIsotherm::Langmuir { n_sat, b })
+            .collect(),
+    );
+    let pressure = 5.0 * BAR;
+    let y = arr1(&[0.3, 0.7]);
+    let n = iast.adsorption(pressure, &y)?;
+    let denominator = 1.0 + ((b[0] * y[0] + b[1] * y[1]) * pressure).into_value();
+    for i in 0..2 {
+        assert_relative_eq!(
+            n.get(i),
+            n_sat * (b[i] * y[i] * pressure / denominator),
+            max_relative = 1e-8
+        );
+    }
+    let s = iast.selectivity(pressure, &y)?;
+    assert_relative_eq!(s[(0, 1)], 5.0, max_relative = 1e-8);
+
+    // tabulated and Toth isotherms are consistent with the Langmuir isotherm
+    let langmuir = &iast.isotherms[0];
+    let p = Pressure::linspace(0.01 * BAR, 50.0 * BAR, 500);
+    let tabulated = PureComponentIsotherm::Tabulated {
+        adsorption: Moles::from_shape_fn(p.len(), |i| langmuir.adsorption(p.get(i))),
+        pressure: p,
+    };
+    let toth = PureComponentIsotherm::Toth {
+        n_sat,
+        b: b[0],
+        t: 1.0,
+    };
+    for iso in [tabulated, toth] {
+        assert_relative_eq!(
+            iso.spreading_pressure(pressure),
+            langmuir.spreading_pressure(pressure),
+            max_relative = 5e-3
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_iast_dft_methane_co2() -> Result<(), Box<dyn Error>> {
+    let functional = |components: Vec<&str>| -> Result<_, Box<dyn Error>> {
+        let params = Arc::new(PcSaftParameters::from_json(
+            components,
+            "tests/pcsaft/test_parameters.json",
+            None,
+            IdentifierOption::Name,
+        )?);
+        Ok(Arc::new(PcSaftFunctional::new(params)))
+    };
+    let temperature = 320.0 * KELVIN;
+    let pressure = Pressure::linspace(0.5 * BAR, 20.0 * BAR, 10);
+    let pore = Pore1D::new(
+        Geometry::Cartesian,
+        20.0 * ANGSTROM,
+        ExternalPotential::LJ93 {
+            sigma_ss: 3.0,
+            epsilon_k_ss: 100.0,
+            rho_s: 0.08,
+        },
+        Some(512),
+        None,
+    );
+
+    // pure-component isotherms over a wider pressure range
+    let pressure_pure = Pressure::linspace(0.01 * BAR, 40.0 * BAR, 40);
+    let pure = ["methane", "carbon-dioxide"]
+        .into_iter()
+        .map(|c| {
+            Adsorption1D::adsorption_isotherm(
+                &functional(vec![c])?,
+                temperature,
+                &pressure_pure,
+                &pore,
+                None,
+                None,
+            )
+            .map_err(|e| e.into())
+        })
+        .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+    let iast = IdealAdsorbedSolution::from_adsorption(&pure)?;
+
+    // mixture isotherm
+    let y = arr1(&[0.5, 0.5]);
+    let mixture = Adsorption1D::adsorption_isotherm(
+        &functional(vec!["methane", "carbon-dioxide"])?,
+        temperature,
+        &pressure,
+        &pore,
+        Some(&y),
+        None,
+    )?;
+    let deviation = iast.deviation(&mixture);
+    println!("{deviation}");
+    assert!(deviation.iter().all(|d| d.abs() < 0.1));
+    let s = iast.selectivity(pressure.get(0), &y)?;
+    println!("{s}");
+    assert!(s[(1, 0)] > 1.0);
+    Ok(())
+}
+
+#[test]
+fn test_checkpoint_profiles() -> Result<(), Box<dyn Error>> {
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let vle = PhaseEquilibrium::pure(&func, 230.0 * KELVIN, None, Default::default())?;
+    let dir = std::env::temp_dir();
+
+    // planar interface with fixed equimolar surface
+    let interface = PlanarInterface::from_pdgt(&vle, 1024, true)?.solve(None)?;
+    let path = dir.join("feos_test_checkpoint_interface.json");
+    interface.to_json(&path)?;
+    let restored = PlanarInterface::from_json(&func, &path)?;
+    std::fs::remove_file(&path)?;
+    assert_eq!(restored.profile.density, interface.profile.density);
+    assert_eq!(
+        restored.profile.grid.grids()[0],
+        interface.profile.grid.grids()[0]
+    );
+    assert_eq!(restored.surface_tension, interface.surface_tension);
+    assert_relative_eq!(
+        restored.vle.liquid().density,
+        interface.vle.liquid().density,
+        max_relative = 1e-10
+    );
+
+    // restarting the solver from the converged profile
+    let restarted = restored.solve(None)?;
+    assert!(restarted.profile.solver_log.unwrap().residual().len() <= 2);
+    assert_relative_eq!(
+        restarted.surface_tension.unwrap(),
+        interface.surface_tension.unwrap(),
+        max_relative = 1e-8
+    );
+
+    // pore profile
+    let pore = Pore1D::new(
+        Geometry::Cartesian,
+        20.0 * ANGSTROM,
+        ExternalPotential::LJ93 {
+            sigma_ss: 3.0,
+            epsilon_k_ss: 100.0,
+            rho_s: 0.08,
+        },
+        Some(256),
+        None,
+    );
+    let solver = DFTSolver::new(None)
+        .picard_iteration(None, Some(50), None, None)
+        .anderson_mixing(None, None, None, None, None);
+    let profile = pore
+        .initialize(vle.liquid(), None, None)?
+        .solve(Some(&solver))?;
+    let path = dir.join("feos_test_checkpoint_pore.json");
+    profile.to_json(&path)?;
+    let restored = PoreProfile1D::from_json(&func, &path)?;
+    std::fs::remove_file(&path)?;
+    assert_eq!(restored.profile.density, profile.profile.density);
+    assert_eq!(
+        restored.profile.external_potential,
+        profile.profile.external_potential
+    );
+    assert_eq!(restored.grand_potential, profile.grand_potential);
+    assert_relative_eq!(
+        restored.profile.bulk.partial_density,
+        profile.profile.bulk.partial_density,
+        max_relative = 1e-14
+    );
+    Ok(())
+}
+
+#[test]
+fn test_non_uniform_grid() -> Result<(), Box<dyn Error>> {
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let vle = PhaseEquilibrium::pure(&func, 230.0 * KELVIN, None, Default::default())?;
+    let solver = DFTSolver::new(None)
+        .picard_iteration(None, Some(50), None, None)
+        .anderson_mixing(None, None, None, None, None);
+
+    // planar interface refined from a coarse uniform grid
+    let reference = PlanarInterface::from_pdgt(&vle, 2048, true)?.solve(Some(&solver))?;
+    let interface = PlanarInterface::from_pdgt(&vle, 128, true)?
+        .solve(Some(&solver))?
+        .refine(128)?;
+    assert!(!interface.profile.grid.axes()[0].is_equidistant());
+    let interface = interface.solve(Some(&solver))?;
+    assert_relative_eq!(
+        interface.surface_tension.unwrap(),
+        reference.surface_tension.unwrap(),
+        max_relative = 1e-3
+    );
+    assert_relative_eq!(
+        interface.interfacial_thickness()?,
+        reference.interfacial_thickness()?,
+        max_relative = 2e-3
+    );
+
+    // spherical pore refined from a coarse uniform grid
+    let potential = ExternalPotential::LJ93 {
+        sigma_ss: 3.0,
+        epsilon_k_ss: 100.0,
+        rho_s: 0.08,
+    };
+    let pore = Pore1D::new(
+        Geometry::Spherical,
+        20.0 * ANGSTROM,
+        potential.clone(),
+        Some(512),
+        None,
+    );
+    let reference = pore
+        .initialize(vle.liquid(), None, None)?
+        .solve(Some(&solver))?;
+    let pore = Pore1D::new(
+        Geometry::Spherical,
+        20.0 * ANGSTROM,
+        potential.clone(),
+        Some(128),
+        None,
+    );
+    let profile = pore
+        .initialize(vle.liquid(), None, None)?
+        .solve(Some(&solver))?;
+    let profile = pore.refine(&profile, 128)?.solve(Some(&solver))?;
+    assert_relative_eq!(
+        profile.grand_potential.unwrap(),
+        reference.grand_potential.unwrap(),
+        max_relative = 2e-3
+    );
+
+    // slit pore with very small cells close to the wall
+    let pore = Pore1D::new(
+        Geometry::Cartesian,
+        20.0 * ANGSTROM,
+        potential.clone(),
+        None,
+        None,
+    );
+    let reference = pore
+        .initialize(vle.liquid(), None, None)?
+        .solve(Some(&solver))?;
+    let edges = Array1::linspace(0.0, 1.0, 257).mapv(|u: f64| 10.0 * (1.0 - (1.0 - u).powi(2)));
+    let axis = feos_dft::Axis::new_cartesian_from_edges(
+        &(edges * ANGSTROM),
+        Some(2.0 * func.parameters.sigma[0]),
+    )?;
+    let profile = pore
+        .with_axis(axis)?
+        .initialize(vle.liquid(), None, None)?
+        .solve(Some(&solver))?;
+    assert_relative_eq!(
+        profile.grand_potential.unwrap(),
+        reference.grand_potential.unwrap(),
+        max_relative = 2e-3
+    );
+
+    // axes that do not match the pore are rejected
+    let pore = Pore1D::new(Geometry::Cartesian, 20.0 * ANGSTROM, potential, None, None);
+    let axis = feos_dft::Axis::new_spherical_from_edges(&(arr1(&[0.0, 5.0, 10.0]) * ANGSTROM))?;
+    assert!(pore.with_axis(axis).is_err());
+    assert!(
+        feos_dft::Axis::new_spherical_from_edges(&(arr1(&[0.0, 5.0, 5.0]) * ANGSTROM)).is_err()
+    );
+    Ok(())
+}
@@ -1,7 +1,8 @@
 use approx::assert_relative_eq;
 use feos::pcsaft::{PcSaft, PcSaftParameters};
 use feos_core::parameter::{IdentifierOption, Parameter};
-use feos_core::{Residual, StateBuilder};
+use feos_core::testing::{check_residual, ConsistencyOptions};
+use feos_core::{DensityInitialization, Residual, State, StateBuilder};
 use ndarray::*;
 use quantity::*;
 use std::error::Error;
@@ -53,3 +54,24 @@ fn test_virial_is_not_nan() -> Result<(), Box<dyn Error>> {
     assert!(!virial_b.is_nan());
     Ok(())
 }
+
+#[test]
+fn test_consistency() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane", "water_np"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let moles = arr1(&[0.2, 0.3, 0.5]) * MOL;
+    for (t, p, density_initialization) in [
+        (300.0 * KELVIN, 100.0 * BAR, DensityInitialization::Liquid),
+        (500.0 * KELVIN, BAR, DensityInitialization::Vapor),
+    ] {
+        let state = State::new_npt(&saft, t, p, &moles, density_initialization)?;
+        let report = check_residual(&state, ConsistencyOptions::default())?;
+        assert!(report.passed(), "{report}");
+    }
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use approx::assert_relative_eq;
+use feos::saftgammamie::{SaftGammaMie, SaftGammaMieParameters};
+use feos_core::parameter::{IdentifierOption, ParameterHetero};
+use feos_core::{Contributions, EosResult, PhaseEquilibrium};
+use quantity::{KELVIN, PASCAL};
+use std::sync::Arc;
+
+fn parameters(substances: &[&str]) -> SaftGammaMieParameters {
+    SaftGammaMieParameters::from_json_segments(
+        substances,
+        "tests/saftgammamie/chemical_records.json",
+        "tests/saftgammamie/groups.json",
+        Some("tests/saftgammamie/binary.json"),
+        IdentifierOption::Name,
+    )
+    .unwrap()
+}
+
+#[test]
+fn normal_boiling_points() -> EosResult<()> {
+    for (substance, tb) in [
+        ("n-butane", 272.66),
+        ("n-hexane", 341.88),
+        ("n-octane", 398.82),
+    ] {
+        let eos = Arc::new(SaftGammaMie::new(Arc::new(parameters(&[substance]))));
+        let vle = PhaseEquilibrium::pure(&eos, tb * KELVIN, None, Default::default())?;
+        let p = vle.vapor().pressure(Contributions::Total);
+        assert_relative_eq!(p.convert_into(PASCAL), 101325.0, max_relative = 0.03);
+    }
+    Ok(())
+}
@@ -0,0 +1,116 @@
+#![cfg(feature = "dft")]
+use approx::assert_relative_eq;
+use feos::hard_sphere::FMTVersion;
+use feos::uvtheory::{
+    Perturbation, UVTheory, UVTheoryFunctional, UVTheoryOptions, UVTheoryParameters, UVTheoryRecord,
+};
+use feos_core::parameter::{Identifier, Parameter, PureRecord};
+use feos_core::{Contributions, PhaseEquilibrium, State};
+use feos_dft::interface::PlanarInterface;
+use ndarray::arr1;
+use quantity::*;
+use std::error::Error;
+use std::sync::Arc;
+use typenum::P3;
+
+#[test]
+fn test_bulk_implementations() -> Result<(), Box<dyn Error>> {
+    let params = Arc::new(UVTheoryParameters::new_simple(12.0, 6.0, 3.4, 120.0)?);
+    let t = 100.0 * KELVIN;
+    let v = 1e-26 * METER.powi::<P3>();
+    let n = arr1(&[2.5e-3]) * MOL * 1e-20;
+    for perturbation in [
+        Perturbation::BarkerHenderson,
+        Perturbation::WeeksChandlerAndersen,
+        Perturbation::WeeksChandlerAndersenB3,
+    ] {
+        let options = UVTheoryOptions {
+            max_eta: 0.5,
+            perturbation,
+        };
+        let eos = Arc::new(UVTheory::with_options(params.clone(), options.clone()));
+        let func = Arc::new(UVTheoryFunctional::with_options(
+            params.clone(),
+            FMTVersion::WhiteBear,
+            options,
+        ));
+        let state_eos = State::new_nvt(&eos, t, v, &n)?;
+        let state_func = State::new_nvt(&func, t, v, &n)?;
+        assert_relative_eq!(
+            state_func.residual_helmholtz_energy(),
+            state_eos.residual_helmholtz_energy(),
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            state_func.pressure(Contributions::Total),
+            state_eos.pressure(Contributions::Total),
+            max_relative = 1e-12
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_bulk_implementations_mixture() -> Result<(), Box<dyn Error>> {
+    let records = vec![
+        PureRecord::new(
+            Identifier::default(),
+            39.948,
+            UVTheoryRecord::new(12.0, 6.0, 3.4, 120.0),
+        ),
+        PureRecord::new(
+            Identifier::default(),
+            83.798,
+            UVTheoryRecord::new(14.0, 6.0, 3.6, 160.0),
+        ),
+    ];
+    let params = Arc::new(UVTheoryParameters::new_binary(records, None)?);
+    let eos = Arc::new(UVTheory::new(params.clone()));
+    let func = Arc::new(UVTheoryFunctional::new(params));
+    let t = 150.0 * KELVIN;
+    let v = 1e-26 * METER.powi::<P3>();
+    let n = arr1(&[1.0e-3, 1.5e-3]) * MOL * 1e-20;
+    let state_eos = State::new_nvt(&eos, t, v, &n)?;
+    let state_func = State::new_nvt(&func, t, v, &n)?;
+    assert_relative_eq!(
+        state_func.residual_helmholtz_energy(),
+        state_eos.residual_helmholtz_energy(),
+        max_relative = 1e-12
+    );
+    assert_relative_eq!(
+        state_func.residual_chemical_potential(),
+        state_eos.residual_chemical_potential(),
+        max_relative = 1e-12
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dft_planar_interface() -> Result<(), Box<dyn Error>> {
+    let params = Arc::new(UVTheoryParameters::new_simple(12.0, 6.0, 3.4, 120.0)?);
+    let func = Arc::new(UVTheoryFunctional::new(params));
+    let t = 100.0 * KELVIN;
+    let w = 100.0 * ANGSTROM;
+    let points = 1024;
+    let tc = State::critical_point(&func, None, None, Default::default())?.temperature;
+    let vle = PhaseEquilibrium::pure(&func, t, None, Default::default())?;
+    let profile = PlanarInterface::from_tanh(&vle, points, w, tc, false).solve(None)?;
+    let surface_tension = profile.surface_tension.unwrap();
+    println!(
+        "{} {} {} {}",
+        tc,
+        vle.vapor().density,
+        vle.liquid().density,
+        surface_tension
+    );
+
+    let rho = profile.profile.density.index_axis(ndarray::Axis(0), 0);
+    assert_relative_eq!(rho.get(0), vle.liquid().density, max_relative = 1e-6);
+    assert_relative_eq!(
+        rho.get(points - 1),
+        vle.vapor().density,
+        max_relative = 1e-6
+    );
+    assert!(surface_tension > 0.0 * NEWTON / METER);
+    Ok(())
+}